@@ -4,8 +4,10 @@
 //!
 //! A cash flow is an amount of money paid or received at a specific date.
 
+use crate::coupon::Coupon;
 use ql_core::Real;
 use ql_time::Date;
+use std::any::Any;
 use std::fmt;
 
 /// Base trait for all cash flows.
@@ -15,7 +17,7 @@ use std::fmt;
 /// for simple cash flows, it is a fixed value.
 ///
 /// Corresponds to `QuantLib::CashFlow`.
-pub trait CashFlow: fmt::Debug + Send + Sync {
+pub trait CashFlow: AsAny + fmt::Debug + Send + Sync {
     /// The date on which this cash flow is paid.
     fn date(&self) -> Date;
 
@@ -34,6 +36,28 @@ pub trait CashFlow: fmt::Debug + Send + Sync {
     fn is_trading_cashflow(&self, ref_date: Date) -> bool {
         !self.has_occurred(ref_date)
     }
+
+    /// This cash flow as a [`Coupon`], or `None` if it is not one.
+    ///
+    /// Coupon types override this to return `Some(self)`.
+    fn as_coupon(&self) -> Option<&dyn Coupon> {
+        None
+    }
+}
+
+/// Upcast to [`Any`], so leg analytics can recognise coupon types.
+///
+/// Implemented for every cash flow; implementors of [`CashFlow`] need not
+/// write it.
+pub trait AsAny {
+    /// The value as [`Any`].
+    fn as_any(&self) -> &dyn Any;
+}
+
+impl<T: CashFlow + 'static> AsAny for T {
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
 }
 
 /// A `Leg` is a sequence of cash flows.
@@ -128,4 +152,27 @@ mod tests {
         assert!((r.amount() - 1000.0).abs() < 1e-15);
         assert_eq!(r.date(), d);
     }
+
+    #[test]
+    fn only_coupons_view_as_coupons() {
+        use crate::fixed_rate_coupon::FixedRateCoupon;
+        use ql_core::Compounding;
+        use ql_time::{Actual365Fixed, Frequency, InterestRate};
+
+        let start = Date::from_ymd(2025, 1, 15).unwrap();
+        let end = Date::from_ymd(2026, 1, 15).unwrap();
+        let rate = InterestRate::new(0.04, Actual365Fixed, Compounding::Simple, Frequency::Annual);
+        let leg: Leg = vec![
+            Box::new(FixedRateCoupon::new(
+                end, 100.0, rate, start, end, start, end,
+            )),
+            Box::new(SimpleCashFlow::new(5.0, end)),
+            Box::new(Redemption::new(100.0, end)),
+        ];
+        let coupon = leg[0].as_coupon().unwrap();
+        assert_eq!(coupon.accrual_start_date(), start);
+        assert!((coupon.nominal() - 100.0).abs() < 1e-15);
+        assert!(leg[1].as_coupon().is_none());
+        assert!(leg[2].as_coupon().is_none());
+    }
 }
@@ -3,17 +3,23 @@
 //! Translates `ql/cashflows/cashflows.hpp` / `cashflows.cpp`.
 //!
 //! Static utility functions that operate on a `Leg`:
-//! - `npv` — present value
+//! - `npv` — present value, with or without flows on the settlement date
 //! - `bps` — basis-point sensitivity
 //! - `duration` — Macaulay / modified / simple
 //! - `convexity`
+//! - `effective_duration`, `effective_convexity` — by bumping the curve
 //! - `yield_rate` — internal rate of return (solver-based)
+//! - `yield_rate_newton` — yield from a clean price, by Newton's method on the duration
+//! - `npv_yield_with_day_counter`, `duration_with_day_counter`,
+//!   `convexity_with_day_counter`, `yield_rate_with_day_counter` — the flat-yield
+//!   functions with cash-flow times measured on a given day counter
 //! - `z_spread` — Z-spread over a yield curve
 //! - `maturity_date`, `previous_cashflow_date`, `next_cashflow_date`
+//! - `validate_leg` — consistency of payment dates and accrual periods
 
-use crate::cashflow::Leg;
-use ql_core::{Compounding, Real};
-use ql_math::solvers1d::brent;
+use crate::cashflow::{CashFlow, Leg};
+use ql_core::{ensure, Compounding, Real};
+use ql_math::solvers1d::{brent, newton_safe};
 use ql_termstructures::YieldTermStructure;
 use ql_time::{Actual365Fixed, Date, DayCounter, Frequency, InterestRate};
 
@@ -51,20 +57,91 @@ pub fn next_cashflow_date(leg: &Leg, ref_date: Date) -> Option<Date> {
         .min()
 }
 
+/// Check that a hand-built leg hangs together.
+///
+/// Payment dates must not decrease along the leg, and the coupons, in leg
+/// order, must accrue over contiguous periods — each starting where the
+/// previous one ended — with no negative year fraction.  Other cash flows
+/// are only checked for their payment date.
+///
+/// # Errors
+/// Reports the first inconsistency found, by position in the leg.
+pub fn validate_leg(leg: &Leg) -> ql_core::errors::Result<()> {
+    let mut previous_payment: Option<Date> = None;
+    let mut previous_end: Option<Date> = None;
+    for (i, cf) in leg.iter().enumerate() {
+        let payment = cf.date();
+        if let Some(previous) = previous_payment {
+            ensure!(
+                payment >= previous,
+                "cash flow {i} is paid on {payment:?}, before cash flow {} on {previous:?}",
+                i - 1
+            );
+        }
+        previous_payment = Some(payment);
+
+        let Some(coupon) = cf.as_coupon() else {
+            continue;
+        };
+        let (start, end) = (coupon.accrual_start_date(), coupon.accrual_end_date());
+        ensure!(
+            coupon.accrual_period() >= 0.0,
+            "coupon {i} accrues over a negative year fraction, {start:?} to {end:?}"
+        );
+        if let Some(previous) = previous_end {
+            ensure!(
+                start >= previous,
+                "coupon {i} starts on {start:?}, overlapping the previous coupon ending on {previous:?}"
+            );
+            ensure!(
+                start <= previous,
+                "coupon {i} starts on {start:?}, leaving a gap after the previous coupon ending on {previous:?}"
+            );
+        }
+        previous_end = Some(end);
+    }
+    Ok(())
+}
+
+/// Amount of `cf`, with coupons projected off `forecast` unless their rate
+/// is already fixed; other cash flows keep their amount.
+fn projected_amount(cf: &dyn CashFlow, forecast: &dyn YieldTermStructure) -> Real {
+    cf.as_coupon()
+        .map_or_else(|| cf.amount(), |c| c.projected_amount(forecast))
+}
+
 // ── NPV with a yield curve ──────────────────────────────────────────────────
 
 /// Net present value of a leg using a yield-term-structure.
 ///
 /// Only cash flows after `settlement_date` are discounted.
 pub fn npv_curve(leg: &Leg, yield_curve: &dyn YieldTermStructure, settlement_date: Date) -> Real {
+    npv(leg, yield_curve, settlement_date, false)
+}
+
+/// Net present value of a leg using a yield-term-structure, discounted to
+/// the curve's reference date.
+///
+/// Cash flows before `settlement_date` are excluded; those paid on it are
+/// included only if `include_settlement_flows` is set, as with QuantLib's
+/// `includeSettlementDateFlows`.
+///
+/// Corresponds to `QuantLib::CashFlows::npv`.
+pub fn npv(
+    leg: &Leg,
+    yield_curve: &dyn YieldTermStructure,
+    settlement_date: Date,
+    include_settlement_flows: bool,
+) -> Real {
     let ref_date = yield_curve.reference_date();
     let dc = Actual365Fixed;
     let mut result = 0.0;
     for cf in leg {
-        if cf.date() <= settlement_date {
+        let date = cf.date();
+        if date < settlement_date || (date == settlement_date && !include_settlement_flows) {
             continue;
         }
-        let t = dc.year_fraction(ref_date, cf.date());
+        let t = dc.year_fraction(ref_date, date);
         result += cf.amount() * yield_curve.discount(t);
     }
     result
@@ -92,15 +169,26 @@ pub fn bps_curve(leg: &Leg, yield_curve: &dyn YieldTermStructure, settlement_dat
 
 /// Net present value of a leg at a flat yield (as an `InterestRate`).
 ///
-/// Cash flows on or before `settlement_date` are excluded.
+/// Cash flows on or before `settlement_date` are excluded, and cash-flow
+/// times are measured on Actual/365 (Fixed).
 pub fn npv_yield(leg: &Leg, yield_rate: &InterestRate, settlement_date: Date) -> Real {
-    let dc = Actual365Fixed;
+    npv_yield_with_day_counter(leg, yield_rate, &Actual365Fixed, settlement_date)
+}
+
+/// [`npv_yield`] with cash-flow times measured with `day_counter`; the
+/// rate's own day counter is unused.
+pub fn npv_yield_with_day_counter(
+    leg: &Leg,
+    yield_rate: &InterestRate,
+    day_counter: &dyn DayCounter,
+    settlement_date: Date,
+) -> Real {
     let mut result = 0.0;
     for cf in leg {
         if cf.date() <= settlement_date {
             continue;
         }
-        let t = dc.year_fraction(settlement_date, cf.date());
+        let t = day_counter.year_fraction(settlement_date, cf.date());
         result += cf.amount() * yield_rate.discount_factor_time(t);
     }
     result
@@ -122,16 +210,34 @@ pub fn bps_yield(leg: &Leg, yield_rate: &InterestRate, settlement_date: Date) ->
 
 // ── Duration ─────────────────────────────────────────────────────────────────
 
-/// Duration of a leg at a flat yield.
+/// Duration of a leg at a flat yield, with cash-flow times on
+/// Actual/365 (Fixed).
 pub fn duration(
     leg: &Leg,
     yield_rate: &InterestRate,
     duration_type: Duration,
     settlement_date: Date,
 ) -> Real {
-    let dc = Actual365Fixed;
-    let y = yield_rate.rate();
-    let npv = npv_yield(leg, yield_rate, settlement_date);
+    duration_with_day_counter(
+        leg,
+        yield_rate,
+        &Actual365Fixed,
+        duration_type,
+        settlement_date,
+    )
+}
+
+/// [`duration`] with cash-flow times measured with `day_counter`; the
+/// rate's own day counter is unused.
+pub fn duration_with_day_counter(
+    leg: &Leg,
+    yield_rate: &InterestRate,
+    day_counter: &dyn DayCounter,
+    duration_type: Duration,
+    settlement_date: Date,
+) -> Real {
+    let dc = day_counter;
+    let npv = npv_yield_with_day_counter(leg, yield_rate, dc, settlement_date);
     if npv.abs() < 1e-30 {
         return 0.0;
     }
@@ -160,24 +266,121 @@ pub fn duration(
             sum_t_pv / npv
         }
         Duration::Modified => {
-            // Modified = Macaulay / (1 + y/k)
-            let mac = duration(leg, yield_rate, Duration::Macaulay, settlement_date);
-            let k = match yield_rate.frequency() {
-                Frequency::NoFrequency | Frequency::Once => 1.0,
-                f => f as i32 as f64,
-            };
-            mac / (1.0 + y / k)
+            // Modified = −(dP/dy) / P, with dB/dy = −B · d ln(1/B)/dy.
+            let mut sum = 0.0;
+            for cf in leg {
+                if cf.date() <= settlement_date {
+                    continue;
+                }
+                let t = dc.year_fraction(settlement_date, cf.date());
+                sum += log_discount_slope(yield_rate, t)
+                    * cf.amount()
+                    * yield_rate.discount_factor_time(t);
+            }
+            sum / npv
         }
     }
 }
 
+/// `d ln(1/B(t))/dy`, the sensitivity of the log discount factor at time
+/// `t` to the yield, under the yield's own compounding.
+fn log_discount_slope(yield_rate: &InterestRate, t: Real) -> Real {
+    let y = yield_rate.rate();
+    let k = match yield_rate.frequency() {
+        Frequency::NoFrequency | Frequency::Once => 1.0,
+        f => f as i32 as f64,
+    };
+    let compounded = |t: Real| t / (1.0 + y / k);
+    let simple = |t: Real| t / (1.0 + y * t);
+    match yield_rate.compounding() {
+        Compounding::Simple => simple(t),
+        Compounding::Compounded => compounded(t),
+        Compounding::Continuous => t,
+        Compounding::SimpleThenCompounded if t <= 1.0 / k => simple(t),
+        Compounding::SimpleThenCompounded => compounded(t),
+        Compounding::CompoundedThenSimple if t <= 1.0 / k => compounded(t),
+        Compounding::CompoundedThenSimple => {
+            // Whole periods compounded, the stub simple.
+            let periods = (k * t).floor();
+            compounded(periods / k) + simple(t - periods / k)
+        }
+    }
+}
+
+/// Effective duration of a leg: minus the relative change in its NPV on
+/// `yield_curve` for a parallel shift of its continuously-compounded zero
+/// rates, by central differences over `±bump`.
+///
+/// Unlike [`duration`], this needs no yield, and carries over to prices
+/// that respond to the curve other than through discounting.
+pub fn effective_duration(
+    leg: &Leg,
+    yield_curve: &dyn YieldTermStructure,
+    settlement_date: Date,
+    bump: Real,
+) -> Real {
+    let (down, base, up) = bumped_npvs(leg, yield_curve, settlement_date, bump);
+    if base.abs() < 1e-30 {
+        return 0.0;
+    }
+    (down - up) / (2.0 * bump * base)
+}
+
+/// Effective convexity of a leg: the relative second difference of its NPV
+/// on `yield_curve` under parallel shifts of `±bump` to its
+/// continuously-compounded zero rates.
+pub fn effective_convexity(
+    leg: &Leg,
+    yield_curve: &dyn YieldTermStructure,
+    settlement_date: Date,
+    bump: Real,
+) -> Real {
+    let (down, base, up) = bumped_npvs(leg, yield_curve, settlement_date, bump);
+    if base.abs() < 1e-30 {
+        return 0.0;
+    }
+    (up - 2.0 * base + down) / (bump * bump * base)
+}
+
+/// NPVs with the curve shifted by `−bump`, unshifted, and by `+bump`.
+fn bumped_npvs(
+    leg: &Leg,
+    yield_curve: &dyn YieldTermStructure,
+    settlement_date: Date,
+    bump: Real,
+) -> (Real, Real, Real) {
+    let shifted = |z| {
+        npv_z_spread(
+            leg,
+            yield_curve,
+            z,
+            Compounding::Continuous,
+            Frequency::NoFrequency,
+            settlement_date,
+        )
+    };
+    (shifted(-bump), shifted(0.0), shifted(bump))
+}
+
 // ── Convexity ────────────────────────────────────────────────────────────────
 
-/// Convexity of a leg at a flat yield.
+/// Convexity of a leg at a flat yield, with cash-flow times on
+/// Actual/365 (Fixed).
 pub fn convexity(leg: &Leg, yield_rate: &InterestRate, settlement_date: Date) -> Real {
-    let dc = Actual365Fixed;
+    convexity_with_day_counter(leg, yield_rate, &Actual365Fixed, settlement_date)
+}
+
+/// [`convexity`] with cash-flow times measured with `day_counter`; the
+/// rate's own day counter is unused.
+pub fn convexity_with_day_counter(
+    leg: &Leg,
+    yield_rate: &InterestRate,
+    day_counter: &dyn DayCounter,
+    settlement_date: Date,
+) -> Real {
+    let dc = day_counter;
     let y = yield_rate.rate();
-    let npv = npv_yield(leg, yield_rate, settlement_date);
+    let npv = npv_yield_with_day_counter(leg, yield_rate, dc, settlement_date);
     if npv.abs() < 1e-30 {
         return 0.0;
     }
@@ -209,14 +412,85 @@ pub fn yield_rate(
     freq: Frequency,
     settlement_date: Date,
     accuracy: Real,
+) -> ql_core::errors::Result<Real> {
+    yield_rate_with_day_counter(
+        leg,
+        target_npv,
+        &Actual365Fixed,
+        comp,
+        freq,
+        settlement_date,
+        accuracy,
+    )
+}
+
+/// [`yield_rate`] with cash-flow times measured with `day_counter`.
+pub fn yield_rate_with_day_counter(
+    leg: &Leg,
+    target_npv: Real,
+    day_counter: &dyn DayCounter,
+    comp: Compounding,
+    freq: Frequency,
+    settlement_date: Date,
+    accuracy: Real,
 ) -> ql_core::errors::Result<Real> {
     let f = |r: f64| -> f64 {
         let ir = InterestRate::new(r, Actual365Fixed, comp, freq);
-        npv_yield(leg, &ir, settlement_date) - target_npv
+        npv_yield_with_day_counter(leg, &ir, day_counter, settlement_date) - target_npv
     };
     brent(f, -0.10, 2.0, accuracy)
 }
 
+/// Find the yield of a leg given its clean price by Newton's method.
+///
+/// The price is quoted in the units of the leg's amounts; the accrued
+/// interest of the coupons running at `settlement_date` is added to it, and
+/// cash-flow times are measured with `day_counter`.  The slope of the
+/// price-yield curve is known analytically as `−D·P`, with `D` the
+/// modified [`duration`], so each iteration prices the leg once and the
+/// iteration converges quadratically.  The safeguarded Newton iteration
+/// runs in a bracket of −5% to 25%; if the yield lies outside it, this
+/// falls back to Brent's method on −10% to 200%, as in [`yield_rate`].
+/// Both solve to an accuracy of `1e-12`.
+pub fn yield_rate_newton(
+    leg: &Leg,
+    clean_price: Real,
+    day_counter: &dyn DayCounter,
+    comp: Compounding,
+    freq: Frequency,
+    settlement_date: Date,
+) -> ql_core::errors::Result<Real> {
+    const ACCURACY: Real = 1e-12;
+    let dirty_price = clean_price + accrued_amount(leg, settlement_date);
+    // The rate's own day counter is unused: times come from `day_counter`.
+    let price_and_slope = |r: Real| -> (Real, Real) {
+        let ir = InterestRate::new(r, Actual365Fixed, comp, freq);
+        let (mut npv, mut slope) = (0.0, 0.0);
+        for cf in leg {
+            if cf.date() <= settlement_date {
+                continue;
+            }
+            let t = day_counter.year_fraction(settlement_date, cf.date());
+            let pv = cf.amount() * ir.discount_factor_time(t);
+            npv += pv;
+            slope -= log_discount_slope(&ir, t) * pv;
+        }
+        (npv - dirty_price, slope)
+    };
+    newton_safe(price_and_slope, -0.05, 0.25, ACCURACY)
+        .or_else(|_| brent(|r| price_and_slope(r).0, -0.10, 2.0, ACCURACY))
+}
+
+/// Interest accrued at `settlement_date` on the coupons of `leg` paid
+/// after it.
+fn accrued_amount(leg: &Leg, settlement_date: Date) -> Real {
+    leg.iter()
+        .filter(|cf| cf.date() > settlement_date)
+        .filter_map(|cf| cf.as_coupon())
+        .map(|c| c.accrued_amount(settlement_date))
+        .sum()
+}
+
 // ── Z-spread ─────────────────────────────────────────────────────────────────
 
 /// NPV of a leg discounted with `yield_curve` plus a parallel spread `z`.
@@ -260,10 +534,79 @@ pub fn z_spread(
     brent(f, -0.20, 5.0, accuracy)
 }
 
+/// NPV of a leg whose floating coupons are projected off `forecast_curve`,
+/// discounted with `discount_curve` plus a parallel spread `z`.
+///
+/// Floating coupons whose rate is already fixed pay that rate.  Times run
+/// from the discount curve's reference date under `day_counter`.
+#[allow(clippy::too_many_arguments)]
+pub fn npv_floating_z_spread(
+    leg: &Leg,
+    forecast_curve: &dyn YieldTermStructure,
+    discount_curve: &dyn YieldTermStructure,
+    z_spread: Real,
+    day_counter: &dyn DayCounter,
+    comp: Compounding,
+    freq: Frequency,
+    settlement_date: Date,
+) -> Real {
+    let ref_date = discount_curve.reference_date();
+    let spread_ir = InterestRate::new(z_spread, Actual365Fixed, comp, freq);
+    let mut result = 0.0;
+    for cf in leg {
+        if cf.date() <= settlement_date {
+            continue;
+        }
+        let t = day_counter.year_fraction(ref_date, cf.date());
+        let base_df = discount_curve.discount_date(cf.date());
+        let spread_df = spread_ir.discount_factor_time(t);
+        result += projected_amount(&**cf, forecast_curve) * base_df * spread_df;
+    }
+    result
+}
+
+/// Find the Z-spread over `discount_curve` at which a floating-rate leg,
+/// with coupons projected off `forecast_curve`, is worth `clean_price`.
+///
+/// Settlement is the discount curve's reference date.  The price is quoted
+/// in the units of the leg's amounts; the accrued interest of the coupon
+/// running at settlement, as given by [`Coupon::accrued_amount`], is added
+/// to it before solving, so a running floating coupon accrues at the rate
+/// it has fixed at.
+///
+/// [`Coupon::accrued_amount`]: crate::coupon::Coupon::accrued_amount
+pub fn floating_z_spread(
+    leg: &Leg,
+    forecast_curve: &dyn YieldTermStructure,
+    discount_curve: &dyn YieldTermStructure,
+    clean_price: Real,
+    day_counter: &dyn DayCounter,
+    comp: Compounding,
+    freq: Frequency,
+) -> ql_core::errors::Result<Real> {
+    let settlement = discount_curve.reference_date();
+    let accrued = accrued_amount(leg, settlement);
+    let dirty_price = clean_price + accrued;
+    let f = |z: f64| -> f64 {
+        npv_floating_z_spread(
+            leg,
+            forecast_curve,
+            discount_curve,
+            z,
+            day_counter,
+            comp,
+            freq,
+            settlement,
+        ) - dirty_price
+    };
+    brent(f, -0.20, 5.0, 1e-12)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::fixed_rate_coupon::FixedRateLegBuilder;
+    use crate::coupon::Coupon;
+    use crate::fixed_rate_coupon::{FixedRateCoupon, FixedRateLegBuilder};
     use ql_time::{NullCalendar, Period, ScheduleBuilder, TimeUnit};
 
     fn make_fixed_leg(coupon_rate: Real) -> Leg {
@@ -283,6 +626,31 @@ mod tests {
             .build()
     }
 
+    #[test]
+    fn settlement_date_flows_follow_the_flag() {
+        use ql_termstructures::FlatForward;
+
+        let leg = make_fixed_leg(0.05);
+        let today = Date::from_ymd(2025, 1, 15).unwrap();
+        let curve = FlatForward::continuous(today, 0.04, Actual365Fixed);
+        let pv = |cf: &dyn CashFlow| cf.amount() * curve.discount_date(cf.date());
+
+        // Settling on the second coupon date: the first coupon is gone
+        // either way, the second counts only when included.
+        let settlement = leg[1].date();
+        let later: Real = leg[2..].iter().map(|cf| pv(cf.as_ref())).sum();
+        let excluded = npv(&leg, &curve, settlement, false);
+        let included = npv(&leg, &curve, settlement, true);
+        assert!((excluded - later).abs() < 1e-10);
+        assert!((included - later - pv(leg[1].as_ref())).abs() < 1e-10);
+        assert_eq!(npv_curve(&leg, &curve, settlement), excluded);
+
+        // A day later the flag no longer matters.
+        let next_day = settlement + 1;
+        assert_eq!(npv(&leg, &curve, next_day, true), excluded);
+        assert_eq!(npv(&leg, &curve, next_day, false), excluded);
+    }
+
     #[test]
     fn npv_at_par() {
         let leg = make_fixed_leg(0.05);
@@ -332,6 +700,110 @@ mod tests {
         assert!((found - 0.05).abs() < 1e-6, "found yield = {found}");
     }
 
+    /// Actual/360, counting the year fractions asked of it.
+    #[derive(Debug, Default)]
+    struct CountingActual360(std::sync::atomic::AtomicUsize);
+
+    impl DayCounter for CountingActual360 {
+        fn name(&self) -> &str {
+            "Actual/360"
+        }
+        fn day_count(&self, d1: Date, d2: Date) -> i64 {
+            ql_time::Actual360.day_count(d1, d2)
+        }
+        fn year_fraction(&self, d1: Date, d2: Date) -> ql_core::Time {
+            self.0.fetch_add(1, std::sync::atomic::Ordering::Relaxed);
+            ql_time::Actual360.year_fraction(d1, d2)
+        }
+    }
+
+    #[test]
+    fn newton_yield_matches_brent_in_fewer_evaluations() {
+        use ql_time::Actual360;
+        use std::cell::Cell;
+
+        let leg = make_fixed_leg(0.05);
+        // At the issue date and mid-period, where the clean price excludes
+        // the accrued coupon.
+        for settlement in [
+            Date::from_ymd(2025, 1, 15).unwrap(),
+            Date::from_ymd(2025, 7, 15).unwrap(),
+        ] {
+            let accrued = accrued_amount(&leg, settlement);
+            let remaining = leg.iter().filter(|cf| cf.date() > settlement).count();
+            // Dirty price of the leg at `r`, with times on Actual/360.
+            let dirty = |r: Real, comp, freq| -> Real {
+                let ir = InterestRate::new(r, Actual365Fixed, comp, freq);
+                leg.iter()
+                    .filter(|cf| cf.date() > settlement)
+                    .map(|cf| {
+                        let t = Actual360.year_fraction(settlement, cf.date());
+                        cf.amount() * ir.discount_factor_time(t)
+                    })
+                    .sum()
+            };
+            for (comp, freq) in [
+                (Compounding::Compounded, Frequency::Annual),
+                (Compounding::Compounded, Frequency::Semiannual),
+                (Compounding::Continuous, Frequency::NoFrequency),
+            ] {
+                for y in [0.01, 0.045, 0.12] {
+                    let clean = dirty(y, comp, freq) - accrued;
+
+                    let brent_evaluations = Cell::new(0);
+                    let bracketed = brent(
+                        |r| {
+                            brent_evaluations.set(brent_evaluations.get() + 1);
+                            dirty(r, comp, freq) - accrued - clean
+                        },
+                        -0.10,
+                        2.0,
+                        1e-12,
+                    )
+                    .unwrap();
+                    // Each pass over the leg takes one time per remaining
+                    // flow from the day counter.
+                    let counting = CountingActual360::default();
+                    let newton =
+                        yield_rate_newton(&leg, clean, &counting, comp, freq, settlement).unwrap();
+                    let leg_passes = counting.0.into_inner() / remaining;
+
+                    assert!(
+                        (newton - bracketed).abs() < 1e-10,
+                        "{newton} vs {bracketed}"
+                    );
+                    assert!((newton - y).abs() < 1e-10, "{comp:?} {y}: {newton}");
+                    // Two bracket ends, the midpoint and a few quadratic
+                    // steps, each pricing the leg once as Brent does.
+                    assert!(
+                        leg_passes <= 8 && leg_passes + 3 <= brent_evaluations.get(),
+                        "{comp:?} {y}: {leg_passes} Newton vs {} Brent leg passes",
+                        brent_evaluations.get()
+                    );
+                }
+            }
+        }
+        assert!(accrued_amount(&leg, Date::from_ymd(2025, 7, 15).unwrap()) > 2.0);
+
+        // Outside the Newton bracket, Brent takes over; simple yields work
+        // as well.
+        let settlement = Date::from_ymd(2025, 1, 15).unwrap();
+        for (y, comp) in [(0.4, Compounding::Compounded), (0.05, Compounding::Simple)] {
+            let ir = InterestRate::new(y, Actual365Fixed, comp, Frequency::Annual);
+            let price = npv_yield(&leg, &ir, settlement);
+            let found = yield_rate_newton(
+                &leg,
+                price,
+                &Actual365Fixed,
+                comp,
+                Frequency::Annual,
+                settlement,
+            )
+            .unwrap();
+            assert!((found - y).abs() < 1e-10, "{comp:?}: y = {found}");
+        }
+    }
+
     #[test]
     fn duration_positive() {
         let leg = make_fixed_leg(0.05);
@@ -370,4 +842,188 @@ mod tests {
         assert!(prev <= ref_date);
         assert!(next > ref_date);
     }
+
+    #[test]
+    fn par_floater_has_zero_z_spread() {
+        use crate::cashflow::CashFlow;
+        use crate::floating_rate_coupon::{IborCoupon, IborLegBuilder};
+        use ql_currencies::currencies::america::USD;
+        use ql_indexes::{IborIndex, Index};
+        use ql_termstructures::FlatForward;
+        use ql_time::BusinessDayConvention;
+        use std::sync::Arc;
+
+        let start = Date::from_ymd(2025, 1, 15).unwrap();
+        let cal = NullCalendar;
+        let schedule = ScheduleBuilder::new(
+            start,
+            Date::from_ymd(2030, 1, 15).unwrap(),
+            Period::new(6, TimeUnit::Months),
+            &cal,
+        )
+        .build()
+        .unwrap();
+        let index = Arc::new(IborIndex::new(
+            "USD-Libor-6M",
+            Period::new(6, TimeUnit::Months),
+            2,
+            &USD,
+            NullCalendar,
+            BusinessDayConvention::ModifiedFollowing,
+            false,
+            Actual365Fixed,
+        ));
+        let leg = IborLegBuilder::new(&schedule, Arc::clone(&index))
+            .with_notionals(vec![100.0])
+            .with_redemption(100.0)
+            .build();
+        let curve = FlatForward::continuous(start, 0.04, Actual365Fixed);
+
+        let z = floating_z_spread(
+            &leg,
+            &curve,
+            &curve,
+            100.0,
+            &Actual365Fixed,
+            Compounding::Continuous,
+            Frequency::Annual,
+        )
+        .unwrap();
+        assert!(z.abs() < 1e-10, "z = {z}");
+
+        // A 50bp discount margin is recovered from the price it implies.
+        let widened = |z| {
+            npv_floating_z_spread(
+                &leg,
+                &curve,
+                &curve,
+                z,
+                &Actual365Fixed,
+                Compounding::Continuous,
+                Frequency::Annual,
+                start,
+            )
+        };
+        let price = widened(0.005);
+        assert!(price < 100.0);
+        let z = floating_z_spread(
+            &leg,
+            &curve,
+            &curve,
+            price,
+            &Actual365Fixed,
+            Compounding::Continuous,
+            Frequency::Annual,
+        )
+        .unwrap();
+        assert!((z - 0.005).abs() < 1e-10, "z = {z}");
+
+        // Fixing the first coupon above the forward lifts the price by the
+        // discounted excess coupon.
+        let first_fixing = index.fixing_calendar().advance_business_days(start, -2);
+        index.add_fixing(first_fixing, 0.06);
+        let first = leg[0].as_any().downcast_ref::<IborCoupon>().unwrap();
+        let tau = first.accrual_period();
+        let forward = (curve.discount_date(first.accrual_start_date())
+            / curve.discount_date(first.accrual_end_date())
+            - 1.0)
+            / tau;
+        let excess = 100.0 * (0.06 - forward) * tau * curve.discount_date(first.date());
+        assert!((widened(0.0) - 100.0 - excess).abs() < 1e-10);
+    }
+
+    #[test]
+    fn floating_z_spread_accrues_every_coupon() {
+        use ql_termstructures::FlatForward;
+
+        // Settling mid-period on a fixed leg: the running coupon is not
+        // floating, but its accrued interest still turns the clean price
+        // into the dirty one.
+        let leg = make_fixed_leg(0.05);
+        let settlement = Date::from_ymd(2025, 7, 15).unwrap();
+        let curve = FlatForward::continuous(settlement, 0.04, Actual365Fixed);
+        let args = (&Actual365Fixed, Compounding::Continuous, Frequency::Annual);
+        let dirty = npv_floating_z_spread(
+            &leg, &curve, &curve, 0.0, args.0, args.1, args.2, settlement,
+        );
+        let accrued = leg[0].as_coupon().unwrap().accrued_amount(settlement);
+        assert!(accrued > 2.0);
+
+        let z = floating_z_spread(
+            &leg,
+            &curve,
+            &curve,
+            dirty - accrued,
+            args.0,
+            args.1,
+            args.2,
+        )
+        .unwrap();
+        assert!(z.abs() < 1e-10, "z = {z}");
+    }
+
+    fn coupon(
+        start: (u16, u8, u8),
+        end: (u16, u8, u8),
+        payment: (u16, u8, u8),
+    ) -> Box<dyn CashFlow> {
+        let date = |(y, m, d)| Date::from_ymd(y, m, d).unwrap();
+        let rate = InterestRate::new(0.04, Actual365Fixed, Compounding::Simple, Frequency::Annual);
+        let (start, end) = (date(start), date(end));
+        Box::new(FixedRateCoupon::new(
+            date(payment),
+            100.0,
+            rate,
+            start,
+            end,
+            start,
+            end,
+        ))
+    }
+
+    #[test]
+    fn well_formed_legs_validate() {
+        assert!(validate_leg(&make_fixed_leg(0.05)).is_ok());
+        assert!(validate_leg(&Vec::new()).is_ok());
+    }
+
+    #[test]
+    fn validate_leg_flags_gaps_and_overlaps() {
+        let gapped: Leg = vec![
+            coupon((2025, 1, 15), (2025, 7, 15), (2025, 7, 15)),
+            coupon((2025, 8, 15), (2026, 1, 15), (2026, 1, 15)),
+        ];
+        let err = validate_leg(&gapped).unwrap_err().to_string();
+        assert!(err.contains("coupon 1") && err.contains("gap"), "{err}");
+
+        let overlapping: Leg = vec![
+            coupon((2025, 1, 15), (2025, 7, 15), (2025, 7, 15)),
+            coupon((2025, 6, 15), (2026, 1, 15), (2026, 1, 15)),
+        ];
+        let err = validate_leg(&overlapping).unwrap_err().to_string();
+        assert!(err.contains("overlapping"), "{err}");
+
+        let reversed: Leg = vec![coupon((2025, 7, 15), (2025, 1, 15), (2025, 7, 15))];
+        let err = validate_leg(&reversed).unwrap_err().to_string();
+        assert!(err.contains("negative year fraction"), "{err}");
+    }
+
+    #[test]
+    fn validate_leg_catches_out_of_order_payments() {
+        // The redemption moved to the front of the leg.
+        let mut leg = make_fixed_leg(0.05);
+        leg.rotate_right(1);
+        let err = validate_leg(&leg).unwrap_err().to_string();
+        assert!(
+            err.contains("cash flow 1") && err.contains("before cash flow 0"),
+            "{err}"
+        );
+
+        // Contiguous accruals do not excuse a payment date out of order.
+        let late_then_early: Leg = vec![
+            coupon((2025, 1, 15), (2025, 7, 15), (2026, 1, 20)),
+            coupon((2025, 7, 15), (2026, 1, 15), (2026, 1, 15)),
+        ];
+        assert!(validate_leg(&late_then_early).is_err());
+    }
 }
@@ -7,6 +7,7 @@
 
 use crate::cashflow::CashFlow;
 use ql_core::Real;
+use ql_termstructures::YieldTermStructure;
 use ql_time::{Date, DayCounter};
 
 /// Base trait for interest-rate coupons.
@@ -41,6 +42,16 @@ pub trait Coupon: CashFlow {
     /// The annualized rate of the coupon.
     fn rate(&self) -> Real;
 
+    /// Amount paid, with any index rate not yet fixed projected off
+    /// `forecast`.
+    ///
+    /// The default is [`amount`](CashFlow::amount), for coupons whose rate
+    /// does not depend on a forecast.
+    fn projected_amount(&self, forecast: &dyn YieldTermStructure) -> Real {
+        let _ = forecast;
+        self.amount()
+    }
+
     /// Accrued amount from the accrual start to the given date.
     fn accrued_amount(&self, date: Date) -> Real {
         if date <= self.accrual_start_date() || date > self.accrual_end_date() {
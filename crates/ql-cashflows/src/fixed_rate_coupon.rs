@@ -5,7 +5,7 @@
 
 use crate::cashflow::{CashFlow, Leg, Redemption};
 use crate::coupon::Coupon;
-use ql_core::{Compounding, Real};
+use ql_core::{ensure, errors::Result, Compounding, Real};
 use ql_time::{
     Actual365Fixed, BusinessDayConvention, Date, DayCounter, Frequency, InterestRate, Schedule,
 };
@@ -106,6 +106,10 @@ impl CashFlow for FixedRateCoupon {
         // amount = nominal * (compound_factor - 1)
         self.nominal * (self.rate.compound_factor_time(self.accrual_period) - 1.0)
     }
+
+    fn as_coupon(&self) -> Option<&dyn Coupon> {
+        Some(self)
+    }
 }
 
 impl Coupon for FixedRateCoupon {
@@ -159,6 +163,7 @@ pub struct FixedRateLegBuilder<'a> {
     payment_convention: BusinessDayConvention,
     add_redemption: bool,
     redemption_amount: Real,
+    notional_redemptions: bool,
 }
 
 impl<'a> FixedRateLegBuilder<'a> {
@@ -174,6 +179,7 @@ impl<'a> FixedRateLegBuilder<'a> {
             payment_convention: BusinessDayConvention::Following,
             add_redemption: false,
             redemption_amount: 100.0,
+            notional_redemptions: false,
         }
     }
 
@@ -183,6 +189,39 @@ impl<'a> FixedRateLegBuilder<'a> {
         self
     }
 
+    /// Amortize `notional` linearly, by `amortizing_amount` at the end of
+    /// each period, and redeem the reductions as they happen (see
+    /// [`with_notional_redemptions`](Self::with_notional_redemptions)).
+    /// A negative `amortizing_amount` accretes the notional instead.
+    ///
+    /// # Errors
+    /// Fails if the notional would turn negative before maturity.
+    pub fn with_amortization(mut self, notional: Real, amortizing_amount: Real) -> Result<Self> {
+        let n = self.schedule.dates().len().saturating_sub(1);
+        self.notionals = (0..n)
+            .map(|i| notional - i as Real * amortizing_amount)
+            .collect();
+        ensure!(
+            self.notionals.iter().all(|&n| n >= 0.0),
+            "amortizing {amortizing_amount} per period exhausts notional {notional} before maturity"
+        );
+        self.notional_redemptions = true;
+        Ok(self)
+    }
+
+    /// Pay each reduction in notional as a redemption at the end of the
+    /// period before it, and the outstanding notional at maturity, as for
+    /// a sinking-fund or amortizing bond.  An increase in notional is paid
+    /// the same way as a negative redemption, the holder funding the extra
+    /// notional, as `QuantLib::Bond` does for accreting schedules.
+    ///
+    /// Takes the place of a fixed [`with_redemption`](Self::with_redemption)
+    /// amount.
+    pub fn with_notional_redemptions(mut self) -> Self {
+        self.notional_redemptions = true;
+        self
+    }
+
     /// Set a single coupon rate for all periods.
     pub fn with_coupon_rate(mut self, rate: Real) -> Self {
         self.coupon_rates = vec![rate];
@@ -250,9 +289,21 @@ impl<'a> FixedRateLegBuilder<'a> {
             leg.push(Box::new(FixedRateCoupon::new(
                 payment, notional, ir, start, end, start, end,
             )));
+
+            if self.notional_redemptions {
+                // What is no longer outstanding next period, or all of it.
+                let next = if i + 1 < n {
+                    self.notionals[(i + 1).min(self.notionals.len() - 1)]
+                } else {
+                    0.0
+                };
+                if notional != next {
+                    leg.push(Box::new(Redemption::new(notional - next, payment)));
+                }
+            }
         }
 
-        if self.add_redemption && n > 0 {
+        if self.add_redemption && !self.notional_redemptions && n > 0 {
             let last_date = dates[n];
             leg.push(Box::new(Redemption::new(self.redemption_amount, last_date)));
         }
@@ -313,6 +364,69 @@ mod tests {
         assert!((leg[4].amount() - 100.0).abs() < 1e-15);
     }
 
+    #[test]
+    fn amortizing_leg_redeems_its_notional() {
+        let start = Date::from_ymd(2025, 1, 15).unwrap();
+        let end = Date::from_ymd(2030, 1, 15).unwrap();
+        let tenor = Period::new(1, TimeUnit::Years);
+        let cal = NullCalendar;
+        let schedule = ScheduleBuilder::new(start, end, tenor, &cal)
+            .build()
+            .unwrap();
+
+        let leg = FixedRateLegBuilder::new(&schedule)
+            .with_coupon_rate(0.04)
+            .with_amortization(1_000.0, 200.0)
+            .unwrap()
+            .build();
+
+        // Five coupons, each followed by a redemption of 200.
+        assert_eq!(leg.len(), 10);
+        let dates = schedule.dates();
+        for (i, pair) in leg.chunks(2).enumerate() {
+            let coupon = pair[0].as_any().downcast_ref::<FixedRateCoupon>().unwrap();
+            let notional = 1_000.0 - 200.0 * i as Real;
+            assert_eq!(coupon.nominal(), notional);
+            let accrual = Actual365Fixed.year_fraction(dates[i], dates[i + 1]);
+            assert!((coupon.amount() - notional * 0.04 * accrual).abs() < 1e-10);
+
+            let redemption = pair[1].as_any().downcast_ref::<Redemption>().unwrap();
+            assert_eq!(redemption.date(), dates[i + 1]);
+            assert!((redemption.amount() - 200.0).abs() < 1e-12);
+        }
+
+        // A sinking fund: the final redemption is what is left outstanding.
+        let sinking = FixedRateLegBuilder::new(&schedule)
+            .with_notionals(vec![100.0, 100.0, 100.0, 60.0, 60.0])
+            .with_coupon_rate(0.04)
+            .with_notional_redemptions()
+            .build();
+        let redemptions: Vec<(Date, Real)> = sinking
+            .iter()
+            .filter(|cf| cf.as_any().is::<Redemption>())
+            .map(|cf| (cf.date(), cf.amount()))
+            .collect();
+        assert_eq!(redemptions, vec![(dates[3], 40.0), (dates[5], 60.0)]);
+
+        // The holder pays in each increase as a negative redemption and is
+        // repaid the accreted notional at maturity.
+        let accreting = FixedRateLegBuilder::new(&schedule)
+            .with_coupon_rate(0.04)
+            .with_amortization(100.0, -10.0)
+            .unwrap()
+            .build();
+        let redemptions: Vec<Real> = accreting
+            .iter()
+            .filter(|cf| cf.as_any().is::<Redemption>())
+            .map(|cf| cf.amount())
+            .collect();
+        assert_eq!(redemptions, vec![-10.0, -10.0, -10.0, -10.0, 140.0]);
+
+        assert!(FixedRateLegBuilder::new(&schedule)
+            .with_amortization(1_000.0, 300.0)
+            .is_err());
+    }
+
     #[test]
     fn fixed_rate_leg_dates_monotone() {
         let start = Date::from_ymd(2025, 1, 15).unwrap();
@@ -1,12 +1,14 @@
-//! Floating-rate coupons and Ibor leg builders.
+//! Floating-rate coupons and Ibor and overnight leg builders.
 //!
 //! Translates `ql/cashflows/floatingratecoupon.hpp`,
-//! `ql/cashflows/iborcoupon.hpp` and parts of `ql/cashflows/iborcoupon.cpp`.
+//! `ql/cashflows/iborcoupon.hpp`, parts of `ql/cashflows/iborcoupon.cpp`
+//! and `ql/cashflows/overnightindexedcoupon.hpp`.
 
 use crate::cashflow::{CashFlow, Leg, Redemption};
 use crate::coupon::Coupon;
 use ql_core::{errors::Result, Real};
-use ql_indexes::{IborIndex, Index, InterestRateIndex};
+use ql_indexes::{IborIndex, Index, InterestRateIndex, OvernightIndex};
+use ql_termstructures::YieldTermStructure;
 use ql_time::{Actual365Fixed, BusinessDayConvention, Date, DayCounter, Schedule};
 use std::sync::Arc;
 
@@ -87,6 +89,11 @@ impl FloatingRateCoupon {
         self.fixing_date
     }
 
+    /// The index rate set with [`with_rate`](Self::with_rate), if any.
+    pub fn index_rate(&self) -> Option<Real> {
+        self.cached_rate
+    }
+
     /// The gearing multiplier.
     pub fn gearing(&self) -> Real {
         self.gearing
@@ -108,6 +115,16 @@ impl FloatingRateCoupon {
     }
 }
 
+/// Simple forward rate over `[start, end]` accruing `accrual_period`.
+fn forward_rate(
+    forecast: &dyn YieldTermStructure,
+    start: Date,
+    end: Date,
+    accrual_period: Real,
+) -> Real {
+    (forecast.discount_date(start) / forecast.discount_date(end) - 1.0) / accrual_period
+}
+
 impl CashFlow for FloatingRateCoupon {
     fn date(&self) -> Date {
         self.payment_date
@@ -116,6 +133,10 @@ impl CashFlow for FloatingRateCoupon {
     fn amount(&self) -> Real {
         self.nominal * self.effective_rate() * self.accrual_period
     }
+
+    fn as_coupon(&self) -> Option<&dyn Coupon> {
+        Some(self)
+    }
 }
 
 impl Coupon for FloatingRateCoupon {
@@ -142,6 +163,20 @@ impl Coupon for FloatingRateCoupon {
     fn rate(&self) -> Real {
         self.effective_rate()
     }
+
+    /// The index rate is projected off `forecast` unless it has already
+    /// been set.
+    fn projected_amount(&self, forecast: &dyn YieldTermStructure) -> Real {
+        let index_rate = self.cached_rate.unwrap_or_else(|| {
+            forward_rate(
+                forecast,
+                self.accrual_start,
+                self.accrual_end,
+                self.accrual_period,
+            )
+        });
+        self.nominal * (self.gearing * index_rate + self.spread) * self.accrual_period
+    }
 }
 
 // ────────────────────────────────────────────────────────────────────────────
@@ -198,6 +233,21 @@ impl IborCoupon {
         &self.index
     }
 
+    /// The fixing date for this coupon.
+    pub fn fixing_date(&self) -> Date {
+        self.inner.fixing_date
+    }
+
+    /// The gearing multiplier.
+    pub fn gearing(&self) -> Real {
+        self.inner.gearing
+    }
+
+    /// The spread.
+    pub fn spread(&self) -> Real {
+        self.inner.spread
+    }
+
     /// The fixing rate from the index. Returns an error if the fixing is
     /// not available and term-structure forecasting is not yet implemented.
     pub fn index_fixing(&self) -> Result<Real> {
@@ -224,6 +274,10 @@ impl CashFlow for IborCoupon {
             Err(_) => self.inner.amount(),
         }
     }
+
+    fn as_coupon(&self) -> Option<&dyn Coupon> {
+        Some(self)
+    }
 }
 
 impl Coupon for IborCoupon {
@@ -251,6 +305,15 @@ impl Coupon for IborCoupon {
         self.effective_rate_result()
             .unwrap_or(self.inner.effective_rate())
     }
+
+    /// Uses the stored fixing if there is one and otherwise the forward
+    /// projected off `forecast`.
+    fn projected_amount(&self, forecast: &dyn YieldTermStructure) -> Real {
+        match self.effective_rate_result() {
+            Ok(r) => self.inner.nominal * r * self.inner.accrual_period,
+            Err(_) => self.inner.projected_amount(forecast),
+        }
+    }
 }
 
 // ────────────────────────────────────────────────────────────────────────────
@@ -384,11 +447,318 @@ impl<'a> IborLegBuilder<'a> {
     }
 }
 
+// ────────────────────────────────────────────────────────────────────────────
+// OvernightIndexedCoupon
+// ────────────────────────────────────────────────────────────────────────────
+
+/// A coupon paying the daily-compounded fixings of an `OvernightIndex`.
+///
+/// The accrual period is split at every business day of the index calendar,
+/// and the rate is `OvernightIndex::compounded_rate` over those dates with
+/// the index's stored fixings:
+///
+///   `amount = nominal * (gearing * (∏(1 + rᵢτᵢ) − 1) / τ + spread) * τ`
+///
+/// Until every fixing of the period is stored the coupon pays only the
+/// spread; see [`Coupon::rate`].
+///
+/// Corresponds to `QuantLib::OvernightIndexedCoupon`.
+#[derive(Debug)]
+pub struct OvernightIndexedCoupon {
+    nominal: Real,
+    payment_date: Date,
+    accrual_start: Date,
+    accrual_end: Date,
+    value_dates: Vec<Date>,
+    index: Arc<OvernightIndex>,
+    gearing: Real,
+    spread: Real,
+    lookback_days: u32,
+    lockout_days: u32,
+    accrual_period: Real,
+}
+
+impl OvernightIndexedCoupon {
+    /// Create a new overnight-indexed coupon.
+    #[allow(clippy::too_many_arguments)]
+    pub fn new(
+        payment_date: Date,
+        nominal: Real,
+        accrual_start: Date,
+        accrual_end: Date,
+        index: Arc<OvernightIndex>,
+        gearing: Real,
+        spread: Real,
+    ) -> Self {
+        let calendar = index.fixing_calendar();
+        let mut value_dates = vec![accrual_start];
+        let mut date = calendar.advance_business_days(accrual_start, 1);
+        while date < accrual_end {
+            value_dates.push(date);
+            date = calendar.advance_business_days(date, 1);
+        }
+        value_dates.push(accrual_end);
+        let accrual_period = index
+            .day_counter()
+            .year_fraction(accrual_start, accrual_end);
+        Self {
+            nominal,
+            payment_date,
+            accrual_start,
+            accrual_end,
+            value_dates,
+            index,
+            gearing,
+            spread,
+            lookback_days: 0,
+            lockout_days: 0,
+            accrual_period,
+        }
+    }
+
+    /// Observe each fixing `days` business days before its sub-period.
+    pub fn with_lookback_days(mut self, days: u32) -> Self {
+        self.lookback_days = days;
+        self
+    }
+
+    /// Freeze the rate for the last `days` sub-periods of the coupon.
+    pub fn with_lockout_days(mut self, days: u32) -> Self {
+        self.lockout_days = days;
+        self
+    }
+
+    /// The underlying index.
+    pub fn overnight_index(&self) -> &OvernightIndex {
+        &self.index
+    }
+
+    /// The dates splitting the accrual period into overnight sub-periods,
+    /// from accrual start to accrual end.
+    pub fn value_dates(&self) -> &[Date] {
+        &self.value_dates
+    }
+
+    /// The gearing multiplier.
+    pub fn gearing(&self) -> Real {
+        self.gearing
+    }
+
+    /// The spread.
+    pub fn spread(&self) -> Real {
+        self.spread
+    }
+
+    /// The compounded coupon rate, or an error if a fixing is missing.
+    pub fn compounded_rate(&self) -> Result<Real> {
+        self.index.stored_compounded_rate(
+            &self.value_dates,
+            self.spread,
+            self.gearing,
+            self.lookback_days,
+            self.lockout_days,
+        )
+    }
+}
+
+impl CashFlow for OvernightIndexedCoupon {
+    fn date(&self) -> Date {
+        self.payment_date
+    }
+
+    fn amount(&self) -> Real {
+        self.nominal * self.rate() * self.accrual_period
+    }
+
+    fn as_coupon(&self) -> Option<&dyn Coupon> {
+        Some(self)
+    }
+}
+
+impl Coupon for OvernightIndexedCoupon {
+    fn nominal(&self) -> Real {
+        self.nominal
+    }
+
+    fn accrual_start_date(&self) -> Date {
+        self.accrual_start
+    }
+
+    fn accrual_end_date(&self) -> Date {
+        self.accrual_end
+    }
+
+    fn accrual_period(&self) -> Real {
+        self.accrual_period
+    }
+
+    fn day_counter(&self) -> &dyn DayCounter {
+        self.index.day_counter()
+    }
+
+    /// The compounded rate, or just the spread if any fixing of the period
+    /// is missing: like `IborCoupon`, the index rate is then taken as zero.
+    /// Use [`compounded_rate`](OvernightIndexedCoupon::compounded_rate) to
+    /// have a missing fixing reported as an error.
+    fn rate(&self) -> Real {
+        self.compounded_rate().unwrap_or(self.spread)
+    }
+
+    /// Compounds the stored fixings if all are known and otherwise
+    /// projects the period's rate off `forecast`.
+    fn projected_amount(&self, forecast: &dyn YieldTermStructure) -> Real {
+        let rate = self.compounded_rate().unwrap_or_else(|_| {
+            let index_rate = forward_rate(
+                forecast,
+                self.accrual_start,
+                self.accrual_end,
+                self.accrual_period,
+            );
+            self.gearing * index_rate + self.spread
+        });
+        self.nominal * rate * self.accrual_period
+    }
+}
+
+// ────────────────────────────────────────────────────────────────────────────
+// Overnight leg builder
+// ────────────────────────────────────────────────────────────────────────────
+
+/// Build an overnight-indexed leg from a schedule and an overnight index.
+///
+/// Corresponds to `QuantLib::OvernightLeg`.
+pub struct OvernightLegBuilder<'a> {
+    schedule: &'a Schedule,
+    index: Arc<OvernightIndex>,
+    notionals: Vec<Real>,
+    gearings: Vec<Real>,
+    spreads: Vec<Real>,
+    lookback_days: u32,
+    lockout_days: u32,
+    payment_convention: BusinessDayConvention,
+    add_redemption: bool,
+    redemption_amount: Real,
+}
+
+impl<'a> OvernightLegBuilder<'a> {
+    /// Create a builder from a schedule and an index.
+    pub fn new(schedule: &'a Schedule, index: Arc<OvernightIndex>) -> Self {
+        Self {
+            schedule,
+            index,
+            notionals: vec![1.0],
+            gearings: vec![1.0],
+            spreads: vec![0.0],
+            lookback_days: 0,
+            lockout_days: 0,
+            payment_convention: BusinessDayConvention::Following,
+            add_redemption: false,
+            redemption_amount: 100.0,
+        }
+    }
+
+    /// Set notional(s).
+    pub fn with_notionals(mut self, notionals: Vec<Real>) -> Self {
+        self.notionals = notionals;
+        self
+    }
+
+    /// Set a single gearing for all periods.
+    pub fn with_gearing(mut self, gearing: Real) -> Self {
+        self.gearings = vec![gearing];
+        self
+    }
+
+    /// Set gearings per period.
+    pub fn with_gearings(mut self, gearings: Vec<Real>) -> Self {
+        self.gearings = gearings;
+        self
+    }
+
+    /// Set a single spread for all periods.
+    pub fn with_spread(mut self, spread: Real) -> Self {
+        self.spreads = vec![spread];
+        self
+    }
+
+    /// Set spreads per period.
+    pub fn with_spreads(mut self, spreads: Vec<Real>) -> Self {
+        self.spreads = spreads;
+        self
+    }
+
+    /// Set the fixing lookback, in business days.
+    pub fn with_lookback_days(mut self, days: u32) -> Self {
+        self.lookback_days = days;
+        self
+    }
+
+    /// Set the rate lockout at the end of each coupon, in business days.
+    pub fn with_lockout_days(mut self, days: u32) -> Self {
+        self.lockout_days = days;
+        self
+    }
+
+    /// Set payment convention, applied on the index calendar.
+    pub fn with_payment_convention(mut self, convention: BusinessDayConvention) -> Self {
+        self.payment_convention = convention;
+        self
+    }
+
+    /// Add a final redemption.
+    pub fn with_redemption(mut self, amount: Real) -> Self {
+        self.add_redemption = true;
+        self.redemption_amount = amount;
+        self
+    }
+
+    /// Build the leg.
+    pub fn build(self) -> Leg {
+        let dates = self.schedule.dates();
+        let n = dates.len().saturating_sub(1);
+        let mut leg: Leg = Vec::with_capacity(n + if self.add_redemption { 1 } else { 0 });
+
+        for i in 0..n {
+            let start = dates[i];
+            let end = dates[i + 1];
+            let payment = self
+                .index
+                .fixing_calendar()
+                .adjust(end, self.payment_convention);
+
+            let notional = self.notionals[i.min(self.notionals.len() - 1)];
+            let gearing = self.gearings[i.min(self.gearings.len() - 1)];
+            let spread = self.spreads[i.min(self.spreads.len() - 1)];
+
+            let coupon = OvernightIndexedCoupon::new(
+                payment,
+                notional,
+                start,
+                end,
+                Arc::clone(&self.index),
+                gearing,
+                spread,
+            )
+            .with_lookback_days(self.lookback_days)
+            .with_lockout_days(self.lockout_days);
+
+            leg.push(Box::new(coupon));
+        }
+
+        if self.add_redemption && n > 0 {
+            let last_date = dates[n];
+            leg.push(Box::new(Redemption::new(self.redemption_amount, last_date)));
+        }
+
+        leg
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
     use ql_currencies::currencies::america::USD;
-    use ql_time::{NullCalendar, Period, ScheduleBuilder, TimeUnit};
+    use ql_time::{Actual360, NullCalendar, Period, ScheduleBuilder, TimeUnit};
 
     fn make_test_index() -> Arc<IborIndex> {
         Arc::new(IborIndex::new(
@@ -514,4 +884,95 @@ mod tests {
             assert!(leg[i].date() > leg[i - 1].date());
         }
     }
+
+    fn make_test_on_index() -> Arc<OvernightIndex> {
+        Arc::new(OvernightIndex::new(
+            "TEST-ON",
+            0,
+            &USD,
+            NullCalendar,
+            Actual360,
+        ))
+    }
+
+    #[test]
+    fn overnight_leg_pays_compounded_rate() {
+        let index = make_test_on_index();
+        let start = Date::from_ymd(2025, 1, 15).unwrap();
+        let end = Date::from_ymd(2026, 1, 15).unwrap();
+        let cal = NullCalendar;
+        let schedule = ScheduleBuilder::new(start, end, Period::new(1, TimeUnit::Years), &cal)
+            .build()
+            .unwrap();
+        // Fixings stepping up by a basis point a month.
+        let mut date = start;
+        while date < end {
+            index.add_fixing(date, 0.04 + 0.0001 * date.month() as Real);
+            date += 1;
+        }
+
+        let notional = 1_000_000.0;
+        let leg = OvernightLegBuilder::new(&schedule, Arc::clone(&index))
+            .with_notionals(vec![notional])
+            .build();
+        assert_eq!(leg.len(), 1);
+
+        let days: Vec<Date> = (0..=365).map(|i| start + i).collect();
+        let compounded = index
+            .compounded_rate(&days, &index.fixing_store().snapshot(), 0.0, 1.0)
+            .unwrap();
+        let tau = 365.0 / 360.0;
+        let total: Real = leg.iter().map(|cf| cf.amount()).sum();
+        assert!((total - notional * compounded * tau).abs() < 1e-6);
+
+        let growth: Real = days[..365]
+            .iter()
+            .map(|d| 1.0 + (0.04 + 0.0001 * d.month() as Real) / 360.0)
+            .product();
+        assert!((total - notional * (growth - 1.0)).abs() < 1e-6);
+    }
+
+    #[test]
+    fn overnight_coupon_follows_coupon_conventions() {
+        let index = make_test_on_index();
+        let start = Date::from_ymd(2025, 3, 3).unwrap();
+        let end = Date::from_ymd(2025, 6, 3).unwrap();
+        let coupon =
+            OvernightIndexedCoupon::new(end, 100.0, start, end, Arc::clone(&index), 1.0, 0.002);
+        assert_eq!(coupon.value_dates().len(), 93);
+        assert!((coupon.accrual_period() - 92.0 / 360.0).abs() < 1e-15);
+        assert_eq!(coupon.day_counter().name(), Actual360.name());
+        // Without fixings only the spread accrues.
+        assert!(coupon.compounded_rate().is_err());
+        assert_eq!(coupon.rate(), 0.002);
+
+        for d in &coupon.value_dates()[..92] {
+            index.add_fixing(*d, 0.03);
+        }
+        let growth = (1.0 + 0.03 / 360.0_f64).powi(92) - 1.0;
+        assert!((coupon.rate() - (growth / (92.0 / 360.0) + 0.002)).abs() < 1e-12);
+        assert!((coupon.amount() - 100.0 * coupon.rate() * coupon.accrual_period()).abs() < 1e-14);
+    }
+
+    #[test]
+    fn overnight_coupon_missing_a_fixing_pays_only_the_spread() {
+        let index = make_test_on_index();
+        let start = Date::from_ymd(2025, 3, 3).unwrap();
+        let end = Date::from_ymd(2025, 4, 3).unwrap();
+        let coupon =
+            OvernightIndexedCoupon::new(end, 100.0, start, end, Arc::clone(&index), 1.0, 0.002);
+        let missing = Date::from_ymd(2025, 3, 17).unwrap();
+        for d in &coupon.value_dates()[..31] {
+            if *d != missing {
+                index.add_fixing(*d, 0.03);
+            }
+        }
+        assert!(coupon.compounded_rate().is_err());
+        assert_eq!(coupon.rate(), 0.002);
+        assert!((coupon.amount() - 100.0 * 0.002 * 31.0 / 360.0).abs() < 1e-15);
+
+        index.add_fixing(missing, 0.03);
+        assert!(coupon.compounded_rate().is_ok());
+        assert!(coupon.rate() > 0.03);
+    }
 }
@@ -44,6 +44,9 @@ impl CashFlow for CPICoupon {
     fn amount(&self) -> Real {
         self.adjusted_notional() * self.fixed_rate
     }
+    fn as_coupon(&self) -> Option<&dyn Coupon> {
+        Some(self)
+    }
 }
 
 impl Coupon for CPICoupon {
@@ -93,6 +96,9 @@ impl CashFlow for YoYInflationCoupon {
     fn amount(&self) -> Real {
         self.notional * self.yoy_rate * self.day_count_fraction
     }
+    fn as_coupon(&self) -> Option<&dyn Coupon> {
+        Some(self)
+    }
 }
 
 impl Coupon for YoYInflationCoupon {
@@ -12,12 +12,17 @@ pub mod fixed_rate_coupon;
 pub mod floating_rate_coupon;
 pub mod inflation_coupon;
 
-pub use cashflow::{CashFlow, Leg, Redemption, SimpleCashFlow};
+pub use cashflow::{AsAny, CashFlow, Leg, Redemption, SimpleCashFlow};
 pub use cashflows::{
-    bps_curve, bps_yield, convexity, duration, maturity_date, next_cashflow_date, npv_curve,
-    npv_yield, npv_z_spread, previous_cashflow_date, yield_rate, z_spread, Duration,
+    bps_curve, bps_yield, convexity, convexity_with_day_counter, duration,
+    duration_with_day_counter, effective_convexity, effective_duration, floating_z_spread,
+    maturity_date, next_cashflow_date, npv, npv_curve, npv_floating_z_spread, npv_yield,
+    npv_yield_with_day_counter, npv_z_spread, previous_cashflow_date, validate_leg, yield_rate,
+    yield_rate_newton, yield_rate_with_day_counter, z_spread, Duration,
 };
 pub use coupon::Coupon;
 pub use fixed_rate_coupon::{FixedRateCoupon, FixedRateLegBuilder};
-pub use floating_rate_coupon::{FloatingRateCoupon, IborCoupon, IborLegBuilder};
+pub use floating_rate_coupon::{
+    FloatingRateCoupon, IborCoupon, IborLegBuilder, OvernightIndexedCoupon, OvernightLegBuilder,
+};
 pub use inflation_coupon::{CPICoupon, YoYInflationCoupon};
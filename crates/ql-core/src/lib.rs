@@ -24,6 +24,9 @@ pub mod handle;
 /// Design patterns: observable, lazy_object, visitor.
 pub mod patterns;
 
+/// Option type (call/put) enum.
+pub mod option_type;
+
 /// Position (long/short) enum.
 pub mod position;
 
@@ -82,6 +85,7 @@ pub type Time = Real;
 pub use compounding::Compounding;
 pub use errors::{Error, Result};
 pub use handle::{Handle, RelinkableHandle};
+pub use option_type::OptionType;
 pub use position::Position;
 pub use settings::{ScopedEvaluationDate, Settings};
 pub use time_series::TimeSeries;
@@ -0,0 +1,34 @@
+//! Option type (translates `Option::Type` of `ql/option.hpp`).
+
+use crate::Real;
+use std::fmt;
+
+/// Option type (call or put).
+///
+/// Corresponds to `QuantLib::Option::Type`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum OptionType {
+    /// A call option (right to buy).
+    Call,
+    /// A put option (right to sell).
+    Put,
+}
+
+impl OptionType {
+    /// +1 for Call, −1 for Put.
+    pub fn sign(self) -> Real {
+        match self {
+            OptionType::Call => 1.0,
+            OptionType::Put => -1.0,
+        }
+    }
+}
+
+impl fmt::Display for OptionType {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            OptionType::Call => write!(f, "Call"),
+            OptionType::Put => write!(f, "Put"),
+        }
+    }
+}
@@ -133,6 +133,36 @@ impl<K: Ord + Clone, V: Clone> TimeSeries<K, V> {
     pub fn as_map(&self) -> &BTreeMap<K, V> {
         &self.data
     }
+
+    // ── Filling ──────────────────────────────────────────────────────────
+
+    /// The series on `keys`, each carrying the latest observation at or
+    /// before it.
+    ///
+    /// Keys before the first observation have nothing to carry and are
+    /// left out.
+    pub fn fill_forward(&self, keys: &[K]) -> Self {
+        keys.iter()
+            .filter_map(|k| {
+                let (_, v) = self.data.range(..=k).next_back()?;
+                Some((k.clone(), v.clone()))
+            })
+            .collect()
+    }
+
+    /// The series on `keys`, each carrying the earliest observation at or
+    /// after it.
+    ///
+    /// Keys after the last observation have nothing to carry and are left
+    /// out.
+    pub fn fill_backward(&self, keys: &[K]) -> Self {
+        keys.iter()
+            .filter_map(|k| {
+                let (_, v) = self.data.range(k..).next()?;
+                Some((k.clone(), v.clone()))
+            })
+            .collect()
+    }
 }
 
 impl<K: Ord + Clone, V: Clone> std::ops::Index<&K> for TimeSeries<K, V> {
@@ -232,6 +262,31 @@ mod tests {
         assert_eq!(ts.len(), 1);
     }
 
+    #[test]
+    fn fill_forward_and_backward_on_gapped_daily_series() {
+        // Daily keys with days 3, 4 and 7 missing.
+        let ts = TimeSeries::from_key_values(&[2, 5, 6, 8], &[1.0, 2.0, 3.0, 4.0]);
+        let days: Vec<i32> = (1..=9).collect();
+
+        let forward = ts.fill_forward(&days);
+        assert_eq!(forward.keys(), (2..=9).collect::<Vec<_>>());
+        assert_eq!(
+            forward.values(),
+            vec![1.0, 1.0, 1.0, 2.0, 3.0, 3.0, 4.0, 4.0]
+        );
+
+        let backward = ts.fill_backward(&days);
+        assert_eq!(backward.keys(), (1..=8).collect::<Vec<_>>());
+        assert_eq!(
+            backward.values(),
+            vec![1.0, 1.0, 2.0, 2.0, 2.0, 3.0, 4.0, 4.0]
+        );
+
+        // Observed keys keep their own values.
+        assert_eq!(ts.fill_forward(&ts.keys()), ts);
+        assert_eq!(ts.fill_backward(&ts.keys()), ts);
+    }
+
     #[test]
     fn display_format() {
         let ts = TimeSeries::from_key_values(&[1, 2], &[3.125, 2.625]);
@@ -0,0 +1,152 @@
+//! Default market conventions per currency.
+//!
+//! QuantLib keeps these conventions in the individual index and swap-index
+//! classes (`USDLibor`, `EuriborSwapIsdaFixA`, …). This module gathers the
+//! standard interbank defaults per currency so that builders can derive
+//! calendars, day counters and swap conventions from a `Currency` alone.
+//!
+//! Currencies without a registered market return `None`.
+
+use crate::currency::Currency;
+use ql_core::Natural;
+use ql_time::calendars::{
+    australia::Australia, canada::Canada, japan::Japan, switzerland::Switzerland, target::Target,
+    united_kingdom::UnitedKingdomSettlement, united_states::UnitedStatesSettlement,
+};
+use ql_time::{
+    Actual360, Actual365Fixed, BusinessDayConvention, Calendar, DayCounter, Frequency, Period,
+    Thirty360, TimeUnit,
+};
+
+/// Standard conventions of a vanilla fixed-vs-floating interest-rate swap.
+#[derive(Debug)]
+pub struct SwapConventions {
+    /// Business days between trade date and spot (start) date.
+    pub settlement_days: Natural,
+    /// Calendar used for the spot lag and for payment-date adjustment.
+    pub calendar: Box<dyn Calendar>,
+    /// Fixed-leg payment frequency.
+    pub fixed_leg_frequency: Frequency,
+    /// Fixed-leg business-day convention.
+    pub fixed_leg_convention: BusinessDayConvention,
+    /// Fixed-leg day counter.
+    pub fixed_leg_day_counter: Box<dyn DayCounter>,
+    /// Tenor of the floating-leg index.
+    pub floating_leg_tenor: Period,
+    /// Floating-leg day counter.
+    pub floating_leg_day_counter: Box<dyn DayCounter>,
+}
+
+impl Currency {
+    /// Calendar used to settle money-market and swap trades in this
+    /// currency (e.g. US settlement for USD, TARGET for EUR).
+    pub fn settlement_calendar(&self) -> Option<Box<dyn Calendar>> {
+        let calendar: Box<dyn Calendar> = match self.code {
+            "USD" => Box::new(UnitedStatesSettlement),
+            "EUR" => Box::new(Target),
+            "GBP" => Box::new(UnitedKingdomSettlement),
+            "JPY" => Box::new(Japan),
+            "CHF" => Box::new(Switzerland),
+            "CAD" => Box::new(Canada),
+            "AUD" => Box::new(Australia),
+            _ => return None,
+        };
+        Some(calendar)
+    }
+
+    /// Spot lag in business days of the settlement calendar.
+    pub fn settlement_days(&self) -> Option<Natural> {
+        match self.code {
+            "USD" | "EUR" | "JPY" | "CHF" => Some(2),
+            "GBP" | "CAD" => Some(0),
+            "AUD" => Some(1),
+            _ => None,
+        }
+    }
+
+    /// Money-market day counter for deposits and floating-rate accruals.
+    pub fn default_day_counter(&self) -> Option<Box<dyn DayCounter>> {
+        let day_counter: Box<dyn DayCounter> = match self.code {
+            "USD" | "EUR" | "CHF" | "JPY" => Box::new(Actual360),
+            "GBP" | "CAD" | "AUD" => Box::new(Actual365Fixed),
+            _ => return None,
+        };
+        Some(day_counter)
+    }
+
+    /// Standard conventions of a vanilla swap in this currency.
+    pub fn default_swap_conventions(&self) -> Option<SwapConventions> {
+        let (fixed_leg_frequency, fixed_leg_day_counter, floating_months): (
+            Frequency,
+            Box<dyn DayCounter>,
+            i32,
+        ) = match self.code {
+            "USD" => (Frequency::Semiannual, Box::new(Thirty360), 3),
+            "EUR" => (Frequency::Annual, Box::new(Thirty360), 6),
+            "GBP" => (Frequency::Semiannual, Box::new(Actual365Fixed), 6),
+            "JPY" => (Frequency::Semiannual, Box::new(Actual365Fixed), 6),
+            "CHF" => (Frequency::Annual, Box::new(Thirty360), 6),
+            "CAD" => (Frequency::Semiannual, Box::new(Actual365Fixed), 3),
+            "AUD" => (Frequency::Semiannual, Box::new(Actual365Fixed), 6),
+            _ => return None,
+        };
+        Some(SwapConventions {
+            settlement_days: self.settlement_days()?,
+            calendar: self.settlement_calendar()?,
+            fixed_leg_frequency,
+            fixed_leg_convention: BusinessDayConvention::ModifiedFollowing,
+            fixed_leg_day_counter,
+            floating_leg_tenor: Period::new(floating_months, TimeUnit::Months),
+            floating_leg_day_counter: self.default_day_counter()?,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::currencies::{BRL, EUR, GBP, USD};
+    use ql_time::{BusinessDayConvention, Frequency, Period, TimeUnit};
+
+    #[test]
+    fn usd_conventions() {
+        assert_eq!(USD.settlement_days(), Some(2));
+        let calendar = USD.settlement_calendar().unwrap();
+        assert_eq!(calendar.name(), "US (Settlement)");
+        assert_eq!(USD.default_day_counter().unwrap().name(), "Actual/360");
+
+        let swap = USD.default_swap_conventions().unwrap();
+        assert_eq!(swap.settlement_days, 2);
+        assert_eq!(swap.calendar.name(), calendar.name());
+        assert_eq!(swap.fixed_leg_frequency, Frequency::Semiannual);
+        assert_eq!(swap.fixed_leg_day_counter.name(), "30/360");
+        assert_eq!(swap.floating_leg_tenor, Period::new(3, TimeUnit::Months));
+    }
+
+    #[test]
+    fn eur_conventions() {
+        assert_eq!(EUR.settlement_days(), Some(2));
+        assert_eq!(EUR.settlement_calendar().unwrap().name(), "TARGET");
+        assert_eq!(EUR.default_day_counter().unwrap().name(), "Actual/360");
+
+        let swap = EUR.default_swap_conventions().unwrap();
+        assert_eq!(swap.fixed_leg_frequency, Frequency::Annual);
+        assert_eq!(
+            swap.fixed_leg_convention,
+            BusinessDayConvention::ModifiedFollowing
+        );
+        assert_eq!(swap.fixed_leg_day_counter.name(), "30/360");
+        assert_eq!(swap.floating_leg_tenor, Period::new(6, TimeUnit::Months));
+        assert_eq!(swap.floating_leg_day_counter.name(), "Actual/360");
+    }
+
+    #[test]
+    fn gbp_and_unregistered_currencies() {
+        assert_eq!(GBP.settlement_days(), Some(0));
+        assert_eq!(
+            GBP.default_day_counter().unwrap().name(),
+            "Actual/365 (Fixed)"
+        );
+        assert!(BRL.settlement_calendar().is_none());
+        assert!(BRL.default_swap_conventions().is_none());
+    }
+}
@@ -1,8 +1,21 @@
 //! `Currency` — definition and metadata for a financial currency.
 //!
-//! Translates `ql/currency.hpp`.
+//! Translates `ql/currency.hpp` and `ql/money.hpp`.
+//!
+//! As in QuantLib, arithmetic between amounts in different currencies is
+//! governed by a per-thread [`MoneySettings`]: it may fail, convert both
+//! amounts to a base currency, or convert the right-hand amount to the
+//! left-hand one's currency.  Conversions use the registered
+//! [`ExchangeRateManager`] at the [`Settings`] evaluation date.
 
-use ql_core::{Integer, Real};
+use crate::exchange_rate::ExchangeRateManager;
+use ql_core::{
+    errors::{Error, Result},
+    Integer, Real, Settings,
+};
+use ql_time::Date;
+use std::cell::RefCell;
+use std::sync::Arc;
 
 /// Data describing a single currency.
 ///
@@ -52,29 +65,151 @@ impl Money {
     pub fn new(value: Real, currency: &'static Currency) -> Self {
         Self { value, currency }
     }
+
+    /// The sum of two amounts, converted as the [`MoneySettings`] say if
+    /// their currencies differ.
+    ///
+    /// # Errors
+    /// Fails on a currency mismatch under
+    /// [`ConversionType::NoConversion`], or if a needed exchange rate,
+    /// base currency or evaluation date is missing.
+    pub fn try_add(&self, rhs: &Money) -> Result<Money> {
+        let (lhs, rhs) = self.in_common_currency(rhs, "add")?;
+        Ok(Money::new(lhs.value + rhs.value, lhs.currency))
+    }
+
+    /// The difference of two amounts, converted as for [`Money::try_add`].
+    ///
+    /// # Errors
+    /// See [`Money::try_add`].
+    pub fn try_sub(&self, rhs: &Money) -> Result<Money> {
+        let (lhs, rhs) = self.in_common_currency(rhs, "subtract")?;
+        Ok(Money::new(lhs.value - rhs.value, lhs.currency))
+    }
+
+    /// Both amounts in the currency the settings prescribe.
+    fn in_common_currency(&self, rhs: &Money, operation: &str) -> Result<(Money, Money)> {
+        if self.currency == rhs.currency {
+            return Ok((self.clone(), rhs.clone()));
+        }
+        let settings = MoneySettings::instance();
+        let target = match settings.conversion_type {
+            ConversionType::NoConversion => {
+                return Err(Error::Runtime(format!(
+                    "cannot {operation} amounts in different currencies ({} vs {})",
+                    self.currency.code, rhs.currency.code
+                )))
+            }
+            ConversionType::BaseCurrencyConversion => settings.base_currency.ok_or_else(|| {
+                Error::Runtime("base-currency conversion needs a base currency".into())
+            })?,
+            ConversionType::AutomatedConversion => self.currency,
+        };
+        let rates = settings
+            .exchange_rates
+            .ok_or_else(|| Error::Runtime("money conversion needs exchange rates".into()))?;
+        let Some(serial) = Settings::instance().evaluation_date_serial() else {
+            return Err(Error::Runtime(
+                "money conversion needs an evaluation date".into(),
+            ));
+        };
+        let date = Date::from_serial(serial)?;
+        Ok((
+            rates.convert(self, target, date)?,
+            rates.convert(rhs, target, date)?,
+        ))
+    }
+}
+
+/// How to combine amounts in different currencies.
+///
+/// Corresponds to `QuantLib::Money::ConversionType`.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum ConversionType {
+    /// Fail on a currency mismatch.
+    #[default]
+    NoConversion,
+    /// Convert both amounts to the base currency.
+    BaseCurrencyConversion,
+    /// Convert the right-hand amount to the left-hand one's currency.
+    AutomatedConversion,
+}
+
+/// Per-thread settings for [`Money`] arithmetic.
+///
+/// Corresponds to `QuantLib::Money::Settings`.
+#[derive(Clone, Debug, Default)]
+pub struct MoneySettings {
+    conversion_type: ConversionType,
+    base_currency: Option<&'static Currency>,
+    exchange_rates: Option<Arc<ExchangeRateManager>>,
+}
+
+thread_local! {
+    static MONEY_SETTINGS: RefCell<MoneySettings> = RefCell::new(MoneySettings::default());
+}
+
+impl MoneySettings {
+    /// A snapshot of the current thread's money settings.
+    pub fn instance() -> MoneySettings {
+        MONEY_SETTINGS.with(|s| s.borrow().clone())
+    }
+
+    /// How mixed-currency amounts are combined.
+    pub fn conversion_type(&self) -> ConversionType {
+        self.conversion_type
+    }
+
+    /// The currency used by [`ConversionType::BaseCurrencyConversion`].
+    pub fn base_currency(&self) -> Option<&'static Currency> {
+        self.base_currency
+    }
+
+    /// The exchange rates conversions are made with.
+    pub fn exchange_rates(&self) -> Option<&Arc<ExchangeRateManager>> {
+        self.exchange_rates.as_ref()
+    }
+
+    /// Set how mixed-currency amounts are combined on the current thread.
+    pub fn set_conversion_type(conversion_type: ConversionType) {
+        MONEY_SETTINGS.with(|s| s.borrow_mut().conversion_type = conversion_type);
+    }
+
+    /// Set the base currency on the current thread.
+    pub fn set_base_currency(currency: &'static Currency) {
+        MONEY_SETTINGS.with(|s| s.borrow_mut().base_currency = Some(currency));
+    }
+
+    /// Set the exchange rates used for conversions on the current thread.
+    pub fn set_exchange_rates(rates: Arc<ExchangeRateManager>) {
+        MONEY_SETTINGS.with(|s| s.borrow_mut().exchange_rates = Some(rates));
+    }
+
+    /// Restore the defaults: no conversion, no base currency, no rates.
+    pub fn reset() {
+        MONEY_SETTINGS.with(|s| *s.borrow_mut() = MoneySettings::default());
+    }
 }
 
+/// Adds two amounts as [`Money::try_add`] does.
+///
+/// # Panics
+/// Panics where [`Money::try_add`] would fail.
 impl std::ops::Add for Money {
     type Output = Self;
     fn add(self, rhs: Self) -> Self {
-        assert_eq!(
-            self.currency, rhs.currency,
-            "cannot add amounts in different currencies ({} vs {})",
-            self.currency.code, rhs.currency.code
-        );
-        Self::new(self.value + rhs.value, self.currency)
+        self.try_add(&rhs).unwrap_or_else(|e| panic!("{e}"))
     }
 }
 
+/// Subtracts two amounts as [`Money::try_sub`] does.
+///
+/// # Panics
+/// Panics where [`Money::try_sub`] would fail.
 impl std::ops::Sub for Money {
     type Output = Self;
     fn sub(self, rhs: Self) -> Self {
-        assert_eq!(
-            self.currency, rhs.currency,
-            "cannot subtract amounts in different currencies ({} vs {})",
-            self.currency.code, rhs.currency.code
-        );
-        Self::new(self.value - rhs.value, self.currency)
+        self.try_sub(&rhs).unwrap_or_else(|e| panic!("{e}"))
     }
 }
 
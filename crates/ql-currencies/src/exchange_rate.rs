@@ -1,13 +1,17 @@
 //! Exchange rate and currency conversion.
 //!
 //! Translates `ql/exchangerate.hpp` and `ql/exchangeratemanager.hpp`.
+//!
+//! Unlike QuantLib, the manager is an explicit registry rather than a
+//! singleton, and cross rates may chain any number of direct rates.
 
 use crate::currency::{Currency, Money};
 use ql_core::{
     errors::{Error, Result},
     Real,
 };
-use std::collections::HashMap;
+use ql_time::Date;
+use std::collections::{BTreeMap, HashMap, VecDeque};
 
 /// An exchange rate between two currencies.
 ///
@@ -56,15 +60,33 @@ impl ExchangeRate {
     }
 }
 
+/// An exchange rate derived by chaining direct rates.
+#[derive(Debug, Clone)]
+pub struct ConversionChain {
+    /// The composed `source → target` rate.
+    pub rate: ExchangeRate,
+    /// The currencies passed through, from source to target inclusive.
+    pub path: Vec<&'static Currency>,
+}
+
+/// A direct rate together with the dates it is valid between.
+#[derive(Debug, Clone)]
+struct DatedRate {
+    rate: ExchangeRate,
+    start_date: Date,
+    end_date: Date,
+}
+
 /// A registry of exchange rates.
 ///
-/// Stores direct rates and can chain through a common currency (typically
-/// USD or EUR) to derive cross rates.
+/// Stores direct rates, each valid over a range of dates, and derives cross
+/// rates by chaining them through as few intermediate currencies as
+/// possible.
 ///
 /// Corresponds loosely to `QuantLib::ExchangeRateManager`.
 #[derive(Debug, Default)]
 pub struct ExchangeRateManager {
-    rates: HashMap<(&'static str, &'static str), ExchangeRate>,
+    rates: BTreeMap<(&'static str, &'static str), Vec<DatedRate>>,
 }
 
 impl ExchangeRateManager {
@@ -73,73 +95,114 @@ impl ExchangeRateManager {
         Self::default()
     }
 
-    /// Register an exchange rate.
+    /// Register an exchange rate valid at all dates.
     pub fn add(&mut self, rate: ExchangeRate) {
+        self.add_between(rate, Date::MIN, Date::MAX);
+    }
+
+    /// Register an exchange rate valid from `start_date` to `end_date`
+    /// inclusive.  Where several rates for a pair are valid on a date, the
+    /// one added last is used.
+    pub fn add_between(&mut self, rate: ExchangeRate, start_date: Date, end_date: Date) {
         let key = (rate.source.code, rate.target.code);
-        self.rates.insert(key, rate);
+        self.rates.entry(key).or_default().push(DatedRate {
+            rate,
+            start_date,
+            end_date,
+        });
     }
 
-    /// Look up a rate for `source → target`, searching direct rates and
-    /// one-hop cross rates through any common currency.
+    /// The direct rates valid on `date`, one per registered pair.
+    fn direct_rates(&self, date: Date) -> impl Iterator<Item = &ExchangeRate> {
+        self.rates.values().filter_map(move |dated| {
+            dated
+                .iter()
+                .rev()
+                .find(|d| d.start_date <= date && date <= d.end_date)
+                .map(|d| &d.rate)
+        })
+    }
+
+    /// Look up the `source → target` rate on `date`.
+    ///
+    /// Direct rates are used in either direction; failing those, a
+    /// breadth-first search over the rates valid on `date` finds a chain
+    /// through the fewest intermediate currencies, and the rates along it
+    /// are composed.
+    ///
+    /// # Errors
+    /// Fails if no chain of rates valid on `date` links the currencies.
     pub fn lookup(
         &self,
         source: &'static Currency,
         target: &'static Currency,
-    ) -> Result<ExchangeRate> {
+        date: Date,
+    ) -> Result<ConversionChain> {
         if source == target {
-            return Ok(ExchangeRate::new(source, target, 1.0));
+            return Ok(ConversionChain {
+                rate: ExchangeRate::new(source, target, 1.0),
+                path: vec![source],
+            });
         }
 
-        // Direct hit?
-        if let Some(r) = self.rates.get(&(source.code, target.code)) {
-            return Ok(r.clone());
-        }
-        // Inverse direct?
-        if let Some(r) = self.rates.get(&(target.code, source.code)) {
-            return Ok(r.inverse());
+        // Each rate is an edge both ways; the conversion factor multiplies
+        // an amount in the edge's first currency into its second.
+        let mut edges: BTreeMap<&str, Vec<(&'static Currency, Real)>> = BTreeMap::new();
+        for rate in self.direct_rates(date) {
+            edges
+                .entry(rate.source.code)
+                .or_default()
+                .push((rate.target, rate.rate));
+            edges
+                .entry(rate.target.code)
+                .or_default()
+                .push((rate.source, 1.0 / rate.rate));
         }
 
-        // Try one-hop cross: source → X → target
-        for rate_sx in self.rates.values() {
-            let x = if rate_sx.source == source {
-                rate_sx.target
-            } else if rate_sx.target == source {
-                rate_sx.source
-            } else {
-                continue;
-            };
-
-            // Now look for X → target
-            if let Some(rate_xt) = self.rates.get(&(x.code, target.code)) {
-                let sx_rate = if rate_sx.source == source {
-                    rate_sx.rate
-                } else {
-                    1.0 / rate_sx.rate
-                };
-                let xt_rate = rate_xt.rate;
-                return Ok(ExchangeRate::new(source, target, sx_rate * xt_rate));
+        // Breadth-first from the source, remembering how each currency was
+        // first reached.
+        let mut reached: HashMap<&str, (&'static Currency, Real)> = HashMap::new();
+        let mut queue = VecDeque::from([source]);
+        reached.insert(source.code, (source, 1.0));
+        while let Some(from) = queue.pop_front() {
+            if from == target {
+                break;
             }
-            if let Some(rate_tx) = self.rates.get(&(target.code, x.code)) {
-                let sx_rate = if rate_sx.source == source {
-                    rate_sx.rate
-                } else {
-                    1.0 / rate_sx.rate
-                };
-                let xt_rate = 1.0 / rate_tx.rate;
-                return Ok(ExchangeRate::new(source, target, sx_rate * xt_rate));
+            for &(to, factor) in edges.get(from.code).into_iter().flatten() {
+                if !reached.contains_key(to.code) {
+                    reached.insert(to.code, (from, factor));
+                    queue.push_back(to);
+                }
             }
         }
+        if !reached.contains_key(target.code) {
+            return Err(Error::Runtime(format!(
+                "no exchange rate found for {}/{} on {date}",
+                source.code, target.code
+            )));
+        }
 
-        Err(Error::Runtime(format!(
-            "no exchange rate found for {}/{}",
-            source.code, target.code
-        )))
+        let mut path = vec![target];
+        let mut rate = 1.0;
+        let mut current = target;
+        while current != source {
+            let (previous, factor) = reached[current.code];
+            rate *= factor;
+            path.push(previous);
+            current = previous;
+        }
+        path.reverse();
+        Ok(ConversionChain {
+            rate: ExchangeRate::new(source, target, rate),
+            path,
+        })
     }
 
-    /// Convert a monetary amount to the target currency.
-    pub fn convert(&self, amount: &Money, target: &'static Currency) -> Result<Money> {
-        let rate = self.lookup(amount.currency, target)?;
-        rate.exchange(amount)
+    /// Convert a monetary amount to the target currency at the rates valid
+    /// on `date`.
+    pub fn convert(&self, amount: &Money, target: &'static Currency, date: Date) -> Result<Money> {
+        let chain = self.lookup(amount.currency, target, date)?;
+        chain.rate.exchange(amount)
     }
 
     /// Remove all registered rates.
@@ -151,7 +214,11 @@ impl ExchangeRateManager {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::currencies::{EUR, GBP, JPY, USD};
+    use crate::currencies::{CHF, EUR, GBP, JPY, USD};
+
+    fn today() -> Date {
+        Date::from_ymd(2025, 3, 14).unwrap()
+    }
 
     #[test]
     fn direct_exchange() {
@@ -175,7 +242,7 @@ mod tests {
     fn manager_direct_lookup() {
         let mut mgr = ExchangeRateManager::new();
         mgr.add(ExchangeRate::new(&USD, &EUR, 0.85));
-        let rate = mgr.lookup(&USD, &EUR).unwrap();
+        let rate = mgr.lookup(&USD, &EUR, today()).unwrap().rate;
         assert!((rate.rate - 0.85).abs() < 1e-12);
     }
 
@@ -183,7 +250,7 @@ mod tests {
     fn manager_inverse_lookup() {
         let mut mgr = ExchangeRateManager::new();
         mgr.add(ExchangeRate::new(&USD, &EUR, 0.85));
-        let rate = mgr.lookup(&EUR, &USD).unwrap();
+        let rate = mgr.lookup(&EUR, &USD, today()).unwrap().rate;
         assert!((rate.rate - 1.0 / 0.85).abs() < 1e-12);
     }
 
@@ -194,7 +261,7 @@ mod tests {
         mgr.add(ExchangeRate::new(&USD, &GBP, 0.75));
         // EUR → GBP via USD: EUR → USD → GBP
         // EUR → USD = 1/0.85, USD → GBP = 0.75
-        let rate = mgr.lookup(&EUR, &GBP).unwrap();
+        let rate = mgr.lookup(&EUR, &GBP, today()).unwrap().rate;
         let expected = (1.0 / 0.85) * 0.75;
         assert!(
             (rate.rate - expected).abs() < 1e-10,
@@ -204,12 +271,58 @@ mod tests {
         );
     }
 
+    #[test]
+    fn manager_triangulates_through_usd() {
+        let mut mgr = ExchangeRateManager::new();
+        mgr.add(ExchangeRate::new(&USD, &EUR, 0.9));
+        mgr.add(ExchangeRate::new(&USD, &JPY, 150.0));
+        mgr.add(ExchangeRate::new(&GBP, &CHF, 1.1));
+
+        let chain = mgr.lookup(&EUR, &JPY, today()).unwrap();
+        assert_eq!(chain.path, vec![&EUR, &USD, &JPY]);
+        assert_eq!(chain.rate.source, &EUR);
+        assert_eq!(chain.rate.target, &JPY);
+        assert!((chain.rate.rate - 150.0 / 0.9).abs() < 1e-10);
+
+        // Two hops past USD once sterling is quoted against it.
+        mgr.add(ExchangeRate::new(&GBP, &USD, 1.25));
+        let chain = mgr.lookup(&JPY, &CHF, today()).unwrap();
+        assert_eq!(chain.path, vec![&JPY, &USD, &GBP, &CHF]);
+        assert!((chain.rate.rate - 1.1 / (150.0 * 1.25)).abs() < 1e-14);
+    }
+
+    #[test]
+    fn manager_unreachable_pair() {
+        let mut mgr = ExchangeRateManager::new();
+        mgr.add(ExchangeRate::new(&USD, &EUR, 0.9));
+        mgr.add(ExchangeRate::new(&GBP, &CHF, 1.1));
+        let err = mgr.lookup(&EUR, &CHF, today()).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            format!("no exchange rate found for EUR/CHF on {}", today())
+        );
+    }
+
+    #[test]
+    fn manager_uses_the_rate_valid_on_the_date() {
+        let mut mgr = ExchangeRateManager::new();
+        let (march, april) = (today(), Date::from_ymd(2025, 4, 14).unwrap());
+        mgr.add(ExchangeRate::new(&USD, &EUR, 0.9));
+        mgr.add_between(ExchangeRate::new(&USD, &EUR, 0.95), april, Date::MAX);
+        mgr.add_between(ExchangeRate::new(&USD, &JPY, 150.0), march, march);
+
+        assert!((mgr.lookup(&USD, &EUR, march).unwrap().rate.rate - 0.9).abs() < 1e-15);
+        assert!((mgr.lookup(&EUR, &USD, april).unwrap().rate.rate - 1.0 / 0.95).abs() < 1e-15);
+        assert!(mgr.lookup(&EUR, &JPY, march).is_ok());
+        assert!(mgr.lookup(&EUR, &JPY, april).is_err());
+    }
+
     #[test]
     fn manager_convert() {
         let mut mgr = ExchangeRateManager::new();
         mgr.add(ExchangeRate::new(&USD, &JPY, 110.0));
         let amount = Money::new(50.0, &USD);
-        let jpy = mgr.convert(&amount, &JPY).unwrap();
+        let jpy = mgr.convert(&amount, &JPY, today()).unwrap();
         assert_eq!(jpy.currency, &JPY);
         assert!((jpy.value - 5500.0).abs() < 1e-10);
     }
@@ -217,10 +330,49 @@ mod tests {
     #[test]
     fn manager_same_currency() {
         let mgr = ExchangeRateManager::new();
-        let rate = mgr.lookup(&USD, &USD).unwrap();
+        let rate = mgr.lookup(&USD, &USD, today()).unwrap().rate;
         assert!((rate.rate - 1.0).abs() < 1e-12);
     }
 
+    #[test]
+    fn money_conversion_follows_the_settings() {
+        use crate::currency::{ConversionType, MoneySettings};
+        use ql_core::settings::ScopedEvaluationDate;
+        use std::sync::Arc;
+
+        let usd = Money::new(100.0, &USD);
+        let eur = Money::new(50.0, &EUR);
+        let same = usd.try_add(&Money::new(20.0, &USD)).unwrap();
+        assert_eq!(same, Money::new(120.0, &USD));
+
+        // Matching QuantLib, mixed currencies are refused by default.
+        MoneySettings::reset();
+        let err = usd.try_add(&eur).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "cannot add amounts in different currencies (USD vs EUR)"
+        );
+        assert!(usd.try_sub(&eur).is_err());
+
+        let mut rates = ExchangeRateManager::new();
+        rates.add(ExchangeRate::new(&EUR, &USD, 1.10));
+        rates.add(ExchangeRate::new(&GBP, &USD, 1.25));
+        MoneySettings::set_exchange_rates(Arc::new(rates));
+        let _today = ScopedEvaluationDate::new(today().serial());
+
+        MoneySettings::set_conversion_type(ConversionType::BaseCurrencyConversion);
+        MoneySettings::set_base_currency(&GBP);
+        let sum = usd.clone() + eur.clone();
+        assert_eq!(sum.currency, &GBP);
+        assert!((sum.value - (100.0 + 50.0 * 1.10) / 1.25).abs() < 1e-12);
+
+        MoneySettings::set_conversion_type(ConversionType::AutomatedConversion);
+        let difference = eur.clone() - usd.clone();
+        assert_eq!(difference.currency, &EUR);
+        assert!((difference.value - (50.0 - 100.0 / 1.10)).abs() < 1e-12);
+        MoneySettings::reset();
+    }
+
     #[test]
     fn money_arithmetic() {
         let a = Money::new(100.0, &USD);
@@ -1,6 +1,6 @@
 //! # ql-currencies
 //!
-//! Currency and exchange-rate definitions.
+//! Currency and exchange-rate definitions, with default market conventions.
 
 #![warn(missing_docs)]
 #![forbid(unsafe_code)]
@@ -14,5 +14,9 @@ pub mod exchange_rate;
 /// Pre-defined world currencies.
 pub mod currencies;
 
-pub use currency::{Currency, Money};
-pub use exchange_rate::{ExchangeRate, ExchangeRateManager};
+/// Default market conventions per currency.
+pub mod conventions;
+
+pub use conventions::SwapConventions;
+pub use currency::{ConversionType, Currency, Money, MoneySettings};
+pub use exchange_rate::{ConversionChain, ExchangeRate, ExchangeRateManager};
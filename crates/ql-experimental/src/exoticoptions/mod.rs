@@ -5,6 +5,7 @@
 //! * **Simple chooser** — Rubinstein (1991) chooser option
 //! * **Complex chooser** — Rubinstein (1991) complex chooser option
 //! * **Compound option** — Geske (1979) compound option (option on an option)
+//! * **Two-asset barrier** — Heynen-Kat outside barrier option
 //! * **Two-asset correlation** — two-asset correlation option
 //! * **Holder-extendible** — holder-extendible option
 //! * **Writer-extendible** — writer-extensible option
@@ -14,6 +15,7 @@ mod complex_chooser;
 mod compound_option;
 mod holder_extensible;
 mod simple_chooser;
+mod two_asset_barrier;
 mod two_asset_correlation;
 mod writer_extensible;
 
@@ -21,5 +23,8 @@ pub use complex_chooser::AnalyticComplexChooserEngine;
 pub use compound_option::AnalyticCompoundOptionEngine;
 pub use holder_extensible::AnalyticHolderExtensibleOptionEngine;
 pub use simple_chooser::AnalyticSimpleChooserEngine;
+pub use two_asset_barrier::{
+    two_asset_barrier_price, AnalyticTwoAssetBarrierEngine, TwoAssetBarrierArgs,
+};
 pub use two_asset_correlation::AnalyticTwoAssetCorrelationEngine;
 pub use writer_extensible::AnalyticWriterExtensibleOptionEngine;
@@ -36,7 +36,8 @@ pub use variance_option::IntegralHestonVarianceOptionEngine;
 pub use exoticoptions::{
     AnalyticComplexChooserEngine, AnalyticCompoundOptionEngine,
     AnalyticHolderExtensibleOptionEngine, AnalyticSimpleChooserEngine,
-    AnalyticTwoAssetCorrelationEngine, AnalyticWriterExtensibleOptionEngine,
+    AnalyticTwoAssetBarrierEngine, AnalyticTwoAssetCorrelationEngine,
+    AnalyticWriterExtensibleOptionEngine,
 };
 
 pub use zabr::{ZabrEvaluationMethod, ZabrModel, ZabrParameters, ZabrSmileSection};
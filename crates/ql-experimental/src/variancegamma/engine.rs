@@ -379,4 +379,54 @@ mod tests {
         // It should be a small positive number for a near-ATM short-dated call
         assert!(result.npv > 0.0 && result.npv < 50.0);
     }
+
+    #[test]
+    fn matches_fourier_pricing_of_the_characteristic_function() {
+        use ql_pricingengines::{fourier_european_price, FourierMethod};
+
+        let today = Date::from_ymd(2025, 1, 2).unwrap();
+        let (r, q, t) = (0.05, 0.02, 1.0);
+        let process = Arc::new(VarianceGammaProcess::new(
+            100.0,
+            flat_ts(r, today),
+            flat_ts(q, today),
+            0.2,
+            0.2,
+            -0.14,
+        ));
+        let engine = VarianceGammaEngine::new(process.clone(), 1e-8);
+        let exercise = Exercise::european(today.advance(360, ql_time::TimeUnit::Days).unwrap());
+        let forward = 100.0 * ((r - q) * t).exp();
+        let discount = (-r * t).exp();
+
+        for option_type in [OptionType::Call, OptionType::Put] {
+            for strike in [80.0, 95.0, 100.0, 110.0, 130.0] {
+                let args = VanillaOptionArguments {
+                    payoff: Arc::new(PlainVanillaPayoff::new(option_type, strike)),
+                    exercise: exercise.clone(),
+                };
+                let expected = engine.calculate(&args).unwrap().npv;
+                for method in [
+                    FourierMethod::CarrMadan,
+                    FourierMethod::Cos,
+                    FourierMethod::GaussLaguerre,
+                ] {
+                    let price = fourier_european_price(
+                        process.as_ref(),
+                        option_type,
+                        forward,
+                        strike,
+                        t,
+                        discount,
+                        method,
+                    )
+                    .unwrap();
+                    assert!(
+                        (price - expected).abs() < 1e-5,
+                        "{option_type:?} K={strike} {method:?}: {price:.8} vs {expected:.8}"
+                    );
+                }
+            }
+        }
+    }
 }
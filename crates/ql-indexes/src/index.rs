@@ -29,6 +29,11 @@ impl FixingStore {
         self.data.read().unwrap().get(&date).copied()
     }
 
+    /// A copy of every stored fixing, in date order.
+    pub fn snapshot(&self) -> BTreeMap<Date, Real> {
+        self.data.read().unwrap().clone()
+    }
+
     /// Number of stored fixings.
     pub fn len(&self) -> usize {
         self.data.read().unwrap().len()
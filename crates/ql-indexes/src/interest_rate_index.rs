@@ -50,15 +50,8 @@ pub(crate) fn advance_period(
     convention: BusinessDayConvention,
     eom: bool,
 ) -> Date {
-    let raw = date
-        .advance(period.length, period.unit)
-        .expect("advance by period");
-    let adjusted = cal.adjust(raw, convention);
-    if eom && cal.is_end_of_month(date) {
-        cal.end_of_month(adjusted)
-    } else {
-        adjusted
-    }
+    cal.advance_by_period(date, period, convention, eom)
+        .expect("advance by period")
 }
 
 /// Common data bundle for concrete interest-rate index implementations.
@@ -3,9 +3,10 @@
 
 use crate::index::{FixingStore, Index};
 use crate::interest_rate_index::{advance_fixing_days, InterestRateIndex, InterestRateIndexData};
-use ql_core::{errors::Result, Real};
+use ql_core::{ensure, errors::Result, fail, Rate, Real, Spread};
 use ql_currencies::Currency;
 use ql_time::{BusinessDayConvention, Calendar, Date, DayCounter, Frequency, Period, TimeUnit};
+use std::collections::BTreeMap;
 
 /// An overnight rate index (e.g. SOFR, ESTR, SONIA).
 ///
@@ -43,6 +44,119 @@ impl OvernightIndex {
             },
         }
     }
+
+    /// Daily-compounded rate over the accrual period spanned by
+    /// `schedule_dates`, as paid by an overnight-indexed coupon:
+    ///
+    /// `gearing · (∏(1 + rᵢ·τᵢ) − 1) / τ + spread`
+    ///
+    /// where `rᵢ` is the fixing for the sub-period `[dᵢ, dᵢ₊₁]`, `τᵢ` its
+    /// year fraction under the index day counter and `τ` their sum.
+    pub fn compounded_rate(
+        &self,
+        schedule_dates: &[Date],
+        fixings: &BTreeMap<Date, Rate>,
+        spread: Spread,
+        gearing: Real,
+    ) -> Result<Rate> {
+        self.compounded_rate_with(schedule_dates, fixings, spread, gearing, 0, 0)
+    }
+
+    /// As [`compounded_rate`](Self::compounded_rate), with each fixing
+    /// observed `lookback_days` business days earlier and the last
+    /// `lockout_days` sub-periods reusing the fixing of the one before them.
+    pub fn compounded_rate_with(
+        &self,
+        schedule_dates: &[Date],
+        fixings: &BTreeMap<Date, Rate>,
+        spread: Spread,
+        gearing: Real,
+        lookback_days: u32,
+        lockout_days: u32,
+    ) -> Result<Rate> {
+        self.compound(
+            schedule_dates,
+            |date| fixings.get(&date).copied(),
+            spread,
+            gearing,
+            lookback_days,
+            lockout_days,
+        )
+    }
+
+    /// As [`compounded_rate_with`](Self::compounded_rate_with), on the
+    /// fixings stored in the index, looked up one by one.
+    pub fn stored_compounded_rate(
+        &self,
+        schedule_dates: &[Date],
+        spread: Spread,
+        gearing: Real,
+        lookback_days: u32,
+        lockout_days: u32,
+    ) -> Result<Rate> {
+        self.compound(
+            schedule_dates,
+            |date| self.data.fixings.get(date),
+            spread,
+            gearing,
+            lookback_days,
+            lockout_days,
+        )
+    }
+
+    fn compound(
+        &self,
+        schedule_dates: &[Date],
+        fixing: impl Fn(Date) -> Option<Rate>,
+        spread: Spread,
+        gearing: Real,
+        lookback_days: u32,
+        lockout_days: u32,
+    ) -> Result<Rate> {
+        ensure!(
+            schedule_dates.len() >= 2,
+            "{}: at least two schedule dates are required",
+            self.data.name
+        );
+        let periods = schedule_dates.len() - 1;
+        ensure!(
+            (lockout_days as usize) < periods,
+            "{}: lockout of {} days leaves no fixing in a {}-period schedule",
+            self.data.name,
+            lockout_days,
+            periods
+        );
+        let locked_from = periods - lockout_days as usize;
+
+        let mut growth = 1.0;
+        let mut total_time = 0.0;
+        let mut rate = 0.0;
+        for (i, window) in schedule_dates.windows(2).enumerate() {
+            let (start, end) = (window[0], window[1]);
+            ensure!(
+                start < end,
+                "{}: schedule dates must be increasing ({} >= {})",
+                self.data.name,
+                start,
+                end
+            );
+            // Locked-out sub-periods keep the last observed fixing.
+            if i < locked_from {
+                let fixing_date = self.data.calendar.advance_business_days(
+                    start,
+                    -((self.data.fixing_days + lookback_days) as i32),
+                );
+                rate = match fixing(fixing_date) {
+                    Some(r) => r,
+                    None => fail!("{}: missing fixing for {}", self.data.name, fixing_date),
+                };
+            }
+            let tau = self.data.day_counter.year_fraction(start, end);
+            growth *= 1.0 + rate * tau;
+            total_time += tau;
+        }
+        Ok(gearing * (growth - 1.0) / total_time + spread)
+    }
 }
 
 impl Index for OvernightIndex {
@@ -155,4 +269,86 @@ mod tests {
         idx.add_fixing(d, 0.053);
         assert_eq!(idx.fixing(d, false).unwrap(), 0.053);
     }
+
+    fn daily_dates(start: Date, days: i32) -> Vec<Date> {
+        (0..=days).map(|i| start + i).collect()
+    }
+
+    #[test]
+    fn constant_fixings_compound_daily() {
+        let idx = make_test_on();
+        let dates = daily_dates(Date::from_ymd(2025, 1, 1).unwrap(), 365);
+        let fixings: BTreeMap<Date, Rate> = dates.iter().map(|&d| (d, 0.05)).collect();
+
+        let rate = idx.compounded_rate(&dates, &fixings, 0.0, 1.0).unwrap();
+        let tau = 365.0 / 360.0;
+        // 365 days at 5%/360 compound to an annual growth of 1.051998.
+        let annual = (1.0 + 0.05 / 360.0_f64).powi(365) - 1.0;
+        assert!(
+            (rate * tau - annual).abs() < 1e-12,
+            "{} vs {annual}",
+            rate * tau
+        );
+        assert!((annual - 0.051998).abs() < 1e-6);
+
+        let geared = idx.compounded_rate(&dates, &fixings, 0.001, 2.0).unwrap();
+        assert!((geared - (2.0 * rate + 0.001)).abs() < 1e-15);
+
+        let mut missing = fixings.clone();
+        missing.remove(&dates[100]);
+        assert!(idx.compounded_rate(&dates, &missing, 0.0, 1.0).is_err());
+    }
+
+    #[test]
+    fn stored_fixings_compound_like_a_fixing_map() {
+        let idx = make_test_on();
+        let dates = daily_dates(Date::from_ymd(2025, 1, 1).unwrap(), 90);
+        let fixings: BTreeMap<Date, Rate> = dates
+            .iter()
+            .enumerate()
+            .map(|(i, &d)| (d - 2, 0.04 + 1e-4 * i as Real))
+            .collect();
+        for (&d, &r) in fixings.iter().skip(1) {
+            idx.add_fixing(d, r);
+        }
+        // The first fixing of the lookback window is still missing.
+        assert!(idx.stored_compounded_rate(&dates, 0.0, 1.0, 2, 3).is_err());
+
+        let (d, r) = fixings.iter().next().unwrap();
+        idx.add_fixing(*d, *r);
+        let stored = idx
+            .stored_compounded_rate(&dates, 0.001, 1.5, 2, 3)
+            .unwrap();
+        let mapped = idx
+            .compounded_rate_with(&dates, &fixings, 0.001, 1.5, 2, 3)
+            .unwrap();
+        assert_eq!(stored, mapped);
+    }
+
+    #[test]
+    fn lockout_repeats_last_observed_fixing() {
+        let idx = make_test_on();
+        let dates = daily_dates(Date::from_ymd(2025, 6, 2).unwrap(), 10);
+        let fixings: BTreeMap<Date, Rate> = dates
+            .iter()
+            .enumerate()
+            .map(|(i, &d)| (d, 0.03 + 0.001 * i as Rate))
+            .collect();
+
+        let rate = idx
+            .compounded_rate_with(&dates, &fixings, 0.0, 1.0, 0, 2)
+            .unwrap();
+        // Sub-periods 8 and 9 reuse the fixing of sub-period 7.
+        let growth: Real = (0..10)
+            .map(|i| 1.0 + (0.03 + 0.001 * i.min(7) as Rate) / 360.0)
+            .product();
+        let expected = (growth - 1.0) / (10.0 / 360.0);
+        assert!((rate - expected).abs() < 1e-14, "{rate} vs {expected}");
+
+        let unlocked = idx.compounded_rate(&dates, &fixings, 0.0, 1.0).unwrap();
+        assert!(rate < unlocked);
+        assert!(idx
+            .compounded_rate_with(&dates, &fixings, 0.0, 1.0, 0, 10)
+            .is_err());
+    }
 }
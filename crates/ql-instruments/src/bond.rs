@@ -4,9 +4,12 @@
 //! `ql/instruments/bonds/fixedratebond.hpp`, `ql/instruments/bonds/floatingratebond.hpp`.
 
 use crate::instrument::{Instrument, PricingEngine, PricingResults};
-use ql_cashflows::{CashFlow, Coupon, FixedRateLegBuilder, IborLegBuilder, Leg, Redemption};
+use ql_cashflows::{
+    CashFlow, Coupon, Duration, FixedRateLegBuilder, IborLegBuilder, Leg, Redemption,
+};
 use ql_core::{errors::Result, Compounding, Real};
 use ql_indexes::IborIndex;
+use ql_termstructures::YieldTermStructure;
 use ql_time::{Actual365Fixed, Calendar, Date, DayCounter, Frequency, InterestRate, Schedule};
 use std::sync::Arc;
 
@@ -91,25 +94,27 @@ impl Bond {
         self.clean_price_from_dirty(dirty, settlement)
     }
 
-    /// Dirty price given a flat yield.
+    /// Dirty price given a flat yield, with cash-flow times measured with
+    /// `dc`.
     pub fn dirty_price_yield(
         &self,
         yield_rate: Real,
-        _dc: &dyn DayCounter,
+        dc: &dyn DayCounter,
         comp: Compounding,
         freq: Frequency,
         settlement: Date,
     ) -> Real {
         let ir = InterestRate::new(yield_rate, Actual365Fixed, comp, freq);
-        let npv = ql_cashflows::npv_yield(&self.cashflows, &ir, settlement);
+        let npv = ql_cashflows::npv_yield_with_day_counter(&self.cashflows, &ir, dc, settlement);
         npv / self.face_amount * 100.0
     }
 
-    /// Yield to maturity given a clean price, solved via Brent's method.
+    /// Yield to maturity given a clean price, solved via Brent's method on
+    /// cash-flow times measured with `dc`.
     pub fn yield_to_maturity(
         &self,
         clean_price: Real,
-        _dc: &dyn DayCounter,
+        dc: &dyn DayCounter,
         comp: Compounding,
         freq: Frequency,
         settlement: Date,
@@ -117,9 +122,10 @@ impl Bond {
     ) -> Result<Real> {
         let dirty_price = self.dirty_price_from_clean(clean_price, settlement);
         let target_npv = dirty_price / 100.0 * self.face_amount;
-        ql_cashflows::yield_rate(
+        ql_cashflows::yield_rate_with_day_counter(
             &self.cashflows,
             target_npv,
+            dc,
             comp,
             freq,
             settlement,
@@ -127,6 +133,59 @@ impl Bond {
         )
     }
 
+    /// Duration of the bond's remaining cash flows at a flat yield, on
+    /// `dc` times.
+    pub fn duration(
+        &self,
+        yield_rate: Real,
+        dc: &dyn DayCounter,
+        comp: Compounding,
+        freq: Frequency,
+        duration_type: Duration,
+        settlement: Date,
+    ) -> Real {
+        let ir = InterestRate::new(yield_rate, Actual365Fixed, comp, freq);
+        ql_cashflows::duration_with_day_counter(&self.cashflows, &ir, dc, duration_type, settlement)
+    }
+
+    /// Convexity of the bond's remaining cash flows at a flat yield, on
+    /// `dc` times.
+    pub fn convexity(
+        &self,
+        yield_rate: Real,
+        dc: &dyn DayCounter,
+        comp: Compounding,
+        freq: Frequency,
+        settlement: Date,
+    ) -> Real {
+        let ir = InterestRate::new(yield_rate, Actual365Fixed, comp, freq);
+        ql_cashflows::convexity_with_day_counter(&self.cashflows, &ir, dc, settlement)
+    }
+
+    /// Effective duration on `curve`, shifting its zero rates by `±bump`.
+    ///
+    /// See [`ql_cashflows::effective_duration`].
+    pub fn effective_duration(
+        &self,
+        curve: &dyn YieldTermStructure,
+        settlement: Date,
+        bump: Real,
+    ) -> Real {
+        ql_cashflows::effective_duration(&self.cashflows, curve, settlement, bump)
+    }
+
+    /// Effective convexity on `curve`, shifting its zero rates by `±bump`.
+    ///
+    /// See [`ql_cashflows::effective_convexity`].
+    pub fn effective_convexity(
+        &self,
+        curve: &dyn YieldTermStructure,
+        settlement: Date,
+        bump: Real,
+    ) -> Real {
+        ql_cashflows::effective_convexity(&self.cashflows, curve, settlement, bump)
+    }
+
     /// Price the bond with an external engine.
     pub fn price(
         &self,
@@ -380,6 +439,133 @@ mod tests {
         assert!((y - 0.05).abs() < 1e-4, "yield = {y}");
     }
 
+    #[test]
+    fn bullet_bond_durations_agree() {
+        let start = Date::from_ymd(2025, 1, 15).unwrap();
+        let end = Date::from_ymd(2035, 1, 15).unwrap();
+        let schedule =
+            ScheduleBuilder::new(start, end, Period::new(6, TimeUnit::Months), &NullCalendar)
+                .build()
+                .unwrap();
+        let bond = fixed_rate_bond(
+            0,
+            100.0,
+            &schedule,
+            vec![0.045],
+            Compounding::Simple,
+            Frequency::Semiannual,
+            NullCalendar,
+        );
+        let dc = Actual365Fixed;
+        let y = 0.04;
+
+        for freq in [
+            Frequency::Annual,
+            Frequency::Semiannual,
+            Frequency::Quarterly,
+        ] {
+            let f = freq as i32 as Real;
+            let duration = |kind| bond.duration(y, &dc, Compounding::Compounded, freq, kind, start);
+            let macaulay = duration(Duration::Macaulay);
+            let modified = duration(Duration::Modified);
+            assert!((macaulay - modified * (1.0 + y / f)).abs() < 1e-10);
+        }
+
+        // On a flat continuous curve the bump recovers the continuous
+        // modified duration, which is the Macaulay one.
+        let curve = ql_termstructures::FlatForward::continuous(start, y, dc);
+        let continuous = |kind| {
+            bond.duration(
+                y,
+                &dc,
+                Compounding::Continuous,
+                Frequency::NoFrequency,
+                kind,
+                start,
+            )
+        };
+        let modified = continuous(Duration::Modified);
+        assert!((modified - continuous(Duration::Macaulay)).abs() < 1e-12);
+        let bump = 1e-4;
+        let effective = bond.effective_duration(&curve, start, bump);
+        // Central differences are off by O(bump²) times the third moment.
+        assert!(
+            (effective - modified).abs() < 1e-5,
+            "{effective} vs {modified}"
+        );
+
+        // The second difference sits where the repricing under a real
+        // shift puts it.
+        let convexity = bond.effective_convexity(&curve, start, bump);
+        let shifted = ql_termstructures::FlatForward::continuous(start, y + 0.01, dc);
+        let base = bond.dirty_price_yield(
+            y,
+            &dc,
+            Compounding::Continuous,
+            Frequency::NoFrequency,
+            start,
+        );
+        let repriced = ql_cashflows::npv_curve(&bond.cashflows, &shifted, start);
+        let estimate = base * (1.0 - 0.01 * effective + 0.5 * 1e-4 * convexity);
+        assert!(convexity > 0.0);
+        assert!(
+            (repriced - estimate).abs() < 1e-3 * base,
+            "{repriced} vs {estimate}"
+        );
+    }
+
+    #[test]
+    fn flat_yield_analytics_use_the_given_day_counter() {
+        use ql_time::Actual360;
+
+        let start = Date::from_ymd(2025, 1, 15).unwrap();
+        let end = Date::from_ymd(2030, 1, 15).unwrap();
+        let schedule =
+            ScheduleBuilder::new(start, end, Period::new(1, TimeUnit::Years), &NullCalendar)
+                .build()
+                .unwrap();
+        let bond = fixed_rate_bond(
+            0,
+            100.0,
+            &schedule,
+            vec![0.05],
+            Compounding::Simple,
+            Frequency::Annual,
+            NullCalendar,
+        );
+        let (y, comp, freq): (Real, _, _) = (0.05, Compounding::Compounded, Frequency::Annual);
+
+        // Price, Macaulay duration and convexity by hand on Actual/360 times.
+        let (mut npv, mut t_pv, mut t2_pv) = (0.0, 0.0, 0.0);
+        for cf in &bond.cashflows {
+            let t = Actual360.year_fraction(start, cf.date());
+            let pv = cf.amount() * (1.0 + y).powf(-t);
+            npv += pv;
+            t_pv += t * pv;
+            t2_pv += t * (t + 1.0) * pv / (1.0 + y).powi(2);
+        }
+
+        let dirty = bond.dirty_price_yield(y, &Actual360, comp, freq, start);
+        assert!((dirty - npv).abs() < 1e-12, "{dirty} vs {npv}");
+        let on_a365 = bond.dirty_price_yield(y, &Actual365Fixed, comp, freq, start);
+        assert!((dirty - on_a365).abs() > 0.1, "{dirty} vs {on_a365}");
+
+        let duration = bond.duration(y, &Actual360, comp, freq, Duration::Macaulay, start);
+        assert!((duration - t_pv / npv).abs() < 1e-12, "duration {duration}");
+        let convexity = bond.convexity(y, &Actual360, comp, freq, start);
+        assert!(
+            (convexity - t2_pv / npv).abs() < 1e-12,
+            "convexity {convexity}"
+        );
+
+        // The yield solved on the same day counter comes back.
+        let clean = bond.clean_price_from_dirty(dirty, start);
+        let solved = bond
+            .yield_to_maturity(clean, &Actual360, comp, freq, start, 1e-12)
+            .unwrap();
+        assert!((solved - y).abs() < 1e-9, "yield = {solved}");
+    }
+
     #[test]
     fn floating_rate_bond_construction() {
         let index = Arc::new(IborIndex::new(
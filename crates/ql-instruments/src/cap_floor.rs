@@ -0,0 +1,270 @@
+//! Interest-rate caps and floors.
+//!
+//! Translates `ql/instruments/capfloor.hpp`.
+//!
+//! A cap (floor) on a floating leg pays, for each coupon, the excess of the
+//! coupon rate over (shortfall below) the strike, on the coupon's nominal
+//! and accrual period: a strip of caplets (floorlets).
+
+use crate::instrument::{Instrument, PricingEngine, PricingResults};
+use ql_cashflows::{Coupon, FloatingRateCoupon, IborCoupon, Leg};
+use ql_core::{ensure, errors::Result, fail, Rate, Real, Settings, Time};
+use ql_time::Date;
+
+/// Cap or floor.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum CapFloorType {
+    /// A strip of calls on the coupon rate.
+    Cap,
+    /// A strip of puts on the coupon rate.
+    Floor,
+}
+
+/// Per-optionlet data for pricing a cap or floor, one entry per coupon of
+/// the floating leg.
+///
+/// Corresponds to `QuantLib::CapFloor::arguments`.
+#[derive(Debug, Clone)]
+pub struct CapFloorArguments {
+    /// Cap or floor.
+    pub cap_floor_type: CapFloorType,
+    /// Accrual start dates, over which the index forward is projected.
+    pub start_dates: Vec<Date>,
+    /// Accrual end dates.
+    pub end_dates: Vec<Date>,
+    /// Index fixing dates, at which each optionlet expires.
+    pub fixing_dates: Vec<Date>,
+    /// Payment dates.
+    pub payment_dates: Vec<Date>,
+    /// Accrual periods (year fractions).
+    pub accrual_times: Vec<Time>,
+    /// Coupon nominals.
+    pub nominals: Vec<Real>,
+    /// Coupon gearings on the index rate.
+    pub gearings: Vec<Real>,
+    /// Coupon spreads over the geared index rate.
+    pub spreads: Vec<Rate>,
+    /// Strikes on the coupon rate.
+    pub strikes: Vec<Rate>,
+    /// Index fixings already stored for each coupon, which price the
+    /// optionlets whose fixing date has passed.
+    pub fixings: Vec<Option<Rate>>,
+}
+
+/// A cap or floor on the coupons of a floating-rate leg.
+///
+/// Corresponds to `QuantLib::CapFloor`.
+#[derive(Debug)]
+pub struct CapFloor {
+    cap_floor_type: CapFloorType,
+    floating_leg: Leg,
+    strikes: Vec<Rate>,
+}
+
+impl CapFloor {
+    /// Create a cap or floor on `floating_leg` with one strike per coupon;
+    /// the last strike is extended to any remaining coupons.
+    ///
+    /// # Errors
+    /// Fails if the leg or the strikes are empty, or the leg holds a cash
+    /// flow other than an Ibor or floating-rate coupon.
+    pub fn new(
+        cap_floor_type: CapFloorType,
+        floating_leg: Leg,
+        strikes: Vec<Rate>,
+    ) -> Result<Self> {
+        ensure!(!floating_leg.is_empty(), "no floating coupons given");
+        ensure!(!strikes.is_empty(), "no strikes given");
+        for (i, cf) in floating_leg.iter().enumerate() {
+            let any = cf.as_any();
+            ensure!(
+                any.is::<IborCoupon>() || any.is::<FloatingRateCoupon>(),
+                "cash flow {i} of the leg is not a floating-rate coupon"
+            );
+        }
+        Ok(Self {
+            cap_floor_type,
+            floating_leg,
+            strikes,
+        })
+    }
+
+    /// A cap struck at `strike` on every coupon of `floating_leg`.
+    ///
+    /// # Errors
+    /// See [`CapFloor::new`].
+    pub fn cap(floating_leg: Leg, strike: Rate) -> Result<Self> {
+        Self::new(CapFloorType::Cap, floating_leg, vec![strike])
+    }
+
+    /// A floor struck at `strike` on every coupon of `floating_leg`.
+    ///
+    /// # Errors
+    /// See [`CapFloor::new`].
+    pub fn floor(floating_leg: Leg, strike: Rate) -> Result<Self> {
+        Self::new(CapFloorType::Floor, floating_leg, vec![strike])
+    }
+
+    /// Cap or floor.
+    pub fn cap_floor_type(&self) -> CapFloorType {
+        self.cap_floor_type
+    }
+
+    /// The underlying floating leg.
+    pub fn floating_leg(&self) -> &Leg {
+        &self.floating_leg
+    }
+
+    /// The strike on the `i`-th coupon.
+    pub fn strike(&self, i: usize) -> Rate {
+        self.strikes[i.min(self.strikes.len() - 1)]
+    }
+
+    /// Engine arguments, one optionlet per coupon.
+    ///
+    /// # Errors
+    /// Fails if a cash flow of the leg is not a floating-rate coupon.
+    pub fn arguments(&self) -> Result<CapFloorArguments> {
+        let n = self.floating_leg.len();
+        let mut args = CapFloorArguments {
+            cap_floor_type: self.cap_floor_type,
+            start_dates: Vec::with_capacity(n),
+            end_dates: Vec::with_capacity(n),
+            fixing_dates: Vec::with_capacity(n),
+            payment_dates: Vec::with_capacity(n),
+            accrual_times: Vec::with_capacity(n),
+            nominals: Vec::with_capacity(n),
+            gearings: Vec::with_capacity(n),
+            spreads: Vec::with_capacity(n),
+            strikes: Vec::with_capacity(n),
+            fixings: Vec::with_capacity(n),
+        };
+        for (i, cf) in self.floating_leg.iter().enumerate() {
+            let any = cf.as_any();
+            let (coupon, fixing_date, gearing, spread, fixing): (&dyn Coupon, _, _, _, _) =
+                if let Some(c) = any.downcast_ref::<IborCoupon>() {
+                    let fixing = c.index_fixing().ok();
+                    (c, c.fixing_date(), c.gearing(), c.spread(), fixing)
+                } else if let Some(c) = any.downcast_ref::<FloatingRateCoupon>() {
+                    (c, c.fixing_date(), c.gearing(), c.spread(), c.index_rate())
+                } else {
+                    fail!("cash flow {i} of the leg is not a floating-rate coupon")
+                };
+            args.start_dates.push(coupon.accrual_start_date());
+            args.end_dates.push(coupon.accrual_end_date());
+            args.fixing_dates.push(fixing_date);
+            args.payment_dates.push(cf.date());
+            args.accrual_times.push(coupon.accrual_period());
+            args.nominals.push(coupon.nominal());
+            args.gearings.push(gearing);
+            args.spreads.push(spread);
+            args.strikes.push(self.strike(i));
+            args.fixings.push(fixing);
+        }
+        Ok(args)
+    }
+
+    /// Price with a pricing engine.
+    pub fn price(&self, engine: &dyn PricingEngine<CapFloorArguments>) -> Result<PricingResults> {
+        engine.calculate(&self.arguments()?)
+    }
+}
+
+impl Instrument for CapFloor {
+    /// Whether the last coupon has been paid by the evaluation date; never
+    /// expired while no evaluation date is set.
+    fn is_expired(&self) -> bool {
+        match (
+            Settings::instance().evaluation_date_serial(),
+            self.maturity_date(),
+        ) {
+            (Some(today), Some(last_payment)) => last_payment.serial() <= today,
+            _ => false,
+        }
+    }
+
+    fn maturity_date(&self) -> Option<Date> {
+        self.floating_leg.iter().map(|cf| cf.date()).max()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use ql_cashflows::{FixedRateLegBuilder, IborLegBuilder, Leg};
+    use ql_currencies::currencies::america::USD;
+    use ql_indexes::IborIndex;
+    use ql_time::{
+        Actual365Fixed, BusinessDayConvention, NullCalendar, Period, ScheduleBuilder, TimeUnit,
+    };
+    use std::sync::Arc;
+
+    #[test]
+    fn arguments_follow_the_coupons() {
+        let start = Date::from_ymd(2025, 1, 15).unwrap();
+        let end = Date::from_ymd(2026, 1, 15).unwrap();
+        let cal = NullCalendar;
+        let schedule = ScheduleBuilder::new(start, end, Period::new(3, TimeUnit::Months), &cal)
+            .build()
+            .unwrap();
+        let index = Arc::new(IborIndex::new(
+            "USD-Libor-3M",
+            Period::new(3, TimeUnit::Months),
+            2,
+            &USD,
+            NullCalendar,
+            BusinessDayConvention::ModifiedFollowing,
+            false,
+            Actual365Fixed,
+        ));
+        let leg = IborLegBuilder::new(&schedule, index)
+            .with_notionals(vec![1_000_000.0])
+            .with_spread(0.001)
+            .build();
+
+        let cap = CapFloor::new(CapFloorType::Cap, leg, vec![0.03, 0.035]).unwrap();
+        let args = cap.arguments().unwrap();
+        assert_eq!(args.strikes, vec![0.03, 0.035, 0.035, 0.035]);
+        assert_eq!(args.start_dates[0], start);
+        assert_eq!(*args.end_dates.last().unwrap(), end);
+        assert_eq!(args.fixing_dates[0], start - 2);
+        assert!(args.spreads.iter().all(|&s| s == 0.001));
+        assert!(args.fixings.iter().all(Option::is_none));
+        assert_eq!(cap.maturity_date(), Some(end));
+
+        // Only floating coupons can be capped.
+        let fixed = FixedRateLegBuilder::new(&schedule)
+            .with_coupon_rate(0.03)
+            .build();
+        assert!(CapFloor::floor(fixed, 0.03).is_err());
+    }
+
+    #[test]
+    fn expires_after_the_last_payment() {
+        use ql_core::ScopedEvaluationDate;
+
+        let start = Date::from_ymd(2025, 1, 15).unwrap();
+        let end = Date::from_ymd(2025, 7, 15).unwrap();
+        let cal = NullCalendar;
+        let schedule = ScheduleBuilder::new(start, end, Period::new(3, TimeUnit::Months), &cal)
+            .build()
+            .unwrap();
+        let leg: Leg = schedule
+            .dates()
+            .windows(2)
+            .map(|w| {
+                Box::new(
+                    FloatingRateCoupon::new(w[1], 1.0, w[0], w[1], w[0], 1.0, 0.0, Actual365Fixed)
+                        .with_rate(0.03),
+                ) as Box<dyn ql_cashflows::CashFlow>
+            })
+            .collect();
+        let cap = CapFloor::cap(leg, 0.03).unwrap();
+        assert_eq!(cap.arguments().unwrap().fixings, vec![Some(0.03); 2]);
+
+        let _today = ScopedEvaluationDate::new((end - 1).serial());
+        assert!(!cap.is_expired());
+        let _today = ScopedEvaluationDate::new(end.serial());
+        assert!(cap.is_expired());
+    }
+}
@@ -0,0 +1,118 @@
+//! Equity dividend future.
+//!
+//! QuantLib has no dividend-future instrument.  The contract settles on the
+//! sum of the dividends an underlying pays over a period; its fair price
+//! under an [`ImpliedDividendCurve`] is the curve's expected dividend over
+//! that period, and a long position gains the excess over the traded price
+//! at settlement.
+
+use crate::instrument::Instrument;
+use ql_core::Real;
+use ql_termstructures::{DividendFutureHelper, ImpliedDividendCurve, YieldTermStructure};
+use ql_time::Date;
+
+/// A future on the dividends paid in `(period_start, period_end]`.
+#[derive(Debug, Clone)]
+pub struct DividendFuture {
+    period_start: Date,
+    period_end: Date,
+    settlement_date: Date,
+    price: Real,
+    quantity: Real,
+}
+
+impl DividendFuture {
+    /// A future traded at `price`, settling on `settlement_date`, on the
+    /// dividends of one share over `(period_start, period_end]`.
+    pub fn new(period_start: Date, period_end: Date, settlement_date: Date, price: Real) -> Self {
+        Self {
+            period_start,
+            period_end,
+            settlement_date,
+            price,
+            quantity: 1.0,
+        }
+    }
+
+    /// Set the number of shares the contract covers (default 1).
+    pub fn with_quantity(mut self, quantity: Real) -> Self {
+        self.quantity = quantity;
+        self
+    }
+
+    /// Start of the dividend period (excluded).
+    pub fn period_start(&self) -> Date {
+        self.period_start
+    }
+
+    /// End of the dividend period (included).
+    pub fn period_end(&self) -> Date {
+        self.period_end
+    }
+
+    /// Traded price.
+    pub fn price(&self) -> Real {
+        self.price
+    }
+
+    /// The contract as a bootstrap quote at its traded price.
+    pub fn helper(&self) -> DividendFutureHelper {
+        DividendFutureHelper::new(self.period_start, self.period_end, self.price)
+    }
+
+    /// Fair price: the expected dividends over the period.
+    pub fn fair_price(&self, dividends: &ImpliedDividendCurve) -> Real {
+        dividends.expected_dividend(self.period_start, self.period_end)
+    }
+
+    /// Value of a long position, discounted from settlement.
+    pub fn npv(&self, dividends: &ImpliedDividendCurve, discount: &dyn YieldTermStructure) -> Real {
+        self.quantity
+            * (self.fair_price(dividends) - self.price)
+            * discount.discount_date(self.settlement_date)
+    }
+}
+
+impl Instrument for DividendFuture {
+    fn is_expired(&self) -> bool {
+        false
+    }
+
+    fn maturity_date(&self) -> Option<Date> {
+        Some(self.settlement_date)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use ql_termstructures::FlatForward;
+    use ql_time::Actual365Fixed;
+
+    #[test]
+    fn futures_reprice_to_zero_on_their_own_curve() {
+        let reference = Date::from_ymd(2025, 1, 2).unwrap();
+        let year_end = Date::from_ymd(2025, 12, 31).unwrap();
+        let futures = [
+            DividendFuture::new(reference, year_end, year_end + 20, 3.4),
+            DividendFuture::new(
+                year_end,
+                Date::from_ymd(2026, 12, 31).unwrap(),
+                year_end + 385,
+                3.7,
+            ),
+        ];
+        let helpers: Vec<_> = futures.iter().map(DividendFuture::helper).collect();
+        let curve = ImpliedDividendCurve::bootstrap(reference, &helpers).unwrap();
+        let discount = FlatForward::continuous(reference, 0.03, Actual365Fixed);
+
+        for future in &futures {
+            assert!((future.fair_price(&curve) - future.price()).abs() < 1e-14);
+            assert!(future.npv(&curve, &discount).abs() < 1e-14);
+        }
+        let cheap =
+            DividendFuture::new(reference, year_end, year_end + 20, 3.0).with_quantity(100.0);
+        let expected = 100.0 * 0.4 * discount.discount_date(year_end + 20);
+        assert!((cheap.npv(&curve, &discount) - expected).abs() < 1e-12);
+    }
+}
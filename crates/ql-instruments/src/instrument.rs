@@ -7,7 +7,8 @@
 //! simpler trait-based approach: concrete instruments hold their market data
 //! and compute results on demand.
 
-use ql_core::{errors::Result, Real};
+use ql_core::{errors::Result, fail, Position, Real};
+use ql_currencies::Currency;
 use ql_time::Date;
 use std::collections::HashMap;
 
@@ -40,8 +41,38 @@ impl PricingResults {
         self.additional_results.insert(key.into(), value);
         self
     }
+
+    /// Express the results for the holder of `position`.
+    ///
+    /// A short position negates the NPV and every additional result except
+    /// the entries in [`POSITION_INVARIANT_RESULTS`] (quotes and leg values
+    /// that describe the instrument rather than the holding). The error
+    /// estimate is unchanged.
+    pub fn with_position(mut self, position: Position) -> Self {
+        let sign = position.sign();
+        self.npv *= sign;
+        for (key, value) in self.additional_results.iter_mut() {
+            if !POSITION_INVARIANT_RESULTS.contains(&key.as_str()) {
+                *value *= sign;
+            }
+        }
+        self
+    }
 }
 
+/// Additional-result keys that [`PricingResults::with_position`] leaves
+/// untouched.
+pub const POSITION_INVARIANT_RESULTS: &[&str] = &[
+    "clean_price",
+    "dirty_price",
+    "fair_rate",
+    "fair_spread",
+    "fixed_leg_npv",
+    "floating_leg_npv",
+    "implied_volatility",
+    "settlement_df",
+];
+
 /// Base trait for all pricing engines.
 ///
 /// A pricing engine computes `PricingResults` for a specific instrument type.
@@ -63,6 +94,21 @@ pub trait Instrument: std::fmt::Debug + Send + Sync {
     fn maturity_date(&self) -> Option<Date> {
         None
     }
+
+    /// Results of the instrument's last pricing.
+    ///
+    /// Most instruments are priced by handing an engine to their `price`
+    /// method and keep no results, so the default fails; see
+    /// [`PricedInstrument`](crate::portfolio::PricedInstrument) for a holder
+    /// that stores them.
+    fn results(&self) -> Result<PricingResults> {
+        fail!("no pricing results are available for this instrument")
+    }
+
+    /// Currency in which the NPV is expressed, if known.
+    fn currency(&self) -> Option<&'static Currency> {
+        None
+    }
 }
 
 #[cfg(test)]
@@ -78,4 +124,19 @@ mod tests {
         assert!((r.additional_results["delta"] - 0.55).abs() < 1e-15);
         assert!((r.additional_results["gamma"] - 0.02).abs() < 1e-15);
     }
+
+    #[test]
+    fn pricing_results_short_position() {
+        let long = PricingResults::from_npv(42.0)
+            .with_result("delta", 0.55)
+            .with_result("fair_spread", 0.01);
+        let mut short = long.clone().with_position(Position::Short);
+        short.error_estimate = Some(0.1);
+        assert_eq!(short.npv, -42.0);
+        assert_eq!(short.additional_results["delta"], -0.55);
+        assert_eq!(short.additional_results["fair_spread"], 0.01);
+        let same = long.clone().with_position(Position::Long);
+        assert_eq!(same.npv, long.npv);
+        assert_eq!(same.additional_results, long.additional_results);
+    }
 }
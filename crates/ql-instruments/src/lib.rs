@@ -6,22 +6,30 @@
 #![forbid(unsafe_code)]
 
 pub mod bond;
+pub mod cap_floor;
+pub mod dividend_future;
 pub mod exercise;
 pub mod instrument;
 pub mod option;
 pub mod payoff;
+pub mod portfolio;
 pub mod swap;
 pub mod zero_coupon_inflation_swap;
 
 pub use bond::{fixed_rate_bond, floating_rate_bond, zero_coupon_bond, Bond, BondArguments};
+pub use cap_floor::{CapFloor, CapFloorArguments, CapFloorType};
+pub use dividend_future::DividendFuture;
 pub use exercise::{Exercise, ExerciseType};
-pub use instrument::{Instrument, PricingEngine, PricingResults};
+pub use instrument::{Instrument, PricingEngine, PricingResults, POSITION_INVARIANT_RESULTS};
 pub use option::{
-    BarrierOption, BarrierOptionArguments, BarrierType, VanillaOption, VanillaOptionArguments,
+    AverageType, BarrierOption, BarrierOptionArguments, BarrierType,
+    ContinuousAveragingAsianOption, ContinuousAveragingAsianOptionArguments, TouchOption,
+    TouchOptionArguments, TouchPayment, VanillaOption, VanillaOptionArguments,
 };
 pub use payoff::{
-    AssetOrNothingPayoff, CashOrNothingPayoff, GapPayoff, OptionType, Payoff, PlainVanillaPayoff,
-    StrikedPayoff,
+    AssetOrNothingPayoff, CashOrNothingPayoff, GapPayoff, OptionType, Payoff, PayoffVisitor,
+    PlainVanillaPayoff, StrikedPayoff,
 };
+pub use portfolio::{AggregateGreeks, Portfolio, PricedInstrument};
 pub use swap::{Swap, SwapArguments, SwapType, VanillaSwap};
 pub use zero_coupon_inflation_swap::{SwapPayerType, ZeroCouponInflationSwap};
@@ -3,10 +3,13 @@
 //! Translates `ql/instruments/vanillaoption.hpp`,
 //! `ql/instruments/oneassetoption.hpp`.
 
-use crate::exercise::Exercise;
+use crate::exercise::{Exercise, ExerciseType};
 use crate::instrument::{Instrument, PricingEngine, PricingResults};
 use crate::payoff::{OptionType, PlainVanillaPayoff, StrikedPayoff};
-use ql_core::{errors::Result, Real};
+use ql_core::{ensure, errors::Result, Position, Real, Volatility};
+use ql_math::distributions::normal_cdf;
+use ql_math::solvers1d::brent;
+use ql_processes::GeneralizedBlackScholesProcess;
 use ql_time::Date;
 use std::sync::Arc;
 
@@ -38,20 +41,37 @@ pub struct VanillaOption {
     payoff: Arc<dyn StrikedPayoff>,
     /// The exercise specification.
     exercise: Exercise,
+    /// Long (bought) or short (sold) holding.
+    position: Position,
 }
 
 impl VanillaOption {
-    /// Create a new vanilla option.
+    /// Create a new (long) vanilla option.
     pub fn new(payoff: Arc<dyn StrikedPayoff>, exercise: Exercise) -> Self {
-        Self { payoff, exercise }
+        Self {
+            payoff,
+            exercise,
+            position: Position::Long,
+        }
     }
 
-    /// Convenience: create a European call/put.
+    /// Convenience: create a (long) European call/put.
     pub fn european(option_type: OptionType, strike: Real, expiry: Date) -> Self {
-        Self {
-            payoff: Arc::new(PlainVanillaPayoff::new(option_type, strike)),
-            exercise: Exercise::european(expiry),
-        }
+        Self::new(
+            Arc::new(PlainVanillaPayoff::new(option_type, strike)),
+            Exercise::european(expiry),
+        )
+    }
+
+    /// Set the position; a short option reports negated NPV and Greeks.
+    pub fn with_position(mut self, position: Position) -> Self {
+        self.position = position;
+        self
+    }
+
+    /// The position held.
+    pub fn position(&self) -> Position {
+        self.position
     }
 
     /// The strike price.
@@ -82,15 +102,88 @@ impl VanillaOption {
         }
     }
 
-    /// Price this option using the given engine.
+    /// Price this option using the given engine, from the point of view of
+    /// the holder's [`position`](Self::position).
     pub fn price(
         &self,
         engine: &dyn PricingEngine<VanillaOptionArguments>,
     ) -> Result<PricingResults> {
-        engine.calculate(&self.arguments())
+        Ok(engine
+            .calculate(&self.arguments())?
+            .with_position(self.position))
+    }
+
+    /// Black-Scholes volatility that reproduces `target_price`.
+    ///
+    /// Spot, discounting and dividends are taken from `process`; its own
+    /// volatility is ignored. The price is that of one long option, whatever
+    /// the [`position`](Self::position). The volatility is searched in
+    /// `[1e-7, max_vol]` with Brent's method.
+    ///
+    /// Fails for non-European exercise, when `target_price` is below the
+    /// discounted intrinsic value or above the no-arbitrage upper bound, or
+    /// when it is above the price at `max_vol`.
+    ///
+    /// Corresponds to `QuantLib::VanillaOption::impliedVolatility`.
+    pub fn implied_volatility(
+        &self,
+        target_price: Real,
+        process: &GeneralizedBlackScholesProcess,
+        accuracy: Real,
+        max_vol: Volatility,
+    ) -> Result<Volatility> {
+        const MIN_VOL: Volatility = 1.0e-7;
+        ensure!(
+            self.exercise.exercise_type() == ExerciseType::European,
+            "implied volatility is only available for European options"
+        );
+        ensure!(
+            max_vol > MIN_VOL,
+            "max_vol ({max_vol}) must exceed {MIN_VOL}"
+        );
+
+        let rf = process.risk_free_rate();
+        let t = rf
+            .day_counter()
+            .year_fraction(rf.reference_date(), self.exercise.last_date());
+        ensure!(t > 0.0, "option has expired");
+
+        let discount = rf.discount(t);
+        let forward = process.spot() * process.dividend_yield().discount(t) / discount;
+        let strike = self.strike();
+        let phi = self.option_type().sign();
+
+        let intrinsic = discount * (phi * (forward - strike)).max(0.0);
+        let upper_bound = discount
+            * match self.option_type() {
+                OptionType::Call => forward,
+                OptionType::Put => strike,
+            };
+        ensure!(
+            target_price >= intrinsic,
+            "target price ({target_price}) is below the intrinsic value ({intrinsic})"
+        );
+        ensure!(
+            target_price < upper_bound,
+            "target price ({target_price}) is above the no-arbitrage bound ({upper_bound})"
+        );
+
+        let sqrt_t = t.sqrt();
+        let f = |sigma: Volatility| {
+            black_price(phi, forward, strike, sigma * sqrt_t, discount) - target_price
+        };
+        brent(f, MIN_VOL, max_vol, accuracy)
     }
 }
 
+/// Discounted Black price for option sign `phi` and standard deviation
+/// `std_dev = σ√T`.
+fn black_price(phi: Real, forward: Real, strike: Real, std_dev: Real, discount: Real) -> Real {
+    let d1 = (forward / strike).ln() / std_dev + 0.5 * std_dev;
+    let d2 = d1 - std_dev;
+    discount * phi * (forward * normal_cdf(phi * d1) - strike * normal_cdf(phi * d2))
+}
+
 impl Instrument for VanillaOption {
     fn is_expired(&self) -> bool {
         false // would need Settings::evaluation_date()
@@ -205,10 +298,216 @@ impl Instrument for BarrierOption {
     }
 }
 
+// ────────────────────────────────────────────────────────────────────────────
+// TouchOption
+// ────────────────────────────────────────────────────────────────────────────
+
+/// When a one-touch pays out.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum TouchPayment {
+    /// As soon as the barrier is hit.
+    AtHit,
+    /// At expiry, provided the barrier was hit.
+    AtExpiry,
+}
+
+/// Arguments for a touch option.
+#[derive(Debug, Clone)]
+pub struct TouchOptionArguments {
+    /// The exercise specification; only the last date is used.
+    pub exercise: Exercise,
+    /// `DownIn`/`UpIn` for a one-touch, `DownOut`/`UpOut` for a no-touch.
+    pub barrier_type: BarrierType,
+    /// Barrier level.
+    pub barrier: Real,
+    /// Cash amount paid.
+    pub payout: Real,
+    /// Payment timing of a one-touch; a no-touch always pays at expiry.
+    pub payment: TouchPayment,
+}
+
+/// A one-touch or no-touch option: a fixed cash amount paid if the
+/// underlying does (one-touch) or does not (no-touch) reach the barrier
+/// before expiry.
+///
+/// Corresponds to a `QuantLib::BarrierOption` on a cash-or-nothing payoff.
+#[derive(Debug)]
+pub struct TouchOption {
+    exercise: Exercise,
+    /// Barrier type: knock-in types are one-touches, knock-out no-touches.
+    pub barrier_type: BarrierType,
+    /// Barrier level.
+    pub barrier: Real,
+    /// Cash amount paid.
+    pub payout: Real,
+    /// Payment timing.
+    pub payment: TouchPayment,
+}
+
+impl TouchOption {
+    /// A one-touch on a `DownIn` or `UpIn` barrier.
+    pub fn one_touch(
+        barrier_type: BarrierType,
+        barrier: Real,
+        payout: Real,
+        payment: TouchPayment,
+        expiry: Date,
+    ) -> Self {
+        Self {
+            exercise: Exercise::european(expiry),
+            barrier_type,
+            barrier,
+            payout,
+            payment,
+        }
+    }
+
+    /// A no-touch on a `DownOut` or `UpOut` barrier, paying at expiry.
+    pub fn no_touch(barrier_type: BarrierType, barrier: Real, payout: Real, expiry: Date) -> Self {
+        Self {
+            exercise: Exercise::european(expiry),
+            barrier_type,
+            barrier,
+            payout,
+            payment: TouchPayment::AtExpiry,
+        }
+    }
+
+    /// The exercise.
+    pub fn exercise(&self) -> &Exercise {
+        &self.exercise
+    }
+
+    /// Get engine arguments.
+    pub fn arguments(&self) -> TouchOptionArguments {
+        TouchOptionArguments {
+            exercise: self.exercise.clone(),
+            barrier_type: self.barrier_type,
+            barrier: self.barrier,
+            payout: self.payout,
+            payment: self.payment,
+        }
+    }
+}
+
+impl Instrument for TouchOption {
+    fn is_expired(&self) -> bool {
+        false
+    }
+
+    fn maturity_date(&self) -> Option<Date> {
+        Some(self.exercise.last_date())
+    }
+}
+
+// ────────────────────────────────────────────────────────────────────────────
+// ContinuousAveragingAsianOption
+// ────────────────────────────────────────────────────────────────────────────
+
+/// How an Asian option averages the underlying.
+///
+/// Corresponds to `QuantLib::Average::Type`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum AverageType {
+    /// Arithmetic mean.
+    Arithmetic,
+    /// Geometric mean.
+    Geometric,
+}
+
+/// Arguments for a continuously averaged Asian option.
+#[derive(Debug, Clone)]
+pub struct ContinuousAveragingAsianOptionArguments {
+    /// Arithmetic or geometric averaging.
+    pub average_type: AverageType,
+    /// Payoff applied to the average price.
+    pub payoff: Arc<dyn StrikedPayoff>,
+    /// The exercise specification; averaging runs from today to its last date.
+    pub exercise: Exercise,
+}
+
+/// An average-price Asian option on the continuous average of the
+/// underlying from today to expiry.
+///
+/// Corresponds to `QuantLib::ContinuousAveragingAsianOption`.
+#[derive(Debug)]
+pub struct ContinuousAveragingAsianOption {
+    average_type: AverageType,
+    payoff: Arc<dyn StrikedPayoff>,
+    exercise: Exercise,
+}
+
+impl ContinuousAveragingAsianOption {
+    /// Create a new continuously averaged Asian option.
+    pub fn new(
+        average_type: AverageType,
+        payoff: Arc<dyn StrikedPayoff>,
+        exercise: Exercise,
+    ) -> Self {
+        Self {
+            average_type,
+            payoff,
+            exercise,
+        }
+    }
+
+    /// The averaging type.
+    pub fn average_type(&self) -> AverageType {
+        self.average_type
+    }
+
+    /// The payoff.
+    pub fn payoff(&self) -> &dyn StrikedPayoff {
+        &*self.payoff
+    }
+
+    /// The exercise.
+    pub fn exercise(&self) -> &Exercise {
+        &self.exercise
+    }
+
+    /// Get engine arguments.
+    pub fn arguments(&self) -> ContinuousAveragingAsianOptionArguments {
+        ContinuousAveragingAsianOptionArguments {
+            average_type: self.average_type,
+            payoff: self.payoff.clone(),
+            exercise: self.exercise.clone(),
+        }
+    }
+}
+
+impl Instrument for ContinuousAveragingAsianOption {
+    fn is_expired(&self) -> bool {
+        false
+    }
+
+    fn maturity_date(&self) -> Option<Date> {
+        Some(self.exercise.last_date())
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::exercise::ExerciseType;
+    use ql_termstructures::{BlackConstantVol, FlatForward};
+    use ql_time::Actual365Fixed;
+
+    fn process(vol: Volatility) -> GeneralizedBlackScholesProcess {
+        let ref_date = Date::from_ymd(2025, 1, 2).unwrap();
+        let rf = Arc::new(FlatForward::continuous(ref_date, 0.05, Actual365Fixed));
+        let div = Arc::new(FlatForward::continuous(ref_date, 0.02, Actual365Fixed));
+        let vol = Arc::new(BlackConstantVol::new(ref_date, vol, Actual365Fixed));
+        GeneralizedBlackScholesProcess::new(100.0, rf, div, vol)
+    }
+
+    /// Price at `vol` with the same Black formula the solver inverts.
+    fn price_at(opt: &VanillaOption, vol: Volatility) -> Real {
+        let (r, q, t) = (0.05_f64, 0.02_f64, 1.0_f64);
+        let discount = (-r * t).exp();
+        let forward = 100.0 * ((r - q) * t).exp();
+        let phi = opt.option_type().sign();
+        black_price(phi, forward, opt.strike(), vol * t.sqrt(), discount)
+    }
 
     #[test]
     fn european_call_construction() {
@@ -246,4 +545,62 @@ mod tests {
         assert!((args.payoff.strike() - 100.0).abs() < 1e-15);
         assert_eq!(args.exercise.exercise_type(), ExerciseType::European);
     }
+
+    #[test]
+    fn implied_volatility_round_trip() {
+        let expiry = Date::from_ymd(2026, 1, 2).unwrap();
+        let process = process(0.35);
+        for (option_type, strike) in [
+            (OptionType::Call, 100.0),
+            (OptionType::Put, 100.0),
+            (OptionType::Call, 120.0),
+            (OptionType::Put, 80.0),
+        ] {
+            let opt = VanillaOption::european(option_type, strike, expiry);
+            let price = price_at(&opt, 0.20);
+            let vol = opt.implied_volatility(price, &process, 1e-10, 4.0).unwrap();
+            assert!(
+                (vol - 0.20).abs() < 1e-6,
+                "{option_type:?} {strike}: recovered {vol}"
+            );
+        }
+    }
+
+    #[test]
+    fn implied_volatility_deep_in_the_money() {
+        let expiry = Date::from_ymd(2026, 1, 2).unwrap();
+        let opt = VanillaOption::european(OptionType::Call, 50.0, expiry);
+        let price = price_at(&opt, 0.20);
+        let vol = opt
+            .implied_volatility(price, &process(0.20), 1e-10, 4.0)
+            .unwrap();
+        assert!((vol - 0.20).abs() < 1e-4, "recovered {vol}");
+    }
+
+    #[test]
+    fn implied_volatility_rejects_arbitrage_prices() {
+        let expiry = Date::from_ymd(2026, 1, 2).unwrap();
+        let process = process(0.20);
+        let call = VanillaOption::european(OptionType::Call, 80.0, expiry);
+        let intrinsic = price_at(&call, 1e-9);
+        assert!(call
+            .implied_volatility(intrinsic - 0.01, &process, 1e-8, 4.0)
+            .is_err());
+        // A call is never worth more than the dividend-discounted spot.
+        let spot_bound = 100.0 * (-0.02_f64).exp();
+        assert!(call
+            .implied_volatility(spot_bound + 0.01, &process, 1e-8, 4.0)
+            .is_err());
+    }
+
+    #[test]
+    fn implied_volatility_fails_above_the_max_vol_price() {
+        let expiry = Date::from_ymd(2026, 1, 2).unwrap();
+        let opt = VanillaOption::european(OptionType::Call, 100.0, expiry);
+        let price = price_at(&opt, 0.60);
+        let process = process(0.20);
+        assert!(opt.implied_volatility(price, &process, 1e-10, 0.5).is_err());
+        let vol = opt.implied_volatility(price, &process, 1e-10, 1.0).unwrap();
+        assert!((vol - 0.60).abs() < 1e-6, "recovered {vol}");
+    }
 }
@@ -1,42 +1,18 @@
 //! Option payoff hierarchy.
 //!
-//! Translates `ql/instruments/payoffs.hpp` and `ql/option.hpp` (Option::Type).
+//! Translates `ql/instruments/payoffs.hpp`.  [`OptionType`] lives in
+//! `ql-core`, so that numerical methods can name it, and is re-exported here.
 //!
 //! Payoffs describe the terminal (or exercise) payoff of an option as a
 //! function of the underlying asset price.
+//!
+//! Engines that price only some payoffs dispatch on the concrete type
+//! through a [`PayoffVisitor`] passed to [`Payoff::accept`].
 
-use ql_core::Real;
+use ql_core::{patterns::visitor::Visitor, Real};
 use std::fmt;
 
-/// Option type (call or put).
-///
-/// Corresponds to `QuantLib::Option::Type`.
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
-pub enum OptionType {
-    /// A call option (right to buy).
-    Call,
-    /// A put option (right to sell).
-    Put,
-}
-
-impl OptionType {
-    /// +1 for Call, −1 for Put.
-    pub fn sign(self) -> Real {
-        match self {
-            OptionType::Call => 1.0,
-            OptionType::Put => -1.0,
-        }
-    }
-}
-
-impl fmt::Display for OptionType {
-    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        match self {
-            OptionType::Call => write!(f, "Call"),
-            OptionType::Put => write!(f, "Put"),
-        }
-    }
-}
+pub use ql_core::OptionType;
 
 /// Base trait for option payoffs.
 ///
@@ -52,6 +28,35 @@ pub trait Payoff: fmt::Debug + Send + Sync {
     fn description(&self) -> String {
         self.name().to_string()
     }
+
+    /// Let `visitor` visit the concrete payoff.
+    ///
+    /// The default does nothing, as for payoffs no visitor knows about.
+    fn accept(&self, visitor: &mut dyn PayoffVisitor) {
+        let _ = visitor;
+    }
+}
+
+/// A visitor of each concrete payoff type.
+///
+/// Implemented for every type that is a [`Visitor`] of all four payoffs.
+///
+/// Corresponds to the `Visitor<Payoff>` family that QuantLib's payoffs
+/// accept.
+pub trait PayoffVisitor:
+    Visitor<PlainVanillaPayoff>
+    + Visitor<CashOrNothingPayoff>
+    + Visitor<AssetOrNothingPayoff>
+    + Visitor<GapPayoff>
+{
+}
+
+impl<V> PayoffVisitor for V where
+    V: Visitor<PlainVanillaPayoff>
+        + Visitor<CashOrNothingPayoff>
+        + Visitor<AssetOrNothingPayoff>
+        + Visitor<GapPayoff>
+{
 }
 
 /// A payoff depending on a strike price.
@@ -97,6 +102,10 @@ impl Payoff for PlainVanillaPayoff {
         "Vanilla"
     }
 
+    fn accept(&self, visitor: &mut dyn PayoffVisitor) {
+        Visitor::<PlainVanillaPayoff>::visit(visitor, self);
+    }
+
     fn description(&self) -> String {
         format!("{} {} @ {}", self.name(), self.option_type, self.strike)
     }
@@ -150,6 +159,10 @@ impl Payoff for CashOrNothingPayoff {
     fn name(&self) -> &str {
         "CashOrNothing"
     }
+
+    fn accept(&self, visitor: &mut dyn PayoffVisitor) {
+        Visitor::<CashOrNothingPayoff>::visit(visitor, self);
+    }
 }
 
 impl StrikedPayoff for CashOrNothingPayoff {
@@ -197,6 +210,10 @@ impl Payoff for AssetOrNothingPayoff {
     fn name(&self) -> &str {
         "AssetOrNothing"
     }
+
+    fn accept(&self, visitor: &mut dyn PayoffVisitor) {
+        Visitor::<AssetOrNothingPayoff>::visit(visitor, self);
+    }
 }
 
 impl StrikedPayoff for AssetOrNothingPayoff {
@@ -245,6 +262,10 @@ impl Payoff for GapPayoff {
     fn name(&self) -> &str {
         "Gap"
     }
+
+    fn accept(&self, visitor: &mut dyn PayoffVisitor) {
+        Visitor::<GapPayoff>::visit(visitor, self);
+    }
 }
 
 impl StrikedPayoff for GapPayoff {
@@ -290,6 +311,57 @@ mod tests {
         assert!((p.value(110.0) - 0.0).abs() < 1e-15);
     }
 
+    /// Records what each visited payoff is.
+    #[derive(Default)]
+    struct Metadata(Vec<(&'static str, Real, Option<Real>)>);
+
+    impl Visitor<PlainVanillaPayoff> for Metadata {
+        fn visit(&mut self, p: &PlainVanillaPayoff) {
+            self.0.push(("vanilla", p.strike, None));
+        }
+    }
+
+    impl Visitor<CashOrNothingPayoff> for Metadata {
+        fn visit(&mut self, p: &CashOrNothingPayoff) {
+            self.0.push(("cash", p.strike, Some(p.cash_payoff)));
+        }
+    }
+
+    impl Visitor<AssetOrNothingPayoff> for Metadata {
+        fn visit(&mut self, p: &AssetOrNothingPayoff) {
+            self.0.push(("asset", p.strike, None));
+        }
+    }
+
+    impl Visitor<GapPayoff> for Metadata {
+        fn visit(&mut self, p: &GapPayoff) {
+            self.0.push(("gap", p.strike, Some(p.second_strike)));
+        }
+    }
+
+    #[test]
+    fn visitor_identifies_each_payoff() {
+        let payoffs: Vec<Box<dyn Payoff>> = vec![
+            Box::new(PlainVanillaPayoff::new(OptionType::Call, 100.0)),
+            Box::new(CashOrNothingPayoff::new(OptionType::Put, 95.0, 10.0)),
+            Box::new(AssetOrNothingPayoff::new(OptionType::Call, 105.0)),
+            Box::new(GapPayoff::new(OptionType::Put, 90.0, 85.0)),
+        ];
+        let mut metadata = Metadata::default();
+        for payoff in &payoffs {
+            payoff.accept(&mut metadata);
+        }
+        assert_eq!(
+            metadata.0,
+            vec![
+                ("vanilla", 100.0, None),
+                ("cash", 95.0, Some(10.0)),
+                ("asset", 105.0, None),
+                ("gap", 90.0, Some(85.0)),
+            ]
+        );
+    }
+
     #[test]
     fn gap_payoff() {
         let p = GapPayoff::new(OptionType::Call, 100.0, 95.0);
@@ -0,0 +1,242 @@
+//! Portfolios of instruments.
+//!
+//! QuantLib has no portfolio class; books are usually valued by summing
+//! instrument NPVs by hand. [`Portfolio`] does the netting (each entry is
+//! held long or short) and the conversion to a reporting currency.
+
+use crate::instrument::{Instrument, PricingResults};
+use ql_core::{errors::Result, fail, Position, Real};
+use ql_currencies::{Currency, ExchangeRateManager, Money};
+use ql_time::Date;
+
+/// An instrument together with the results of a pricing and the currency
+/// they are expressed in.
+#[derive(Debug)]
+pub struct PricedInstrument<I> {
+    instrument: I,
+    results: PricingResults,
+    currency: &'static Currency,
+}
+
+impl<I: Instrument> PricedInstrument<I> {
+    /// Attach `results`, expressed in `currency`, to `instrument`.
+    pub fn new(instrument: I, results: PricingResults, currency: &'static Currency) -> Self {
+        Self {
+            instrument,
+            results,
+            currency,
+        }
+    }
+
+    /// The underlying instrument.
+    pub fn instrument(&self) -> &I {
+        &self.instrument
+    }
+}
+
+impl<I: Instrument> Instrument for PricedInstrument<I> {
+    fn is_expired(&self) -> bool {
+        self.instrument.is_expired()
+    }
+
+    fn maturity_date(&self) -> Option<Date> {
+        self.instrument.maturity_date()
+    }
+
+    fn results(&self) -> Result<PricingResults> {
+        Ok(self.results.clone())
+    }
+
+    fn currency(&self) -> Option<&'static Currency> {
+        Some(self.currency)
+    }
+}
+
+/// Sensitivities summed over the entries of a [`Portfolio`].
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub struct AggregateGreeks {
+    /// Sum of the signed `"delta"` results.
+    pub delta: Real,
+    /// Sum of the signed `"vega"` results.
+    pub vega: Real,
+}
+
+/// A book of instruments, each held long or short.
+///
+/// An entry's position applies on top of whatever position the instrument
+/// itself was priced for, so a short entry of a short option is long.
+#[derive(Debug, Default)]
+pub struct Portfolio {
+    /// The entries of the book.
+    pub entries: Vec<(Position, Box<dyn Instrument>)>,
+}
+
+impl Portfolio {
+    /// Create an empty portfolio.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Add an instrument held with the given position.
+    pub fn add(&mut self, position: Position, instrument: Box<dyn Instrument>) {
+        self.entries.push((position, instrument));
+    }
+
+    /// Builder form of [`add`](Self::add).
+    pub fn with(mut self, position: Position, instrument: Box<dyn Instrument>) -> Self {
+        self.add(position, instrument);
+        self
+    }
+
+    /// Number of entries.
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    /// Whether the portfolio has no entries.
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    /// Net NPV, summing the signed NPVs of all entries regardless of their
+    /// currency.
+    pub fn npv(&self) -> Result<Real> {
+        self.entries
+            .iter()
+            .map(|(position, instrument)| Ok(position.sign() * instrument.results()?.npv))
+            .sum()
+    }
+
+    /// Net NPV converted to `currency` with the rates in `rates` valid on
+    /// `date`.
+    ///
+    /// Fails if an entry has no results or no currency, or if a rate is
+    /// missing.
+    pub fn npv_in(
+        &self,
+        currency: &'static Currency,
+        rates: &ExchangeRateManager,
+        date: Date,
+    ) -> Result<Money> {
+        let mut total = 0.0;
+        for (position, instrument) in &self.entries {
+            let npv = position.sign() * instrument.results()?.npv;
+            let Some(source) = instrument.currency() else {
+                fail!("instrument {instrument:?} has no currency");
+            };
+            total += rates
+                .convert(&Money::new(npv, source), currency, date)?
+                .value;
+        }
+        Ok(Money::new(total, currency))
+    }
+
+    /// Signed deltas and vegas summed over the entries that report them.
+    ///
+    /// Entries without results, or whose results lack a Greek, contribute
+    /// nothing to that Greek.
+    pub fn aggregate_greeks(&self) -> AggregateGreeks {
+        let mut greeks = AggregateGreeks::default();
+        for (position, instrument) in &self.entries {
+            let Ok(results) = instrument.results() else {
+                continue;
+            };
+            let sign = position.sign();
+            let get = |key: &str| results.additional_results.get(key).copied();
+            greeks.delta += sign * get("delta").unwrap_or(0.0);
+            greeks.vega += sign * get("vega").unwrap_or(0.0);
+        }
+        greeks
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::option::VanillaOption;
+    use crate::payoff::OptionType;
+    use crate::swap::{SwapType, VanillaSwap};
+    use ql_currencies::currencies::{EUR, USD};
+    use ql_currencies::ExchangeRate;
+
+    fn swap() -> VanillaSwap {
+        let maturity = Date::from_ymd(2027, 1, 15).unwrap();
+        VanillaSwap {
+            swap_type: SwapType::Payer,
+            nominal: 100.0,
+            fixed_rate: 0.03,
+            spread: 0.0,
+            fixed_leg: vec![Box::new(ql_cashflows::SimpleCashFlow::new(6.0, maturity))],
+            floating_leg: vec![Box::new(ql_cashflows::SimpleCashFlow::new(5.0, maturity))],
+            fixed_maturity: maturity,
+            position: Position::Long,
+        }
+    }
+
+    fn priced_swap() -> Box<dyn Instrument> {
+        let swap = swap();
+        let npv = swap.npv_flat(0.03, Date::from_ymd(2025, 1, 15).unwrap());
+        Box::new(PricedInstrument::new(
+            swap,
+            PricingResults::from_npv(npv),
+            &USD,
+        ))
+    }
+
+    fn priced_option(npv: Real, currency: &'static Currency) -> Box<dyn Instrument> {
+        let expiry = Date::from_ymd(2026, 1, 15).unwrap();
+        let results = PricingResults::from_npv(npv)
+            .with_result("delta", 0.5)
+            .with_result("vega", 40.0);
+        Box::new(PricedInstrument::new(
+            VanillaOption::european(OptionType::Call, 100.0, expiry),
+            results,
+            currency,
+        ))
+    }
+
+    #[test]
+    fn long_and_short_swap_net_to_zero() {
+        let book = Portfolio::new()
+            .with(Position::Long, priced_swap())
+            .with(Position::Short, priced_swap());
+        assert_eq!(book.len(), 2);
+        assert!(book.entries[0].1.results().unwrap().npv != 0.0);
+        assert_eq!(book.npv().unwrap(), 0.0);
+    }
+
+    #[test]
+    fn converts_to_reporting_currency() {
+        let mut rates = ExchangeRateManager::new();
+        rates.add(ExchangeRate::new(&EUR, &USD, 1.10));
+        let book = Portfolio::new()
+            .with(Position::Long, priced_option(10.0, &USD))
+            .with(Position::Long, priced_option(20.0, &EUR));
+
+        let today = Date::from_ymd(2025, 1, 15).unwrap();
+        let usd = book.npv_in(&USD, &rates, today).unwrap();
+        assert_eq!(usd.currency, &USD);
+        assert!((usd.value - (10.0 + 20.0 * 1.10)).abs() < 1e-12);
+        let eur = book.npv_in(&EUR, &rates, today).unwrap();
+        assert!((eur.value - (10.0 / 1.10 + 20.0)).abs() < 1e-12);
+    }
+
+    #[test]
+    fn greeks_and_missing_data() {
+        let expiry = Date::from_ymd(2026, 1, 15).unwrap();
+        let bare = Box::new(VanillaOption::european(OptionType::Put, 90.0, expiry));
+        let book = Portfolio::new()
+            .with(Position::Long, priced_option(10.0, &USD))
+            .with(Position::Short, priced_option(20.0, &EUR))
+            .with(Position::Long, priced_option(5.0, &USD))
+            .with(Position::Long, bare);
+
+        let greeks = book.aggregate_greeks();
+        assert!((greeks.delta - 0.5).abs() < 1e-15);
+        assert!((greeks.vega - 40.0).abs() < 1e-12);
+        assert!(book.npv().is_err());
+        assert!(book
+            .npv_in(&USD, &ExchangeRateManager::new(), expiry)
+            .is_err());
+    }
+}
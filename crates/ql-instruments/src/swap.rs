@@ -5,7 +5,7 @@
 
 use crate::instrument::{Instrument, PricingEngine, PricingResults};
 use ql_cashflows::{FixedRateLegBuilder, IborLegBuilder, Leg};
-use ql_core::{errors::Result, Compounding, Real};
+use ql_core::{errors::Result, Compounding, Position, Real};
 use ql_indexes::IborIndex;
 use ql_time::{Actual365Fixed, Date, Frequency, Schedule};
 use std::sync::Arc;
@@ -116,6 +116,8 @@ pub struct VanillaSwap {
     pub floating_leg: Leg,
     /// Fixed leg maturity / overall maturity.
     pub fixed_maturity: Date,
+    /// Long or short holding of the swap as specified by `swap_type`.
+    pub position: Position,
 }
 
 impl VanillaSwap {
@@ -158,9 +160,16 @@ impl VanillaSwap {
             fixed_leg,
             floating_leg,
             fixed_maturity: maturity,
+            position: Position::Long,
         }
     }
 
+    /// Set the position; a short swap reports negated NPVs.
+    pub fn with_position(mut self, position: Position) -> Self {
+        self.position = position;
+        self
+    }
+
     /// The fixed leg NPV using a flat yield.
     pub fn fixed_leg_npv(&self, yield_rate: Real, settlement: Date) -> Real {
         let ir = ql_time::InterestRate::new(
@@ -183,13 +192,14 @@ impl VanillaSwap {
         ql_cashflows::npv_yield(&self.floating_leg, &ir, settlement)
     }
 
-    /// Fair value (NPV) at a flat yield from the payer's perspective.
+    /// Fair value (NPV) at a flat yield for the holder.
     ///
-    /// `NPV = sign * (floating_leg_npv - fixed_leg_npv)` for a payer swap.
+    /// `NPV = position * sign * (floating_leg_npv - fixed_leg_npv)`, where
+    /// `sign` is +1 for a payer swap.
     pub fn npv_flat(&self, yield_rate: Real, settlement: Date) -> Real {
         let fixed = self.fixed_leg_npv(yield_rate, settlement);
         let floating = self.floating_leg_npv(yield_rate, settlement);
-        self.swap_type.sign() * (floating - fixed)
+        self.position.sign() * self.swap_type.sign() * (floating - fixed)
     }
 
     /// Get engine arguments.
@@ -202,9 +212,12 @@ impl VanillaSwap {
         }
     }
 
-    /// Price with a pricing engine.
+    /// Price with a pricing engine, from the point of view of the holder's
+    /// position.
     pub fn price(&self, engine: &dyn PricingEngine<SwapArguments>) -> Result<PricingResults> {
-        engine.calculate(&self.arguments())
+        Ok(engine
+            .calculate(&self.arguments())?
+            .with_position(self.position))
     }
 }
 
@@ -269,6 +282,43 @@ mod tests {
         assert!((swap.nominal - 1_000_000.0).abs() < 1e-15);
     }
 
+    #[test]
+    fn short_swap_nets_long_swap() {
+        let start = Date::from_ymd(2025, 1, 15).unwrap();
+        let end = Date::from_ymd(2028, 1, 15).unwrap();
+        let cal = NullCalendar;
+        let fixed_schedule =
+            ScheduleBuilder::new(start, end, Period::new(1, TimeUnit::Years), &cal)
+                .build()
+                .unwrap();
+        let float_schedule =
+            ScheduleBuilder::new(start, end, Period::new(3, TimeUnit::Months), &cal)
+                .build()
+                .unwrap();
+        let swap = || {
+            VanillaSwap::new(
+                SwapType::Payer,
+                1_000_000.0,
+                &fixed_schedule,
+                0.04,
+                Compounding::Simple,
+                Frequency::Annual,
+                &float_schedule,
+                make_index(),
+                0.001,
+            )
+        };
+        let long = swap();
+        let short = swap().with_position(Position::Short);
+        assert_eq!(long.position, Position::Long);
+
+        let long_npv = long.npv_flat(0.03, start);
+        let short_npv = short.npv_flat(0.03, start);
+        assert!(long_npv != 0.0);
+        assert_eq!(short_npv, -long_npv);
+        assert_eq!(long_npv + short_npv, 0.0);
+    }
+
     #[test]
     fn swap_type_sign() {
         assert!((SwapType::Payer.sign() - 1.0).abs() < 1e-15);
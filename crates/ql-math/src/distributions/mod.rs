@@ -16,7 +16,7 @@ pub use beta::{
     BetaDistribution,
 };
 pub use binomial::BinomialDistribution;
-pub use chi_square::ChiSquareDistribution;
+pub use chi_square::{ChiSquareDistribution, NonCentralChiSquareDistribution};
 pub use gamma::GammaDistribution;
 pub use normal::{bivariate_normal_cdf, normal_cdf, normal_cdf_inverse, normal_pdf};
 pub use poisson::PoissonDistribution;
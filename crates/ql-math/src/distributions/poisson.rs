@@ -52,6 +52,31 @@ impl PoissonDistribution {
     pub fn variance(&self) -> Real {
         self.lambda
     }
+
+    /// The smallest `k` with `P(X ≤ k) ≥ u`, for sampling by inversion.
+    ///
+    /// The cumulative probability is built up with the recurrence
+    /// `P(X = k + 1) = P(X = k)·λ/(k + 1)`.
+    ///
+    /// `u` is clamped to `[0, 1]`: `u ≤ 0` (or NaN) gives 0, and `u ≥ 1`
+    /// gives the first `k` whose mass underflows to zero, the furthest
+    /// quantile the recurrence can resolve.
+    ///
+    /// Corresponds to `QuantLib::InverseCumulativePoisson`.
+    pub fn inverse_cdf(&self, u: Real) -> u64 {
+        let u = u.clamp(0.0, 1.0);
+        let mut k = 0;
+        let mut mass = (-self.lambda).exp();
+        let mut cumulative = mass;
+        // Rounding can leave the sum a hair short of u near 1; the mass
+        // vanishing stops the search there.
+        while cumulative < u && mass > 0.0 {
+            k += 1;
+            mass *= self.lambda / k as Real;
+            cumulative += mass;
+        }
+        k
+    }
 }
 
 #[cfg(test)]
@@ -96,4 +121,28 @@ mod tests {
             prev = c;
         }
     }
+
+    #[test]
+    fn poisson_inverse_cdf_brackets_the_quantile() {
+        let d = PoissonDistribution::new(2.5);
+        assert_eq!(d.inverse_cdf(0.0), 0);
+        assert_eq!(d.inverse_cdf(0.5 * d.cdf(0)), 0);
+        for k in 1..15 {
+            // Just above P(X ≤ k − 1) the quantile is k.
+            let u = d.cdf(k - 1) + 1e-9;
+            assert_eq!(d.inverse_cdf(u), k, "u = {u}");
+            assert_eq!(d.inverse_cdf(d.cdf(k) - 1e-9), k);
+        }
+    }
+
+    #[test]
+    fn poisson_inverse_cdf_clamps_u() {
+        let d = PoissonDistribution::new(2.5);
+        assert_eq!(d.inverse_cdf(-0.5), 0);
+        assert_eq!(d.inverse_cdf(Real::NAN), 0);
+        let tail = d.inverse_cdf(1.0);
+        assert!(tail > 100 && d.pmf(tail) == 0.0, "k = {tail}");
+        assert_eq!(d.inverse_cdf(1.5), tail);
+        assert!(d.inverse_cdf(1.0 - 1e-12) < tail);
+    }
 }
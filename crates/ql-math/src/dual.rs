@@ -0,0 +1,205 @@
+//! Forward-mode automatic differentiation with dual numbers.
+//!
+//! QuantLib proper relies on external AAD tools (e.g. XAD through
+//! `QuantLib-Risks`). This module offers the lightweight forward mode: a
+//! [`Dual`] carries a value and its derivative with respect to one chosen
+//! input, and pricing formulas written against [`Scalar`] propagate both in
+//! a single evaluation.
+//!
+//! ```
+//! use ql_math::dual::{Dual, Scalar};
+//!
+//! // d/dx (x² · eˣ) at x = 1 is 3e.
+//! let x = Dual::variable(1.0);
+//! let y = x * x * x.exp();
+//! assert!((y.derivative - 3.0 * 1.0_f64.exp()).abs() < 1e-14);
+//! ```
+
+use crate::distributions::{normal_cdf, normal_pdf};
+use ql_core::Real;
+use std::ops::{Add, Div, Mul, Neg, Sub};
+
+/// A number type that pricing formulas can be written against, so that they
+/// run on plain `Real`s or on [`Dual`]s alike.
+///
+/// Elementary functions are expressed through [`map`](Self::map), which
+/// applies a real function given its value and derivative at
+/// [`value`](Self::value).
+pub trait Scalar:
+    Copy
+    + Add<Output = Self>
+    + Sub<Output = Self>
+    + Mul<Output = Self>
+    + Div<Output = Self>
+    + Neg<Output = Self>
+{
+    /// A constant, i.e. with zero derivative.
+    fn from_real(x: Real) -> Self;
+
+    /// The real part.
+    fn value(self) -> Real;
+
+    /// Apply a function `g` to `self`, given `g(self.value())` and
+    /// `g'(self.value())` (chain rule).
+    fn map(self, g: Real, dg: Real) -> Self;
+
+    /// `eˣ`.
+    fn exp(self) -> Self {
+        let e = self.value().exp();
+        self.map(e, e)
+    }
+
+    /// Natural logarithm.
+    fn ln(self) -> Self {
+        let x = self.value();
+        self.map(x.ln(), 1.0 / x)
+    }
+
+    /// Square root.
+    fn sqrt(self) -> Self {
+        let r = self.value().sqrt();
+        self.map(r, 0.5 / r)
+    }
+
+    /// Standard normal cumulative distribution function.
+    fn normal_cdf(self) -> Self {
+        let x = self.value();
+        self.map(normal_cdf(x), normal_pdf(x))
+    }
+
+    /// Standard normal density.
+    fn normal_pdf(self) -> Self {
+        let x = self.value();
+        let p = normal_pdf(x);
+        self.map(p, -x * p)
+    }
+}
+
+impl Scalar for Real {
+    fn from_real(x: Real) -> Self {
+        x
+    }
+
+    fn value(self) -> Real {
+        self
+    }
+
+    fn map(self, g: Real, _dg: Real) -> Self {
+        g
+    }
+}
+
+/// A dual number `value + derivative·ε` with `ε² = 0`.
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub struct Dual {
+    /// The value.
+    pub value: Real,
+    /// The derivative with respect to the seeded input.
+    pub derivative: Real,
+}
+
+impl Dual {
+    /// Create a dual number.
+    pub fn new(value: Real, derivative: Real) -> Self {
+        Self { value, derivative }
+    }
+
+    /// The input to differentiate with respect to (derivative one).
+    pub fn variable(value: Real) -> Self {
+        Self::new(value, 1.0)
+    }
+
+    /// A constant (derivative zero).
+    pub fn constant(value: Real) -> Self {
+        Self::new(value, 0.0)
+    }
+}
+
+impl Scalar for Dual {
+    fn from_real(x: Real) -> Self {
+        Self::constant(x)
+    }
+
+    fn value(self) -> Real {
+        self.value
+    }
+
+    fn map(self, g: Real, dg: Real) -> Self {
+        Self::new(g, dg * self.derivative)
+    }
+}
+
+impl From<Real> for Dual {
+    fn from(x: Real) -> Self {
+        Self::constant(x)
+    }
+}
+
+impl Add for Dual {
+    type Output = Self;
+    fn add(self, rhs: Self) -> Self {
+        Self::new(self.value + rhs.value, self.derivative + rhs.derivative)
+    }
+}
+
+impl Sub for Dual {
+    type Output = Self;
+    fn sub(self, rhs: Self) -> Self {
+        Self::new(self.value - rhs.value, self.derivative - rhs.derivative)
+    }
+}
+
+impl Mul for Dual {
+    type Output = Self;
+    fn mul(self, rhs: Self) -> Self {
+        Self::new(
+            self.value * rhs.value,
+            self.derivative * rhs.value + self.value * rhs.derivative,
+        )
+    }
+}
+
+impl Div for Dual {
+    type Output = Self;
+    fn div(self, rhs: Self) -> Self {
+        Self::new(
+            self.value / rhs.value,
+            (self.derivative * rhs.value - self.value * rhs.derivative) / (rhs.value * rhs.value),
+        )
+    }
+}
+
+impl Neg for Dual {
+    type Output = Self;
+    fn neg(self) -> Self {
+        Self::new(-self.value, -self.derivative)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn arithmetic_rules() {
+        let x = Dual::variable(2.0);
+        let c = Dual::constant(3.0);
+        assert_eq!(x + c, Dual::new(5.0, 1.0));
+        assert_eq!(x - c, Dual::new(-1.0, 1.0));
+        assert_eq!(x * c, Dual::new(6.0, 3.0));
+        // d/dx (3 / x) = −3 / x²
+        assert_eq!(c / x, Dual::new(1.5, -0.75));
+        assert_eq!(-x, Dual::new(-2.0, -1.0));
+    }
+
+    #[test]
+    fn elementary_functions() {
+        let x = Dual::variable(0.7);
+        assert_eq!(x.ln().derivative, 1.0 / 0.7);
+        assert_eq!(x.sqrt().derivative, 0.5 / 0.7_f64.sqrt());
+        assert_eq!(x.normal_cdf().derivative, normal_pdf(0.7));
+        assert!((x.normal_pdf().derivative + 0.7 * normal_pdf(0.7)).abs() < 1e-16);
+        // Reals ignore the derivative entirely.
+        assert_eq!(Scalar::exp(0.7_f64), 0.7_f64.exp());
+    }
+}
@@ -0,0 +1,126 @@
+//! Fast Fourier transform.
+//!
+//! Translates `ql/math/fastfouriertransform.hpp`: an iterative radix-2
+//! Cooley-Tukey transform on `2ⁿ` points.  Neither direction is
+//! normalised, so `inverse(forward(x)) = N·x`.
+
+use num_complex::Complex64;
+use ql_core::{ensure, errors::Result};
+use std::f64::consts::PI;
+
+/// Radix-2 FFT of a fixed order.
+///
+/// Corresponds to `QuantLib::FastFourierTransform`.
+#[derive(Debug, Clone)]
+pub struct FastFourierTransform {
+    /// `e^{−2πik/N}` for `k < N/2`.
+    twiddles: Vec<Complex64>,
+}
+
+impl FastFourierTransform {
+    /// Create a transform on `2^order` points.
+    pub fn new(order: u32) -> Self {
+        let n = 1_usize << order;
+        let twiddles = (0..n / 2)
+            .map(|k| Complex64::from_polar(1.0, -2.0 * PI * k as f64 / n as f64))
+            .collect();
+        Self { twiddles }
+    }
+
+    /// The smallest order whose transform holds `size` points.
+    pub fn min_order(size: usize) -> u32 {
+        size.max(1).next_power_of_two().trailing_zeros()
+    }
+
+    /// Number of points `N` of the transform.
+    pub fn output_size(&self) -> usize {
+        (2 * self.twiddles.len()).max(1)
+    }
+
+    /// `Xⱼ = Σₖ xₖ e^{−2πijk/N}`; the input is zero-padded to `N` points.
+    ///
+    /// Fails if the input holds more than `N` points.
+    pub fn forward_transform(&self, input: &[Complex64]) -> Result<Vec<Complex64>> {
+        self.transform(input, false)
+    }
+
+    /// `xₖ = Σⱼ Xⱼ e^{+2πijk/N}`; the input is zero-padded to `N` points.
+    ///
+    /// Fails if the input holds more than `N` points.
+    pub fn inverse_transform(&self, input: &[Complex64]) -> Result<Vec<Complex64>> {
+        self.transform(input, true)
+    }
+
+    fn transform(&self, input: &[Complex64], inverse: bool) -> Result<Vec<Complex64>> {
+        let n = self.output_size();
+        ensure!(
+            input.len() <= n,
+            "{} points do not fit a transform of size {n}",
+            input.len()
+        );
+        let bits = n.trailing_zeros();
+        let mut data = vec![Complex64::new(0.0, 0.0); n];
+        for (k, &x) in input.iter().enumerate() {
+            let j = if bits == 0 {
+                0
+            } else {
+                k.reverse_bits() >> (usize::BITS - bits)
+            };
+            data[j] = x;
+        }
+
+        let mut half = 1;
+        while half < n {
+            let stride = n / (2 * half);
+            for start in (0..n).step_by(2 * half) {
+                for k in 0..half {
+                    let w = self.twiddles[k * stride];
+                    let w = if inverse { w.conj() } else { w };
+                    let t = w * data[start + k + half];
+                    data[start + k + half] = data[start + k] - t;
+                    data[start + k] += t;
+                }
+            }
+            half *= 2;
+        }
+        Ok(data)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn matches_direct_dft_and_inverts() {
+        let input: Vec<Complex64> = (0..13)
+            .map(|k| Complex64::new((k as f64 * 0.7).sin(), 1.0 / (1.0 + k as f64)))
+            .collect();
+        let fft = FastFourierTransform::new(FastFourierTransform::min_order(input.len()));
+        assert_eq!(fft.output_size(), 16);
+
+        let output = fft.forward_transform(&input).unwrap();
+        for (j, x) in output.iter().enumerate() {
+            let direct: Complex64 = input
+                .iter()
+                .enumerate()
+                .map(|(k, &v)| v * Complex64::from_polar(1.0, -2.0 * PI * (j * k) as f64 / 16.0))
+                .sum();
+            assert!((x - direct).norm() < 1e-12, "bin {j}: {x} vs {direct}");
+        }
+
+        let back = fft.inverse_transform(&output).unwrap();
+        for (k, x) in back.iter().enumerate() {
+            let expected = input.get(k).copied().unwrap_or_default() * 16.0;
+            assert!((x - expected).norm() < 1e-12);
+        }
+    }
+
+    #[test]
+    fn oversized_input_is_an_error() {
+        let fft = FastFourierTransform::new(2);
+        let input = vec![Complex64::new(1.0, 0.0); 5];
+        assert!(fft.forward_transform(&input).is_err());
+        assert!(fft.inverse_transform(&input).is_err());
+    }
+}
@@ -3,8 +3,10 @@
 //! Provides classical Gauss quadrature rules with pre-computed nodes and weights
 //! for common families: Legendre, Hermite, Laguerre, Chebyshev, and Jacobi.
 
+use crate::matrix::Matrix;
+use crate::matrix_utilities::symmetric_eigenvalues;
 use ql_core::Real;
-use std::f64::consts::PI;
+use std::f64::consts::{PI, SQRT_2};
 
 /// A Gauss quadrature rule defined by nodes and weights.
 ///
@@ -97,6 +99,39 @@ impl GaussHermiteIntegration {
     }
 }
 
+/// Gauss-Hermite quadrature rescaled to the standard normal density.
+///
+/// The nodes and weights of [`GaussHermiteIntegration`] are rescaled once at
+/// construction, so that repeated [`integrate`](Self::integrate) calls cost
+/// only the function evaluations.
+#[derive(Debug, Clone)]
+pub struct GaussHermiteNormalIntegration {
+    rule: GaussianQuadrature,
+}
+
+impl GaussHermiteNormalIntegration {
+    /// Build the rule from a Gauss-Hermite quadrature of given `order`.
+    pub fn new(order: usize) -> Self {
+        let hermite = GaussHermiteIntegration::new(order);
+        let rule = GaussianQuadrature {
+            x: hermite.x.iter().map(|&x| SQRT_2 * x).collect(),
+            w: hermite.w.iter().map(|&w| w / PI.sqrt()).collect(),
+        };
+        Self { rule }
+    }
+
+    /// Number of quadrature points.
+    pub fn order(&self) -> usize {
+        self.rule.order()
+    }
+
+    /// Expectation `E[f(Z)] = ∫ f(x) φ(x) dx` of `f` of a standard normal
+    /// `Z`, by substituting `x = √2·y`: `E[f(Z)] ≈ Σ wᵢ f(√2·xᵢ) / √π`.
+    pub fn integrate<F: Fn(Real) -> Real>(&self, f: F) -> Real {
+        self.rule.integrate(f)
+    }
+}
+
 // ═══════════════════════════════════════════════════════════════════════════════
 // Gauss-Laguerre quadrature (weight e^{-x} on [0, ∞))
 // ═══════════════════════════════════════════════════════════════════════════════
@@ -217,7 +252,7 @@ pub fn gauss_jacobi_nodes_weights(n: usize, alpha: Real, beta: Real) -> Gaussian
     // a_0
     a_diag[0] = (beta - alpha) / (ab + 2.0);
     // b_1 (used as sub-diagonal)
-    b_sub[0] = mu0; // this will be sqrt'd later, used as μ₀ here
+    b_sub[0] = mu0;
 
     for i in 1..n {
         let ii = i as Real;
@@ -231,21 +266,7 @@ pub fn gauss_jacobi_nodes_weights(n: usize, alpha: Real, beta: Real) -> Gaussian
         b_sub[i] = num / (d * (denom1 + 1.0) * (denom1 - 1.0));
     }
 
-    // Build symmetric tridiagonal matrix, eigensolve via QR
-    let mut diag = a_diag;
-    let mut off = vec![0.0; n - 1];
-    for i in 0..(n - 1) {
-        off[i] = b_sub[i + 1].sqrt();
-    }
-
-    let (eigenvalues, eigenvectors) = symmetric_tridiagonal_qr(&mut diag, &mut off, n);
-
-    let x = eigenvalues;
-    let w: Vec<Real> = (0..n)
-        .map(|i| mu0 * eigenvectors[i][0] * eigenvectors[i][0])
-        .collect();
-
-    GaussianQuadrature { x, w }
+    golub_welsch(&a_diag, &b_sub)
 }
 
 /// Golub-Welsch for general three-term recurrence.
@@ -267,125 +288,59 @@ fn golub_welsch(alpha: &[Real], beta: &[Real]) -> GaussianQuadrature {
         };
     }
 
-    let mut diag = alpha.to_vec();
-    let mut off: Vec<Real> = (1..n).map(|i| beta[i].abs().sqrt()).collect();
-
-    let (eigenvalues, eigenvectors) = symmetric_tridiagonal_qr(&mut diag, &mut off, n);
-
-    let mu0 = beta[0];
-    let w: Vec<Real> = (0..n)
-        .map(|i| mu0 * eigenvectors[i][0] * eigenvectors[i][0])
+    // The nodes are the eigenvalues of the Jacobi matrix.
+    let mut jacobi = Matrix::zeros(n, n);
+    for i in 0..n {
+        jacobi[(i, i)] = alpha[i];
+        if i + 1 < n {
+            let off = beta[i + 1].abs().sqrt();
+            jacobi[(i, i + 1)] = off;
+            jacobi[(i + 1, i)] = off;
+        }
+    }
+    let eigenvalues = symmetric_eigenvalues(&jacobi).expect("the Jacobi matrix is square");
+
+    // The weights are μ₀ times the squared first components of the unit
+    // eigenvectors, but these are only accurate to ~1e-16 in absolute terms,
+    // which loses the tiny outer weights of Laguerre and Hermite rules.  The
+    // equivalent Christoffel numbers 1/Σₖ p̃ₖ(xᵢ)², with p̃ₖ the orthonormal
+    // polynomials of the recurrence, keep full relative accuracy.
+    let x: Vec<Real> = eigenvalues.iter().copied().collect();
+    let w = x
+        .iter()
+        .map(|&x| christoffel_number(alpha, beta, x))
         .collect();
-
-    GaussianQuadrature { x: eigenvalues, w }
+    GaussianQuadrature { x, w }
 }
 
-/// Implicit QR algorithm for a symmetric tridiagonal matrix.
-///
-/// Returns (eigenvalues, eigenvectors) where eigenvectors[i] is the i-th
-/// eigenvector.
-fn symmetric_tridiagonal_qr(
-    diag: &mut [Real],
-    off: &mut [Real],
-    n: usize,
-) -> (Vec<Real>, Vec<Vec<Real>>) {
-    // Initialize eigenvector matrix to identity
-    let mut z: Vec<Vec<Real>> = (0..n)
-        .map(|i| {
-            let mut row = vec![0.0; n];
-            row[i] = 1.0;
-            row
-        })
-        .collect();
-
-    let max_iter = 100 * n;
-    let mut m = n;
-
-    for _iteration in 0..max_iter {
-        if m <= 1 {
-            break;
-        }
-
-        // Find the largest unreduced submatrix
-        let mut l = m - 1;
-        while l > 0 && off[l - 1].abs() > 1e-15 * (diag[l - 1].abs() + diag[l].abs()) {
-            l -= 1;
-        }
-
-        if l == m - 1 {
-            m -= 1;
-            continue;
-        }
-
-        // Wilkinson shift
-        let d = (diag[m - 2] - diag[m - 1]) / 2.0;
-        let mu = if d.abs() < 1e-300 {
-            diag[m - 1] - off[m - 2].abs()
-        } else {
-            diag[m - 1]
-                - off[m - 2] * off[m - 2]
-                    / (d + d.signum() * (d * d + off[m - 2] * off[m - 2]).sqrt())
-        };
-
-        // QR step with implicit shift
-        let mut x = diag[l] - mu;
-        let mut y = off[l];
-
-        for k in l..(m - 1) {
-            // Givens rotation
-            let (c, s) = if x.abs() > y.abs() {
-                let t = -y / x;
-                let c_ = 1.0 / (1.0 + t * t).sqrt();
-                (c_, c_ * t)
-            } else if y.abs() > 1e-300 {
-                let t = -x / y;
-                let s_ = 1.0 / (1.0 + t * t).sqrt();
-                (s_ * t, s_)
+/// `1/Σₖ p̃ₖ(x)²` over the orthonormal polynomials `p̃₀ … p̃ₙ₋₁` of the
+/// recurrence `√βₖ₊₁ p̃ₖ₊₁ = (x − αₖ) p̃ₖ − √βₖ p̃ₖ₋₁`, `p̃₀ = 1/√μ₀`.
+fn christoffel_number(alpha: &[Real], beta: &[Real], x: Real) -> Real {
+    const RESCALE: Real = 1e100;
+    let mut previous = 0.0;
+    let mut current = 1.0 / beta[0].sqrt();
+    let mut sum = current * current;
+    // `sum` is tracked in units of RESCALE^(2·scale) to avoid overflow.
+    let mut scale = 0;
+    for k in 0..alpha.len() - 1 {
+        let next = ((x - alpha[k]) * current
+            - if k > 0 {
+                beta[k].abs().sqrt() * previous
             } else {
-                (1.0, 0.0)
-            };
-
-            let w_val = c * x - s * y;
-            let d_val = diag[k] - diag[k + 1];
-            let z_val = (2.0 * c * off[k] + d_val * s) * s;
-            diag[k] -= z_val;
-            diag[k + 1] += z_val;
-            if k > l {
-                off[k - 1] = w_val;
-            }
-            off[k] = d_val * c * s + (c * c - s * s) * off[k];
-
-            // Update eigenvectors
-            for row in z.iter_mut() {
-                let t0 = row[k];
-                let t1 = row[k + 1];
-                row[k] = c * t0 - s * t1;
-                row[k + 1] = s * t0 + c * t1;
-            }
-
-            x = off[k];
-            if k < m - 2 {
-                y = -s * off[k + 1];
-                off[k + 1] *= c;
-            }
+                0.0
+            })
+            / beta[k + 1].abs().sqrt();
+        previous = current;
+        current = next;
+        if current.abs() > RESCALE {
+            previous /= RESCALE;
+            current /= RESCALE;
+            sum /= RESCALE * RESCALE;
+            scale += 1;
         }
+        sum += current * current;
     }
-
-    // Sort eigenvalues and corresponding eigenvectors
-    let mut idx: Vec<usize> = (0..n).collect();
-    idx.sort_by(|&a, &b| {
-        diag[a]
-            .partial_cmp(&diag[b])
-            .unwrap_or(std::cmp::Ordering::Equal)
-    });
-
-    let eigenvalues: Vec<Real> = idx.iter().map(|&i| diag[i]).collect();
-    let eigenvectors: Vec<Vec<Real>> = idx
-        .iter()
-        .map(|&i| (0..n).map(|j| z[j][i]).collect())
-        .collect();
-
-    (eigenvalues, eigenvectors)
+    (0..scale).fold(1.0 / sum, |w, _| w / (RESCALE * RESCALE))
 }
 
 #[cfg(test)]
@@ -450,6 +405,33 @@ mod tests {
         assert_near(sum, PI.sqrt(), 1e-10);
     }
 
+    #[test]
+    fn gauss_hermite_normal_expectations() {
+        let rule = GaussHermiteNormalIntegration::new(20);
+        assert_eq!(rule.order(), 20);
+        assert_near(rule.integrate(|_| 1.0), 1.0, 1e-13);
+        assert_near(rule.integrate(|x| x), 0.0, 1e-13);
+        assert_near(rule.integrate(|x| x * x), 1.0, 1e-12);
+        assert_near(rule.integrate(|x| x.powi(4)), 3.0, 1e-11);
+
+        // E[S_T] of a lognormal spot is its Black-Scholes forward.
+        let (spot, r, q, sigma, t): (Real, Real, Real, Real, Real) = (100.0, 0.05, 0.02, 0.3, 2.0);
+        let expected = GaussHermiteNormalIntegration::new(40)
+            .integrate(|z| spot * ((r - q - 0.5 * sigma * sigma) * t + sigma * t.sqrt() * z).exp());
+        assert_near(expected, spot * ((r - q) * t).exp(), 1e-9);
+    }
+
+    #[test]
+    fn gauss_hermite_nodes_are_symmetric_roots() {
+        let q = GaussHermiteIntegration::new(5);
+        // H₅(x) = 32x⁵ − 160x³ + 120x.
+        for (&x, &mirror) in q.x().iter().zip(q.x().iter().rev()) {
+            assert_near(x, -mirror, 1e-13);
+            assert_near(32.0 * x.powi(5) - 160.0 * x.powi(3) + 120.0 * x, 0.0, 1e-10);
+        }
+        assert!(q.x().windows(2).all(|w| w[0] < w[1]));
+    }
+
     #[test]
     fn gauss_laguerre_exponential_integral() {
         // ∫_0^∞ e^{-x} dx = 1  (weight function itself)
@@ -467,6 +449,39 @@ mod tests {
         assert_near(result, 2.0, 1e-10);
     }
 
+    #[test]
+    fn gauss_laguerre_two_point_rule_is_exact() {
+        // L₂ has roots 2 ∓ √2, with weights (2 ± √2)/4.
+        let q = GaussLaguerreIntegration::new(2, 0.0);
+        let r = SQRT_2;
+        assert_near(q.x()[0], 2.0 - r, 1e-14);
+        assert_near(q.x()[1], 2.0 + r, 1e-14);
+        assert_near(q.w()[0], (2.0 + r) / 4.0, 1e-15);
+        assert_near(q.w()[1], (2.0 - r) / 4.0, 1e-15);
+    }
+
+    #[test]
+    fn gauss_laguerre_outer_weights_keep_relative_accuracy() {
+        // ∫ x¹⁵⁰ e^{−x} dx = 150!, in log space: the integrand peaks near
+        // x = 150, where the weights are around e^{−150} and weights from
+        // eigenvector components, accurate only to ~1e-16 absolute, would be
+        // pure noise.
+        let log_factorial = statrs::function::gamma::ln_gamma(151.0);
+        for order in [128, 200] {
+            let q = GaussLaguerreIntegration::new(order, 0.0);
+            assert!(q.x().windows(2).all(|w| w[0] < w[1]));
+            assert!(q.w().iter().all(|&w| w >= 0.0));
+            let moment: Real = q
+                .x()
+                .iter()
+                .zip(q.w())
+                .filter(|&(_, &w)| w > 0.0)
+                .map(|(&x, &w)| (w.ln() + 150.0 * x.ln() - log_factorial).exp())
+                .sum();
+            assert_near(moment, 1.0, 1e-9);
+        }
+    }
+
     #[test]
     fn gauss_jacobi_reduces_to_legendre() {
         // Jacobi(α=0, β=0) is Legendre
@@ -98,10 +98,14 @@ fn hyman_boundary_correction(ts: &mut [Real], s: &[Real]) {
 /// Full Hyman monotonicity filter applied to all slopes.
 ///
 /// For each interior slope, if the adjacent secants have different signs,
-/// the slope is set to zero.  Otherwise, the slope magnitude is clipped
-/// to `3 · min(|S_{i−1}|, |S_i|)`.  This matches the monotonicity
-/// constraint applied by C++ `CubicInterpolation` when `monotonic = true`.
-fn hyman_full_filter(ts: &mut [Real], s: &[Real]) {
+/// or the slope points against them, the slope is set to zero.  Otherwise,
+/// the slope magnitude is clipped to `3 · min(|S_{i−1}|, |S_i|)`.  This
+/// matches the monotonicity constraint applied by C++ `CubicInterpolation`
+/// when `monotonic = true`.
+///
+/// Only clipping kept the sign of a slope pointing against both secants,
+/// which Fritsch-Butland slopes never do but natural-spline slopes can.
+pub(super) fn hyman_full_filter(ts: &mut [Real], s: &[Real]) {
     let n = ts.len();
     // Boundary corrections
     hyman_boundary_correction(ts, s);
@@ -111,6 +115,9 @@ fn hyman_full_filter(ts: &mut [Real], s: &[Real]) {
         if s[i - 1] * s[i] <= 0.0 {
             // Adjacent secants have different signs → zero slope
             ts[i] = 0.0;
+        } else if ts[i] * s[i] < 0.0 {
+            // Slope against both secants → zero slope
+            ts[i] = 0.0;
         } else {
             let bound = 3.0 * s[i - 1].abs().min(s[i].abs());
             if ts[i].abs() > bound {
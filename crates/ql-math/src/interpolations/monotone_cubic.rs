@@ -2,11 +2,22 @@
 //! (translates `ql/math/interpolations/cubicinterpolation.hpp`, monotone variant).
 //!
 //! Implements the Fritsch-Carlson algorithm that modifies cubic Hermite slopes
-//! to guarantee monotonicity on each sub-interval where the data is monotone.
+//! to guarantee monotonicity on each sub-interval where the data is monotone,
+//! and the Hyman filter applied on top of natural-spline slopes.
+//!
+//! The two differ in what they start from and what they touch:
+//!
+//! - **Fritsch-Carlson** starts from local three-point slopes and rescales
+//!   every pair of end slopes that leaves the monotone region
+//!   `α² + β² ≤ 9`, so the result is only C¹ everywhere.
+//! - **Hyman** starts from the (C²) natural cubic spline and clamps a slope
+//!   only where it exceeds `3·min(|δᵢ₋₁|, |δᵢ|)` or the adjacent secants
+//!   change sign.  Where the spline is already monotone the slopes are left
+//!   alone and the interpolant keeps its continuous second derivative.
 
 use ql_core::{errors::Result, Real};
 
-use super::Interpolation1D;
+use super::{cubic::hyman_full_filter, CubicNaturalSpline, Interpolation1D};
 
 /// Monotone-preserving cubic Hermite spline.
 ///
@@ -69,6 +80,29 @@ impl MonotoneCubicSpline {
 
         Ok(Self { xs, ys, ts })
     }
+
+    /// Build a monotone cubic from natural-spline slopes passed through the
+    /// Hyman monotonicity filter.
+    ///
+    /// Corresponds to `QuantLib::MonotonicCubicNaturalSpline`, i.e.
+    /// `CubicInterpolation` with `Spline` derivatives and
+    /// `monotonic = true`.
+    ///
+    /// # Errors
+    /// Returns an error if fewer than 3 points are provided or lengths differ.
+    pub fn with_hyman_filter(xs: &[Real], ys: &[Real]) -> Result<Self> {
+        let spline = CubicNaturalSpline::new(xs, ys)?;
+        let delta: Vec<Real> = (0..xs.len() - 1)
+            .map(|i| (ys[i + 1] - ys[i]) / (xs[i + 1] - xs[i]))
+            .collect();
+        let mut ts = spline.knot_slopes();
+        hyman_full_filter(&mut ts, &delta);
+        Ok(Self {
+            xs: xs.to_vec(),
+            ys: ys.to_vec(),
+            ts,
+        })
+    }
 }
 
 impl Interpolation1D for MonotoneCubicSpline {
@@ -143,6 +177,84 @@ mod tests {
         }
     }
 
+    /// Jump in the second derivative across each interior knot.
+    fn second_derivative_jumps(s: &MonotoneCubicSpline) -> Vec<Real> {
+        let n = s.xs.len();
+        (1..n - 1)
+            .map(|i| {
+                let (hl, hr) = (s.xs[i] - s.xs[i - 1], s.xs[i + 1] - s.xs[i]);
+                let dl = (s.ys[i] - s.ys[i - 1]) / hl;
+                let dr = (s.ys[i + 1] - s.ys[i]) / hr;
+                let left = (-6.0 * dl + 2.0 * s.ts[i - 1] + 4.0 * s.ts[i]) / hl;
+                let right = (6.0 * dr - 4.0 * s.ts[i] - 2.0 * s.ts[i + 1]) / hr;
+                (right - left).abs()
+            })
+            .collect()
+    }
+
+    /// RPN15A data from `testSplineOnRPN15AValues`.
+    #[test]
+    fn hyman_filter_on_rpn15a() {
+        let xs = [7.99, 8.09, 8.19, 8.7, 9.2, 10.0, 12.0, 15.0, 20.0];
+        let ys = [
+            0.0, 2.76429e-5, 4.37498e-5, 0.169183, 0.469428, 0.943740, 0.998636, 0.999919, 0.999994,
+        ];
+        let hyman = MonotoneCubicSpline::with_hyman_filter(&xs, &ys).unwrap();
+        let fritsch_carlson = MonotoneCubicSpline::new(&xs, &ys).unwrap();
+
+        let mut prev = -1.0;
+        for i in 0..=1200 {
+            let x = 7.99 + (20.0 - 7.99) * i as Real / 1200.0;
+            let v = hyman.operator(x);
+            assert!(v <= 1.0, "overshoot at x={x}: {v}");
+            assert!(v >= prev - 1e-15, "not monotone at x={x}: {v} < {prev}");
+            prev = v;
+        }
+        for (&x, &y) in xs.iter().zip(ys.iter()) {
+            assert!((hyman.operator(x) - y).abs() < 1e-15);
+        }
+
+        // Smooth region: the rising part of the curve, knots 8.7 and 9.2.
+        let hyman_jumps = second_derivative_jumps(&hyman);
+        let fc_jumps = second_derivative_jumps(&fritsch_carlson);
+        for k in [2, 3] {
+            assert!(
+                hyman_jumps[k] < fc_jumps[k],
+                "knot x={}: Hyman jump {:.4e} vs Fritsch-Carlson {:.4e}",
+                xs[k + 1],
+                hyman_jumps[k],
+                fc_jumps[k]
+            );
+        }
+    }
+
+    #[test]
+    fn hyman_filter_zeroes_slopes_against_the_secants() {
+        // The natural spline dips between the two nearly flat secants: its
+        // slope at x = 2 is about −0.25 while both secants are +0.001.
+        let xs = [0.0, 1.0, 2.0, 3.0, 4.0];
+        let ys = [0.0, 1.0, 1.001, 1.002, 2.0];
+        let spline_slope = CubicNaturalSpline::new(&xs, &ys).unwrap().knot_slopes()[2];
+        assert!(spline_slope < -0.2, "{spline_slope}");
+
+        let is_monotone = |s: &MonotoneCubicSpline| {
+            (0..=400)
+                .map(|i| s.operator(i as Real / 100.0))
+                .collect::<Vec<_>>()
+                .windows(2)
+                .all(|w| w[1] >= w[0] - 1e-15)
+        };
+        let after = MonotoneCubicSpline::with_hyman_filter(&xs, &ys).unwrap();
+        assert_eq!(after.ts[2], 0.0);
+        assert!(is_monotone(&after));
+
+        // Clipping alone, as the filter did before, keeps the sign of the
+        // slope and the interpolant decreases around x = 2.
+        let mut before = after.clone();
+        before.ts[2] = -3.0 * 0.001;
+        assert!(!is_monotone(&before));
+    }
+
     #[test]
     fn monotone_step_function() {
         // Step: 0,0,1,1 — should stay in [0,1]
@@ -0,0 +1,114 @@
+//! Resampling of a [`TimeSeries`] through a 1D interpolation.
+//!
+//! QuantLib has no counterpart.  `TimeSeries` lives in `ql-core`, below
+//! this crate, so resampling is offered as the extension trait
+//! [`Resample`].  The series' keys are mapped to a numeric axis — the
+//! serial number of a `Date`, typically — the interpolation is built on
+//! the observations along it, and the result is read off at the requested
+//! keys.
+
+use super::Interpolation1D;
+use ql_core::{ensure, errors::Result, Real, TimeSeries};
+
+/// Resampling of a real-valued time series onto new keys.
+pub trait Resample<K: Ord + Clone> {
+    /// The series interpolated at `keys`.
+    ///
+    /// `axis` maps a key to its position on the interpolation axis and
+    /// must be increasing; `interpolation` builds the interpolant from the
+    /// observations' positions and values, e.g.
+    /// [`LinearInterpolation::new`](super::LinearInterpolation::new).
+    /// Keys outside the observed range error unless
+    /// `allow_extrapolation` is set, in which case the first or last
+    /// observation is extended flat.
+    ///
+    /// # Errors
+    /// Fails if the interpolation cannot be built on the observations, or
+    /// if a key lies outside them without `allow_extrapolation`.
+    fn resample<I: Interpolation1D>(
+        &self,
+        keys: &[K],
+        axis: impl Fn(&K) -> Real,
+        interpolation: impl FnOnce(&[Real], &[Real]) -> Result<I>,
+        allow_extrapolation: bool,
+    ) -> Result<TimeSeries<K, Real>>;
+}
+
+impl<K: Ord + Clone> Resample<K> for TimeSeries<K, Real> {
+    fn resample<I: Interpolation1D>(
+        &self,
+        keys: &[K],
+        axis: impl Fn(&K) -> Real,
+        interpolation: impl FnOnce(&[Real], &[Real]) -> Result<I>,
+        allow_extrapolation: bool,
+    ) -> Result<TimeSeries<K, Real>> {
+        let (xs, ys): (Vec<Real>, Vec<Real>) = self.iter().map(|(k, &v)| (axis(k), v)).unzip();
+        let interpolant = interpolation(&xs, &ys)?;
+        let (x_min, x_max) = (interpolant.x_min(), interpolant.x_max());
+
+        let mut resampled = TimeSeries::new();
+        for key in keys {
+            let x = axis(key);
+            ensure!(
+                allow_extrapolation || interpolant.is_in_range(x),
+                "{x} is outside the observed range [{x_min}, {x_max}]"
+            );
+            resampled.insert(key.clone(), interpolant.operator(x.clamp(x_min, x_max)));
+        }
+        Ok(resampled)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::interpolations::{
+        BackwardFlatInterpolation, ForwardFlatInterpolation, LinearInterpolation,
+    };
+
+    /// Daily serial numbers with days 3, 4 and 7 missing.
+    fn gapped_daily() -> TimeSeries<i32, Real> {
+        TimeSeries::from_key_values(&[2, 5, 6, 8], &[1.0, 4.0, 2.0, 6.0])
+    }
+
+    #[test]
+    fn linear_resampling_fills_gaps() {
+        let days: Vec<i32> = (2..=8).collect();
+        let resampled = gapped_daily()
+            .resample(&days, |&d| d as Real, LinearInterpolation::new, false)
+            .unwrap();
+        assert_eq!(resampled.keys(), days);
+        let expected = [1.0, 2.0, 3.0, 4.0, 2.0, 4.0, 6.0];
+        for (v, e) in resampled.values().iter().zip(expected) {
+            assert!((v - e).abs() < 1e-14, "{v} vs {e}");
+        }
+
+        // The flat interpolations reproduce filling.
+        let forward = gapped_daily()
+            .resample(&days, |&d| d as Real, ForwardFlatInterpolation::new, false)
+            .unwrap();
+        assert_eq!(forward, gapped_daily().fill_forward(&days));
+        let backward = gapped_daily()
+            .resample(&days, |&d| d as Real, BackwardFlatInterpolation::new, false)
+            .unwrap();
+        assert_eq!(backward, gapped_daily().fill_backward(&days));
+    }
+
+    #[test]
+    fn keys_outside_observations_need_extrapolation() {
+        let series = gapped_daily();
+        let axis = |&d: &i32| d as Real;
+        assert!(series
+            .resample(&[1, 5], axis, LinearInterpolation::new, false)
+            .is_err());
+        assert!(series
+            .resample(&[5, 9], axis, LinearInterpolation::new, false)
+            .is_err());
+
+        // Flat, not linear, beyond either end.
+        let extended = series
+            .resample(&[0, 1, 9, 12], axis, LinearInterpolation::new, true)
+            .unwrap();
+        assert_eq!(extended.values(), vec![1.0, 1.0, 6.0, 6.0]);
+    }
+}
@@ -21,6 +21,12 @@ pub mod copulas;
 /// Probability distributions.
 pub mod distributions;
 
+/// Dual numbers for forward-mode differentiation.
+pub mod dual;
+
+/// Fast Fourier transform.
+pub mod fast_fourier_transform;
+
 /// Numerical integration.
 pub mod integrals;
 
@@ -60,12 +66,16 @@ pub use array::Array;
 pub use comparison::{close, close_enough};
 pub use distributions::{
     normal_cdf, normal_cdf_inverse, normal_pdf, BetaDistribution, BinomialDistribution,
-    ChiSquareDistribution, GammaDistribution, PoissonDistribution, StudentTDistribution,
+    ChiSquareDistribution, GammaDistribution, NonCentralChiSquareDistribution, PoissonDistribution,
+    StudentTDistribution,
 };
+pub use dual::{Dual, Scalar};
+pub use fast_fourier_transform::FastFourierTransform;
 pub use interpolations::{
-    akima::AkimaSpline, monotone_cubic::MonotoneCubicSpline, CubicNaturalSpline, FlatInterpolation,
-    ForwardFlatInterpolation, Interpolation1D, LagrangeInterpolation, LinearInterpolation,
-    LogLinearInterpolation,
+    akima::AkimaSpline, monotone_cubic::MonotoneCubicSpline, time_series::Resample,
+    BackwardFlatInterpolation, BoundaryCondition, CubicNaturalSpline, CubicSpline,
+    FlatInterpolation, ForwardFlatInterpolation, Interpolation1D, LagrangeInterpolation,
+    LinearInterpolation, LogLinearInterpolation,
 };
 pub use matrix::Matrix;
 pub use rounding::{round, Rounding};
@@ -100,6 +100,19 @@ pub fn symmetric_eigen(m: &Matrix) -> Result<(Array, Matrix)> {
     ))
 }
 
+/// Eigenvalues of a symmetric matrix, in ascending order.
+///
+/// Cheaper than [`symmetric_eigen`] when the eigenvectors are not needed.
+pub fn symmetric_eigenvalues(m: &Matrix) -> Result<Array> {
+    let inner = m.inner();
+    if inner.nrows() != inner.ncols() {
+        return Err(Error::InvalidArgument("matrix must be square".into()));
+    }
+    let mut eigenvalues: Vec<Real> = inner.symmetric_eigenvalues().iter().copied().collect();
+    eigenvalues.sort_by(|a, b| a.total_cmp(b));
+    Ok(Array::from_vec(eigenvalues))
+}
+
 /// Pseudo square-root of a symmetric positive-semidefinite matrix.
 ///
 /// Computes `S` such that `S * Sᵀ ≈ M` using the eigenvalue decomposition,
@@ -215,6 +228,7 @@ pub fn covariance_decompose(covariance: &Matrix) -> Result<(Array, Matrix)> {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use std::f64::consts::SQRT_2;
 
     #[test]
     fn cholesky_2x2() {
@@ -278,6 +292,18 @@ mod tests {
         assert!((sorted[2] - 5.0).abs() < 1e-10);
     }
 
+    #[test]
+    fn symmetric_eigenvalues_are_ascending() {
+        let m = Matrix::from_row_slice(3, 3, &[2.0, 1.0, 0.0, 1.0, 2.0, 1.0, 0.0, 1.0, 2.0]);
+        let vals = symmetric_eigenvalues(&m).unwrap();
+        // Eigenvalues of the tridiagonal (1, 2, 1) matrix: 2 − √2, 2, 2 + √2.
+        let expected = [2.0 - SQRT_2, 2.0, 2.0 + SQRT_2];
+        for (v, e) in vals.iter().zip(expected) {
+            assert!((v - e).abs() < 1e-12, "{v} vs {e}");
+        }
+        assert!(symmetric_eigenvalues(&Matrix::zeros(2, 3)).is_err());
+    }
+
     #[test]
     fn pseudo_sqrt_identity() {
         let m = Matrix::identity(3);
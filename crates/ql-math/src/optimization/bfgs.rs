@@ -6,7 +6,7 @@
 
 use crate::array::Array;
 use crate::optimization::{
-    Constraint, CostFunction, EndCriteria, EndCriteriaType, OptimizationResult,
+    Constraint, CostFunction, EndCriteria, EndCriteriaType, OptimizationMethod, OptimizationResult,
 };
 use ql_core::{errors::Result, Real};
 
@@ -33,6 +33,18 @@ impl Bfgs {
         constraint: &K,
         initial_values: &Array,
         end_criteria: &EndCriteria,
+    ) -> Result<OptimizationResult> {
+        OptimizationMethod::minimize(self, cost_fn, constraint, initial_values, end_criteria)
+    }
+}
+
+impl OptimizationMethod for Bfgs {
+    fn minimize(
+        &self,
+        cost_fn: &dyn CostFunction,
+        constraint: &dyn Constraint,
+        initial_values: &Array,
+        end_criteria: &EndCriteria,
     ) -> Result<OptimizationResult> {
         let n = initial_values.size();
         let mut x = initial_values.clone();
@@ -154,6 +166,18 @@ impl SteepestDescent {
         constraint: &K,
         initial_values: &Array,
         end_criteria: &EndCriteria,
+    ) -> Result<OptimizationResult> {
+        OptimizationMethod::minimize(self, cost_fn, constraint, initial_values, end_criteria)
+    }
+}
+
+impl OptimizationMethod for SteepestDescent {
+    fn minimize(
+        &self,
+        cost_fn: &dyn CostFunction,
+        constraint: &dyn Constraint,
+        initial_values: &Array,
+        end_criteria: &EndCriteria,
     ) -> Result<OptimizationResult> {
         let mut x = initial_values.clone();
         let mut value = cost_fn.value(&x);
@@ -256,6 +280,18 @@ impl DifferentialEvolution {
         constraint: &K,
         initial_values: &Array,
         end_criteria: &EndCriteria,
+    ) -> Result<OptimizationResult> {
+        OptimizationMethod::minimize(self, cost_fn, constraint, initial_values, end_criteria)
+    }
+}
+
+impl OptimizationMethod for DifferentialEvolution {
+    fn minimize(
+        &self,
+        cost_fn: &dyn CostFunction,
+        constraint: &dyn Constraint,
+        initial_values: &Array,
+        end_criteria: &EndCriteria,
     ) -> Result<OptimizationResult> {
         let n = initial_values.size();
         let np = self.population_size.max(4); // need at least 4 for DE
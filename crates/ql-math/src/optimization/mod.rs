@@ -192,6 +192,29 @@ pub struct OptimizationResult {
     pub end_type: EndCriteriaType,
 }
 
+// ── Optimization method trait ─────────────────────────────────────────────────
+
+/// An optimizer minimizing a [`CostFunction`] under a [`Constraint`].
+///
+/// The trait is dyn-compatible, so calibration code can take a
+/// `&dyn OptimizationMethod` and leave the choice of optimizer to the
+/// caller.  Each optimizer also keeps a generic inherent `minimize` that
+/// forwards here, so existing calls on a concrete optimizer need not
+/// import the trait.
+///
+/// Corresponds to `QuantLib::OptimizationMethod`.
+pub trait OptimizationMethod {
+    /// Minimize `cost_fn` subject to `constraint`, starting from
+    /// `initial_values` and stopping on `end_criteria`.
+    fn minimize(
+        &self,
+        cost_fn: &dyn CostFunction,
+        constraint: &dyn Constraint,
+        initial_values: &Array,
+        end_criteria: &EndCriteria,
+    ) -> Result<OptimizationResult>;
+}
+
 // ── Simplex (Nelder–Mead) ─────────────────────────────────────────────────────
 
 /// Nelder–Mead simplex optimizer.
@@ -214,6 +237,18 @@ impl Simplex {
         constraint: &K,
         initial_values: &Array,
         end_criteria: &EndCriteria,
+    ) -> Result<OptimizationResult> {
+        OptimizationMethod::minimize(self, cost_fn, constraint, initial_values, end_criteria)
+    }
+}
+
+impl OptimizationMethod for Simplex {
+    fn minimize(
+        &self,
+        cost_fn: &dyn CostFunction,
+        constraint: &dyn Constraint,
+        initial_values: &Array,
+        end_criteria: &EndCriteria,
     ) -> Result<OptimizationResult> {
         let n = initial_values.size();
         let np1 = n + 1;
@@ -355,6 +390,11 @@ impl Simplex {
 
 /// Levenberg–Marquardt least-squares optimizer.
 ///
+/// Each iteration solves the damped normal equations
+/// `(JᵀJ + μ·diag(JᵀJ))·δ = −Jᵀr` for the residuals `r` and Jacobian `J`
+/// of the cost function, shrinking `μ` after a successful step and growing
+/// it after a rejected one.  Steps that leave the constraint are rejected.
+///
 /// Corresponds to `QuantLib::LevenbergMarquardt`.
 pub struct LevenbergMarquardt {
     epsfcn: Real,
@@ -364,6 +404,10 @@ pub struct LevenbergMarquardt {
 
 impl LevenbergMarquardt {
     /// Create a new L-M optimizer.
+    ///
+    /// `xtol` bounds the relative step size and `gtol` the gradient norm at
+    /// convergence.  `epsfcn` is kept for signature compatibility with
+    /// QuantLib; the Jacobian comes from [`CostFunction::jacobian`].
     pub fn new(epsfcn: Real, xtol: Real, gtol: Real) -> Self {
         Self { epsfcn, xtol, gtol }
     }
@@ -372,66 +416,107 @@ impl LevenbergMarquardt {
     pub fn minimize<C: CostFunction, K: Constraint>(
         &self,
         cost_fn: &C,
-        _constraint: &K,
+        constraint: &K,
         initial_values: &Array,
         end_criteria: &EndCriteria,
     ) -> Result<OptimizationResult> {
+        OptimizationMethod::minimize(self, cost_fn, constraint, initial_values, end_criteria)
+    }
+}
+
+impl OptimizationMethod for LevenbergMarquardt {
+    fn minimize(
+        &self,
+        cost_fn: &dyn CostFunction,
+        constraint: &dyn Constraint,
+        initial_values: &Array,
+        end_criteria: &EndCriteria,
+    ) -> Result<OptimizationResult> {
+        let _ = self.epsfcn;
+        let n = initial_values.size();
         let mut x = initial_values.clone();
-        let mut lambda = 1e-3;
-        let mut value = cost_fn.value(&x);
+        let mut residuals = cost_fn.values(&x);
+        let mut value = 0.5 * residuals.norm_squared();
+        let mut mu = 1e-3;
+        let mut stationary_count = 0;
+
+        let done = |x: Array, value: Real, iterations: usize, end_type: EndCriteriaType| {
+            Ok(OptimizationResult {
+                x,
+                value,
+                iterations,
+                end_type,
+            })
+        };
 
         for iteration in 0..end_criteria.max_iterations {
             if value < end_criteria.root_epsilon {
-                return Ok(OptimizationResult {
-                    x,
-                    value,
-                    iterations: iteration,
-                    end_type: EndCriteriaType::RootEpsilon,
-                });
+                return done(x, value, iteration, EndCriteriaType::RootEpsilon);
             }
 
-            let grad = cost_fn.gradient(&x);
-            let grad_norm = grad.norm();
-            if grad_norm < self.gtol {
-                return Ok(OptimizationResult {
-                    x,
-                    value,
-                    iterations: iteration,
-                    end_type: EndCriteriaType::GradientNormEpsilon,
-                });
+            let jacobian = cost_fn.jacobian(&x);
+            let mut jtj = nalgebra::DMatrix::<Real>::zeros(n, n);
+            let mut gradient = nalgebra::DVector::<Real>::zeros(n);
+            for (row, &r) in jacobian.iter().zip(residuals.iter()) {
+                for a in 0..n {
+                    gradient[a] += row[a] * r;
+                    for b in 0..n {
+                        jtj[(a, b)] += row[a] * row[b];
+                    }
+                }
             }
-
-            // Simple gradient descent with Levenberg damping
-            let step = &grad * (-1.0 / (grad_norm + lambda));
-            if step.norm() < self.xtol {
-                return Ok(OptimizationResult {
-                    x,
-                    value,
-                    iterations: iteration,
-                    end_type: EndCriteriaType::FunctionEpsilon,
-                });
+            if gradient.norm() < self.gtol {
+                return done(x, value, iteration, EndCriteriaType::GradientNormEpsilon);
             }
 
-            let x_new = &x + &step;
-            let value_new = cost_fn.value(&x_new);
+            let mut accepted = None;
+            for _ in 0..30 {
+                let mut damped = jtj.clone();
+                for a in 0..n {
+                    damped[(a, a)] += mu * jtj[(a, a)].max(1e-12);
+                }
+                let Some(step) = damped.cholesky().map(|c| c.solve(&(-&gradient))) else {
+                    mu *= 10.0;
+                    continue;
+                };
+                if step.norm() < self.xtol * (x.norm() + self.xtol) {
+                    return done(x, value, iteration, EndCriteriaType::FunctionEpsilon);
+                }
+                let candidate = &x + &Array::from(step);
+                if constraint.test(&candidate) {
+                    let candidate_residuals = cost_fn.values(&candidate);
+                    let candidate_value = 0.5 * candidate_residuals.norm_squared();
+                    if candidate_value < value {
+                        mu = (mu * 0.1).max(1e-12);
+                        accepted = Some((candidate, candidate_residuals, candidate_value));
+                        break;
+                    }
+                }
+                mu *= 10.0;
+            }
 
-            if value_new < value {
-                x = x_new;
-                value = value_new;
-                lambda *= 0.1;
+            let Some((candidate, candidate_residuals, candidate_value)) = accepted else {
+                return done(x, value, iteration, EndCriteriaType::StationaryPoint);
+            };
+            if value - candidate_value < end_criteria.function_epsilon * value.max(1.0) {
+                stationary_count += 1;
             } else {
-                lambda *= 10.0;
+                stationary_count = 0;
+            }
+            x = candidate;
+            residuals = candidate_residuals;
+            value = candidate_value;
+            if stationary_count >= end_criteria.max_stationary_state_iterations {
+                return done(x, value, iteration + 1, EndCriteriaType::StationaryPoint);
             }
-
-            let _ = self.epsfcn; // use to suppress unused field warning
         }
 
-        Ok(OptimizationResult {
+        done(
             x,
             value,
-            iterations: end_criteria.max_iterations,
-            end_type: EndCriteriaType::MaxIterations,
-        })
+            end_criteria.max_iterations,
+            EndCriteriaType::MaxIterations,
+        )
     }
 }
 
@@ -455,6 +540,18 @@ impl ConjugateGradient {
         constraint: &K,
         initial_values: &Array,
         end_criteria: &EndCriteria,
+    ) -> Result<OptimizationResult> {
+        OptimizationMethod::minimize(self, cost_fn, constraint, initial_values, end_criteria)
+    }
+}
+
+impl OptimizationMethod for ConjugateGradient {
+    fn minimize(
+        &self,
+        cost_fn: &dyn CostFunction,
+        constraint: &dyn Constraint,
+        initial_values: &Array,
+        end_criteria: &EndCriteria,
     ) -> Result<OptimizationResult> {
         let mut x = initial_values.clone();
         let mut value = cost_fn.value(&x);
@@ -594,6 +691,129 @@ mod tests {
         assert!((result.x[0] - 3.0).abs() < 0.1, "got x = {}", result.x[0]);
     }
 
+    #[test]
+    fn levenberg_marquardt_rosenbrock() {
+        let opt = LevenbergMarquardt::new(1e-8, 1e-14, 1e-14);
+        let ec = EndCriteria::new(200, 20, 1e-24, 1e-16, 1e-14);
+        let result = opt
+            .minimize(
+                &Rosenbrock,
+                &NoConstraint,
+                &Array::from_slice(&[-1.2, 1.0]),
+                &ec,
+            )
+            .unwrap();
+        assert!(result.iterations < 50, "{} iterations", result.iterations);
+        assert!((result.x[0] - 1.0).abs() < 1e-8, "x[0] = {}", result.x[0]);
+        assert!((result.x[1] - 1.0).abs() < 1e-8, "x[1] = {}", result.x[1]);
+    }
+
+    /// Straight-line fit `a + b·t` to five points.
+    struct LineFit;
+    impl CostFunction for LineFit {
+        fn values(&self, x: &Array) -> Array {
+            let points = [(0.0, 1.1), (1.0, 2.9), (2.0, 5.2), (3.0, 6.8), (4.0, 9.1)];
+            Array::from_vec(points.iter().map(|&(t, y)| x[0] + x[1] * t - y).collect())
+        }
+    }
+
+    #[test]
+    fn levenberg_marquardt_solves_linear_least_squares() {
+        // The normal equations give b = Σ(t−t̄)(y−ȳ)/Σ(t−t̄)² = 1.99 and
+        // a = ȳ − b·t̄ = 1.04.  Damped gradient descent, as this optimizer
+        // used to be, ran through its whole iteration budget on this fit;
+        // Gauss-Newton steps land on the solution in a handful.
+        let opt = LevenbergMarquardt::new(1e-8, 1e-12, 1e-12);
+        let ec = EndCriteria::new(100, 10, 1e-30, 1e-16, 1e-12);
+        let result = opt
+            .minimize(
+                &LineFit,
+                &NoConstraint,
+                &Array::from_slice(&[0.0, 0.0]),
+                &ec,
+            )
+            .unwrap();
+        assert!(result.iterations < 10, "{} iterations", result.iterations);
+        assert!((result.x[0] - 1.04).abs() < 1e-7, "a = {}", result.x[0]);
+        assert!((result.x[1] - 1.99).abs() < 1e-7, "b = {}", result.x[1]);
+    }
+
+    #[test]
+    fn levenberg_marquardt_rejects_steps_outside_the_constraint() {
+        // The unconstrained minimum of (x + 3)² is at −3.  The constraint
+        // used to be ignored; now steps leaving x > 0 are rejected and the
+        // optimizer stops at a point inside it.
+        struct Shifted;
+        impl CostFunction for Shifted {
+            fn values(&self, x: &Array) -> Array {
+                Array::from_slice(&[x[0] + 3.0])
+            }
+        }
+        let opt = LevenbergMarquardt::new(1e-8, 1e-12, 1e-12);
+        let result = opt
+            .minimize(
+                &Shifted,
+                &PositiveConstraint,
+                &Array::from_slice(&[1.0]),
+                &EndCriteria::default(),
+            )
+            .unwrap();
+        assert!(result.x[0] > 0.0, "x = {}", result.x[0]);
+        assert!(result.value < Shifted.value(&Array::from_slice(&[1.0])));
+    }
+
+    #[test]
+    fn levenberg_marquardt_xtol_is_relative_to_x() {
+        // Steps shorter than xtol·(|x| + xtol) end the run, so a coarse
+        // xtol stops a fit around 1e6 once it is within ~1e-3 relative.
+        struct Large;
+        impl CostFunction for Large {
+            fn values(&self, x: &Array) -> Array {
+                Array::from_slice(&[x[0] - 1e6, 1e-3 * (x[0] - 1e6).powi(2)])
+            }
+        }
+        let opt = LevenbergMarquardt::new(1e-8, 1e-3, 0.0);
+        let ec = EndCriteria::new(1000, 1000, 0.0, 0.0, 0.0);
+        let result = opt
+            .minimize(&Large, &NoConstraint, &Array::from_slice(&[1.0]), &ec)
+            .unwrap();
+        assert_eq!(result.end_type, EndCriteriaType::FunctionEpsilon);
+        assert!(
+            (result.x[0] / 1e6 - 1.0).abs() < 1e-2,
+            "x = {}",
+            result.x[0]
+        );
+    }
+
+    #[test]
+    fn every_optimizer_minimizes_through_the_trait_object() {
+        use super::bfgs::{Bfgs, DifferentialEvolution, SteepestDescent};
+        let methods: Vec<Box<dyn OptimizationMethod>> = vec![
+            Box::new(Simplex::new(0.5)),
+            Box::new(LevenbergMarquardt::new(1e-8, 1e-12, 1e-12)),
+            Box::new(ConjugateGradient::new()),
+            Box::new(Bfgs::new()),
+            Box::new(SteepestDescent::new()),
+            Box::new(DifferentialEvolution::new(20, 0.9, 0.8).with_seed(42)),
+        ];
+        let ec = EndCriteria::new(1000, 100, 1e-12, 1e-12, 1e-12);
+        let x0 = Array::from_slice(&[0.0]);
+        for method in &methods {
+            let result = method
+                .minimize(&SimpleQuadratic, &NoConstraint, &x0, &ec)
+                .unwrap();
+            assert!((result.x[0] - 3.0).abs() < 1e-3, "got x = {}", result.x[0]);
+        }
+        // The inherent generic method is the same optimizer.
+        let direct = Simplex::new(0.5)
+            .minimize(&SimpleQuadratic, &NoConstraint, &x0, &ec)
+            .unwrap();
+        let via_trait = methods[0]
+            .minimize(&SimpleQuadratic, &NoConstraint, &x0, &ec)
+            .unwrap();
+        assert_eq!(direct.x[0], via_trait.x[0]);
+    }
+
     #[test]
     fn positive_constraint() {
         let c = PositiveConstraint;
@@ -70,6 +70,74 @@ impl InverseCumulativeNormalRng {
     }
 }
 
+// ── Uniform sequence generator trait ──────────────────────────────────────────
+
+/// A generator of uniform vectors in `[0, 1)^d`, either pseudo-random or
+/// low-discrepancy.
+///
+/// Monte Carlo code that only needs uniform draws can be written once against
+/// this trait and run with any of [`RandomSequenceGenerator`], [`HaltonRsg`],
+/// or [`SobolRsg`](sobol::SobolRsg).
+///
+/// Corresponds to the `PseudoRandom` / `LowDiscrepancy` RNG traits'
+/// `ursg_type` in QuantLib.
+pub trait UniformRsg: Send {
+    /// Build a generator of the given dimension.
+    ///
+    /// Low-discrepancy sequences are deterministic and ignore `seed`.
+    fn from_seed(dimension: usize, seed: u64) -> Self
+    where
+        Self: Sized;
+
+    /// Dimension of the generated sequences.
+    fn dimension(&self) -> usize;
+
+    /// Generate the next vector.
+    fn next_sequence(&mut self) -> Vec<Real>;
+}
+
+impl UniformRsg for RandomSequenceGenerator {
+    fn from_seed(dimension: usize, seed: u64) -> Self {
+        Self::new(dimension, seed)
+    }
+
+    fn dimension(&self) -> usize {
+        self.dimension
+    }
+
+    fn next_sequence(&mut self) -> Vec<Real> {
+        RandomSequenceGenerator::next_sequence(self)
+    }
+}
+
+impl UniformRsg for HaltonRsg {
+    fn from_seed(dimension: usize, _seed: u64) -> Self {
+        Self::new(dimension, 0)
+    }
+
+    fn dimension(&self) -> usize {
+        self.dimension
+    }
+
+    fn next_sequence(&mut self) -> Vec<Real> {
+        HaltonRsg::next_sequence(self)
+    }
+}
+
+impl UniformRsg for sobol::SobolRsg {
+    fn from_seed(dimension: usize, _seed: u64) -> Self {
+        Self::new(dimension, 0)
+    }
+
+    fn dimension(&self) -> usize {
+        sobol::SobolRsg::dimension(self)
+    }
+
+    fn next_sequence(&mut self) -> Vec<Real> {
+        sobol::SobolRsg::next_sequence(self)
+    }
+}
+
 // ── Random Sequence Generator ─────────────────────────────────────────────────
 
 /// Generates sequences of pseudo-random numbers as `Vec<Real>`.
@@ -246,6 +314,24 @@ mod tests {
         assert!((pt[2] - 0.2).abs() < 1e-12);
     }
 
+    #[test]
+    fn uniform_rsg_trait_dispatch() {
+        fn first<R: UniformRsg>(dimension: usize) -> Vec<Real> {
+            let mut rsg = R::from_seed(dimension, 42);
+            assert_eq!(rsg.dimension(), dimension);
+            rsg.next_sequence()
+        }
+        assert_eq!(
+            first::<RandomSequenceGenerator>(3),
+            RandomSequenceGenerator::new(3, 42).next_sequence()
+        );
+        assert_eq!(first::<HaltonRsg>(3), HaltonRsg::new(3, 0).next_sequence());
+        assert_eq!(
+            first::<sobol::SobolRsg>(3),
+            sobol::SobolRsg::new(3, 0).next_sequence()
+        );
+    }
+
     #[test]
     fn halton_fills_unit_cube() {
         let mut halton = HaltonRsg::new(2, 0);
@@ -15,6 +15,7 @@ pub const MAX_DIMENSION: usize = 21201;
 /// Implements the Gray-code optimisation for fast point generation.
 ///
 /// Corresponds to `QuantLib::SobolRsg`.
+#[derive(Clone)]
 pub struct SobolRsg {
     dimension: usize,
     sequence_count: u64,
@@ -44,13 +45,68 @@ impl SobolRsg {
             int_sequence,
             direction_numbers,
         };
+        rsg.skip_to(skip);
+        rsg
+    }
 
-        // Skip the first `skip` points
-        for _ in 0..skip {
-            rsg.next_int_sequence();
+    /// Number of points in the sequence before it repeats.
+    pub const PERIOD: u64 = 1 << Self::BITS;
+
+    /// Position the generator so that the next vector drawn is the one at
+    /// `index`, as if `index` vectors had been drawn from the start.
+    ///
+    /// The `index`-th state is the XOR of the direction numbers selected
+    /// by the bits of the Gray code `index ^ (index >> 1)`, so the jump
+    /// costs one pass over the bits rather than `index` draws.
+    ///
+    /// # Panics
+    /// Panics if `index` is not below [`PERIOD`](Self::PERIOD).
+    pub fn skip_to(&mut self, index: u64) {
+        assert!(
+            index < Self::PERIOD,
+            "Sobol index {index} is beyond the period 2^{}",
+            Self::BITS
+        );
+        let gray = index ^ (index >> 1);
+        for (value, directions) in self.int_sequence.iter_mut().zip(&self.direction_numbers) {
+            *value = directions
+                .iter()
+                .enumerate()
+                .filter(|&(bit, _)| gray >> bit & 1 == 1)
+                .fold(0, |acc, (_, &v)| acc ^ v);
         }
+        self.sequence_count = index;
+    }
 
-        rsg
+    /// Split the rest of the period into `n_chunks` generators positioned
+    /// at disjoint, evenly spaced offsets from the current position.
+    ///
+    /// Each chunk covers [`chunk_length`](Self::chunk_length) points, so
+    /// workers that each draw no more than that from their own generator
+    /// never see the same vector, and the split depends only on the
+    /// current position and `n_chunks`.
+    ///
+    /// # Panics
+    /// Panics if `n_chunks` is zero or exceeds the points left in the period.
+    pub fn split(&self, n_chunks: usize) -> Vec<SobolRsg> {
+        let length = self.chunk_length(n_chunks);
+        (0..n_chunks as u64)
+            .map(|i| {
+                let mut chunk = self.clone();
+                chunk.skip_to(self.sequence_count + i * length);
+                chunk
+            })
+            .collect()
+    }
+
+    /// Number of points in each chunk returned by [`split`](Self::split).
+    pub fn chunk_length(&self, n_chunks: usize) -> u64 {
+        let remaining = Self::PERIOD - self.sequence_count;
+        assert!(
+            n_chunks > 0 && n_chunks as u64 <= remaining,
+            "cannot split {remaining} remaining Sobol points into {n_chunks} chunks"
+        );
+        remaining / n_chunks as u64
     }
 
     /// Dimension of the generated sequences.
@@ -308,4 +364,43 @@ mod tests {
             );
         }
     }
+
+    #[test]
+    fn sobol_skip_to_matches_sequential_draws() {
+        let mut sequential = SobolRsg::new(8, 0);
+        let draws: Vec<Vec<Real>> = (0..600).map(|_| sequential.next_sequence()).collect();
+        for index in [0, 1, 2, 3, 127, 128, 255, 256, 511, 597] {
+            let mut jumped = SobolRsg::new(8, 0);
+            jumped.next_sequence();
+            jumped.skip_to(index);
+            assert_eq!(jumped.sequence_count(), index);
+            for expected in &draws[index as usize..index as usize + 3] {
+                assert_eq!(&jumped.next_sequence(), expected, "from index {index}");
+            }
+        }
+    }
+
+    #[test]
+    fn sobol_chunks_are_disjoint() {
+        let base = SobolRsg::new(4, 10);
+        let length = base.chunk_length(4);
+        assert_eq!(length, (SobolRsg::PERIOD - 10) / 4);
+
+        let mut seen = std::collections::HashSet::new();
+        for (i, mut chunk) in base.split(4).into_iter().enumerate() {
+            let start = 10 + i as u64 * length;
+            assert_eq!(chunk.sequence_count(), start);
+            let mut reference = SobolRsg::new(4, 0);
+            reference.skip_to(start);
+            for _ in 0..500 {
+                let v = chunk.next_sequence();
+                assert_eq!(v, reference.next_sequence());
+                assert!(seen.insert(v.iter().map(|x| x.to_bits()).collect::<Vec<_>>()));
+            }
+        }
+        // The first chunk continues where the base generator would.
+        let mut first = base.split(4).remove(0);
+        let mut cloned = base.clone();
+        assert_eq!(first.next_sequence(), cloned.next_sequence());
+    }
 }
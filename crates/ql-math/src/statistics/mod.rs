@@ -240,18 +240,82 @@ impl GeneralStatistics {
         self.data.iter().map(|(x, _)| *x).reduce(f64::max)
     }
 
-    /// Percentile (0..=100). Uses linear interpolation between sorted samples.
+    /// Percentile (0..=100) of the weighted empirical distribution.
+    ///
+    /// Sorted samples sit at the cumulative-weight midpoints
+    /// `(Cᵢ − wᵢ/2 − w₁/2) / (W − w₁/2 − wₙ/2)`, which run from 0 at the
+    /// smallest sample to 1 at the largest, and the percentile interpolates
+    /// linearly between them.  With equal weights these are the positions
+    /// `i/(n−1)` of the usual unweighted definition.  Samples of zero
+    /// weight are ignored.
     pub fn percentile(&mut self, p: Real) -> Option<Real> {
-        if self.data.is_empty() {
-            return None;
-        }
         assert!((0.0..=100.0).contains(&p), "percentile must be in [0, 100]");
+        self.sort();
+
+        let first_weight = self.data.first()?.1;
+        if self.data.iter().all(|&(_, w)| w == first_weight) {
+            return self.unweighted_percentile(p);
+        }
+
+        let samples: Vec<(Real, Real)> = self
+            .data
+            .iter()
+            .copied()
+            .filter(|&(_, w)| w > 0.0)
+            .collect();
+        let (w_first, w_last) = (samples.first()?.1, samples.last()?.1);
+        if samples.len() == 1 {
+            return Some(samples[0].0);
+        }
+        let span = self.sum_weights() - 0.5 * (w_first + w_last);
+        let target = p / 100.0 * span;
+
+        let mut lower = (samples[0].0, 0.0);
+        let mut cumulative = w_first;
+        for &(x, w) in &samples[1..] {
+            let position = cumulative + 0.5 * w - 0.5 * w_first;
+            cumulative += w;
+            if target <= position {
+                let frac = (target - lower.1) / (position - lower.1);
+                return Some(lower.0 + (x - lower.0) * frac);
+            }
+            lower = (x, position);
+        }
+        Some(lower.0)
+    }
+
+    /// Value at risk at `confidence` (e.g. 0.99): the negative of the
+    /// `1 − confidence` quantile of the weighted distribution.
+    pub fn value_at_risk(&mut self, confidence: Real) -> Option<Real> {
+        assert!(
+            confidence > 0.0 && confidence < 1.0,
+            "confidence must be in (0, 1)"
+        );
+        self.percentile(100.0 * (1.0 - confidence)).map(|q| -q)
+    }
+
+    /// Expected shortfall at `confidence`: the negative of the weighted
+    /// mean of the samples at or below the `1 − confidence` quantile.
+    pub fn expected_shortfall(&mut self, confidence: Real) -> Option<Real> {
+        let quantile = -self.value_at_risk(confidence)?;
+        let (sum, weight) = self
+            .data
+            .iter()
+            .filter(|&&(x, _)| x <= quantile)
+            .fold((0.0, 0.0), |(s, sw), &(x, w)| (s + w * x, sw + w));
+        (weight > 0.0).then(|| -sum / weight)
+    }
 
+    /// Sort the samples by value, if not already sorted.
+    fn sort(&mut self) {
         if !self.sorted {
             self.data.sort_by(|a, b| a.0.partial_cmp(&b.0).unwrap());
             self.sorted = true;
         }
+    }
 
+    /// Percentile of sorted, equally weighted samples.
+    fn unweighted_percentile(&self, p: Real) -> Option<Real> {
         let n = self.data.len();
         if n == 1 {
             return Some(self.data[0].0);
@@ -332,12 +396,17 @@ impl IncrementalStatistics {
         let delta_n2 = delta_n * delta_n;
         let term1 = delta * delta_n * n1;
 
+        // `term1` already carries one factor of `w`; the higher-order terms
+        // must not pick up more.
+        let delta_w = delta / self.sum_w;
         self.m1 += delta_n;
-        self.m4 +=
-            term1 * delta_n2 * (self.sum_w * self.sum_w - 3.0 * w * self.sum_w + 3.0 * w * w)
-                + 6.0 * delta_n2 * self.m2
-                - 4.0 * delta_n * self.m3;
-        self.m3 += term1 * delta_n * (self.sum_w - 2.0 * w) - 3.0 * delta_n * self.m2;
+        self.m4 += term1
+            * delta_w
+            * delta_w
+            * (self.sum_w * self.sum_w - 3.0 * w * self.sum_w + 3.0 * w * w)
+            + 6.0 * delta_n2 * self.m2
+            - 4.0 * delta_n * self.m3;
+        self.m3 += term1 * delta_w * (self.sum_w - 2.0 * w) - 3.0 * delta_n * self.m2;
         self.m2 += term1;
 
         if x < self.min {
@@ -417,6 +486,46 @@ impl IncrementalStatistics {
         }
     }
 
+    /// Fold in the samples accumulated by `other`, as if they had been
+    /// added to `self` one by one.
+    ///
+    /// The running moments are combined with the pairwise update of Chan,
+    /// Golub and LeVeque, so independently filled accumulators (one per
+    /// thread, say) can be merged without revisiting their samples.
+    pub fn merge(&mut self, other: &IncrementalStatistics) {
+        if other.n == 0 {
+            return;
+        }
+        if self.n == 0 {
+            *self = other.clone();
+            return;
+        }
+        let (wa, wb) = (self.sum_w, other.sum_w);
+        let w = wa + wb;
+        let delta = other.m1 - self.m1;
+        let delta2 = delta * delta;
+
+        let m2 = self.m2 + other.m2 + delta2 * wa * wb / w;
+        let m3 = self.m3
+            + other.m3
+            + delta * delta2 * wa * wb * (wa - wb) / (w * w)
+            + 3.0 * delta * (wa * other.m2 - wb * self.m2) / w;
+        let m4 = self.m4
+            + other.m4
+            + delta2 * delta2 * wa * wb * (wa * wa - wa * wb + wb * wb) / (w * w * w)
+            + 6.0 * delta2 * (wa * wa * other.m2 + wb * wb * self.m2) / (w * w)
+            + 4.0 * delta * (wa * other.m3 - wb * self.m3) / w;
+
+        self.n += other.n;
+        self.sum_w = w;
+        self.m1 += delta * wb / w;
+        self.m2 = m2;
+        self.m3 = m3;
+        self.m4 = m4;
+        self.min = self.min.min(other.min);
+        self.max = self.max.max(other.max);
+    }
+
     /// Reset.
     pub fn reset(&mut self) {
         *self = Self::new();
@@ -425,11 +534,12 @@ impl IncrementalStatistics {
 
 // ── ConvergenceStatistics ─────────────────────────────────────────────────────
 
-/// A statistics accumulator that records convergence snapshots at power-of-2
+/// A statistics accumulator that records convergence snapshots at chosen
 /// sample counts.
 ///
 /// Wraps an `IncrementalStatistics` and captures the running mean (and error
-/// estimate) at 1, 2, 4, 8, 16, … samples. This is used in Monte Carlo
+/// estimate) at 1, 2, 4, 8, 16, … samples, or at an explicit schedule given
+/// to [`with_triggers`](Self::with_triggers). This is used in Monte Carlo
 /// pricing to assess convergence.
 ///
 /// Corresponds to `QuantLib::ConvergenceStatistics`.
@@ -438,6 +548,9 @@ pub struct ConvergenceStatistics {
     inner: IncrementalStatistics,
     /// (samples, mean, error_estimate) snapshots
     snapshots: Vec<(usize, Real, Real)>,
+    /// Explicit sample counts to snapshot at, ascending; `None` for the
+    /// powers of two.
+    triggers: Option<Vec<usize>>,
     next_trigger: usize,
 }
 
@@ -447,31 +560,51 @@ impl ConvergenceStatistics {
         Self {
             inner: IncrementalStatistics::new(),
             snapshots: Vec::new(),
+            triggers: None,
             next_trigger: 1,
         }
     }
 
+    /// Create an accumulator snapshotting at the given sample counts
+    /// instead of the powers of two.
+    ///
+    /// The counts may come in any order; duplicates and zero are ignored.
+    pub fn with_triggers(mut triggers: Vec<usize>) -> Self {
+        triggers.retain(|&n| n > 0);
+        triggers.sort_unstable();
+        triggers.dedup();
+        Self {
+            next_trigger: triggers.first().copied().unwrap_or(usize::MAX),
+            triggers: Some(triggers),
+            ..Self::new()
+        }
+    }
+
     /// Add a value.
     pub fn add(&mut self, x: Real) {
         self.inner.add(x);
-        let n = self.inner.samples();
-        if n == self.next_trigger {
-            let mean = self.inner.mean().unwrap_or(0.0);
-            let err = self.inner.error_estimate().unwrap_or(0.0);
-            self.snapshots.push((n, mean, err));
-            self.next_trigger *= 2;
-        }
+        self.snapshot_if_triggered();
     }
 
     /// Add a weighted value.
     pub fn add_weighted(&mut self, x: Real, w: Real) {
         self.inner.add_weighted(x, w);
+        self.snapshot_if_triggered();
+    }
+
+    fn snapshot_if_triggered(&mut self) {
         let n = self.inner.samples();
         if n == self.next_trigger {
             let mean = self.inner.mean().unwrap_or(0.0);
             let err = self.inner.error_estimate().unwrap_or(0.0);
             self.snapshots.push((n, mean, err));
-            self.next_trigger *= 2;
+            self.next_trigger = match &self.triggers {
+                Some(triggers) => triggers
+                    .get(self.snapshots.len())
+                    .copied()
+                    .unwrap_or(usize::MAX),
+                None => self.next_trigger * 2,
+            };
         }
     }
 
@@ -480,14 +613,23 @@ impl ConvergenceStatistics {
         &self.snapshots
     }
 
+    /// Root-mean-square error of the mean at the latest snapshot, i.e. its
+    /// standard error `σ/√n`; `None` before the first snapshot.
+    pub fn rms_error(&self) -> Option<Real> {
+        self.snapshots.last().map(|&(_, _, error)| error)
+    }
+
     /// Access the underlying statistics accumulator.
     pub fn statistics(&self) -> &IncrementalStatistics {
         &self.inner
     }
 
-    /// Reset.
+    /// Reset, keeping the snapshot schedule.
     pub fn reset(&mut self) {
-        *self = Self::new();
+        *self = match self.triggers.take() {
+            Some(triggers) => Self::with_triggers(triggers),
+            None => Self::new(),
+        };
     }
 }
 
@@ -671,6 +813,71 @@ mod tests {
         assert!((p25 - 25.75).abs() < 1e-10, "p25 = {p25}");
     }
 
+    #[test]
+    fn weighted_percentile_follows_the_weights() {
+        let mut unweighted = GeneralStatistics::new();
+        let mut weighted = GeneralStatistics::new();
+        for (x, w) in [(1.0, 1.0), (2.0, 1.0), (3.0, 1.0), (4.0, 1.0), (5.0, 10.0)] {
+            unweighted.add(x);
+            weighted.add_weighted(x, w);
+        }
+        assert_eq!(unweighted.median(), Some(3.0));
+        // Positions 0, 1, 2, 3 and 8.5 out of 8.5: the median falls
+        // between 4 and 5, at 4 + (4.25 − 3)/5.5.
+        let median = weighted.median().unwrap();
+        assert!(
+            (median - (4.0 + 1.25 / 5.5)).abs() < 1e-12,
+            "median = {median}"
+        );
+        assert_eq!(weighted.percentile(0.0), Some(1.0));
+        assert_eq!(weighted.percentile(100.0), Some(5.0));
+
+        // Doubling every weight, or adding zero-weight samples, changes nothing.
+        let mut doubled = GeneralStatistics::new();
+        for (x, w) in [
+            (1.0, 2.0),
+            (2.0, 2.0),
+            (3.0, 2.0),
+            (4.0, 2.0),
+            (5.0, 20.0),
+            (0.0, 0.0),
+        ] {
+            doubled.add_weighted(x, w);
+        }
+        for p in [10.0, 37.0, 50.0, 90.0] {
+            let (a, b) = (
+                weighted.percentile(p).unwrap(),
+                doubled.percentile(p).unwrap(),
+            );
+            assert!((a - b).abs() < 1e-12, "p{p}: {a} vs {b}");
+        }
+    }
+
+    #[test]
+    fn value_at_risk_and_expected_shortfall() {
+        // P&L of -10..=89 with unit weights: the 5% quantile is -5.05.
+        let mut pnl = GeneralStatistics::new();
+        for i in -10..90 {
+            pnl.add(i as Real);
+        }
+        let var = pnl.value_at_risk(0.95).unwrap();
+        assert!((var - 5.05).abs() < 1e-12, "VaR = {var}");
+        // Samples -10..=-6 lie below it.
+        let es = pnl.expected_shortfall(0.95).unwrap();
+        assert!((es - 8.0).abs() < 1e-12, "ES = {es}");
+
+        // Heavier weights on the worst losses push both measures out.
+        let mut skewed = GeneralStatistics::new();
+        for i in -10..90 {
+            skewed.add_weighted(i as Real, if i < -5 { 5.0 } else { 1.0 });
+        }
+        let skewed_var = skewed.value_at_risk(0.95).unwrap();
+        let skewed_es = skewed.expected_shortfall(0.95).unwrap();
+        assert!(skewed_var > var, "{skewed_var} vs {var}");
+        assert!(skewed_es > skewed_var);
+        assert!(skewed_es <= 10.0);
+    }
+
     #[test]
     fn incremental_statistics_mean_variance() {
         let mut is = IncrementalStatistics::new();
@@ -701,6 +908,101 @@ mod tests {
         );
     }
 
+    #[test]
+    fn incremental_statistics_merge_matches_single_pass() {
+        // Skewed, unevenly split data so that every moment matters.
+        let data: Vec<Real> = (0..1000)
+            .map(|i| {
+                let x = (i as Real * 0.6180339887).fract();
+                (3.0 * x).exp() + 0.1 * i as Real
+            })
+            .collect();
+        let mut whole = IncrementalStatistics::new();
+        data.iter().for_each(|&x| whole.add(x));
+
+        for split in [1, 137, 500, 999] {
+            let (mut left, mut right) =
+                (IncrementalStatistics::new(), IncrementalStatistics::new());
+            data[..split].iter().for_each(|&x| left.add(x));
+            data[split..].iter().for_each(|&x| right.add(x));
+            left.merge(&right);
+
+            assert_eq!(left.samples(), whole.samples());
+            let close = |a: Option<Real>, b: Option<Real>| {
+                let (a, b) = (a.unwrap(), b.unwrap());
+                (a - b).abs() < 1e-10 * b.abs().max(1.0)
+            };
+            assert!(close(left.mean(), whole.mean()), "mean, split {split}");
+            assert!(
+                close(left.variance(), whole.variance()),
+                "variance, split {split}"
+            );
+            assert!(
+                close(left.skewness(), whole.skewness()),
+                "skewness, split {split}"
+            );
+            assert!(
+                close(left.kurtosis(), whole.kurtosis()),
+                "kurtosis, split {split}"
+            );
+            assert_eq!(left.minimum(), whole.minimum());
+            assert_eq!(left.maximum(), whole.maximum());
+        }
+    }
+
+    #[test]
+    fn incremental_statistics_merge_weighted_and_empty() {
+        let samples = [(1.0, 2.0), (4.0, 0.5), (-2.0, 1.5), (3.0, 1.0), (0.5, 3.0)];
+        let mut whole = IncrementalStatistics::new();
+        let (mut left, mut right) = (IncrementalStatistics::new(), IncrementalStatistics::new());
+        for (i, &(x, w)) in samples.iter().enumerate() {
+            whole.add_weighted(x, w);
+            if i < 2 { &mut left } else { &mut right }.add_weighted(x, w);
+        }
+        left.merge(&right);
+        // Weighted central moments computed directly: W = 8, mean = 0.6875,
+        // Σw(x−m)² = 21.96875, Σw(x−m)⁴ = 167.06994628906...
+        let direct_kurtosis = 8.0 * 167.0699462890625 / (21.96875 * 21.96875) - 3.0;
+        assert!((whole.kurtosis().unwrap() - direct_kurtosis).abs() < 1e-12);
+        assert!((left.mean().unwrap() - whole.mean().unwrap()).abs() < 1e-12);
+        assert!((left.variance().unwrap() - whole.variance().unwrap()).abs() < 1e-12);
+        assert!((left.kurtosis().unwrap() - whole.kurtosis().unwrap()).abs() < 1e-12);
+
+        // Merging with an empty accumulator, either way round, is a no-op.
+        let mut empty = IncrementalStatistics::new();
+        empty.merge(&whole);
+        assert_eq!(empty.samples(), whole.samples());
+        assert_eq!(empty.mean(), whole.mean());
+        whole.merge(&IncrementalStatistics::new());
+        assert_eq!(empty.variance(), whole.variance());
+    }
+
+    #[test]
+    fn convergence_statistics_explicit_triggers() {
+        let mut cs = ConvergenceStatistics::with_triggers(vec![100, 10, 50]);
+        assert_eq!(cs.rms_error(), None);
+        for i in 1..=120 {
+            cs.add(i as Real);
+        }
+        let table = cs.convergence_table();
+        let counts: Vec<usize> = table.iter().map(|&(n, ..)| n).collect();
+        assert_eq!(counts, vec![10, 50, 100]);
+        for &(n, mean, _) in table {
+            // Running mean of 1..=n.
+            assert!((mean - (n as Real + 1.0) / 2.0).abs() < 1e-12, "n = {n}");
+        }
+        // Standard error of 1..=100 at the last snapshot.
+        let expected = (100.0 * 101.0 / 12.0 / 100.0_f64).sqrt();
+        assert!((cs.rms_error().unwrap() - expected).abs() < 1e-12);
+
+        cs.reset();
+        for i in 1..=60 {
+            cs.add(i as Real);
+        }
+        let counts: Vec<usize> = cs.convergence_table().iter().map(|&(n, ..)| n).collect();
+        assert_eq!(counts, vec![10, 50]);
+    }
+
     #[test]
     fn convergence_statistics_snapshots() {
         let mut cs = ConvergenceStatistics::new();
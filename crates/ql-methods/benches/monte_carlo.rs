@@ -1,7 +1,35 @@
-use criterion::{criterion_group, criterion_main, Criterion};
+use std::sync::Arc;
 
-fn monte_carlo_benchmarks(_c: &mut Criterion) {
-    // TODO: Add Monte Carlo benchmarks
+use criterion::{criterion_group, criterion_main, BenchmarkId, Criterion};
+use ql_core::Real;
+use ql_methods::monte_carlo::{EuropeanPathPricer, MonteCarloModel};
+use ql_processes::GeneralizedBlackScholesProcess;
+use ql_termstructures::{BlackConstantVol, FlatForward};
+use ql_time::{Actual365Fixed, Date};
+
+fn black_scholes_process() -> GeneralizedBlackScholesProcess {
+    let ref_date = Date::from_ymd(2025, 1, 15).unwrap();
+    let rf = Arc::new(FlatForward::continuous(ref_date, 0.05, Actual365Fixed));
+    let div = Arc::new(FlatForward::continuous(ref_date, 0.0, Actual365Fixed));
+    let vol = Arc::new(BlackConstantVol::new(ref_date, 0.20, Actual365Fixed));
+    GeneralizedBlackScholesProcess::new(100.0, rf, div, vol)
+}
+
+fn monte_carlo_benchmarks(c: &mut Criterion) {
+    let process = black_scholes_process();
+    let pricer = EuropeanPathPricer::new(|s: Real| (s - 100.0).max(0.0), (-0.05_f64).exp());
+    let model = MonteCarloModel::new(&process, 1.0, 50, 42);
+    let cores = std::thread::available_parallelism().map_or(1, |n| n.get());
+
+    // Wall-clock scaling of the parallel simulation with the thread count.
+    let mut group = c.benchmark_group("simulate_parallel");
+    group.sample_size(10);
+    for threads in [1, 2, 4].into_iter().filter(|&n| n <= cores) {
+        group.bench_with_input(BenchmarkId::from_parameter(threads), &threads, |b, &n| {
+            b.iter(|| model.simulate_parallel(&pricer, 40_000, n))
+        });
+    }
+    group.finish();
 }
 
 criterion_group!(benches, monte_carlo_benchmarks);
@@ -6,22 +6,100 @@
 //! # Overview
 //!
 //! * [`TridiagonalOperator`] — tridiagonal matrix with Thomas-algorithm solver
-//! * [`Fdm1dSolver`] — 1-D finite difference solver for the BS PDE
-//! * [`FdmScheme`] — explicit, implicit, or Crank-Nicolson
+//! * [`Fdm1dSolver`] — 1-D finite difference solver for the BS PDE, with
+//!   optional early exercise
+//! * [`FdmScheme`] — explicit, implicit, Crank-Nicolson, Douglas, or a general θ-scheme
+//! * [`FdmSpatialScheme`] — central, upwind, or exponentially-fitted convection
 
-use ql_core::Real;
+use ql_core::{ensure, errors::Result, Real};
 
 // ─── FDM scheme selection ─────────────────────────────────────────────────────
 
 /// Finite difference time-stepping scheme.
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+///
+/// Every scheme is a member of the θ-family
+/// `(I − θ·Δt·L)·V^n = (I + (1−θ)·Δt·L)·V^{n+1}`; the named variants are
+/// presets for particular values of θ.
+#[derive(Debug, Clone, Copy, PartialEq)]
 pub enum FdmScheme {
-    /// Explicit: `V^{n} = A · V^{n+1}` — simple but conditionally stable.
+    /// Explicit: `V^{n} = A · V^{n+1}` — simple but conditionally stable (θ = 0).
     Explicit,
-    /// Fully implicit: `A · V^{n} = V^{n+1}` — unconditionally stable.
+    /// Fully implicit: `A · V^{n} = V^{n+1}` — unconditionally stable (θ = 1).
     Implicit,
-    /// Crank-Nicolson: θ-average of explicit and implicit — second-order in time.
+    /// Crank-Nicolson: θ-average of explicit and implicit — second-order in time
+    /// (θ = ½).
     CrankNicolson,
+    /// Douglas: θ = ½ + √3/6, trading some accuracy for stronger damping of
+    /// high-frequency modes than Crank-Nicolson.
+    Douglas,
+    /// General θ-scheme with `θ ∈ [0, 1]`.
+    Theta {
+        /// Implicitness weight: 0 is explicit, 1 fully implicit.
+        theta: Real,
+    },
+}
+
+impl FdmScheme {
+    /// The implicitness weight θ of the scheme.
+    pub fn theta(self) -> Real {
+        match self {
+            FdmScheme::Explicit => 0.0,
+            FdmScheme::Implicit => 1.0,
+            FdmScheme::CrankNicolson => 0.5,
+            FdmScheme::Douglas => 0.5 + 3.0_f64.sqrt() / 6.0,
+            FdmScheme::Theta { theta } => theta,
+        }
+    }
+}
+
+/// Discretisation of the first-derivative (convection) term.
+///
+/// Central differences are second-order accurate but lose monotonicity once
+/// the cell Péclet number `|β|·Δx / (2α)` exceeds one, which shows up as
+/// spurious oscillations near payoff kinks.  The two monotone schemes also
+/// solve on a grid widened by the drift; see [`Fdm1dSolver::solve`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum FdmSpatialScheme {
+    /// Central differences: `V_x ≈ (V[i+1] − V[i−1]) / 2Δx`.
+    #[default]
+    Central,
+    /// First-order one-sided differences taken in the direction the
+    /// information flows — monotone for any Péclet number.
+    Upwind,
+    /// Il'in exponential fitting: central differences with the diffusion
+    /// coefficient replaced by `α·P·coth(P)`, where `P` is the cell Péclet
+    /// number. Monotone, and reduces to [`Central`](Self::Central) as `P → 0`.
+    ExponentialFitting,
+}
+
+impl FdmSpatialScheme {
+    /// The `(lower, diag, upper)` stencil of `α·V_xx + β·V_x − r·V` on a
+    /// uniform grid with spacing `dx`.
+    pub fn stencil(self, alpha: Real, beta: Real, r: Real, dx: Real) -> (Real, Real, Real) {
+        let dx2 = dx * dx;
+        match self {
+            FdmSpatialScheme::Central => {
+                let a = alpha / dx2 - beta / (2.0 * dx);
+                let c = alpha / dx2 + beta / (2.0 * dx);
+                (a, -2.0 * alpha / dx2 - r, c)
+            }
+            FdmSpatialScheme::Upwind => {
+                let a = alpha / dx2 + (-beta).max(0.0) / dx;
+                let c = alpha / dx2 + beta.max(0.0) / dx;
+                (a, -(a + c) - r, c)
+            }
+            FdmSpatialScheme::ExponentialFitting => {
+                // P·coth(P)·α → |β|·Δx/2 as α → 0, and → α as β → 0.
+                let peclet = beta * dx / (2.0 * alpha);
+                let fitted = if beta == 0.0 || peclet.abs() < 1e-8 {
+                    alpha
+                } else {
+                    0.5 * beta * dx / peclet.tanh()
+                };
+                FdmSpatialScheme::Central.stencil(fitted, beta, r, dx)
+            }
+        }
+    }
 }
 
 // ─── Tridiagonal operator ─────────────────────────────────────────────────────
@@ -149,6 +227,10 @@ pub struct Fdm1dSolver {
     nt: usize,
     /// Finite difference scheme.
     scheme: FdmScheme,
+    /// Discretisation of the convection term.
+    spatial_scheme: FdmSpatialScheme,
+    /// Number of initial CN steps replaced by implicit half-steps.
+    rannacher_steps: usize,
 }
 
 impl Fdm1dSolver {
@@ -162,6 +244,8 @@ impl Fdm1dSolver {
     /// * `nx` — number of spatial (log-price) grid points
     /// * `nt` — number of time steps
     /// * `scheme` — time-stepping scheme
+    ///
+    /// Fails if the scheme's θ lies outside `[0, 1]`.
     pub fn new(
         r: Real,
         q: Real,
@@ -170,8 +254,13 @@ impl Fdm1dSolver {
         nx: usize,
         nt: usize,
         scheme: FdmScheme,
-    ) -> Self {
-        Self {
+    ) -> Result<Self> {
+        let theta = scheme.theta();
+        ensure!(
+            (0.0..=1.0).contains(&theta),
+            "theta must be in [0, 1], got {theta}"
+        );
+        Ok(Self {
             r,
             q,
             sigma,
@@ -179,111 +268,267 @@ impl Fdm1dSolver {
             nx,
             nt,
             scheme,
-        }
+            spatial_scheme: FdmSpatialScheme::Central,
+            rannacher_steps: 0,
+        })
+    }
+
+    /// Use the given discretisation for the convection term
+    /// (default: [`FdmSpatialScheme::Central`]).
+    pub fn with_spatial_scheme(mut self, spatial_scheme: FdmSpatialScheme) -> Self {
+        self.spatial_scheme = spatial_scheme;
+        self
+    }
+
+    /// Replace the first `rannacher_steps` Crank-Nicolson steps, counted
+    /// from maturity, with two fully implicit half-steps each (default: 0).
+    ///
+    /// Ignored by every other scheme, [`FdmScheme::Theta`] included: the
+    /// start-up damps the undamped high-frequency modes of θ = ½, which the
+    /// schemes with θ > ½ already damp themselves.
+    pub fn with_rannacher_steps(mut self, rannacher_steps: usize) -> Self {
+        self.rannacher_steps = rannacher_steps;
+        self
     }
 
     /// Solve and return the option value at `spot`.
     ///
     /// `payoff` takes a stock price `S` and returns the terminal payoff.
     pub fn price(&self, spot: Real, payoff: &dyn Fn(Real) -> Real) -> Real {
+        let (s_grid, values) = self.solve(spot, payoff);
+        interpolate(&s_grid, &values, spot)
+    }
+
+    /// Solve with early exercise allowed at every time step and return the
+    /// option value at `spot`.
+    ///
+    /// `payoff` is both the terminal payoff and the exercise value.
+    pub fn price_american(&self, spot: Real, payoff: &dyn Fn(Real) -> Real) -> Real {
+        let (s_grid, values) = self.solve_american(spot, payoff);
+        interpolate(&s_grid, &values, spot)
+    }
+
+    /// Solve and return `(price, delta, gamma, theta)` at `spot`.
+    ///
+    /// Delta and gamma come from central differences on the log-space grid
+    /// at the nodes either side of `spot`, converted with `∂V/∂S = V_x/S`
+    /// and `∂²V/∂S² = (V_xx − V_x)/S²`; theta is `∂V/∂t` per year of
+    /// calendar time, from the values one time step after t = 0.  All four
+    /// are interpolated linearly in log-space between the two nodes, as
+    /// [`price`](Self::price) is.
+    pub fn price_with_greeks(
+        &self,
+        spot: Real,
+        payoff: &dyn Fn(Real) -> Real,
+    ) -> (Real, Real, Real, Real) {
+        let (s_grid, values, later) = self.roll_back(spot, payoff, false);
+        let n = s_grid.len();
+        let x_min = s_grid[0].ln();
+        let dx = (s_grid[n - 1].ln() - x_min) / (n - 1) as Real;
+        let dt = self.maturity / self.nt as Real;
+        let greeks = |i: usize| {
+            let v_x = (values[i + 1] - values[i - 1]) / (2.0 * dx);
+            let v_xx = (values[i + 1] - 2.0 * values[i] + values[i - 1]) / (dx * dx);
+            let s = s_grid[i];
+            (v_x / s, (v_xx - v_x) / (s * s), (later[i] - values[i]) / dt)
+        };
+
+        let i = (((spot.ln() - x_min) / dx).floor() as usize).clamp(1, n - 3);
+        let frac = (spot.ln() - s_grid[i].ln()) / dx;
+        let ((d0, g0, t0), (d1, g1, t1)) = (greeks(i), greeks(i + 1));
+        let lerp = |a: Real, b: Real| a * (1.0 - frac) + b * frac;
+        (
+            interpolate(&s_grid, &values, spot),
+            lerp(d0, d1),
+            lerp(g0, g1),
+            lerp(t0, t1),
+        )
+    }
+
+    /// Solve on this grid and on one with twice as many space and time
+    /// intervals, and Richardson-extrapolate the two prices assuming
+    /// second-order convergence.
+    ///
+    /// Returns `(price, error_estimate)`, where the error estimate is that of
+    /// the finer single-grid price, `|V_fine − V_coarse| / 3`.
+    pub fn price_extrapolated(&self, spot: Real, payoff: &dyn Fn(Real) -> Real) -> (Real, Real) {
+        let fine = Self {
+            nx: 2 * (self.nx - 1) + 1,
+            nt: 2 * self.nt,
+            rannacher_steps: 2 * self.rannacher_steps,
+            ..*self
+        };
+        let coarse_price = self.price(spot, payoff);
+        let fine_price = fine.price(spot, payoff);
+        let correction = (fine_price - coarse_price) / 3.0;
+        (fine_price + correction, correction.abs())
+    }
+
+    /// Solve and return the stock-price grid together with the option values
+    /// on it at t = 0.
+    ///
+    /// With [`FdmSpatialScheme::Central`] the grid covers ±4σ√T around
+    /// `spot`.  [`Upwind`](FdmSpatialScheme::Upwind) and
+    /// [`ExponentialFitting`](FdmSpatialScheme::ExponentialFitting) widen
+    /// it on one side by the drift `(r − q − σ²/2)·T`, so that the forward
+    /// stays well inside it even when `|drift| > 4σ√T`, and shift it so
+    /// that `spot` lies on a node.  For the same number of nodes the
+    /// widening grows the spacing by `|drift| / 8σ√T`.
+    pub fn solve(&self, spot: Real, payoff: &dyn Fn(Real) -> Real) -> (Vec<Real>, Vec<Real>) {
+        let (s_grid, values, _) = self.roll_back(spot, payoff, false);
+        (s_grid, values)
+    }
+
+    /// [`solve`](Self::solve) for an American option: after every time
+    /// step the values are projected onto the exercise constraint
+    /// `V ≥ payoff(S)`.
+    pub fn solve_american(
+        &self,
+        spot: Real,
+        payoff: &dyn Fn(Real) -> Real,
+    ) -> (Vec<Real>, Vec<Real>) {
+        let (s_grid, values, _) = self.roll_back(spot, payoff, true);
+        (s_grid, values)
+    }
+
+    /// Roll the payoff back to t = 0, returning the stock-price grid, the
+    /// values at t = 0 and those one time step later.
+    fn roll_back(
+        &self,
+        spot: Real,
+        payoff: &dyn Fn(Real) -> Real,
+        american: bool,
+    ) -> (Vec<Real>, Vec<Real>, Vec<Real>) {
         let sigma2 = self.sigma * self.sigma;
         let dt = self.maturity / self.nt as Real;
         let n = self.nx;
 
+        // Coefficients of the PDE in log-space (constant coefficients):
+        // ∂V/∂t + α·∂²V/∂x² + β·∂V/∂x − r·V = 0
+        // where α = σ²/2, β = r − q − σ²/2
+        let alpha = 0.5 * sigma2;
+        let beta = self.r - self.q - 0.5 * sigma2;
+
         // Log-space grid: x ∈ [x_min, x_max]
         let x_center = spot.ln();
         let x_range = 4.0 * self.sigma * self.maturity.sqrt(); // ±4σ√T
-        let x_min = x_center - x_range;
-        let x_max = x_center + x_range;
-        let dx = (x_max - x_min) / (n - 1) as Real;
+        let (x_min, dx) = match self.spatial_scheme {
+            FdmSpatialScheme::Central => {
+                let x_min = x_center - x_range;
+                let x_max = x_center + x_range;
+                (x_min, (x_max - x_min) / (n - 1) as Real)
+            }
+            FdmSpatialScheme::Upwind | FdmSpatialScheme::ExponentialFitting => {
+                // Widen by the drift, which can carry the forward past
+                // ±4σ√T in the convection-dominated problems these schemes
+                // are for, then shift the grid so that the spot lies on a
+                // node.
+                let drift = beta * self.maturity;
+                let x_min = x_center + drift.min(0.0) - x_range;
+                let x_max = x_center + drift.max(0.0) + x_range;
+                let dx = (x_max - x_min) / (n - 1) as Real;
+                (x_center - ((x_center - x_min) / dx).round() * dx, dx)
+            }
+        };
 
         // Grid values
         let x_grid: Vec<Real> = (0..n).map(|i| x_min + i as Real * dx).collect();
         let s_grid: Vec<Real> = x_grid.iter().map(|&x| x.exp()).collect();
 
-        // Terminal condition
-        let mut values: Vec<Real> = s_grid.iter().map(|&s| payoff(s)).collect();
+        // Terminal condition, which is also the exercise value
+        let exercise: Vec<Real> = s_grid.iter().map(|&s| payoff(s)).collect();
+        let mut values = exercise.clone();
 
-        // Coefficients of the PDE in log-space (constant coefficients):
-        // ∂V/∂t + α·∂²V/∂x² + β·∂V/∂x − r·V = 0
-        // where α = σ²/2, β = r − q − σ²/2
-        let alpha = 0.5 * sigma2;
-        let beta = self.r - self.q - 0.5 * sigma2;
-
-        // Build the spatial operator L such that LV ≈ α·V_xx + β·V_x − r·V
-        // Using central differences:
-        // V_xx ≈ (V[i+1] - 2V[i] + V[i-1]) / dx²
-        // V_x  ≈ (V[i+1] - V[i-1]) / (2dx)
-        let a = alpha / (dx * dx) - beta / (2.0 * dx); // lower
-        let b = -2.0 * alpha / (dx * dx) - self.r; // diag
-        let c = alpha / (dx * dx) + beta / (2.0 * dx); // upper
+        // Spatial operator L such that LV ≈ α·V_xx + β·V_x − r·V
+        let stencil = self.spatial_scheme.stencil(alpha, beta, self.r, dx);
 
         // Time stepping: V^{n} from V^{n+1}
         // PDE: dV/dt = -L·V  (backward in time)
         // Explicit: V^n = V^{n+1} + dt·L·V^{n+1} = (I + dt·L)·V^{n+1}
         // Implicit: (I - dt·L)·V^n = V^{n+1}
         // CN: (I - 0.5·dt·L)·V^n = (I + 0.5·dt·L)·V^{n+1}
-        for _step in 0..self.nt {
-            match self.scheme {
-                FdmScheme::Explicit => {
-                    let mut new_values = values.clone();
-                    for i in 1..n - 1 {
-                        new_values[i] = values[i]
-                            + dt * (a * values[i - 1] + b * values[i] + c * values[i + 1]);
-                    }
-                    // Boundary conditions: linearity in log-space
-                    new_values[0] = 2.0 * new_values[1] - new_values[2];
-                    new_values[n - 1] = 2.0 * new_values[n - 2] - new_values[n - 3];
-                    values = new_values;
-                }
-                FdmScheme::Implicit => {
-                    let mut op = TridiagonalOperator::new(n);
-                    for i in 1..n - 1 {
-                        op.lower[i] = -dt * a;
-                        op.diag[i] = 1.0 - dt * b;
-                        op.upper[i] = -dt * c;
-                    }
-                    // Boundaries: identity (Dirichlet-like)
-                    op.diag[0] = 1.0;
-                    op.upper[0] = 0.0;
-                    op.diag[n - 1] = 1.0;
-                    op.lower[n - 1] = 0.0;
-                    values = op.solve(&values);
+        // θ: (I - θ·dt·L)·V^n = (I + (1-θ)·dt·L)·V^{n+1}
+        let mut previous = values.clone();
+        for step in 0..self.nt {
+            previous.clone_from(&values);
+            values = match self.scheme {
+                FdmScheme::Explicit => explicit_step(&values, stencil, dt),
+                FdmScheme::Implicit => implicit_step(&values, stencil, dt),
+                FdmScheme::CrankNicolson if step < self.rannacher_steps => {
+                    // Rannacher startup: two implicit half-steps damp the
+                    // high-frequency error modes seeded by the payoff kink,
+                    // which CN would otherwise carry undamped.
+                    let half = implicit_step(&values, stencil, 0.5 * dt);
+                    implicit_step(&half, stencil, 0.5 * dt)
                 }
-                FdmScheme::CrankNicolson => {
-                    // RHS: (I + 0.5·dt·L) · V^{n+1}
-                    let mut rhs = values.clone();
-                    for i in 1..n - 1 {
-                        rhs[i] = values[i]
-                            + 0.5 * dt * (a * values[i - 1] + b * values[i] + c * values[i + 1]);
-                    }
-
-                    // LHS: (I - 0.5·dt·L) · V^n = rhs
-                    let mut op = TridiagonalOperator::new(n);
-                    for i in 1..n - 1 {
-                        op.lower[i] = -0.5 * dt * a;
-                        op.diag[i] = 1.0 - 0.5 * dt * b;
-                        op.upper[i] = -0.5 * dt * c;
-                    }
-                    op.diag[0] = 1.0;
-                    op.upper[0] = 0.0;
-                    op.diag[n - 1] = 1.0;
-                    op.lower[n - 1] = 0.0;
-                    values = op.solve(&rhs);
+                scheme => theta_step(&values, stencil, dt, scheme.theta()),
+            };
+            if american {
+                for (v, &e) in values.iter_mut().zip(&exercise) {
+                    *v = v.max(e);
                 }
             }
         }
 
-        // Interpolate at `spot`
-        let x_spot = spot.ln();
-        // Find the grid index
-        let idx = ((x_spot - x_min) / dx).floor() as usize;
-        let idx = idx.min(n - 2);
-        let frac = (x_spot - x_grid[idx]) / dx;
-        values[idx] * (1.0 - frac) + values[idx + 1] * frac
+        (s_grid, values, previous)
     }
 }
 
+/// Linear interpolation in log-space of grid values at `spot`.
+fn interpolate(s_grid: &[Real], values: &[Real], spot: Real) -> Real {
+    let n = s_grid.len();
+    let x_min = s_grid[0].ln();
+    let dx = (s_grid[n - 1].ln() - x_min) / (n - 1) as Real;
+    let x_spot = spot.ln();
+    let idx = (((x_spot - x_min) / dx).floor() as usize).min(n - 2);
+    let frac = (x_spot - s_grid[idx].ln()) / dx;
+    values[idx] * (1.0 - frac) + values[idx + 1] * frac
+}
+
+/// One explicit Euler step, with boundaries extrapolated linearly in log-space.
+fn explicit_step(values: &[Real], (a, b, c): (Real, Real, Real), dt: Real) -> Vec<Real> {
+    let n = values.len();
+    let mut new_values = values.to_vec();
+    for i in 1..n - 1 {
+        new_values[i] = values[i] + dt * (a * values[i - 1] + b * values[i] + c * values[i + 1]);
+    }
+    // Boundary conditions: linearity in log-space
+    new_values[0] = 2.0 * new_values[1] - new_values[2];
+    new_values[n - 1] = 2.0 * new_values[n - 2] - new_values[n - 3];
+    new_values
+}
+
+/// One fully implicit step, with identity (Dirichlet-like) boundary rows.
+fn implicit_step(values: &[Real], stencil: (Real, Real, Real), dt: Real) -> Vec<Real> {
+    implicit_operator(values.len(), stencil, dt).solve(values)
+}
+
+/// One θ-scheme step, with identity (Dirichlet-like) boundary rows.
+fn theta_step(values: &[Real], (a, b, c): (Real, Real, Real), dt: Real, theta: Real) -> Vec<Real> {
+    let n = values.len();
+    // RHS: (I + (1−θ)·dt·L) · V^{n+1}
+    let explicit_dt = (1.0 - theta) * dt;
+    let mut rhs = values.to_vec();
+    for i in 1..n - 1 {
+        rhs[i] = values[i] + explicit_dt * (a * values[i - 1] + b * values[i] + c * values[i + 1]);
+    }
+    // LHS: (I − θ·dt·L) · V^n = rhs
+    implicit_operator(n, (a, b, c), theta * dt).solve(&rhs)
+}
+
+/// `I − dt·L` with identity boundary rows.
+fn implicit_operator(n: usize, (a, b, c): (Real, Real, Real), dt: Real) -> TridiagonalOperator {
+    let mut op = TridiagonalOperator::new(n);
+    for i in 1..n - 1 {
+        op.lower[i] = -dt * a;
+        op.diag[i] = 1.0 - dt * b;
+        op.upper[i] = -dt * c;
+    }
+    op.diag[0] = 1.0;
+    op.diag[n - 1] = 1.0;
+    op
+}
+
 // ─── Tests ────────────────────────────────────────────────────────────────────
 
 #[cfg(test)]
@@ -331,7 +576,8 @@ mod tests {
     #[test]
     fn fdm_cn_european_call_converges_to_bs() {
         let bs = bs_call_ref();
-        let solver = Fdm1dSolver::new(0.05, 0.0, 0.20, 1.0, 200, 200, FdmScheme::CrankNicolson);
+        let solver =
+            Fdm1dSolver::new(0.05, 0.0, 0.20, 1.0, 200, 200, FdmScheme::CrankNicolson).unwrap();
         let price = solver.price(100.0, &|s| (s - 100.0).max(0.0));
         assert!(
             (price - bs).abs() < 0.20,
@@ -342,7 +588,7 @@ mod tests {
     #[test]
     fn fdm_implicit_european_call_converges_to_bs() {
         let bs = bs_call_ref();
-        let solver = Fdm1dSolver::new(0.05, 0.0, 0.20, 1.0, 200, 200, FdmScheme::Implicit);
+        let solver = Fdm1dSolver::new(0.05, 0.0, 0.20, 1.0, 200, 200, FdmScheme::Implicit).unwrap();
         let price = solver.price(100.0, &|s| (s - 100.0).max(0.0));
         assert!(
             (price - bs).abs() < 0.30,
@@ -352,7 +598,8 @@ mod tests {
 
     #[test]
     fn fdm_cn_european_put_converges() {
-        let solver = Fdm1dSolver::new(0.05, 0.0, 0.20, 1.0, 200, 200, FdmScheme::CrankNicolson);
+        let solver =
+            Fdm1dSolver::new(0.05, 0.0, 0.20, 1.0, 200, 200, FdmScheme::CrankNicolson).unwrap();
         let call = solver.price(100.0, &|s| (s - 100.0).max(0.0));
         let put = solver.price(100.0, &|s| (100.0 - s).max(0.0));
 
@@ -364,4 +611,340 @@ mod tests {
             "parity: {parity:.4} vs {expected:.4}"
         );
     }
+
+    #[test]
+    fn exponential_fitting_reduces_to_central_without_convection() {
+        let central = FdmSpatialScheme::Central.stencil(0.02, 0.0, 0.05, 0.01);
+        let fitted = FdmSpatialScheme::ExponentialFitting.stencil(0.02, 0.0, 0.05, 0.01);
+        assert_eq!(central, fitted);
+
+        // Upwind and fitted stencils have non-negative off-diagonals at any Péclet number
+        for scheme in [
+            FdmSpatialScheme::Upwind,
+            FdmSpatialScheme::ExponentialFitting,
+        ] {
+            let (a, _, c) = scheme.stencil(1e-5, 0.1, 0.05, 0.01);
+            assert!(a >= 0.0 && c >= 0.0, "{scheme:?}: a={a}, c={c}");
+        }
+    }
+
+    #[test]
+    fn exponential_fitting_without_diffusion_is_upwind() {
+        let (r, dx) = (0.05, 0.01);
+        assert_eq!(
+            FdmSpatialScheme::ExponentialFitting.stencil(0.0, 0.0, r, dx),
+            (0.0, -r, 0.0)
+        );
+        for beta in [0.1, -0.1] {
+            let (fitted, upwind) = (
+                FdmSpatialScheme::ExponentialFitting.stencil(0.0, beta, r, dx),
+                FdmSpatialScheme::Upwind.stencil(0.0, beta, r, dx),
+            );
+            assert!(
+                (fitted.0 - upwind.0).abs() < 1e-12,
+                "{fitted:?} vs {upwind:?}"
+            );
+            assert!(
+                (fitted.1 - upwind.1).abs() < 1e-12,
+                "{fitted:?} vs {upwind:?}"
+            );
+            assert!(
+                (fitted.2 - upwind.2).abs() < 1e-12,
+                "{fitted:?} vs {upwind:?}"
+            );
+        }
+    }
+
+    #[test]
+    fn monotone_schemes_widen_the_grid_by_the_drift() {
+        let (r, q, sigma, maturity) = (0.10, 0.0, 0.005, 1.0);
+        let solver = |scheme| {
+            Fdm1dSolver::new(r, q, sigma, maturity, 100, 100, FdmScheme::CrankNicolson)
+                .unwrap()
+                .with_spatial_scheme(scheme)
+        };
+        let drift = (r - q - 0.5 * sigma * sigma) * maturity;
+        let width = 4.0 * sigma * maturity.sqrt();
+        assert!(width < drift);
+
+        // Central differences keep the symmetric ±4σ√T grid, which ends
+        // below the forward; with an even node count spot is mid-cell.
+        let (s_grid, _) = solver(FdmSpatialScheme::Central).solve(100.0, &|s| s);
+        let (lower, upper) = (s_grid[0], s_grid[s_grid.len() - 1]);
+        assert!((lower.ln() - (100.0_f64.ln() - width)).abs() < 1e-12);
+        assert!((upper.ln() - (100.0_f64.ln() + width)).abs() < 1e-12);
+        assert!(s_grid.iter().all(|&s| (s / 100.0 - 1.0).abs() > 1e-6));
+
+        // The monotone schemes' grid (shifted by at most a node to put
+        // spot on one) keeps a margin on both sides of spot and forward.
+        for scheme in [
+            FdmSpatialScheme::Upwind,
+            FdmSpatialScheme::ExponentialFitting,
+        ] {
+            let (s_grid, _) = solver(scheme).solve(100.0, &|s| s);
+            let (lower, upper) = (s_grid[0], s_grid[s_grid.len() - 1]);
+            assert!(lower < 100.0 * (-0.9 * width).exp(), "lower end {lower}");
+            assert!(
+                upper > 100.0 * (drift + 0.9 * width).exp(),
+                "upper end {upper}"
+            );
+            assert!(s_grid.iter().any(|&s| (s / 100.0 - 1.0).abs() < 1e-12));
+        }
+    }
+
+    #[test]
+    fn exponential_fitting_removes_convection_oscillations() {
+        use ql_instruments::OptionType;
+        use ql_pricingengines::analytic_european_engine::black_scholes_merton;
+
+        // σ = 0.5% against a 10% drift: cell Péclet number ≈ 5.6
+        let (r, sigma, strike) = (0.10, 0.005, 116.0);
+        let put = |s: Real| (strike - s).max(0.0);
+        let (bs, ..) = black_scholes_merton(OptionType::Put, 100.0, strike, r, 0.0, sigma, 1.0);
+        let solver = |scheme| {
+            Fdm1dSolver::new(r, 0.0, sigma, 1.0, 101, 100, FdmScheme::CrankNicolson)
+                .unwrap()
+                .with_spatial_scheme(scheme)
+        };
+        // A put value is non-negative and non-increasing in S
+        let worst = |values: &[Real]| {
+            let min = values.iter().cloned().fold(Real::INFINITY, Real::min);
+            let max_rise = values
+                .windows(2)
+                .map(|w| w[1] - w[0])
+                .fold(Real::NEG_INFINITY, Real::max);
+            (min, max_rise)
+        };
+
+        // Central differences oscillate, and their ±4σ√T grid ends well
+        // below the forward at 110.5, so the price is far out as well.
+        let central = solver(FdmSpatialScheme::Central);
+        let (_, values) = central.solve(100.0, &put);
+        let (_, max_rise) = worst(&values);
+        assert!(max_rise > 1.0, "central: max rise {max_rise:.4}");
+        let price = central.price(100.0, &put);
+        assert!(
+            (price - bs).abs() > 5.0,
+            "central put = {price:.4}, BS = {bs:.4}"
+        );
+
+        let fitted = solver(FdmSpatialScheme::ExponentialFitting);
+        let (_, values) = fitted.solve(100.0, &put);
+        let (min, max_rise) = worst(&values);
+        assert!(
+            min > -1e-10 && max_rise < 0.01,
+            "fitted: min {min:.4}, max rise {max_rise:.4}"
+        );
+        let price = fitted.price(100.0, &put);
+        assert!(
+            (price - bs).abs() < 0.05,
+            "fitted put = {price:.4}, BS = {bs:.4}"
+        );
+    }
+
+    #[test]
+    fn rannacher_startup_removes_digital_gamma_oscillations() {
+        // Sign changes of Γ = ∂²V/∂S² within ±30% (in log terms) of the strike
+        let gamma_sign_changes = |solver: &Fdm1dSolver| {
+            let (s, v) = solver.solve(100.0, &|s| if s > 100.0 { 1.0 } else { 0.0 });
+            let gamma: Vec<Real> = (1..s.len() - 1)
+                .filter(|&i| (s[i] / 100.0).ln().abs() < 0.3)
+                .map(|i| {
+                    let (h0, h1) = (s[i] - s[i - 1], s[i + 1] - s[i]);
+                    2.0 * (h0 * v[i + 1] - (h0 + h1) * v[i] + h1 * v[i - 1]) / (h0 * h1 * (h0 + h1))
+                })
+                .collect();
+            gamma.windows(2).filter(|w| w[0] * w[1] < 0.0).count()
+        };
+
+        let plain =
+            Fdm1dSolver::new(0.05, 0.0, 0.20, 1.0, 201, 25, FdmScheme::CrankNicolson).unwrap();
+        let smoothed = Fdm1dSolver::new(0.05, 0.0, 0.20, 1.0, 201, 25, FdmScheme::CrankNicolson)
+            .unwrap()
+            .with_rannacher_steps(2);
+
+        // A digital call's gamma changes sign exactly once
+        let plain_changes = gamma_sign_changes(&plain);
+        assert!(plain_changes > 3, "plain CN: {plain_changes} sign changes");
+        assert_eq!(gamma_sign_changes(&smoothed), 1);
+
+        // The smooth-payoff result is essentially unchanged
+        let call = |s: Real| (s - 100.0).max(0.0);
+        let bs = bs_call_ref();
+        let plain_call = plain.price(100.0, &call);
+        let smoothed_call = smoothed.price(100.0, &call);
+        assert!((smoothed_call - plain_call).abs() < 0.02);
+        assert!(
+            (smoothed_call - bs).abs() < 0.01,
+            "Rannacher call = {smoothed_call:.4}, BS = {bs:.4}"
+        );
+    }
+
+    #[test]
+    fn richardson_extrapolation_improves_cn_call() {
+        let bs = bs_call_ref();
+        let call = |s: Real| (s - 100.0).max(0.0);
+        let solver =
+            Fdm1dSolver::new(0.05, 0.0, 0.20, 1.0, 101, 100, FdmScheme::CrankNicolson).unwrap();
+        let fine =
+            Fdm1dSolver::new(0.05, 0.0, 0.20, 1.0, 201, 200, FdmScheme::CrankNicolson).unwrap();
+        let coarse_error = (solver.price(100.0, &call) - bs).abs();
+        let fine_error = (fine.price(100.0, &call) - bs).abs();
+
+        let (price, error_estimate) = solver.price_extrapolated(100.0, &call);
+        let error = (price - bs).abs();
+        assert!(
+            error < 0.1 * fine_error && error < 0.1 * coarse_error,
+            "extrapolated error {error:e}, single-grid errors {coarse_error:e} / {fine_error:e}"
+        );
+        assert!(
+            error_estimate > 0.5 * fine_error && error_estimate < 2.0 * fine_error,
+            "estimate {error_estimate:e} vs fine-grid error {fine_error:e}"
+        );
+    }
+
+    #[test]
+    fn theta_half_reproduces_crank_nicolson() {
+        let call = |s: Real| (s - 100.0).max(0.0);
+        let cn = Fdm1dSolver::new(0.05, 0.0, 0.20, 1.0, 101, 50, FdmScheme::CrankNicolson).unwrap();
+        let theta = Fdm1dSolver::new(
+            0.05,
+            0.0,
+            0.20,
+            1.0,
+            101,
+            50,
+            FdmScheme::Theta { theta: 0.5 },
+        )
+        .unwrap();
+        assert_eq!(cn.solve(100.0, &call), theta.solve(100.0, &call));
+
+        let implicit =
+            Fdm1dSolver::new(0.05, 0.0, 0.20, 1.0, 101, 50, FdmScheme::Implicit).unwrap();
+        let theta_one = Fdm1dSolver::new(
+            0.05,
+            0.0,
+            0.20,
+            1.0,
+            101,
+            50,
+            FdmScheme::Theta { theta: 1.0 },
+        )
+        .unwrap();
+        assert_eq!(implicit.price(100.0, &call), theta_one.price(100.0, &call));
+    }
+
+    #[test]
+    fn douglas_scheme_is_stable_and_accurate() {
+        let bs = bs_call_ref();
+        let call = |s: Real| (s - 100.0).max(0.0);
+        // Large Δt relative to Δx²: explicit would blow up
+        let solver = Fdm1dSolver::new(0.05, 0.0, 0.20, 1.0, 201, 50, FdmScheme::Douglas).unwrap();
+        let (_, values) = solver.solve(100.0, &call);
+        assert!(values.iter().all(|v| v.is_finite() && *v > -1e-10));
+        assert!(values.windows(2).all(|w| w[1] >= w[0] - 1e-10));
+        let price = solver.price(100.0, &call);
+        assert!(
+            (price - bs).abs() < 0.02,
+            "Douglas call = {price:.4}, BS = {bs:.4}"
+        );
+    }
+
+    #[test]
+    fn rannacher_steps_only_change_crank_nicolson() {
+        let call = |s: Real| (s - 100.0).max(0.0);
+        let price = |scheme, rannacher_steps| {
+            Fdm1dSolver::new(0.05, 0.02, 0.20, 1.0, 101, 50, scheme)
+                .unwrap()
+                .with_rannacher_steps(rannacher_steps)
+                .price(100.0, &call)
+        };
+        for scheme in [
+            FdmScheme::Implicit,
+            FdmScheme::Douglas,
+            FdmScheme::Theta { theta: 0.5 },
+            FdmScheme::Theta { theta: 0.0 },
+        ] {
+            assert_eq!(price(scheme, 2), price(scheme, 0), "{scheme:?}");
+        }
+        assert_ne!(
+            price(FdmScheme::CrankNicolson, 2),
+            price(FdmScheme::CrankNicolson, 0)
+        );
+    }
+
+    #[test]
+    fn greeks_match_black_scholes() {
+        use ql_instruments::OptionType;
+        use ql_pricingengines::analytic_european_engine::black_scholes_merton_greeks;
+
+        let call = |s: Real| (s - 100.0).max(0.0);
+        let solver = Fdm1dSolver::new(0.05, 0.02, 0.20, 1.0, 400, 400, FdmScheme::CrankNicolson)
+            .unwrap()
+            .with_rannacher_steps(2);
+        let (price, delta, gamma, theta) = solver.price_with_greeks(100.0, &call);
+        let bs = black_scholes_merton_greeks(OptionType::Call, 100.0, 100.0, 0.05, 0.02, 0.20, 1.0);
+        assert!((price - bs.price).abs() < 5e-3, "{price} vs {}", bs.price);
+        assert!((delta - bs.delta).abs() < 1e-3, "{delta} vs {}", bs.delta);
+        assert!((gamma - bs.gamma).abs() < 1e-4, "{gamma} vs {}", bs.gamma);
+        assert!((theta - bs.theta).abs() < 1e-2, "{theta} vs {}", bs.theta);
+        assert!((price - solver.price(100.0, &call)).abs() < 1e-12);
+    }
+
+    #[test]
+    fn american_put_matches_binomial_tree() {
+        use crate::lattice::{price_american, BinomialTree};
+        use ql_processes::GeneralizedBlackScholesProcess;
+        use ql_termstructures::{BlackConstantVol, FlatForward};
+        use ql_time::{Actual365Fixed, Date};
+        use std::sync::Arc;
+
+        let put = |s: Real| (100.0 - s).max(0.0);
+        let solver = Fdm1dSolver::new(0.05, 0.0, 0.20, 1.0, 201, 200, FdmScheme::CrankNicolson)
+            .unwrap()
+            .with_rannacher_steps(2);
+        let european = solver.price(100.0, &put);
+        let american = solver.price_american(100.0, &put);
+        assert!(
+            american > european + 0.1,
+            "American {american:.4} vs European {european:.4}"
+        );
+
+        // Deep in the money the put is exercised at once.
+        let (s, values) = solver.solve_american(100.0, &put);
+        assert!(values.iter().zip(&s).all(|(v, &s)| *v >= put(s) - 1e-12));
+        let deep = s.iter().position(|&s| s > 70.0).unwrap() - 1;
+        assert!((values[deep] - put(s[deep])).abs() < 1e-12);
+
+        let ref_date = Date::from_ymd(2025, 1, 15).unwrap();
+        let process = GeneralizedBlackScholesProcess::new(
+            100.0,
+            Arc::new(FlatForward::continuous(ref_date, 0.05, Actual365Fixed)),
+            Arc::new(FlatForward::continuous(ref_date, 0.0, Actual365Fixed)),
+            Arc::new(BlackConstantVol::new(ref_date, 0.20, Actual365Fixed)),
+        );
+        let tree = BinomialTree::cox_ross_rubinstein(&process, 1.0, 1000);
+        let binomial = price_american(&tree, &put, (-0.05 * tree.dt()).exp());
+        assert!(
+            (american / binomial - 1.0).abs() < 0.01,
+            "FDM {american:.4} vs binomial {binomial:.4}"
+        );
+    }
+
+    #[test]
+    fn theta_out_of_range_is_rejected() {
+        let err = Fdm1dSolver::new(
+            0.05,
+            0.0,
+            0.20,
+            1.0,
+            101,
+            50,
+            FdmScheme::Theta { theta: 1.5 },
+        )
+        .err()
+        .expect("θ = 1.5 was accepted")
+        .to_string();
+        assert!(err.contains("theta must be in [0, 1]"), "{err}");
+    }
 }
@@ -221,11 +221,48 @@ impl BinomialTree {
         strike: Real,
     ) -> Self {
         assert!(strike > 0.0, "strike must be positive");
-        let odd_steps = if steps % 2 != 0 { steps } else { steps + 1 };
         let x0 = process.x0();
+        let drift = process.drift_1d(0.0, x0) / x0;
+        Self::leisen_reimer_from_moments(
+            x0,
+            drift,
+            log_total_variance(process, end),
+            end,
+            steps,
+            strike,
+        )
+    }
+
+    /// Leisen-Reimer tree for a log-normal underlying starting at `spot`
+    /// under Black-Scholes with constant rates `r`, `q` and volatility
+    /// `vol`, without building a process.
+    #[allow(clippy::too_many_arguments)]
+    pub(crate) fn leisen_reimer_black_scholes(
+        spot: Real,
+        r: Real,
+        q: Real,
+        vol: Real,
+        end: Real,
+        steps: usize,
+        strike: Real,
+    ) -> Self {
+        let drift = r - q - 0.5 * vol * vol;
+        Self::leisen_reimer_from_moments(spot, drift, vol * vol * end, end, steps, strike)
+    }
+
+    /// Leisen-Reimer tree from the log-space drift rate and the total
+    /// log-space variance to `end`.
+    fn leisen_reimer_from_moments(
+        x0: Real,
+        drift: Real,
+        total_var: Real,
+        end: Real,
+        steps: usize,
+        strike: Real,
+    ) -> Self {
+        let odd_steps = if steps % 2 != 0 { steps } else { steps + 1 };
         let dt = end / odd_steps as Real;
-        let dps = process.drift_1d(0.0, x0) * dt / x0;
-        let total_var = log_total_variance(process, end);
+        let dps = drift * dt;
         let ermqdt = (dps + 0.5 * total_var / odd_steps as Real).exp();
         let d2 = ((x0 / strike).ln() + dps * odd_steps as Real) / total_var.sqrt();
 
@@ -10,6 +10,11 @@
 //! * [`TrinomialTree`] — recombining trinomial tree
 //! * [`TimeGrid`] — grid of time points used by tree methods
 //! * [`price_european`] / [`price_american`] — backward-induction pricing
+//! * [`price_european_leisen_reimer`] — Black-Scholes European pricing on
+//!   an odd-step Leisen-Reimer tree, and
+//!   [`price_european_leisen_reimer_extrapolated`] combining two of them
+//! * [`binomial_richardson`] / [`binomial_richardson_american`] — two-grid
+//!   Richardson extrapolation of the binomial price
 
 pub mod binomial_tree;
 pub mod trinomial_tree;
@@ -17,17 +22,18 @@ pub mod trinomial_tree;
 pub use binomial_tree::BinomialTree;
 pub use trinomial_tree::TrinomialTree;
 
-use ql_core::Real;
+use ql_core::{ensure, errors::Result, OptionType, Real};
 
 // ─── TimeGrid ─────────────────────────────────────────────────────────────────
 
-/// A grid of time points used by lattice methods.
+/// A grid of time points used by lattice and Monte Carlo methods.
 ///
 /// Corresponds to `QuantLib::TimeGrid`.
 #[derive(Debug, Clone)]
 pub struct TimeGrid {
     times: Vec<Real>,
     dts: Vec<Real>,
+    mandatory: Vec<usize>,
 }
 
 impl TimeGrid {
@@ -37,7 +43,11 @@ impl TimeGrid {
         let dt = end / steps as Real;
         let times: Vec<Real> = (0..=steps).map(|i| i as Real * dt).collect();
         let dts = vec![dt; steps];
-        Self { times, dts }
+        Self {
+            times,
+            dts,
+            mandatory: vec![steps],
+        }
     }
 
     /// Create from a set of mandatory time points, ensuring at least `min_steps`
@@ -61,10 +71,16 @@ impl TimeGrid {
         }
 
         let dts: Vec<Real> = all_times.windows(2).map(|w| w[1] - w[0]).collect();
-        Self {
+        let mut grid = Self {
             times: all_times,
             dts,
-        }
+            mandatory: Vec::new(),
+        };
+        grid.mandatory = mandatory
+            .iter()
+            .map(|&t| grid.index(t).expect("mandatory times are grid points"))
+            .collect();
+        grid
     }
 
     /// Number of time points (= steps + 1).
@@ -96,6 +112,35 @@ impl TimeGrid {
     pub fn times(&self) -> &[Real] {
         &self.times
     }
+
+    /// Index of the grid point equal to `t` (within 1e-12), if any.
+    pub fn index(&self, t: Real) -> Option<usize> {
+        let i = self.times.partition_point(|&x| x < t - 1e-12);
+        (i < self.times.len() && (self.times[i] - t).abs() < 1e-12).then_some(i)
+    }
+
+    /// Index of the grid point closest to `t`; ties go to the earlier point.
+    ///
+    /// Grid points are found by [`index`](Self::index); times outside the
+    /// grid map to the first or last index.
+    pub fn index_of(&self, t: Real) -> usize {
+        self.index(t).unwrap_or_else(|| {
+            let i = self.times.partition_point(|&x| x < t);
+            if i == 0 {
+                0
+            } else if i == self.times.len() || t - self.times[i - 1] <= self.times[i] - t {
+                i - 1
+            } else {
+                i
+            }
+        })
+    }
+
+    /// Grid indices of the mandatory times, one per input time in the order
+    /// given to [`TimeGrid::from_times`] (for a uniform grid, just the end).
+    pub fn mandatory_indices(&self) -> &[usize] {
+        &self.mandatory
+    }
 }
 
 // ─── Backward-induction pricing ───────────────────────────────────────────────
@@ -153,6 +198,173 @@ pub fn price_american(tree: &BinomialTree, payoff: &dyn Fn(Real) -> Real, discou
     values[0]
 }
 
+/// Price a European option by Richardson extrapolation over two binomial
+/// trees, of `steps` and `2 · steps` steps.
+///
+/// A binomial price converges as `P(N) ≈ P + c/N`, so two prices on `N₁`
+/// and `N₂` steps combine into `(N₂·P(N₂) − N₁·P(N₁)) / (N₂ − N₁)`, which
+/// cancels the leading error.  `N₁` and `N₂` are the step counts of the
+/// trees `tree_builder` actually returns, so variants that round up to an
+/// odd number of steps (Leisen-Reimer, Joshi4) are handled as well.
+///
+/// The gain depends on `c` being the same on both grids.  It is when the
+/// strike falls on a node of both trees, as at the money on even grids;
+/// otherwise the error also oscillates with the strike's position between
+/// nodes, which the extrapolation does not remove.  Leisen-Reimer and
+/// Joshi4 have no `1/N` term to cancel and gain nothing.
+///
+/// # Arguments
+/// * `tree_builder` — builds the tree for a requested number of steps
+/// * `steps` — steps of the coarser tree
+/// * `payoff` — payoff function `S → value`
+/// * `discount` — per-step discount factor for a step of length `dt`,
+///   typically `|dt| (−r · dt).exp()`
+///
+/// Returns an error if `steps` is zero or if `tree_builder` does not
+/// return a finer tree for `2 · steps` than for `steps`.
+pub fn binomial_richardson(
+    tree_builder: &dyn Fn(usize) -> BinomialTree,
+    steps: usize,
+    payoff: &dyn Fn(Real) -> Real,
+    discount: &dyn Fn(Real) -> Real,
+) -> Result<Real> {
+    richardson(tree_builder, steps, payoff, discount, price_european)
+}
+
+/// American counterpart of [`binomial_richardson`], rolling back with
+/// [`price_american`].
+pub fn binomial_richardson_american(
+    tree_builder: &dyn Fn(usize) -> BinomialTree,
+    steps: usize,
+    payoff: &dyn Fn(Real) -> Real,
+    discount: &dyn Fn(Real) -> Real,
+) -> Result<Real> {
+    richardson(tree_builder, steps, payoff, discount, price_american)
+}
+
+fn richardson(
+    tree_builder: &dyn Fn(usize) -> BinomialTree,
+    steps: usize,
+    payoff: &dyn Fn(Real) -> Real,
+    discount: &dyn Fn(Real) -> Real,
+    price: fn(&BinomialTree, &dyn Fn(Real) -> Real, Real) -> Real,
+) -> Result<Real> {
+    ensure!(steps > 0, "steps must be > 0");
+    let coarse = tree_builder(steps);
+    let fine = tree_builder(2 * steps);
+    let (n1, n2) = (coarse.steps() as Real, fine.steps() as Real);
+    ensure!(
+        n2 > n1,
+        "the finer tree must have more steps than the coarser one ({n2} vs {n1})"
+    );
+    let p1 = price(&coarse, payoff, discount(coarse.dt()));
+    let p2 = price(&fine, payoff, discount(fine.dt()));
+    Ok((n2 * p2 - n1 * p1) / (n2 - n1))
+}
+
+/// Price a European option under Black-Scholes on a Leisen-Reimer tree.
+///
+/// Leisen-Reimer places the tree's nodes by the Peizer-Pratt (method 2)
+/// inversion of the Black-Scholes `d₁` and `d₂`, centring the terminal
+/// layer on the strike; that centring, and with it the smooth
+/// second-order convergence, requires an odd number of steps.  An even
+/// `steps` is therefore raised to the next odd number.  The error then
+/// falls as `1/N²`, to a few parts in `10⁴` of the spot by 25 steps,
+/// where a Cox-Ross-Rubinstein tree is still out by several cents.
+///
+/// `r` and `q` are continuously-compounded risk-free and dividend rates,
+/// `vol` the Black volatility and `t` the time to expiry in years.
+///
+/// Returns an error if `spot`, `strike`, `vol` or `t` is not positive, or
+/// if `steps` is zero.
+#[allow(clippy::too_many_arguments)]
+pub fn price_european_leisen_reimer(
+    spot: Real,
+    strike: Real,
+    r: Real,
+    q: Real,
+    vol: Real,
+    t: Real,
+    steps: usize,
+    option_type: OptionType,
+) -> Result<Real> {
+    check_leisen_reimer_inputs(spot, strike, vol, t, steps)?;
+    Ok(leisen_reimer_tree_price(
+        spot,
+        strike,
+        r,
+        q,
+        vol,
+        t,
+        steps,
+        option_type,
+    ))
+}
+
+/// [`price_european_leisen_reimer`] with its leading error term removed.
+///
+/// A single tree's error is `c/N² + O(N⁻³)` on odd `N`, with `c` the same
+/// on every odd tree.  Combining the trees of `N` and `N − 2` steps as
+/// `(N²·P(N) − (N − 2)²·P(N − 2)) / (N² − (N − 2)²)` cancels it, which
+/// brings the error at 25 steps to a few parts in `10⁵` without a larger
+/// tree.  With `N = 1` the single tree is used.
+///
+/// Returns an error on the same inputs as [`price_european_leisen_reimer`].
+#[allow(clippy::too_many_arguments)]
+pub fn price_european_leisen_reimer_extrapolated(
+    spot: Real,
+    strike: Real,
+    r: Real,
+    q: Real,
+    vol: Real,
+    t: Real,
+    steps: usize,
+    option_type: OptionType,
+) -> Result<Real> {
+    check_leisen_reimer_inputs(spot, strike, vol, t, steps)?;
+    let n = if steps % 2 == 0 { steps + 1 } else { steps };
+    let price = |n| leisen_reimer_tree_price(spot, strike, r, q, vol, t, n, option_type);
+    let fine = price(n);
+    if n == 1 {
+        return Ok(fine);
+    }
+    let (n2, n1) = ((n * n) as Real, ((n - 2).pow(2)) as Real);
+    Ok((n2 * fine - n1 * price(n - 2)) / (n2 - n1))
+}
+
+fn check_leisen_reimer_inputs(
+    spot: Real,
+    strike: Real,
+    vol: Real,
+    t: Real,
+    steps: usize,
+) -> Result<()> {
+    ensure!(
+        spot > 0.0 && strike > 0.0 && vol > 0.0 && t > 0.0,
+        "spot, strike, vol and t must be positive"
+    );
+    ensure!(steps > 0, "steps must be > 0");
+    Ok(())
+}
+
+/// Price on the Leisen-Reimer tree of `steps` steps, raised to odd.
+#[allow(clippy::too_many_arguments)]
+fn leisen_reimer_tree_price(
+    spot: Real,
+    strike: Real,
+    r: Real,
+    q: Real,
+    vol: Real,
+    t: Real,
+    steps: usize,
+    option_type: OptionType,
+) -> Real {
+    let tree = BinomialTree::leisen_reimer_black_scholes(spot, r, q, vol, t, steps, strike);
+    let phi = option_type.sign();
+    let payoff = |s: Real| (phi * (s - strike)).max(0.0);
+    price_european(&tree, &payoff, (-r * tree.dt()).exp())
+}
+
 /// Price a European option on a trinomial tree by backward induction.
 #[allow(clippy::needless_range_loop)]
 pub fn price_european_trinomial(
@@ -241,4 +453,210 @@ mod tests {
         assert!(g.times().iter().any(|&t| (t - 0.5).abs() < 1e-12));
         assert!(g.times().iter().any(|&t| (t - 1.0).abs() < 1e-12));
     }
+
+    #[test]
+    fn time_grid_index_of_and_mandatory_indices() {
+        // Uniform points 0, 0.2, …, 1.0 plus the mandatory 0.3 and 0.7.
+        let g = TimeGrid::from_times(&[0.3, 1.0, 0.7], 5);
+        assert_eq!(g.size(), 8);
+        assert_eq!(g.mandatory_indices(), &[2, 7, 5]);
+        for (&i, t) in g.mandatory_indices().iter().zip([0.3, 1.0, 0.7]) {
+            assert!((g.time(i) - t).abs() < 1e-12);
+        }
+
+        assert_eq!(g.index(g.time(4) + 1e-13), Some(4));
+        assert_eq!(g.index(0.33), None);
+        assert_eq!(g.index_of(g.time(4)), 4);
+        assert_eq!(g.index_of(0.33), 2);
+        assert_eq!(g.index_of(0.36), 3);
+        assert_eq!(g.index_of(-1.0), 0);
+        assert_eq!(g.index_of(5.0), 7);
+
+        assert_eq!(TimeGrid::uniform(1.0, 4).mandatory_indices(), &[4]);
+    }
+
+    fn bsm_process() -> ql_processes::GeneralizedBlackScholesProcess {
+        use ql_termstructures::{BlackConstantVol, FlatForward};
+        use ql_time::{Actual365Fixed, Date};
+        use std::sync::Arc;
+
+        let ref_date = Date::from_ymd(2025, 1, 15).unwrap();
+        ql_processes::GeneralizedBlackScholesProcess::new(
+            100.0,
+            Arc::new(FlatForward::continuous(ref_date, 0.05, Actual365Fixed)),
+            Arc::new(FlatForward::continuous(ref_date, 0.02, Actual365Fixed)),
+            Arc::new(BlackConstantVol::new(ref_date, 0.25, Actual365Fixed)),
+        )
+    }
+
+    fn atm_put(s: Real) -> Real {
+        (100.0 - s).max(0.0)
+    }
+
+    fn discount(dt: Real) -> Real {
+        (-0.05 * dt).exp()
+    }
+
+    #[test]
+    fn richardson_converges_an_order_of_magnitude_faster() {
+        use ql_pricingengines::analytic_european_engine::black_scholes_merton;
+        let process = bsm_process();
+        let (bs, ..) = black_scholes_merton(
+            ql_instruments::OptionType::Put,
+            100.0,
+            100.0,
+            0.05,
+            0.02,
+            0.25,
+            1.0,
+        );
+        // At the money on even grids the strike sits on a node and the
+        // equal-jump trees converge smoothly as c/N.
+        let builders: [&dyn Fn(usize) -> BinomialTree; 2] = [
+            &|n| BinomialTree::cox_ross_rubinstein(&process, 1.0, n),
+            &|n| BinomialTree::trigeorgis(&process, 1.0, n),
+        ];
+        for build in builders {
+            for n in [50, 100] {
+                let extrapolated = binomial_richardson(build, n, &atm_put, &discount).unwrap();
+                let tree = build(10 * n);
+                let single = price_european(&tree, &atm_put, discount(tree.dt()));
+                assert!(
+                    (extrapolated - bs).abs() < (single - bs).abs(),
+                    "n = {n}: extrapolated {extrapolated:.6}, {} steps {single:.6}, BS {bs:.6}",
+                    10 * n
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn richardson_accepts_every_variant() {
+        use ql_pricingengines::analytic_european_engine::black_scholes_merton;
+        let process = bsm_process();
+        let (bs, ..) = black_scholes_merton(
+            ql_instruments::OptionType::Put,
+            100.0,
+            100.0,
+            0.05,
+            0.02,
+            0.25,
+            1.0,
+        );
+        // Leisen-Reimer trees of 2001 and 6001 steps agree on this to 1e-5.
+        let american_reference = 8.56523;
+        // Tian's tree does not keep the strike on a node, so its error
+        // oscillates between grids and the extrapolation removes little.
+        let builders: [(&dyn Fn(usize) -> BinomialTree, Real); 7] = [
+            (&|n| BinomialTree::jarrow_rudd(&process, 1.0, n), 3e-3),
+            (
+                &|n| BinomialTree::cox_ross_rubinstein(&process, 1.0, n),
+                2e-4,
+            ),
+            (&|n| BinomialTree::additive_eqp(&process, 1.0, n), 3e-3),
+            (&|n| BinomialTree::trigeorgis(&process, 1.0, n), 2e-4),
+            (&|n| BinomialTree::tian(&process, 1.0, n), 3e-2),
+            (
+                &|n| BinomialTree::leisen_reimer(&process, 1.0, n, 100.0),
+                2e-4,
+            ),
+            (&|n| BinomialTree::joshi4(&process, 1.0, n, 100.0), 2e-4),
+        ];
+        for (build, tolerance) in builders {
+            let european = binomial_richardson(build, 100, &atm_put, &discount).unwrap();
+            let american = binomial_richardson_american(build, 100, &atm_put, &discount).unwrap();
+            assert!(american > european);
+            assert!(
+                (european - bs).abs() < tolerance,
+                "European {european} vs {bs}"
+            );
+            assert!(
+                (american - american_reference).abs() < tolerance,
+                "American {american} vs {american_reference}"
+            );
+        }
+        assert!(binomial_richardson(builders[0].0, 0, &atm_put, &discount).is_err());
+    }
+
+    #[test]
+    fn leisen_reimer_is_accurate_at_few_steps() {
+        use ql_pricingengines::analytic_european_engine::black_scholes_merton;
+        let process = bsm_process();
+        let lr = |strike, steps, option_type| {
+            price_european_leisen_reimer(100.0, strike, 0.05, 0.02, 0.25, 1.0, steps, option_type)
+                .unwrap()
+        };
+        let extrapolated = |strike, steps, option_type| {
+            price_european_leisen_reimer_extrapolated(
+                100.0,
+                strike,
+                0.05,
+                0.02,
+                0.25,
+                1.0,
+                steps,
+                option_type,
+            )
+            .unwrap()
+        };
+        for (option_type, strike) in [
+            (OptionType::Call, 100.0),
+            (OptionType::Put, 100.0),
+            (OptionType::Call, 115.0),
+            (OptionType::Put, 90.0),
+        ] {
+            let (bs, ..) = black_scholes_merton(option_type, 100.0, strike, 0.05, 0.02, 0.25, 1.0);
+            let phi = option_type.sign();
+            let payoff = |s: Real| (phi * (s - strike)).max(0.0);
+
+            // The plain price is that of the single 25-step tree built on
+            // the process, and an even count is raised to the same tree.
+            let price = lr(strike, 25, option_type);
+            let tree = BinomialTree::leisen_reimer(&process, 1.0, 25, strike);
+            let on_process = price_european(&tree, &payoff, discount(tree.dt()));
+            assert!(
+                (price - on_process).abs() < 1e-12,
+                "{price} vs {on_process}"
+            );
+            assert_eq!(lr(strike, 24, option_type), price);
+
+            let tree = BinomialTree::cox_ross_rubinstein(&process, 1.0, 25);
+            let crr = price_european(&tree, &payoff, discount(tree.dt()));
+            assert!(
+                (price - bs).abs() < 1e-3,
+                "{option_type:?} K = {strike}: LR {price:.6} vs BS {bs:.6}"
+            );
+            assert!(
+                (crr - bs).abs() > 20.0 * (price - bs).abs(),
+                "{option_type:?} K = {strike}: CRR {crr:.6}, LR {price:.6}, BS {bs:.6}"
+            );
+
+            // Cancelling the 1/N² term reaches 1e-4 at the same count.
+            let better = extrapolated(strike, 25, option_type);
+            assert_eq!(extrapolated(strike, 24, option_type), better);
+            assert!(
+                (better - bs).abs() < 1e-4,
+                "{option_type:?} K = {strike}: extrapolated LR {better:.6} vs BS {bs:.6}"
+            );
+        }
+    }
+
+    #[test]
+    fn leisen_reimer_rejects_invalid_inputs() {
+        let call = OptionType::Call;
+        assert!(price_european_leisen_reimer(100.0, 100.0, 0.05, 0.0, 0.2, 1.0, 0, call).is_err());
+        assert!(price_european_leisen_reimer(100.0, 100.0, 0.05, 0.0, 0.0, 1.0, 25, call).is_err());
+        assert!(price_european_leisen_reimer(100.0, -1.0, 0.05, 0.0, 0.2, 1.0, 25, call).is_err());
+        assert!(price_european_leisen_reimer_extrapolated(
+            100.0, 100.0, 0.05, 0.0, 0.2, 1.0, 0, call
+        )
+        .is_err());
+        // A single step still prices, and has nothing to extrapolate from.
+        let single = price_european_leisen_reimer(100.0, 100.0, 0.05, 0.0, 0.2, 1.0, 1, call);
+        assert_eq!(
+            single.unwrap(),
+            price_european_leisen_reimer_extrapolated(100.0, 100.0, 0.05, 0.0, 0.2, 1.0, 1, call)
+                .unwrap()
+        );
+    }
 }
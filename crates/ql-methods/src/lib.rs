@@ -28,12 +28,17 @@ pub mod finite_differences;
 
 // ── Convenience re-exports ────────────────────────────────────────────────────
 
-pub use finite_differences::{Fdm1dSolver, FdmScheme, TridiagonalOperator};
+pub use finite_differences::{Fdm1dSolver, FdmScheme, FdmSpatialScheme, TridiagonalOperator};
 pub use lattice::{
-    price_american, price_american_trinomial, price_european, price_european_trinomial,
-    BinomialTree, TimeGrid, TrinomialTree,
+    binomial_richardson, binomial_richardson_american, price_american, price_american_trinomial,
+    price_european, price_european_leisen_reimer, price_european_leisen_reimer_extrapolated,
+    price_european_trinomial, BinomialTree, TimeGrid, TrinomialTree,
 };
 pub use monte_carlo::{
-    mc_european_price, AntitheticPathGenerator, EuropeanPathPricer, MonteCarloModel, Path,
-    PathGenerator, PathPricer,
+    mc_european_price, AndersenBroadieUpperBound, AntitheticPathGenerator, AutocallableCashFlows,
+    AutocallablePathPricer, BrownianBridgePathGenerator, DualityBounds, EuropeanPathPricer,
+    ExerciseStrategy, GaussianSobolPathGenerator, JumpDiffusionPathGenerator,
+    LocalVolPathGenerator, LongstaffSchwartzPathPricer, MonteCarloModel, MultiPath,
+    MultiPathGenerator, Path, PathGenerator, PathPricer, RegressionExerciseStrategy,
+    ShortRateBermudanSimulation,
 };
@@ -0,0 +1,286 @@
+//! Path pricer for autocallable (snowball / phoenix) notes.
+//!
+//! QuantLib has no counterpart.  Levels are taken relative to the initial
+//! fixing; at each observation date `tᵢ`, with `xᵢ = S(tᵢ)/S(0)`:
+//!
+//! * if `xᵢ` is at or above the coupon barrier, every coupon accrued since the
+//!   last payment is paid (the "memory" feature);
+//! * if `xᵢ` is at or above the autocall barrier, the note redeems at par
+//!   with any outstanding coupons, and the path ends.
+//!
+//! A note surviving to the last date redeems at par less a put struck at
+//! `strike` — but only if the path has touched the knock-in barrier at some
+//! point of the simulation grid.  Coupon barriers set equal to the autocall
+//! barriers give a pure snowball, whose coupons are paid only on redemption.
+
+use super::{Path, PathPricer};
+use crate::lattice::TimeGrid;
+use ql_core::errors::{Error, Result};
+use ql_core::{ensure, DiscountFactor, Real, Time};
+
+/// Present values of the two legs of an autocallable along one path.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct AutocallableCashFlows {
+    /// Discounted coupons paid.
+    pub coupons: Real,
+    /// Discounted redemption amount.
+    pub redemption: Real,
+    /// Index of the observation at which the note redeemed.
+    pub redemption_index: usize,
+    /// Whether the note was called before maturity.
+    pub called: bool,
+}
+
+/// Prices an autocallable note with memory coupons and a down-and-in put
+/// at maturity.
+#[derive(Debug)]
+pub struct AutocallablePathPricer {
+    observations: Vec<usize>,
+    autocall_barriers: Vec<Real>,
+    coupon_barriers: Vec<Real>,
+    discounts: Vec<DiscountFactor>,
+    coupon: Real,
+    knock_in_barrier: Real,
+    strike: Real,
+    notional: Real,
+}
+
+impl AutocallablePathPricer {
+    /// Create a pricer for paths generated on `grid`.
+    ///
+    /// `observation_times` must be points of the grid; the barriers are
+    /// fractions of the initial level, one per observation, and `discounts`
+    /// are the discount factors to each observation date.  `coupon` is paid
+    /// per observation period, as a fraction of the notional; the knock-in
+    /// put is struck at the initial level.
+    pub fn new(
+        grid: &TimeGrid,
+        observation_times: &[Time],
+        autocall_barriers: Vec<Real>,
+        coupon_barriers: Vec<Real>,
+        coupon: Real,
+        knock_in_barrier: Real,
+        discounts: Vec<DiscountFactor>,
+    ) -> Result<Self> {
+        let n = observation_times.len();
+        ensure!(n > 0, "at least one observation date is required");
+        ensure!(
+            autocall_barriers.len() == n && coupon_barriers.len() == n && discounts.len() == n,
+            "{} observation dates but {} autocall barriers, {} coupon barriers and {} discounts",
+            n,
+            autocall_barriers.len(),
+            coupon_barriers.len(),
+            discounts.len()
+        );
+        ensure!(
+            observation_times.windows(2).all(|w| w[0] < w[1]),
+            "observation times must be increasing"
+        );
+        let observations = observation_times
+            .iter()
+            .map(|&t| {
+                grid.index(t).filter(|&i| i > 0).ok_or_else(|| {
+                    Error::Runtime(format!("observation time {t} is not on the grid"))
+                })
+            })
+            .collect::<Result<Vec<_>>>()?;
+        Ok(Self {
+            observations,
+            autocall_barriers,
+            coupon_barriers,
+            discounts,
+            coupon,
+            knock_in_barrier,
+            strike: 1.0,
+            notional: 1.0,
+        })
+    }
+
+    /// Set the notional (default 1).
+    pub fn with_notional(mut self, notional: Real) -> Self {
+        self.notional = notional;
+        self
+    }
+
+    /// Set the strike of the knock-in put as a fraction of the initial
+    /// level (default 1).
+    pub fn with_put_strike(mut self, strike: Real) -> Self {
+        self.strike = strike;
+        self
+    }
+
+    /// Discounted coupons and redemption along `path`.
+    pub fn cash_flows(&self, path: &Path) -> AutocallableCashFlows {
+        let s0 = path.front();
+        let mut coupons = 0.0;
+        let mut unpaid = 0;
+        let last = self.observations.len() - 1;
+        for (i, &index) in self.observations.iter().enumerate() {
+            let level = path.values[index] / s0;
+            unpaid += 1;
+            let called = level >= self.autocall_barriers[i];
+            if called || level >= self.coupon_barriers[i] {
+                coupons += self.notional * self.coupon * unpaid as Real * self.discounts[i];
+                unpaid = 0;
+            }
+            if called {
+                return AutocallableCashFlows {
+                    coupons,
+                    redemption: self.notional * self.discounts[i],
+                    redemption_index: i,
+                    called: i < last,
+                };
+            }
+        }
+
+        let end = self.observations[last];
+        let final_level = path.values[end] / s0;
+        let knocked_in = path.values[..=end]
+            .iter()
+            .any(|&s| s <= self.knock_in_barrier * s0);
+        let put = if knocked_in {
+            (self.strike - final_level).max(0.0) / self.strike
+        } else {
+            0.0
+        };
+        AutocallableCashFlows {
+            coupons,
+            redemption: self.notional * (1.0 - put) * self.discounts[last],
+            redemption_index: last,
+            called: false,
+        }
+    }
+}
+
+impl PathPricer for AutocallablePathPricer {
+    fn value(&self, path: &Path) -> Real {
+        let flows = self.cash_flows(path);
+        flows.coupons + flows.redemption
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::monte_carlo::PathGenerator;
+    use ql_math::distributions::normal_cdf;
+    use ql_math::statistics::IncrementalStatistics;
+    use ql_processes::GeneralizedBlackScholesProcess;
+    use ql_termstructures::{BlackConstantVol, FlatForward};
+    use ql_time::{Actual365Fixed, Date};
+    use std::sync::Arc;
+
+    const SPOT: Real = 100.0;
+    const R: Real = 0.03;
+    const Q: Real = 0.01;
+    const SIGMA: Real = 0.25;
+
+    fn process() -> GeneralizedBlackScholesProcess {
+        let ref_date = Date::from_ymd(2025, 1, 2).unwrap();
+        GeneralizedBlackScholesProcess::new(
+            SPOT,
+            Arc::new(FlatForward::continuous(ref_date, R, Actual365Fixed)),
+            Arc::new(FlatForward::continuous(ref_date, Q, Actual365Fixed)),
+            Arc::new(BlackConstantVol::new(ref_date, SIGMA, Actual365Fixed)),
+        )
+    }
+
+    #[test]
+    fn higher_autocall_barrier_lowers_expected_coupon() {
+        let process = process();
+        let times: Vec<Time> = (1..=8).map(|i| 0.25 * i as Real).collect();
+        let grid = TimeGrid::from_times(&times, 104);
+        let discounts: Vec<Real> = times.iter().map(|&t| (-R * t).exp()).collect();
+
+        // A snowball's coupons accrue over its life, so a higher barrier
+        // delays the call (adding coupons) as well as making it rarer;
+        // above the money the second effect wins.
+        let coupons: Vec<(Real, Real)> = [1.15, 1.3, 1.45]
+            .iter()
+            .map(|&barrier| {
+                let pricer = AutocallablePathPricer::new(
+                    &grid,
+                    &times,
+                    vec![barrier; times.len()],
+                    vec![barrier; times.len()],
+                    0.02,
+                    0.7,
+                    discounts.clone(),
+                )
+                .unwrap();
+                let mut generator = PathGenerator::with_time_grid(&process, &grid, 11);
+                let mut stats = IncrementalStatistics::new();
+                for _ in 0..10_000 {
+                    stats.add(pricer.cash_flows(&generator.next_path()).coupons);
+                }
+                (stats.mean().unwrap(), stats.error_estimate().unwrap())
+            })
+            .collect();
+        for pair in coupons.windows(2) {
+            let ((high, high_err), (low, low_err)) = (pair[0], pair[1]);
+            assert!(high - low > 3.0 * high_err.hypot(low_err), "{coupons:?}");
+        }
+    }
+
+    #[test]
+    fn single_observation_is_a_digital_plus_put() {
+        let process = process();
+        let t = 1.0;
+        let (coupon, knock_in) = (0.08, 0.75);
+        let discount = (-R * t).exp();
+        let grid = TimeGrid::uniform(t, 1);
+        let pricer = AutocallablePathPricer::new(
+            &grid,
+            &[t],
+            vec![1.0],
+            vec![1.0],
+            coupon,
+            knock_in,
+            vec![discount],
+        )
+        .unwrap()
+        .with_notional(100.0);
+
+        // With one step the knock-in is observed on S_T alone, so the note
+        // is par plus a digital call paying the coupon, less a gap put of
+        // strike S₀ triggered below the knock-in level.
+        let std_dev = SIGMA * t.sqrt();
+        let d2 = |k: Real| ((SPOT / k).ln() + (R - Q - 0.5 * SIGMA * SIGMA) * t) / std_dev;
+        let digital = discount * normal_cdf(d2(SPOT));
+        let trigger = knock_in * SPOT;
+        let gap_put = SPOT * discount * normal_cdf(-d2(trigger))
+            - SPOT * (-Q * t).exp() * normal_cdf(-d2(trigger) - std_dev);
+        let expected = 100.0 * (discount + coupon * digital - gap_put / SPOT);
+
+        let mut generator = PathGenerator::with_time_grid(&process, &grid, 3);
+        let mut stats = IncrementalStatistics::new();
+        for _ in 0..50_000 {
+            stats.add(pricer.value(&generator.next_path()));
+        }
+        let (mean, error) = (stats.mean().unwrap(), stats.error_estimate().unwrap());
+        assert!(
+            (mean - expected).abs() < 3.0 * error,
+            "MC {mean:.4} ± {error:.4} vs closed form {expected:.4}"
+        );
+    }
+
+    #[test]
+    fn rejects_observations_off_the_grid() {
+        let grid = TimeGrid::uniform(1.0, 4);
+        let make = |times: &[Time]| {
+            AutocallablePathPricer::new(
+                &grid,
+                times,
+                vec![1.0; times.len()],
+                vec![1.0; times.len()],
+                0.02,
+                0.7,
+                vec![1.0; times.len()],
+            )
+        };
+        assert!(make(&[0.5, 1.0]).is_ok());
+        assert!(make(&[0.3, 1.0]).is_err());
+        assert!(make(&[1.0, 0.5]).is_err());
+        assert!(make(&[]).is_err());
+    }
+}
@@ -0,0 +1,176 @@
+//! Longstaff-Schwartz least-squares Monte Carlo for early exercise.
+//!
+//! Translates `ql/methods/montecarlo/longstaffschwartzpathpricer.hpp`.
+//!
+//! The pricer works in two phases, as in QuantLib. During calibration the
+//! continuation value at each exercise time is regressed, over the
+//! in-the-money paths, on the polynomials `1, x, x², x³` of the normalised
+//! spot `x = S/S₀`, working backwards from the last exercise time. During
+//! pricing, each fresh path is exercised at the first time where the
+//! immediate payoff beats the fitted continuation value.
+
+use super::{Path, PathPricer};
+use ql_core::{ensure, errors::Result, Real, Time};
+use ql_math::linear_least_squares::LinearLeastSquaresRegression;
+use ql_termstructures::YieldTermStructure;
+use std::sync::Arc;
+
+/// Degree of the polynomial regression basis.
+const BASIS_DEGREE: i32 = 3;
+
+/// Path pricer for American/Bermudan payoffs by least-squares regression.
+///
+/// Call [`calibrate`](Self::calibrate) on a set of paths before using it as
+/// a [`PathPricer`]; an uncalibrated pricer only exercises at the last
+/// exercise time. Exercise times are matched to the nearest time of each
+/// path.
+///
+/// Corresponds to `QuantLib::LongstaffSchwartzPathPricer`.
+pub struct LongstaffSchwartzPathPricer<F> {
+    exercise_times: Vec<Time>,
+    payoff: F,
+    discount_curve: Arc<dyn YieldTermStructure>,
+    /// Regression coefficients per exercise time but the last; `None` where
+    /// too few paths were in the money to fit.
+    coefficients: Vec<Option<Vec<Real>>>,
+    calibrated: bool,
+}
+
+impl<F: Fn(Real) -> Real + Send + Sync> LongstaffSchwartzPathPricer<F> {
+    /// Create a pricer exercising `payoff(S)` at any of `exercise_times`,
+    /// discounting on `discount_curve`.
+    ///
+    /// # Errors
+    /// Fails if `exercise_times` is empty or not strictly increasing.
+    pub fn new(
+        exercise_times: Vec<Time>,
+        payoff: F,
+        discount_curve: Arc<dyn YieldTermStructure>,
+    ) -> Result<Self> {
+        ensure!(!exercise_times.is_empty(), "no exercise times given");
+        ensure!(
+            exercise_times.windows(2).all(|w| w[0] < w[1]),
+            "exercise times must be increasing"
+        );
+        Ok(Self {
+            exercise_times,
+            payoff,
+            discount_curve,
+            coefficients: Vec::new(),
+            calibrated: false,
+        })
+    }
+
+    /// The exercise times.
+    pub fn exercise_times(&self) -> &[Time] {
+        &self.exercise_times
+    }
+
+    /// Whether [`calibrate`](Self::calibrate) has been run.
+    pub fn is_calibrated(&self) -> bool {
+        self.calibrated
+    }
+
+    /// Fit the exercise boundary on `paths`.
+    pub fn calibrate(&mut self, paths: &[Path]) -> Result<()> {
+        ensure!(!paths.is_empty(), "no calibration paths given");
+        let n_exercises = self.exercise_times.len();
+        let discounts: Vec<Real> = self
+            .exercise_times
+            .iter()
+            .map(|&t| self.discount_curve.discount(t))
+            .collect();
+        let states: Vec<(Real, Vec<usize>)> = paths
+            .iter()
+            .map(|p| (p.front(), self.exercise_indices(p)))
+            .collect();
+
+        // Discounted (to t = 0) cash flow of each path under the current
+        // exercise policy, starting from exercise at the last time.
+        let last = n_exercises - 1;
+        let mut cash_flows: Vec<Real> = paths
+            .iter()
+            .zip(&states)
+            .map(|(path, (_, idx))| (self.payoff)(path.values[idx[last]]) * discounts[last])
+            .collect();
+
+        let basis: Vec<_> = (0..=BASIS_DEGREE)
+            .map(|k| move |x: Real| x.powi(k))
+            .collect();
+        let mut coefficients = vec![None; last];
+        for i in (0..last).rev() {
+            let mut xs = Vec::new();
+            let mut ys = Vec::new();
+            let mut itm = Vec::new();
+            for (j, (path, (s0, idx))) in paths.iter().zip(&states).enumerate() {
+                let spot = path.values[idx[i]];
+                if (self.payoff)(spot) > 0.0 {
+                    xs.push(spot / s0);
+                    ys.push(cash_flows[j]);
+                    itm.push(j);
+                }
+            }
+            if xs.len() <= basis.len() {
+                continue;
+            }
+            let fit = LinearLeastSquaresRegression::new(&xs, &ys, &basis)?;
+            let beta = fit.coefficients().as_slice().to_vec();
+            for (&j, &x) in itm.iter().zip(&xs) {
+                let exercise = (self.payoff)(x * states[j].0) * discounts[i];
+                if exercise > continuation(&beta, x) {
+                    cash_flows[j] = exercise;
+                }
+            }
+            coefficients[i] = Some(beta);
+        }
+        self.coefficients = coefficients;
+        self.calibrated = true;
+        Ok(())
+    }
+
+    /// Index into the path of the grid time nearest to each exercise time.
+    fn exercise_indices(&self, path: &Path) -> Vec<usize> {
+        self.exercise_times
+            .iter()
+            .map(|&t| {
+                let i = path.times.partition_point(|&x| x < t);
+                if i == 0 {
+                    0
+                } else if i == path.times.len() || t - path.times[i - 1] <= path.times[i] - t {
+                    i - 1
+                } else {
+                    i
+                }
+            })
+            .collect()
+    }
+}
+
+/// Fitted continuation value `Σ βₖ xᵏ`.
+pub(super) fn continuation(beta: &[Real], x: Real) -> Real {
+    beta.iter().rev().fold(0.0, |acc, &b| acc * x + b)
+}
+
+impl<F: Fn(Real) -> Real + Send + Sync> PathPricer for LongstaffSchwartzPathPricer<F> {
+    fn value(&self, path: &Path) -> Real {
+        let idx = self.exercise_indices(path);
+        let s0 = path.front();
+        for (i, beta) in self.coefficients.iter().enumerate() {
+            let spot = path.values[idx[i]];
+            let exercise = (self.payoff)(spot);
+            if exercise <= 0.0 {
+                continue;
+            }
+            let Some(beta) = beta else {
+                continue;
+            };
+            let exercise = exercise * self.discount_curve.discount(self.exercise_times[i]);
+            if exercise > continuation(beta, spot / s0) {
+                return exercise;
+            }
+        }
+        let last = self.exercise_times.len() - 1;
+        (self.payoff)(path.values[idx[last]])
+            * self.discount_curve.discount(self.exercise_times[last])
+    }
+}
@@ -9,9 +9,38 @@
 //! * [`PathPricer`] — trait for evaluating payoffs on generated paths
 //! * [`MonteCarloModel`] — orchestrates path generation and statistics collection
 //! * [`Path`] — a single realisation of the process (times + values)
-
-use ql_core::Real;
-use ql_math::random_numbers::InverseCumulativeNormalRng;
+//! * [`MultiPath`] / [`MultiPathGenerator`] — paths of multi-factor processes
+//! * [`GaussianSobolPathGenerator`] — Sobol + Brownian-bridge multi-factor paths
+//! * [`BrownianBridgePathGenerator`] — Sobol + Brownian-bridge 1-D paths
+//! * [`JumpDiffusionPathGenerator`] — spot paths of the Merton jump-diffusion
+//! * [`LocalVolPathGenerator`] — spot paths under a local-volatility surface
+//! * [`LongstaffSchwartzPathPricer`] — least-squares early-exercise pricer
+//! * [`AutocallablePathPricer`] — autocallable notes with memory coupons
+//! * [`AndersenBroadieUpperBound`] — primal-dual bounds for Bermudan short-rate options
+
+pub mod andersen_broadie;
+pub mod autocallable_path_pricer;
+pub mod jump_diffusion_path_generator;
+pub mod local_vol_path_generator;
+pub mod longstaff_schwartz;
+pub mod multi_path;
+pub mod sobol_path_generator;
+
+pub use andersen_broadie::{
+    AndersenBroadieUpperBound, DualityBounds, ExerciseStrategy, RegressionExerciseStrategy,
+    ShortRateBermudanSimulation,
+};
+pub use autocallable_path_pricer::{AutocallableCashFlows, AutocallablePathPricer};
+pub use jump_diffusion_path_generator::JumpDiffusionPathGenerator;
+pub use local_vol_path_generator::LocalVolPathGenerator;
+pub use longstaff_schwartz::LongstaffSchwartzPathPricer;
+pub use multi_path::{MultiPath, MultiPathGenerator};
+pub use sobol_path_generator::{BrownianBridgePathGenerator, GaussianSobolPathGenerator};
+
+use crate::lattice::TimeGrid;
+use ql_core::{errors::Result, Real};
+use ql_math::distributions::normal_cdf_inverse;
+use ql_math::random_numbers::{InverseCumulativeNormalRng, UniformRsg};
 use ql_math::statistics::IncrementalStatistics;
 use ql_processes::StochasticProcess1D;
 
@@ -60,18 +89,19 @@ impl Path {
 /// Generates sample paths of a 1-D stochastic process.
 ///
 /// Uses the process's `evolve_1d` method to step forward from the initial
-/// value through a uniform time grid.
+/// value through a time grid — uniform by default, or any [`TimeGrid`] so
+/// that path-dependent payoffs can be sampled exactly at their observation
+/// times.
 ///
 /// Corresponds to `QuantLib::PathGenerator`.
 pub struct PathGenerator<'a> {
     process: &'a dyn StochasticProcess1D,
-    dt: Real,
-    steps: usize,
+    grid: TimeGrid,
     rng: InverseCumulativeNormalRng,
 }
 
 impl<'a> PathGenerator<'a> {
-    /// Create a new path generator.
+    /// Create a new path generator on a uniform grid.
     ///
     /// # Arguments
     /// * `process` — the stochastic process to simulate
@@ -83,34 +113,48 @@ impl<'a> PathGenerator<'a> {
         maturity: Real,
         steps: usize,
         seed: u64,
+    ) -> Self {
+        Self::with_time_grid(process, &TimeGrid::uniform(maturity, steps), seed)
+    }
+
+    /// Create a path generator on an arbitrary (possibly non-uniform) grid,
+    /// e.g. one built with [`TimeGrid::from_times`] from fixing times.
+    pub fn with_time_grid(
+        process: &'a dyn StochasticProcess1D,
+        grid: &TimeGrid,
+        seed: u64,
     ) -> Self {
         Self {
             process,
-            dt: maturity / steps as Real,
-            steps,
+            grid: grid.clone(),
             rng: InverseCumulativeNormalRng::new(seed),
         }
     }
 
+    /// The time grid paths are generated on.
+    pub fn time_grid(&self) -> &TimeGrid {
+        &self.grid
+    }
+
     /// Generate one sample path.
     pub fn next_path(&mut self) -> Path {
-        let mut times = Vec::with_capacity(self.steps + 1);
-        let mut values = Vec::with_capacity(self.steps + 1);
-
         let x0 = self.process.x0();
-        times.push(0.0);
+        let mut values = Vec::with_capacity(self.grid.size());
         values.push(x0);
 
         let mut x = x0;
-        for i in 0..self.steps {
-            let t = i as Real * self.dt;
+        for i in 0..self.grid.steps() {
             let dw = self.rng.next_real();
-            x = self.process.evolve_1d(t, x, self.dt, dw);
-            times.push(t + self.dt);
+            x = self
+                .process
+                .evolve_1d(self.grid.time(i), x, self.grid.dt(i), dw);
             values.push(x);
         }
 
-        Path { times, values }
+        Path {
+            times: self.grid.times().to_vec(),
+            values,
+        }
     }
 }
 
@@ -238,6 +282,35 @@ impl<F: Fn(Real) -> Real + Send + Sync> PathPricer for AsianArithmeticPathPricer
     }
 }
 
+/// A geometric-average Asian payoff pricer.
+///
+/// The geometric average of a lognormal path is itself lognormal, so its
+/// price is known in closed form; this makes it the usual control variate
+/// for [`AsianArithmeticPathPricer`].
+pub struct AsianGeometricPathPricer<F> {
+    payoff: F,
+    discount: Real,
+}
+
+impl<F: Fn(Real) -> Real + Send + Sync> AsianGeometricPathPricer<F> {
+    /// Create an Asian geometric-average pricer.
+    pub fn new(payoff: F, discount: Real) -> Self {
+        Self { payoff, discount }
+    }
+}
+
+impl<F: Fn(Real) -> Real + Send + Sync> PathPricer for AsianGeometricPathPricer<F> {
+    fn value(&self, path: &Path) -> Real {
+        // Same fixings as the arithmetic pricer: all values but the initial
+        let n = path.steps();
+        if n == 0 {
+            return 0.0;
+        }
+        let log_avg: Real = path.values[1..].iter().map(|v| v.ln()).sum::<Real>() / n as Real;
+        (self.payoff)(log_avg.exp()) * self.discount
+    }
+}
+
 // ─── MonteCarloModel ──────────────────────────────────────────────────────────
 
 /// A Monte Carlo simulation orchestrator.
@@ -283,6 +356,55 @@ impl<'a> MonteCarloModel<'a> {
         stats
     }
 
+    /// Run `n_paths` simulations spread over `n_threads` scoped threads.
+    ///
+    /// The paths are dealt out as evenly as possible and thread `i` draws
+    /// its share from a [`PathGenerator`] seeded with `seed + i`, so a
+    /// given `(seed, n_threads)` always reproduces the same result.  The
+    /// per-thread statistics are then merged with
+    /// [`IncrementalStatistics::merge`].
+    ///
+    /// Changing `n_threads` changes which paths are drawn: the merged mean
+    /// estimates the same price whatever the thread count, but the sample
+    /// values, and with them the error estimate, differ from one count to
+    /// another and from [`simulate`](Self::simulate).
+    ///
+    /// # Panics
+    /// Panics if `n_threads` is zero.
+    pub fn simulate_parallel(
+        &self,
+        pricer: &dyn PathPricer,
+        n_paths: usize,
+        n_threads: usize,
+    ) -> IncrementalStatistics {
+        assert!(n_threads > 0, "at least one thread is required");
+        let (share, extra) = (n_paths / n_threads, n_paths % n_threads);
+
+        std::thread::scope(|scope| {
+            let workers: Vec<_> = (0..n_threads)
+                .map(|i| {
+                    let paths = share + usize::from(i < extra);
+                    let seed = self.seed.wrapping_add(i as u64);
+                    scope.spawn(move || {
+                        let mut gen =
+                            PathGenerator::new(self.process, self.maturity, self.steps, seed);
+                        let mut stats = IncrementalStatistics::new();
+                        for _ in 0..paths {
+                            stats.add(pricer.value(&gen.next_path()));
+                        }
+                        stats
+                    })
+                })
+                .collect();
+
+            let mut stats = IncrementalStatistics::new();
+            for worker in workers {
+                stats.merge(&worker.join().expect("Monte Carlo worker panicked"));
+            }
+            stats
+        })
+    }
+
     /// Run with antithetic variates for variance reduction.
     pub fn simulate_antithetic(
         &self,
@@ -303,6 +425,189 @@ impl<'a> MonteCarloModel<'a> {
 
         stats
     }
+
+    /// Run `n_paths` simulations with a control variate whose coefficient is
+    /// estimated from a pilot batch.
+    ///
+    /// Each sample is `pricer(path) − β·(control_pricer(path) − control_value)`,
+    /// where `control_value` is the control's exact expectation.  The
+    /// variance-minimising `β = Cov(payoff, control) / Var(control)` is
+    /// estimated on `max(n_paths / 10, 2)` pilot paths drawn ahead of, and
+    /// independently of, the `n_paths` priced ones, so the estimate stays
+    /// unbiased.
+    pub fn simulate_with_control<P, C>(
+        &self,
+        pricer: &P,
+        control_pricer: &C,
+        control_value: Real,
+        n_paths: usize,
+    ) -> IncrementalStatistics
+    where
+        P: PathPricer + ?Sized,
+        C: PathPricer + ?Sized,
+    {
+        let mut gen = PathGenerator::new(self.process, self.maturity, self.steps, self.seed);
+        let draw = || {
+            let path = gen.next_path();
+            (pricer.value(&path), control_pricer.value(&path))
+        };
+        controlled_statistics(draw, control_value, n_paths)
+    }
+
+    /// Run `n_paths` antithetic pairs with a control variate whose
+    /// coefficient is estimated from a pilot batch of pairs.
+    ///
+    /// As in [`simulate_antithetic`](Self::simulate_antithetic), a sample
+    /// averages a path and its mirror image; both the payoff and the control
+    /// are averaged over the pair before the correction of
+    /// [`simulate_with_control`](Self::simulate_with_control) is applied, so
+    /// `β` is fitted to the pair averages actually being summed.
+    pub fn simulate_antithetic_with_control<P, C>(
+        &self,
+        pricer: &P,
+        control_pricer: &C,
+        control_value: Real,
+        n_paths: usize,
+    ) -> IncrementalStatistics
+    where
+        P: PathPricer + ?Sized,
+        C: PathPricer + ?Sized,
+    {
+        let mut gen =
+            AntitheticPathGenerator::new(self.process, self.maturity, self.steps, self.seed);
+        let draw = || {
+            let (path1, path2) = (gen.next_path(), gen.next_path());
+            (
+                0.5 * (pricer.value(&path1) + pricer.value(&path2)),
+                0.5 * (control_pricer.value(&path1) + control_pricer.value(&path2)),
+            )
+        };
+        controlled_statistics(draw, control_value, n_paths)
+    }
+
+    /// Run `n_paths` simulations driven by a uniform sequence generator
+    /// (pseudo-random or low-discrepancy) of dimension `steps`.
+    ///
+    /// Each uniform vector is mapped to normals through the inverse
+    /// cumulative normal. With `antithetic`, every sample is the average of
+    /// the path and its mirror image (normals negated). With a control
+    /// variate `(cv_pricer, cv_value)`, every sample is corrected by
+    /// `cv_value − cv_pricer(path)`, where `cv_value` is the control's exact
+    /// expectation.
+    ///
+    /// The model's seed is not used: `rsg` determines the draws.
+    pub fn simulate_sequence(
+        &self,
+        rsg: &mut dyn UniformRsg,
+        pricer: &dyn PathPricer,
+        n_paths: usize,
+        antithetic: bool,
+        control_variate: Option<(&dyn PathPricer, Real)>,
+    ) -> IncrementalStatistics {
+        assert_eq!(
+            rsg.dimension(),
+            self.steps,
+            "sequence dimension must equal the number of time steps"
+        );
+        let dt = self.maturity / self.steps as Real;
+        let sample = |path: &Path| match control_variate {
+            Some((cv_pricer, cv_value)) => pricer.value(path) + cv_value - cv_pricer.value(path),
+            None => pricer.value(path),
+        };
+
+        let mut stats = IncrementalStatistics::new();
+        for _ in 0..n_paths {
+            let normals: Vec<Real> = rsg
+                .next_sequence()
+                .into_iter()
+                .map(|u| normal_cdf_inverse(u.clamp(Real::MIN_POSITIVE, 1.0 - Real::EPSILON)))
+                .collect();
+            let path = path_from_normals(self.process, dt, &normals, 1.0);
+            if antithetic {
+                let mirror = path_from_normals(self.process, dt, &normals, -1.0);
+                stats.add(0.5 * (sample(&path) + sample(&mirror)));
+            } else {
+                stats.add(sample(&path));
+            }
+        }
+
+        stats
+    }
+
+    /// Price an early-exercise payoff by Longstaff-Schwartz regression.
+    ///
+    /// The first `calibration_paths` paths fit `pricer`'s exercise boundary;
+    /// the next `n_paths` independent paths are then priced with it, so the
+    /// estimate is free of the upward foresight bias.
+    ///
+    /// # Errors
+    /// Fails if the calibration fails, e.g. for zero calibration paths.
+    pub fn simulate_american<F: Fn(Real) -> Real + Send + Sync>(
+        &self,
+        pricer: &mut LongstaffSchwartzPathPricer<F>,
+        calibration_paths: usize,
+        n_paths: usize,
+    ) -> Result<IncrementalStatistics> {
+        let mut gen = PathGenerator::new(self.process, self.maturity, self.steps, self.seed);
+        let paths: Vec<Path> = (0..calibration_paths).map(|_| gen.next_path()).collect();
+        pricer.calibrate(&paths)?;
+
+        let mut stats = IncrementalStatistics::new();
+        for _ in 0..n_paths {
+            stats.add(pricer.value(&gen.next_path()));
+        }
+        Ok(stats)
+    }
+}
+
+/// Accumulate `y − β·(c − control_value)` over `n_paths` draws of
+/// `(y, c)`, with `β` estimated on `max(n_paths / 10, 2)` earlier draws.
+fn controlled_statistics(
+    mut draw: impl FnMut() -> (Real, Real),
+    control_value: Real,
+    n_paths: usize,
+) -> IncrementalStatistics {
+    let pilot: Vec<(Real, Real)> = (0..(n_paths / 10).max(2)).map(|_| draw()).collect();
+    let n = pilot.len() as Real;
+    let mean_y = pilot.iter().map(|p| p.0).sum::<Real>() / n;
+    let mean_c = pilot.iter().map(|p| p.1).sum::<Real>() / n;
+    let (cov, var) = pilot.iter().fold((0.0, 0.0), |(cov, var), &(y, c)| {
+        (
+            cov + (y - mean_y) * (c - mean_c),
+            var + (c - mean_c).powi(2),
+        )
+    });
+    let beta = if var > 0.0 { cov / var } else { 0.0 };
+
+    let mut stats = IncrementalStatistics::new();
+    for _ in 0..n_paths {
+        let (y, c) = draw();
+        stats.add(y - beta * (c - control_value));
+    }
+    stats
+}
+
+/// Evolve `process` over a uniform grid using the normals `sign·dw[i]`.
+fn path_from_normals(
+    process: &dyn StochasticProcess1D,
+    dt: Real,
+    normals: &[Real],
+    sign: Real,
+) -> Path {
+    let mut times = Vec::with_capacity(normals.len() + 1);
+    let mut values = Vec::with_capacity(normals.len() + 1);
+
+    let mut x = process.x0();
+    times.push(0.0);
+    values.push(x);
+    for (i, &dw) in normals.iter().enumerate() {
+        let t = i as Real * dt;
+        x = process.evolve_1d(t, x, dt, sign * dw);
+        times.push(t + dt);
+        values.push(x);
+    }
+
+    Path { times, values }
 }
 
 /// Convenience function: Monte Carlo price of a European option.
@@ -393,6 +698,179 @@ mod tests {
         );
     }
 
+    #[test]
+    fn parallel_simulation_matches_serial_mean() {
+        let process = test_process();
+        let pricer = EuropeanPathPricer::new(|s: Real| (s - 100.0).max(0.0), (-0.05_f64).exp());
+        let model = MonteCarloModel::new(&process, 1.0, 10, 42);
+        let serial = model.simulate(&pricer, 20_000);
+
+        // One thread draws exactly the serial paths.
+        let single = model.simulate_parallel(&pricer, 20_000, 1);
+        assert_eq!(single.samples(), 20_000);
+        assert!((single.mean().unwrap() - serial.mean().unwrap()).abs() < 1e-10);
+
+        for n_threads in [2, 3, 8] {
+            let parallel = model.simulate_parallel(&pricer, 20_000, n_threads);
+            assert_eq!(parallel.samples(), 20_000);
+            let (mean, error) = (parallel.mean().unwrap(), parallel.error_estimate().unwrap());
+            let noise = 3.0 * (error * error + serial.error_estimate().unwrap().powi(2)).sqrt();
+            assert!(
+                (mean - serial.mean().unwrap()).abs() < noise,
+                "{n_threads} threads: {mean} vs {}",
+                serial.mean().unwrap()
+            );
+            // Reproducible for a fixed thread count.
+            let again = model.simulate_parallel(&pricer, 20_000, n_threads);
+            assert_eq!(again.mean(), parallel.mean());
+        }
+    }
+
+    #[test]
+    fn sequence_simulation_reproduces_pseudo_random_model() {
+        use ql_math::random_numbers::RandomSequenceGenerator;
+
+        let process = test_process();
+        let pricer = EuropeanPathPricer::new(|s: Real| (s - 100.0).max(0.0), (-0.05_f64).exp());
+        let model = MonteCarloModel::new(&process, 1.0, 10, 42);
+        let plain = model.simulate(&pricer, 1000);
+
+        // The same Mersenne Twister stream, consumed step by step
+        let mut rsg = RandomSequenceGenerator::new(10, 42);
+        let seq = model.simulate_sequence(&mut rsg, &pricer, 1000, false, None);
+        assert!((plain.mean().unwrap() - seq.mean().unwrap()).abs() < 1e-10);
+
+        // A control variate equal to the pricer itself removes all variance
+        let mut rsg = RandomSequenceGenerator::new(10, 42);
+        let exact = model.simulate_sequence(&mut rsg, &pricer, 100, true, Some((&pricer, 10.0)));
+        assert!((exact.mean().unwrap() - 10.0).abs() < 1e-10);
+        assert!(exact.error_estimate().unwrap() < 1e-10);
+    }
+
+    #[test]
+    fn geometric_control_reduces_asian_error() {
+        use ql_math::distributions::normal_cdf;
+
+        let process = test_process();
+        let (r, sigma, spot, strike): (Real, Real, Real, Real) = (0.05, 0.20, 100.0, 100.0);
+        let (maturity, steps): (Real, usize) = (1.0, 12);
+        let discount = (-r * maturity).exp();
+        let payoff = move |avg: Real| (avg - strike).max(0.0);
+
+        // Closed form for the discrete geometric average over t_i = i·T/n,
+        // i = 1..n: ln G is normal with the moments below.
+        let n = steps as Real;
+        let dt = maturity / n;
+        let mean_time = dt * (n + 1.0) / 2.0;
+        let variance = sigma * sigma * dt * (n + 1.0) * (2.0 * n + 1.0) / (6.0 * n);
+        let log_mean = spot.ln() + (r - 0.5 * sigma * sigma) * mean_time;
+        let d2 = (log_mean - strike.ln()) / variance.sqrt();
+        let d1 = d2 + variance.sqrt();
+        let geometric = discount
+            * ((log_mean + 0.5 * variance).exp() * normal_cdf(d1) - strike * normal_cdf(d2));
+
+        let model = MonteCarloModel::new(&process, maturity, steps, 7);
+        let arithmetic = AsianArithmeticPathPricer::new(payoff, discount);
+        let control = AsianGeometricPathPricer::new(payoff, discount);
+
+        // The control itself is unbiased against its closed form.
+        let check = model.simulate(&control, 20_000);
+        assert!(
+            (check.mean().unwrap() - geometric).abs() < 3.0 * check.error_estimate().unwrap(),
+            "geometric MC {} vs analytic {geometric}",
+            check.mean().unwrap()
+        );
+
+        let plain = model.simulate(&arithmetic, 20_000);
+        let controlled = model.simulate_with_control(&arithmetic, &control, geometric, 20_000);
+        let (plain_err, cv_err) = (
+            plain.error_estimate().unwrap(),
+            controlled.error_estimate().unwrap(),
+        );
+        assert!(
+            cv_err < plain_err / 3.0,
+            "control-variate error {cv_err:.2e} vs plain {plain_err:.2e}"
+        );
+        let (plain_mean, cv_mean) = (plain.mean().unwrap(), controlled.mean().unwrap());
+        assert!((plain_mean - cv_mean).abs() < 3.0 * plain_err);
+        assert!(cv_mean > geometric);
+
+        // Half as many antithetic pairs draw the same number of paths.
+        let combined = model.simulate_antithetic_with_control(
+            &arithmetic as &dyn PathPricer,
+            &control,
+            geometric,
+            10_000,
+        );
+        let combined_err = combined.error_estimate().unwrap();
+        assert_eq!(combined.samples(), 10_000);
+        assert!(
+            combined_err < plain_err / 3.0,
+            "antithetic control-variate error {combined_err:.2e} vs plain {plain_err:.2e}"
+        );
+        assert!((combined.mean().unwrap() - cv_mean).abs() < 3.0 * cv_err + 3.0 * combined_err);
+    }
+
+    /// Arithmetic average of the path values at the given grid indices.
+    struct FixingAsianPricer {
+        fixings: Vec<usize>,
+        strike: Real,
+        discount: Real,
+    }
+
+    impl PathPricer for FixingAsianPricer {
+        fn value(&self, path: &Path) -> Real {
+            let avg = self.fixings.iter().map(|&i| path.values[i]).sum::<Real>()
+                / self.fixings.len() as Real;
+            (avg - self.strike).max(0.0) * self.discount
+        }
+    }
+
+    #[test]
+    fn time_grid_path_generator_samples_fixing_times() {
+        let process = test_process();
+        let fixing_times = [0.1, 0.35, 0.6, 0.85, 1.0];
+        let price = |min_steps: usize, seed: u64| {
+            let grid = TimeGrid::from_times(&fixing_times, min_steps);
+            let fixings: Vec<usize> = fixing_times
+                .iter()
+                .map(|&t| grid.index(t).expect("fixing time on grid"))
+                .collect();
+            let mut gen = PathGenerator::with_time_grid(&process, &grid, seed);
+            let path = gen.next_path();
+            assert_eq!(path.times, grid.times());
+            for (&i, &t) in fixings.iter().zip(&fixing_times) {
+                assert_eq!(path.times[i], t);
+            }
+
+            let pricer = FixingAsianPricer {
+                fixings,
+                strike: 100.0,
+                discount: (-0.05_f64).exp(),
+            };
+            let mut stats = IncrementalStatistics::new();
+            for _ in 0..20_000 {
+                stats.add(pricer.value(&gen.next_path()));
+            }
+            (
+                grid.steps(),
+                stats.mean().unwrap(),
+                stats.error_estimate().unwrap(),
+            )
+        };
+
+        let (coarse_steps, coarse, coarse_err) = price(0, 1);
+        let (fine_steps, fine, fine_err) = price(50, 2);
+        assert_eq!(coarse_steps, 5);
+        assert!(fine_steps > 50);
+        // Exact GBM steps: filler points change the paths but not the law
+        // of the fixings.
+        assert!(
+            (coarse - fine).abs() < 3.0 * (coarse_err.hypot(fine_err)),
+            "coarse {coarse:.4} ± {coarse_err:.4}, fine {fine:.4} ± {fine_err:.4}"
+        );
+    }
+
     #[test]
     fn path_generator_produces_positive_gbm() {
         let process = test_process();
@@ -420,4 +898,75 @@ mod tests {
         // Asian call is cheaper than vanilla call (~10.45), typically 5-8
         assert!(price > 2.0 && price < 12.0, "Asian arith call = {price:.2}");
     }
+
+    #[test]
+    fn longstaff_schwartz_american_put() {
+        use ql_instruments::OptionType;
+        use ql_pricingengines::black_scholes_merton;
+        use ql_termstructures::YieldTermStructure;
+
+        let process = test_process();
+        let ref_date = Date::from_ymd(2025, 1, 15).unwrap();
+        let curve: Arc<dyn YieldTermStructure> =
+            Arc::new(FlatForward::continuous(ref_date, 0.05, Actual365Fixed));
+        let strike = 100.0;
+        let steps = 50;
+        let exercise_times: Vec<Real> = (1..=steps).map(|i| i as Real / steps as Real).collect();
+        let mut pricer =
+            LongstaffSchwartzPathPricer::new(exercise_times, move |s| (strike - s).max(0.0), curve)
+                .unwrap();
+        assert!(!pricer.is_calibrated());
+
+        let model = MonteCarloModel::new(&process, 1.0, steps, 7);
+        assert!(model.simulate_american(&mut pricer, 0, 10).is_err());
+        assert!(!pricer.is_calibrated());
+        let stats = model
+            .simulate_american(&mut pricer, 20_000, 50_000)
+            .unwrap();
+        assert!(pricer.is_calibrated());
+        let american = stats.mean().unwrap();
+        let error = stats.error_estimate().unwrap();
+
+        let european = black_scholes_merton(OptionType::Put, 100.0, strike, 0.05, 0.0, 0.20, 1.0).0;
+        // Early exercise adds a premium: the American value is about 6.09…
+        assert!(
+            american > european + 3.0 * error,
+            "American {american:.4} ± {error:.4} vs European {european:.4}"
+        );
+        // …but at most the interest earned by receiving the strike early.
+        let bound = european + strike * (1.0 - (-0.05_f64).exp());
+        assert!(
+            american < bound,
+            "American {american:.4} vs bound {bound:.4}"
+        );
+        assert!((american - 6.09).abs() < 0.15, "American {american:.4}");
+    }
+
+    #[test]
+    fn longstaff_schwartz_single_exercise_is_european() {
+        use ql_termstructures::YieldTermStructure;
+
+        let process = test_process();
+        let ref_date = Date::from_ymd(2025, 1, 15).unwrap();
+        let curve: Arc<dyn YieldTermStructure> =
+            Arc::new(FlatForward::continuous(ref_date, 0.05, Actual365Fixed));
+        let payoff = |s: Real| (100.0 - s).max(0.0);
+        let mut pricer =
+            LongstaffSchwartzPathPricer::new(vec![1.0], payoff, curve.clone()).unwrap();
+        let model = MonteCarloModel::new(&process, 1.0, 10, 7);
+        let american = model.simulate_american(&mut pricer, 100, 1_000).unwrap();
+        // Nothing to regress, but the pricer has still been calibrated.
+        assert!(pricer.is_calibrated());
+
+        let european = EuropeanPathPricer::new(payoff, (-0.05_f64).exp());
+        let mut gen = PathGenerator::new(&process, 1.0, 10, 7);
+        let mut stats = IncrementalStatistics::new();
+        for path in (0..1_100).map(|_| gen.next_path()).skip(100) {
+            stats.add(european.value(&path));
+        }
+        assert!((american.mean().unwrap() - stats.mean().unwrap()).abs() < 1e-12);
+
+        assert!(LongstaffSchwartzPathPricer::new(vec![], payoff, curve.clone()).is_err());
+        assert!(LongstaffSchwartzPathPricer::new(vec![1.0, 0.5], payoff, curve).is_err());
+    }
 }
@@ -0,0 +1,402 @@
+//! Sobol low-discrepancy path generation with Brownian-bridge construction
+//! (translates `ql/models/marketmodels/browniangenerators/sobolbrowniangenerator.hpp`).
+//!
+//! A path of a process with `F` factors over `N` time steps consumes a
+//! single `F·N`-dimensional Sobol point. Dimensions are allocated so that
+//! bridge step `k` of factor `f` uses coordinate `k·F + f`: the first
+//! coordinates — the best-distributed ones — drive the longest-span bridge
+//! increments of every factor, instead of all being spent on the first
+//! factor.
+//!
+//! [`BrownianBridgePathGenerator`] is the single-factor counterpart,
+//! producing [`Path`]s of a `StochasticProcess1D`.
+
+use super::multi_path::{uniform_grid, MultiPath};
+use super::Path;
+use crate::lattice::TimeGrid;
+use ql_core::Real;
+use ql_math::distributions::normal_cdf_inverse;
+use ql_math::random_numbers::brownian_bridge::BrownianBridge;
+use ql_math::random_numbers::sobol::SobolRsg;
+use ql_math::Array;
+use ql_processes::{StochasticProcess, StochasticProcess1D};
+
+/// Generates sample paths of a multi-dimensional process from Sobol points
+/// routed through a Brownian bridge, one bridge per factor.
+///
+/// Corresponds to `QuantLib::SobolBrownianGenerator` (with `Steps`
+/// ordering) combined with `QuantLib::MultiPathGenerator`.
+pub struct GaussianSobolPathGenerator<'a> {
+    process: &'a dyn StochasticProcess,
+    times: Vec<Real>,
+    sqrt_dt: Vec<Real>,
+    bridge: BrownianBridge,
+    rsg: SobolRsg,
+    factors: usize,
+}
+
+impl<'a> GaussianSobolPathGenerator<'a> {
+    /// Create a generator on a uniform grid of `steps` steps up to `maturity`,
+    /// skipping the first `skip` Sobol points.
+    pub fn new(
+        process: &'a dyn StochasticProcess,
+        maturity: Real,
+        steps: usize,
+        skip: u64,
+    ) -> Self {
+        Self::with_times(process, &uniform_grid(maturity, steps), skip)
+    }
+
+    /// Create a generator on an arbitrary time grid.
+    ///
+    /// `times` must start at 0 and be strictly increasing.
+    pub fn with_times(process: &'a dyn StochasticProcess, times: &[Real], skip: u64) -> Self {
+        assert!(times.len() >= 2, "need at least 2 time points");
+        assert!(times[0] == 0.0, "time grid must start at 0");
+        assert!(
+            times.windows(2).all(|w| w[1] > w[0]),
+            "time grid must be strictly increasing"
+        );
+        let factors = process.factors();
+        let steps = times.len() - 1;
+        Self {
+            process,
+            times: times.to_vec(),
+            sqrt_dt: times.windows(2).map(|w| (w[1] - w[0]).sqrt()).collect(),
+            bridge: BrownianBridge::with_times(times),
+            rsg: SobolRsg::new(factors * steps, skip),
+            factors,
+        }
+    }
+
+    /// Dimension of the underlying Sobol sequence (`factors × steps`).
+    pub fn dimension(&self) -> usize {
+        self.rsg.dimension()
+    }
+
+    /// The time grid (including t=0).
+    pub fn times(&self) -> &[Real] {
+        &self.times
+    }
+
+    /// Draw the next Sobol point and return the standardized Brownian
+    /// increments, indexed as `increments[step][factor]`.
+    pub fn next_increments(&mut self) -> Vec<Array> {
+        let steps = self.bridge.size();
+        let point = self.rsg.next_sequence();
+
+        let mut increments = vec![Array::zeros(self.factors); steps];
+        let mut variates = vec![0.0; steps];
+        let mut w = vec![0.0; steps];
+        for f in 0..self.factors {
+            for (k, z) in variates.iter_mut().enumerate() {
+                let u = point[k * self.factors + f].clamp(Real::MIN_POSITIVE, 1.0 - Real::EPSILON);
+                *z = normal_cdf_inverse(u);
+            }
+            self.bridge.transform(&variates, &mut w);
+            let mut previous = 0.0;
+            for (k, inc) in increments.iter_mut().enumerate() {
+                inc[f] = (w[k] - previous) / self.sqrt_dt[k];
+                previous = w[k];
+            }
+        }
+        increments
+    }
+
+    /// Generate one sample path.
+    pub fn next_path(&mut self) -> MultiPath {
+        let increments = self.next_increments();
+        let mut x = self.process.initial_values();
+        let mut path = MultiPath::start(&self.times, &x);
+
+        for (k, dw) in increments.iter().enumerate() {
+            let t = self.times[k];
+            x = self.process.evolve(t, &x, self.times[k + 1] - t, dw);
+            path.push(&x);
+        }
+
+        path
+    }
+}
+
+// ─── BrownianBridgePathGenerator ──────────────────────────────────────────────
+
+/// Generates sample paths of a 1-D process from Sobol points routed through
+/// a Brownian bridge.
+///
+/// Coordinate 0 of each point fixes the terminal Brownian value, coordinate
+/// 1 the mid-point, and so on, so the best-distributed Sobol dimensions
+/// carry most of the path variance. With a single step the bridge is just
+/// one normal draw.
+///
+/// Corresponds to `QuantLib::PathGenerator<SobolBrownianBridgeRsg>`.
+pub struct BrownianBridgePathGenerator<'a> {
+    process: &'a dyn StochasticProcess1D,
+    grid: TimeGrid,
+    sqrt_dt: Vec<Real>,
+    bridge: BrownianBridge,
+    rsg: SobolRsg,
+}
+
+impl<'a> BrownianBridgePathGenerator<'a> {
+    /// Create a generator on a uniform grid of `steps` steps up to `maturity`,
+    /// skipping the first `skip` Sobol points.
+    pub fn new(
+        process: &'a dyn StochasticProcess1D,
+        maturity: Real,
+        steps: usize,
+        skip: u64,
+    ) -> Self {
+        Self::with_time_grid(process, &TimeGrid::uniform(maturity, steps), skip)
+    }
+
+    /// Create a generator on an arbitrary time grid.
+    pub fn with_time_grid(
+        process: &'a dyn StochasticProcess1D,
+        grid: &TimeGrid,
+        skip: u64,
+    ) -> Self {
+        Self {
+            process,
+            grid: grid.clone(),
+            sqrt_dt: (0..grid.steps()).map(|i| grid.dt(i).sqrt()).collect(),
+            bridge: BrownianBridge::with_times(grid.times()),
+            rsg: SobolRsg::new(grid.steps(), skip),
+        }
+    }
+
+    /// Dimension of the underlying Sobol sequence (one per step).
+    pub fn dimension(&self) -> usize {
+        self.rsg.dimension()
+    }
+
+    /// The time grid paths are generated on.
+    pub fn time_grid(&self) -> &TimeGrid {
+        &self.grid
+    }
+
+    /// Generate one sample path.
+    pub fn next_path(&mut self) -> Path {
+        let variates: Vec<Real> = self
+            .rsg
+            .next_sequence()
+            .into_iter()
+            .map(|u| normal_cdf_inverse(u.clamp(Real::MIN_POSITIVE, 1.0 - Real::EPSILON)))
+            .collect();
+        let mut w = vec![0.0; variates.len()];
+        self.bridge.transform(&variates, &mut w);
+
+        let mut x = self.process.x0();
+        let mut values = Vec::with_capacity(self.grid.size());
+        values.push(x);
+        let mut previous = 0.0;
+        for (i, &wi) in w.iter().enumerate() {
+            let dw = (wi - previous) / self.sqrt_dt[i];
+            previous = wi;
+            x = self
+                .process
+                .evolve_1d(self.grid.time(i), x, self.grid.dt(i), dw);
+            values.push(x);
+        }
+
+        Path {
+            times: self.grid.times().to_vec(),
+            values,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::monte_carlo::multi_path::MultiPathGenerator;
+    use crate::monte_carlo::PathGenerator;
+    use ql_math::statistics::IncrementalStatistics;
+    use ql_models::G2Model;
+    use ql_processes::{G2Process, GeneralizedBlackScholesProcess};
+    use ql_termstructures::{BlackConstantVol, FlatForward, YieldTermStructure};
+    use ql_time::{Actual365Fixed, Date};
+    use std::sync::Arc;
+
+    const A: Real = 0.1;
+    const SIGMA: Real = 0.01;
+    const B: Real = 0.2;
+    const ETA: Real = 0.015;
+    const RHO: Real = -0.5;
+    const EXPIRY: Real = 1.0;
+    const PAY_TIMES: [Real; 4] = [2.0, 3.0, 4.0, 5.0];
+    const STRIKE: Real = 0.05;
+
+    fn curve() -> Arc<dyn YieldTermStructure> {
+        let ref_date = Date::from_ymd(2025, 1, 2).unwrap();
+        Arc::new(FlatForward::continuous(ref_date, 0.05, Actual365Fixed))
+    }
+
+    /// Discounted payer-swaption payoff on a G2 path (trapezoidal ∫(x+y)).
+    fn swaption_value(model: &G2Model, path: &MultiPath, deterministic_df: Real) -> Real {
+        let (x, y) = (&path.values[0], &path.values[1]);
+        let n = path.steps();
+        let integral: Real = (0..n)
+            .map(|i| {
+                0.5 * (x[i] + y[i] + x[i + 1] + y[i + 1]) * (path.times[i + 1] - path.times[i])
+            })
+            .sum();
+        let mut fixed_leg = 0.0;
+        let mut previous = EXPIRY;
+        for (i, &t) in PAY_TIMES.iter().enumerate() {
+            let c = STRIKE * (t - previous) + if i == PAY_TIMES.len() - 1 { 1.0 } else { 0.0 };
+            fixed_leg += c * model.discount_bond_factors(EXPIRY, t, x[n], y[n]);
+            previous = t;
+        }
+        deterministic_df * (-integral).exp() * (1.0 - fixed_leg).max(0.0)
+    }
+
+    #[test]
+    fn sobol_generator_dimension_and_increments() {
+        let process = G2Process::new(A, SIGMA, B, ETA, RHO, curve());
+        let mut gen = GaussianSobolPathGenerator::new(&process, 1.0, 8, 0);
+        assert_eq!(gen.dimension(), 16);
+        let inc = gen.next_increments();
+        assert_eq!(inc.len(), 8);
+        // The first Sobol point is (½, …, ½): all variates are zero.
+        for dw in &inc {
+            assert_eq!(dw.size(), 2);
+            assert!(dw.iter().all(|v| v.abs() < 1e-12));
+        }
+    }
+
+    #[test]
+    fn sobol_generator_increments_are_standard_normal() {
+        let process = G2Process::new(A, SIGMA, B, ETA, RHO, curve());
+        let mut gen = GaussianSobolPathGenerator::new(&process, 1.0, 4, 0);
+        let n = 4096;
+        let mut sum = [[0.0; 2]; 4];
+        let mut sum_sq = [[0.0; 2]; 4];
+        for _ in 0..n {
+            for (k, dw) in gen.next_increments().iter().enumerate() {
+                for f in 0..2 {
+                    sum[k][f] += dw[f];
+                    sum_sq[k][f] += dw[f] * dw[f];
+                }
+            }
+        }
+        for k in 0..4 {
+            for f in 0..2 {
+                let mean = sum[k][f] / n as Real;
+                let var = sum_sq[k][f] / n as Real - mean * mean;
+                assert!(mean.abs() < 0.01, "step {k} factor {f}: mean {mean}");
+                assert!((var - 1.0).abs() < 0.02, "step {k} factor {f}: var {var}");
+            }
+        }
+    }
+
+    #[test]
+    fn g2_swaption_sobol_beats_pseudo_random() {
+        let ts = curve();
+        let process = G2Process::new(A, SIGMA, B, ETA, RHO, ts.clone());
+        let model = G2Model::new(ts.clone(), A, SIGMA, B, ETA, RHO);
+        let analytic = model
+            .swaption(true, 1.0, EXPIRY, &PAY_TIMES, STRIKE, 8.0, 1000)
+            .unwrap();
+
+        // exp(−∫₀ᵀ φ) = P(0,T)·exp(−½V(0,T))
+        let deterministic_df = ts.discount(EXPIRY) * (-0.5 * v_0t(EXPIRY)).exp();
+
+        let steps = 8;
+        let n_paths = 4096;
+
+        let mut sobol = GaussianSobolPathGenerator::new(&process, EXPIRY, steps, 0);
+        let mut stats = IncrementalStatistics::new();
+        for _ in 0..n_paths {
+            stats.add(swaption_value(&model, &sobol.next_path(), deterministic_df));
+        }
+        let sobol_price = stats.mean().unwrap();
+        let sobol_error = (sobol_price - analytic).abs();
+
+        let seeds = 10;
+        let mut sq_error = 0.0;
+        for seed in 0..seeds {
+            let mut gen = MultiPathGenerator::new(&process, EXPIRY, steps, 1000 + seed);
+            let mut stats = IncrementalStatistics::new();
+            for _ in 0..n_paths {
+                stats.add(swaption_value(&model, &gen.next_path(), deterministic_df));
+            }
+            let e = stats.mean().unwrap() - analytic;
+            sq_error += e * e;
+        }
+        let pseudo_rmse = (sq_error / seeds as Real).sqrt();
+
+        assert!(
+            sobol_error / analytic < 0.02,
+            "Sobol {sobol_price:.6} vs analytic {analytic:.6}"
+        );
+        assert!(
+            sobol_error < pseudo_rmse,
+            "Sobol error {sobol_error:e} not below pseudo-random RMSE {pseudo_rmse:e}"
+        );
+    }
+
+    /// `V(0,T)` of the G2 model with the test parameters.
+    fn v_0t(t: Real) -> Real {
+        let (ea, eb) = ((-A * t).exp(), (-B * t).exp());
+        let (cx, cy) = (SIGMA / A, ETA / B);
+        cx * cx * (t + (2.0 * ea - 0.5 * ea * ea - 1.5) / A)
+            + cy * cy * (t + (2.0 * eb - 0.5 * eb * eb - 1.5) / B)
+            + 2.0
+                * RHO
+                * cx
+                * cy
+                * (t + (ea - 1.0) / A + (eb - 1.0) / B - (ea * eb - 1.0) / (A + B))
+    }
+
+    fn bs_process() -> GeneralizedBlackScholesProcess {
+        let ref_date = Date::from_ymd(2025, 1, 2).unwrap();
+        let rf = Arc::new(FlatForward::continuous(ref_date, 0.05, Actual365Fixed));
+        let div = Arc::new(FlatForward::continuous(ref_date, 0.0, Actual365Fixed));
+        let vol = Arc::new(BlackConstantVol::new(ref_date, 0.20, Actual365Fixed));
+        GeneralizedBlackScholesProcess::new(100.0, rf, div, vol)
+    }
+
+    #[test]
+    fn brownian_bridge_call_beats_plain_monte_carlo() {
+        // Black-Scholes price of the 1y ATM call with r = 5%, σ = 20%.
+        let bs = 10.450_583_572_185_565;
+        let process = bs_process();
+        let discount = (-0.05_f64).exp();
+        let n = 4096;
+
+        let mut bridge = BrownianBridgePathGenerator::new(&process, 1.0, 16, 0);
+        assert_eq!(bridge.dimension(), 16);
+        let mut plain = PathGenerator::new(&process, 1.0, 16, 42);
+        let mut bridge_stats = IncrementalStatistics::new();
+        let mut plain_stats = IncrementalStatistics::new();
+        for _ in 0..n {
+            let path = bridge.next_path();
+            assert_eq!(path.len(), 17);
+            bridge_stats.add(discount * (path.back() - 100.0).max(0.0));
+            plain_stats.add(discount * (plain.next_path().back() - 100.0).max(0.0));
+        }
+        let plain_error = plain_stats.error_estimate().unwrap();
+        let bridge_error = (bridge_stats.mean().unwrap() - bs).abs();
+        assert!(
+            bridge_error < 0.1 * plain_error,
+            "bridge error {bridge_error:e} vs plain standard error {plain_error:e}"
+        );
+    }
+
+    #[test]
+    fn brownian_bridge_single_step_is_one_normal_draw() {
+        let process = bs_process();
+        let mut gen = BrownianBridgePathGenerator::new(&process, 1.0, 1, 0);
+        let mut reference = SobolRsg::new(1, 0);
+        for _ in 0..8 {
+            let path = gen.next_path();
+            let z = normal_cdf_inverse(
+                reference.next_sequence()[0].clamp(Real::MIN_POSITIVE, 1.0 - Real::EPSILON),
+            );
+            let expected = process.evolve_1d(0.0, 100.0, 1.0, z);
+            assert_eq!(path.steps(), 1);
+            assert!((path.back() - expected).abs() < 1e-12);
+        }
+    }
+}
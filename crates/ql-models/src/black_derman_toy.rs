@@ -0,0 +1,460 @@
+//! Black-Derman-Toy short-rate model.
+//!
+//! Translates `ql/models/shortrate/onefactormodels/blackdermantoy.hpp`.
+//!
+//! ```text
+//! d ln(r) = (θ(t) + σ'(t)/σ(t)·ln(r)) dt + σ(t) dW
+//! ```
+//!
+//! The model is defined through its recombining binomial tree: at step `i`
+//! the short rate on node `j` is `r(i,j) = U(i)·exp(σ(tᵢ)·(2j − i)·√Δt)`,
+//! where the median rate `U(i)` is solved numerically so that the tree
+//! reprices the initial zero-coupon bond maturing at `tᵢ₊₁`.
+
+use crate::calibrated_model::{CalibratedModel, Parameter, PositiveConstraint};
+use crate::short_rate_model::ShortRateModel;
+use ql_core::{ensure, errors::Result, Real, Time};
+use ql_math::solvers1d::brent;
+use ql_termstructures::YieldTermStructure;
+use std::sync::Arc;
+
+/// Black-Derman-Toy short-rate model.
+///
+/// The volatility of the log short rate is either flat (`sigma`) or
+/// piecewise flat, see [`BlackDermanToy::with_volatility_curve`].
+///
+/// Corresponds to `QuantLib::BlackDermanToy`.
+#[derive(Debug)]
+pub struct BlackDermanToy {
+    /// Volatility of ln(r) (used when no volatility curve is set).
+    pub sigma: Real,
+    /// Initial yield curve.
+    term_structure: Arc<dyn YieldTermStructure>,
+    /// Right end-points of the piecewise-flat volatility intervals.
+    vol_times: Vec<Time>,
+    /// Volatility on each interval; the last value is extended flat.
+    vol_values: Vec<Real>,
+    params: Vec<Parameter>,
+}
+
+impl BlackDermanToy {
+    /// Create a new Black-Derman-Toy model with a flat volatility.
+    pub fn new(term_structure: Arc<dyn YieldTermStructure>, sigma: Real) -> Self {
+        Self {
+            sigma,
+            term_structure,
+            vol_times: Vec::new(),
+            vol_values: Vec::new(),
+            params: vec![Parameter::new(vec![sigma], PositiveConstraint)],
+        }
+    }
+
+    /// Use a piecewise-flat volatility term structure.
+    ///
+    /// `vols[k]` applies up to `times[k]`; the last volatility is extended
+    /// flat beyond the last time.
+    pub fn with_volatility_curve(mut self, times: Vec<Time>, vols: Vec<Real>) -> Result<Self> {
+        ensure!(!vols.is_empty(), "at least one volatility is required");
+        ensure!(
+            times.len() == vols.len(),
+            "volatility times and values must have the same length"
+        );
+        ensure!(
+            times.windows(2).all(|w| w[1] > w[0]),
+            "volatility times must be strictly increasing"
+        );
+        ensure!(
+            vols.iter().all(|&v| v > 0.0),
+            "volatilities must be positive"
+        );
+        self.params = vec![Parameter::new(vols.clone(), PositiveConstraint)];
+        self.vol_times = times;
+        self.vol_values = vols;
+        Ok(self)
+    }
+
+    /// Volatility of ln(r) at time `t`.
+    pub fn volatility(&self, t: Time) -> Real {
+        if self.vol_values.is_empty() {
+            return self.sigma;
+        }
+        let k = self.vol_times.partition_point(|&end| end <= t);
+        self.vol_values[k.min(self.vol_values.len() - 1)]
+    }
+
+    /// Build a binomial tree of `steps` uniform steps up to `maturity`,
+    /// calibrated to the initial term structure.
+    pub fn tree(&self, maturity: Time, steps: usize) -> Result<BdtLattice> {
+        ensure!(maturity > 0.0, "maturity must be positive");
+        ensure!(steps > 0, "at least one time step is required");
+
+        let dt = maturity / steps as Real;
+        let sqrt_dt = dt.sqrt();
+        let mut medians = Vec::with_capacity(steps);
+        let mut spreads = Vec::with_capacity(steps);
+        let mut arrow_debreu = vec![vec![1.0]];
+
+        for i in 0..steps {
+            let q = &arrow_debreu[i];
+            // Multiplicative spacing between adjacent nodes at this step.
+            let spread = (2.0 * self.volatility(i as Real * dt) * sqrt_dt).exp();
+            let target = self.term_structure.discount((i + 1) as Real * dt);
+            let lowest = spread.powf(-0.5 * i as Real);
+            let price = |u: Real| -> Real {
+                let mut r = u * lowest;
+                let mut sum = 0.0;
+                for &qj in q {
+                    sum += qj * (-r * dt).exp();
+                    r *= spread;
+                }
+                sum
+            };
+            // Bond prices decrease with the median rate, so the root is bracketed
+            // as long as the target lies between P(U=lo) and P(U=hi).
+            let median = brent(|u| price(u) - target, -1.0, 10.0, 1e-15)?;
+
+            let mut next = vec![0.0; i + 2];
+            let mut r = median * lowest;
+            for (j, &qj) in q.iter().enumerate() {
+                let half = 0.5 * qj * (-r * dt).exp();
+                next[j] += half;
+                next[j + 1] += half;
+                r *= spread;
+            }
+            medians.push(median);
+            spreads.push(spread);
+            arrow_debreu.push(next);
+        }
+
+        Ok(BdtLattice {
+            dt,
+            medians,
+            spreads,
+            arrow_debreu,
+        })
+    }
+}
+
+impl CalibratedModel for BlackDermanToy {
+    fn params(&self) -> &[Parameter] {
+        &self.params
+    }
+
+    fn set_params(&mut self, values: &[Real]) {
+        if values.is_empty() {
+            return;
+        }
+        if self.vol_values.is_empty() {
+            self.sigma = values[0];
+            self.params[0].set_values(vec![values[0]]);
+        } else if values.len() == self.vol_values.len() {
+            self.vol_values = values.to_vec();
+            self.params[0].set_values(values.to_vec());
+        }
+    }
+}
+
+impl ShortRateModel for BlackDermanToy {
+    /// Bond price on a tree, as the model has no closed form.
+    ///
+    /// At `t = 0` the calibrated tree reprices the curve, so this is
+    /// `P(0, T)` whatever `rate`.  Otherwise a tree is built with
+    /// `⌈t·TREE_STEPS_PER_YEAR⌉` steps up to `t` and continued with the same
+    /// step past `T`; the node values of bonds maturing at the two steps
+    /// around `T` are interpolated log-linearly in maturity, and then
+    /// linearly in `ln r` between the nodes at `t` (held flat beyond the
+    /// outermost nodes).
+    ///
+    /// # Panics
+    /// Panics if the tree cannot be calibrated to the term structure.
+    fn discount_bond(&self, t: Time, big_t: Time, rate: Real) -> Real {
+        if big_t <= t {
+            return 1.0;
+        }
+        if t <= 0.0 {
+            return self.term_structure.discount(big_t);
+        }
+        let i = ((t * TREE_STEPS_PER_YEAR).ceil() as usize).max(1);
+        let dt = t / i as Real;
+        let m = ((big_t / dt).floor() as usize).max(i);
+        let tree = self
+            .tree((m + 1) as Real * dt, m + 1)
+            .expect("Black-Derman-Toy tree calibration failed");
+
+        let w = (big_t / dt - m as Real).clamp(0.0, 1.0);
+        let bonds: Vec<Real> = tree
+            .discount_bond_values(i, m)
+            .iter()
+            .zip(tree.discount_bond_values(i, m + 1))
+            .map(|(&before, after)| before.powf(1.0 - w) * after.powf(w))
+            .collect();
+
+        // r(i, j) = U(i)·spreadᵢ^{j − i/2}, so j is affine in ln r.
+        let j = ((rate / tree.medians[i]).ln() / tree.spreads[i].ln() + 0.5 * i as Real)
+            .clamp(0.0, i as Real);
+        let lower = (j.floor() as usize).min(i - 1);
+        let frac = j - lower as Real;
+        bonds[lower] * (1.0 - frac) + bonds[lower + 1] * frac
+    }
+
+    fn term_structure(&self) -> &Arc<dyn YieldTermStructure> {
+        &self.term_structure
+    }
+}
+
+/// Tree resolution of [`BlackDermanToy`]'s `discount_bond`.
+const TREE_STEPS_PER_YEAR: Real = 50.0;
+
+// ─── BdtLattice ───────────────────────────────────────────────────────────────
+
+/// Recombining binomial short-rate tree of the Black-Derman-Toy model.
+///
+/// Step `i` has `i + 1` nodes; from node `j` the rate moves to nodes `j`
+/// and `j + 1` of step `i + 1` with probability ½ each. The rate on a node
+/// applies, continuously compounded, over the following `dt`.
+///
+/// Built by [`BlackDermanToy::tree`].
+#[derive(Debug, Clone)]
+pub struct BdtLattice {
+    dt: Time,
+    medians: Vec<Real>,
+    spreads: Vec<Real>,
+    arrow_debreu: Vec<Vec<Real>>,
+}
+
+impl BdtLattice {
+    /// Number of time steps.
+    pub fn steps(&self) -> usize {
+        self.medians.len()
+    }
+
+    /// Length of each time step.
+    pub fn dt(&self) -> Time {
+        self.dt
+    }
+
+    /// Calibrated median short rate `U(i)` at step `i`.
+    pub fn median_rate(&self, i: usize) -> Real {
+        self.medians[i]
+    }
+
+    /// Short rate on node `j` of step `i`.
+    pub fn rate(&self, i: usize, j: usize) -> Real {
+        assert!(j <= i, "node {j} does not exist at step {i}");
+        self.medians[i] * self.spreads[i].powf(j as Real - 0.5 * i as Real)
+    }
+
+    /// Arrow-Debreu price of node `j` at step `i` (for `i ≤ steps`).
+    pub fn arrow_debreu(&self, i: usize, j: usize) -> Real {
+        self.arrow_debreu[i][j]
+    }
+
+    /// Price at time 0 of a unit zero-coupon bond maturing at step
+    /// `maturity_step`, by backward induction.
+    pub fn zero_coupon_bond(&self, maturity_step: usize) -> Real {
+        self.discount_bond_values(0, maturity_step)[0]
+    }
+
+    /// Price at time 0 of a bond paying `cashflows[k]` at step `k + 1`.
+    pub fn bond(&self, cashflows: &[Real]) -> Real {
+        assert!(
+            cashflows.len() <= self.steps(),
+            "cash flows extend beyond the tree"
+        );
+        let mut values = vec![0.0; cashflows.len() + 1];
+        for i in (0..cashflows.len()).rev() {
+            let coupon = cashflows[i];
+            values = (0..=i)
+                .map(|j| {
+                    let continuation = 0.5 * (values[j] + values[j + 1]) + coupon;
+                    continuation * (-self.rate(i, j) * self.dt).exp()
+                })
+                .collect();
+        }
+        values[0]
+    }
+
+    /// Values on each node of step `from_step` of a unit zero-coupon bond
+    /// maturing at step `maturity_step`.
+    pub fn discount_bond_values(&self, from_step: usize, maturity_step: usize) -> Vec<Real> {
+        assert!(
+            from_step <= maturity_step && maturity_step <= self.steps(),
+            "invalid bond steps {from_step}..{maturity_step}"
+        );
+        let mut values = vec![1.0; maturity_step + 1];
+        for i in (from_step..maturity_step).rev() {
+            values = self.rollback_step(i, &values);
+        }
+        values
+    }
+
+    /// Price at time 0 of a caplet on the simply-compounded rate fixing at
+    /// step `reset_step` for the period ending at step `pay_step`.
+    ///
+    /// The payoff `τ·(L − K)⁺` at the payment date is valued at the reset
+    /// date as `(1 + Kτ)·(1/(1 + Kτ) − P(t_reset, t_pay))⁺`.
+    pub fn caplet(&self, strike: Real, reset_step: usize, pay_step: usize, nominal: Real) -> Real {
+        assert!(reset_step < pay_step, "payment must follow the reset");
+        let tau = (pay_step - reset_step) as Real * self.dt;
+        let k = 1.0 + strike * tau;
+        self.discount_bond_values(reset_step, pay_step)
+            .iter()
+            .zip(&self.arrow_debreu[reset_step])
+            .map(|(&p, &q)| q * nominal * (1.0 - k * p).max(0.0))
+            .sum()
+    }
+
+    /// Discount node values at step `i + 1` back to step `i`.
+    fn rollback_step(&self, i: usize, values: &[Real]) -> Vec<Real> {
+        (0..=i)
+            .map(|j| 0.5 * (values[j] + values[j + 1]) * (-self.rate(i, j) * self.dt).exp())
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use ql_math::distributions::normal_cdf;
+    use ql_termstructures::{FlatForward, InterpolatedZeroCurve, Linear};
+    use ql_time::{Actual365Fixed, Date};
+
+    fn flat_ts(rate: Real) -> Arc<dyn YieldTermStructure> {
+        let ref_date = Date::from_ymd(2025, 1, 2).unwrap();
+        Arc::new(FlatForward::continuous(ref_date, rate, Actual365Fixed))
+    }
+
+    fn upward_ts() -> Arc<dyn YieldTermStructure> {
+        let ref_date = Date::from_ymd(2025, 1, 2).unwrap();
+        let dates = [
+            ref_date,
+            ref_date + 365,
+            ref_date + 2 * 365,
+            ref_date + 5 * 365,
+            ref_date + 10 * 365,
+        ];
+        let rates = [0.02, 0.025, 0.03, 0.038, 0.045];
+        Arc::new(InterpolatedZeroCurve::new(&dates, &rates, Actual365Fixed, &Linear).unwrap())
+    }
+
+    #[test]
+    fn bdt_tree_reprices_zero_coupon_bonds() {
+        let ts = upward_ts();
+        let model = BlackDermanToy::new(ts.clone(), 0.2)
+            .with_volatility_curve(vec![2.0, 5.0, 10.0], vec![0.25, 0.2, 0.15])
+            .unwrap();
+        let steps = 40;
+        let tree = model.tree(10.0, steps).unwrap();
+        for i in 1..=steps {
+            let expected = ts.discount(i as Real * tree.dt());
+            let ad_sum: Real = (0..=i).map(|j| tree.arrow_debreu(i, j)).sum();
+            assert!(
+                (tree.zero_coupon_bond(i) - expected).abs() < 1e-8,
+                "step {i}: {} vs {expected}",
+                tree.zero_coupon_bond(i)
+            );
+            assert!((ad_sum - expected).abs() < 1e-8);
+        }
+    }
+
+    #[test]
+    fn bdt_rates_are_lognormally_spaced() {
+        let model = BlackDermanToy::new(flat_ts(0.05), 0.2);
+        let tree = model.tree(2.0, 8).unwrap();
+        let expected = (2.0 * 0.2 * tree.dt().sqrt()).exp();
+        for i in 1..tree.steps() {
+            for j in 0..i {
+                let ratio = tree.rate(i, j + 1) / tree.rate(i, j);
+                assert!((ratio - expected).abs() < 1e-12);
+            }
+        }
+        // Coupon bond = sum of discounted zero-coupon bonds.
+        let cashflows = [0.05, 0.0, 0.05, 0.0, 0.05, 0.0, 0.05, 1.0];
+        let by_zeros: Real = cashflows
+            .iter()
+            .enumerate()
+            .map(|(k, c)| c * tree.zero_coupon_bond(k + 1))
+            .sum();
+        assert!((tree.bond(&cashflows) - by_zeros).abs() < 1e-12);
+    }
+
+    #[test]
+    fn bdt_caplet_consistent_with_lognormal_rate() {
+        // The one-period forward is a monotone function of the lognormal
+        // short rate, so Black's caplet formula with the same volatility
+        // should be close to the tree price.
+        let ts = flat_ts(0.05);
+        let sigma = 0.2;
+        let model = BlackDermanToy::new(ts.clone(), sigma);
+        let steps = 80;
+        let tree = model.tree(4.25, steps).unwrap();
+        let dt = tree.dt();
+        let (reset, pay) = (76, 80);
+        let (t_reset, t_pay) = (reset as Real * dt, pay as Real * dt);
+        let tau = t_pay - t_reset;
+        let p_reset = ts.discount(t_reset);
+        let p_pay = ts.discount(t_pay);
+        let forward = (p_reset / p_pay - 1.0) / tau;
+
+        for strike in [0.04, forward, 0.06] {
+            let sd = sigma * t_reset.sqrt();
+            let d1 = ((forward / strike).ln() + 0.5 * sd * sd) / sd;
+            let black = p_pay * tau * (forward * normal_cdf(d1) - strike * normal_cdf(d1 - sd));
+            let price = tree.caplet(strike, reset, pay, 1.0);
+            assert!(
+                (price / black - 1.0).abs() < 0.05,
+                "K={strike}: tree {price:.6e} vs Black {black:.6e}"
+            );
+        }
+    }
+
+    #[test]
+    fn bdt_discount_bond_uses_the_tree() {
+        let ts = upward_ts();
+        let model = BlackDermanToy::new(ts.clone(), 0.2);
+        assert_eq!(model.discount_bond(0.0, 4.0, 0.9), ts.discount(4.0));
+        assert_eq!(model.discount_bond(2.0, 2.0, 0.03), 1.0);
+
+        // Averaged over the tree's nodes at t with their Arrow-Debreu
+        // prices, P(t, T) reprices the curve, on and off the tree's grid.
+        let (t, dt) = (2.0, 1.0 / TREE_STEPS_PER_YEAR);
+        let i = (t / dt).round() as usize;
+        let tree = model.tree(t + dt, i + 1).unwrap();
+        for big_t in [5.0, 5.013] {
+            let repriced: Real = (0..=i)
+                .map(|j| tree.arrow_debreu(i, j) * model.discount_bond(t, big_t, tree.rate(i, j)))
+                .sum();
+            assert!(
+                (repriced / ts.discount(big_t) - 1.0).abs() < 1e-6,
+                "T = {big_t}: {repriced} vs {}",
+                ts.discount(big_t)
+            );
+        }
+
+        // Bond prices fall as the short rate rises, unlike the curve ratio.
+        let low = model.discount_bond(t, 5.0, 0.02);
+        let high = model.discount_bond(t, 5.0, 0.06);
+        assert!(low > ts.discount(5.0) / ts.discount(t) && high < low);
+    }
+
+    #[test]
+    fn bdt_volatility_curve_and_params() {
+        let mut model = BlackDermanToy::new(flat_ts(0.05), 0.2);
+        assert_eq!(model.params().len(), 1);
+        model.set_params(&[0.3]);
+        assert!((model.sigma - 0.3).abs() < 1e-15);
+        assert!((model.volatility(7.0) - 0.3).abs() < 1e-15);
+
+        let mut model = model
+            .with_volatility_curve(vec![1.0, 3.0], vec![0.25, 0.15])
+            .unwrap();
+        assert!((model.volatility(0.5) - 0.25).abs() < 1e-15);
+        assert!((model.volatility(2.0) - 0.15).abs() < 1e-15);
+        assert!((model.volatility(9.0) - 0.15).abs() < 1e-15);
+        model.set_params(&[0.2, 0.1]);
+        assert!((model.volatility(2.0) - 0.1).abs() < 1e-15);
+        assert!(BlackDermanToy::new(flat_ts(0.05), 0.2)
+            .with_volatility_curve(vec![1.0], vec![0.2, 0.1])
+            .is_err());
+    }
+}
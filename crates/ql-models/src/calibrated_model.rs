@@ -2,7 +2,9 @@
 //!
 //! Translates `ql/models/parameter.hpp` and `ql/models/calibrationhelper.hpp`.
 
-use ql_core::Real;
+use crate::calibration_helper::{calibrate_model, CalibrationHelper, CalibrationReport};
+use ql_core::{errors::Result, Real};
+use ql_math::optimization::{EndCriteria, OptimizationMethod};
 use std::fmt;
 
 // ────────────────────────────────────────────────────────────────────────────
@@ -138,6 +140,30 @@ pub trait CalibratedModel: fmt::Debug + Send + Sync {
     /// Set model parameters from a flat vector of values
     /// (used by optimizers during calibration).
     fn set_params(&mut self, values: &[Real]);
+
+    /// Calibrate the model to `helpers`, minimising the weighted sum of
+    /// squared calibration errors `Σ wᵢ·errorᵢ²` with `method`, which may
+    /// be any [`OptimizationMethod`], while keeping each parameter within
+    /// its constraint, and leave the model at the optimum found.
+    ///
+    /// The report holds each helper's calibration error at the optimum.
+    ///
+    /// # Errors
+    /// Fails if no helper is given, if `weights` does not hold one finite,
+    /// non-negative weight per helper, or if the optimiser or a final
+    /// pricing fails.
+    fn calibrate(
+        &mut self,
+        helpers: &[&dyn CalibrationHelper<Self>],
+        method: &dyn OptimizationMethod,
+        weights: &[Real],
+        end_criteria: &EndCriteria,
+    ) -> Result<CalibrationReport>
+    where
+        Self: Sized,
+    {
+        calibrate_model(self, helpers, method, weights, end_criteria)
+    }
 }
 
 #[cfg(test)]
@@ -0,0 +1,458 @@
+//! Calibration helpers and weighted calibration baskets.
+//!
+//! Translates `ql/models/calibrationhelper.hpp`; the basket generalises the
+//! weight vector of `CalibratedModel::calibrate`.
+//!
+//! A [`CalibrationHelper`] pairs a market quote with its price under a
+//! model.  A [`CalibrationBasket`] collects helpers, weights their price
+//! errors by a [`CalibrationWeighting`] scheme, and drops helpers whose
+//! vega is too small to carry information about the model parameters;
+//! [`CalibratedModel::calibrate`] takes the helpers and weights as given.
+
+use crate::calibrated_model::CalibratedModel;
+use ql_core::{ensure, errors::Result, Real};
+use ql_math::optimization::{
+    Constraint, CostFunction, EndCriteria, OptimizationMethod, OptimizationResult,
+};
+use ql_math::Array;
+use std::cell::RefCell;
+
+/// A calibration instrument quoted in the market.
+///
+/// Corresponds to `QuantLib::BlackCalibrationHelper`.
+pub trait CalibrationHelper<M: ?Sized>: Send + Sync {
+    /// Market price of the instrument.
+    fn market_value(&self) -> Real;
+
+    /// Price of the instrument under `model`.
+    fn model_value(&self, model: &M) -> Result<Real>;
+
+    /// Sensitivity of the market price to the quoted volatility.
+    fn vega(&self) -> Real;
+
+    /// Error of the model price against the market, `model − market`.
+    ///
+    /// Corresponds to `QuantLib::CalibrationHelper::calibrationError`,
+    /// measured as a price error.
+    fn calibration_error(&self, model: &M) -> Result<Real> {
+        Ok(self.model_value(model)? - self.market_value())
+    }
+}
+
+/// The outcome of [`CalibratedModel::calibrate`].
+#[derive(Debug, Clone)]
+pub struct CalibrationReport {
+    /// The optimiser's result, on the flattened model parameters.
+    pub optimization: OptimizationResult,
+    /// The calibration error of each helper at the optimum, unweighted.
+    pub residuals: Vec<Real>,
+}
+
+impl CalibrationReport {
+    /// The largest absolute calibration error.
+    pub fn max_residual(&self) -> Real {
+        self.residuals.iter().fold(0.0, |m, r| m.max(r.abs()))
+    }
+}
+
+/// How the price errors of a basket are weighted in the calibration
+/// objective `Σ wᵢ·(modelᵢ − marketᵢ)²`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum CalibrationWeighting {
+    /// Every helper counts the same.
+    #[default]
+    Equal,
+    /// Weights proportional to vega, favouring liquid near-the-money
+    /// quotes.
+    Vega,
+    /// Weights `1/vega²`: a common uncertainty on the quoted volatilities
+    /// gives price quotes of variance proportional to `vega²`, so this
+    /// fits implied volatilities rather than prices.
+    InverseVariance,
+}
+
+/// A weighted collection of calibration helpers for a model `M`.
+pub struct CalibrationBasket<M: ?Sized> {
+    helpers: Vec<Box<dyn CalibrationHelper<M>>>,
+    weighting: CalibrationWeighting,
+    min_relative_vega: Real,
+}
+
+impl<M: ?Sized> std::fmt::Debug for CalibrationBasket<M> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("CalibrationBasket")
+            .field("helpers", &self.helpers.len())
+            .field("weighting", &self.weighting)
+            .field("min_relative_vega", &self.min_relative_vega)
+            .finish()
+    }
+}
+
+impl<M: ?Sized> Default for CalibrationBasket<M> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<M: ?Sized> CalibrationBasket<M> {
+    /// An empty, equally weighted basket.
+    pub fn new() -> Self {
+        Self {
+            helpers: Vec::new(),
+            weighting: CalibrationWeighting::Equal,
+            min_relative_vega: 1e-6,
+        }
+    }
+
+    /// Set the weighting scheme (default [`CalibrationWeighting::Equal`]).
+    pub fn with_weighting(mut self, weighting: CalibrationWeighting) -> Self {
+        self.weighting = weighting;
+        self
+    }
+
+    /// Exclude helpers whose vega is below `threshold` times the largest
+    /// vega in the basket (default `1e-6`).
+    pub fn with_min_relative_vega(mut self, threshold: Real) -> Self {
+        self.min_relative_vega = threshold;
+        self
+    }
+
+    /// Add a helper to the basket.
+    pub fn push(&mut self, helper: impl CalibrationHelper<M> + 'static) {
+        self.helpers.push(Box::new(helper));
+    }
+
+    /// Number of helpers, including excluded ones.
+    pub fn len(&self) -> usize {
+        self.helpers.len()
+    }
+
+    /// Whether the basket holds no helpers.
+    pub fn is_empty(&self) -> bool {
+        self.helpers.is_empty()
+    }
+
+    /// The `i`-th helper.
+    pub fn helper(&self, i: usize) -> &dyn CalibrationHelper<M> {
+        &*self.helpers[i]
+    }
+
+    /// The weighting scheme.
+    pub fn weighting(&self) -> CalibrationWeighting {
+        self.weighting
+    }
+
+    /// Indices of the helpers taking part in the calibration.
+    pub fn active_helpers(&self) -> Vec<usize> {
+        let max_vega = self.helpers.iter().map(|h| h.vega()).fold(0.0, Real::max);
+        let cutoff = self.min_relative_vega * max_vega;
+        (0..self.helpers.len())
+            .filter(|&i| {
+                let vega = self.helpers[i].vega();
+                vega.is_finite() && vega > cutoff
+            })
+            .collect()
+    }
+
+    /// Weight of each helper, zero for excluded ones; the weights of the
+    /// active helpers sum to one.
+    pub fn weights(&self) -> Vec<Real> {
+        let mut weights = vec![0.0; self.helpers.len()];
+        let active = self.active_helpers();
+        for &i in &active {
+            let vega = self.helpers[i].vega();
+            weights[i] = match self.weighting {
+                CalibrationWeighting::Equal => 1.0,
+                CalibrationWeighting::Vega => vega,
+                CalibrationWeighting::InverseVariance => 1.0 / (vega * vega),
+            };
+        }
+        let total: Real = weights.iter().sum();
+        if total > 0.0 {
+            weights.iter_mut().for_each(|w| *w /= total);
+        }
+        weights
+    }
+
+    /// Weighted price errors `√wᵢ·(modelᵢ − marketᵢ)` of the active
+    /// helpers under `model`.
+    pub fn residuals(&self, model: &M) -> Result<Array> {
+        let weights = self.weights();
+        let residuals = self
+            .active_helpers()
+            .into_iter()
+            .map(|i| Ok(weights[i].sqrt() * self.helpers[i].calibration_error(model)?))
+            .collect::<Result<Vec<_>>>()?;
+        Ok(Array::from_vec(residuals))
+    }
+
+    /// Unweighted calibration error of every helper under `model`,
+    /// excluded ones included.
+    pub fn calibration_errors(&self, model: &M) -> Result<Vec<Real>> {
+        self.helpers
+            .iter()
+            .map(|h| h.calibration_error(model))
+            .collect()
+    }
+}
+
+impl<M: CalibratedModel> CalibrationBasket<M> {
+    /// Calibrate `model` to the basket, leaving it at the optimum found.
+    ///
+    /// The optimiser runs on the flattened values of `model.params()`,
+    /// keeping each parameter within its own constraint.
+    pub fn calibrate(
+        &self,
+        model: &mut M,
+        method: &dyn OptimizationMethod,
+        end_criteria: &EndCriteria,
+    ) -> Result<OptimizationResult> {
+        let active = self.active_helpers();
+        ensure!(
+            !active.is_empty(),
+            "no calibration helper has a usable vega"
+        );
+        let weights = self.weights();
+        let helpers: Vec<&dyn CalibrationHelper<M>> =
+            active.iter().map(|&i| &*self.helpers[i]).collect();
+        let weights: Vec<Real> = active.iter().map(|&i| weights[i]).collect();
+        minimize_weighted_errors(model, &helpers, &weights, method, end_criteria)
+    }
+}
+
+/// Calibrate `model` to `helpers` with explicit weights; the engine of
+/// [`CalibratedModel::calibrate`].
+pub(crate) fn calibrate_model<M: CalibratedModel>(
+    model: &mut M,
+    helpers: &[&dyn CalibrationHelper<M>],
+    method: &dyn OptimizationMethod,
+    weights: &[Real],
+    end_criteria: &EndCriteria,
+) -> Result<CalibrationReport> {
+    ensure!(!helpers.is_empty(), "no calibration helpers given");
+    ensure!(
+        weights.len() == helpers.len(),
+        "{} weights given for {} helpers",
+        weights.len(),
+        helpers.len()
+    );
+    ensure!(
+        weights.iter().all(|&w| w.is_finite() && w >= 0.0),
+        "calibration weights must be finite and non-negative"
+    );
+    let optimization = minimize_weighted_errors(model, helpers, weights, method, end_criteria)?;
+    let residuals = helpers
+        .iter()
+        .map(|h| h.calibration_error(model))
+        .collect::<Result<Vec<_>>>()?;
+    Ok(CalibrationReport {
+        optimization,
+        residuals,
+    })
+}
+
+/// Minimise `Σ wᵢ·errorᵢ²` over the flattened values of `model.params()`,
+/// keeping each parameter within its own constraint, and leave `model` at
+/// the optimum found.
+fn minimize_weighted_errors<M: CalibratedModel>(
+    model: &mut M,
+    helpers: &[&dyn CalibrationHelper<M>],
+    weights: &[Real],
+    method: &dyn OptimizationMethod,
+    end_criteria: &EndCriteria,
+) -> Result<OptimizationResult> {
+    let initial = Array::from_vec(
+        model
+            .params()
+            .iter()
+            .flat_map(|p| p.values().iter().copied())
+            .collect(),
+    );
+    let sizes: Vec<usize> = model.params().iter().map(|p| p.values().len()).collect();
+    let model = RefCell::new(model);
+    let result = {
+        let cost = WeightedErrors {
+            helpers,
+            scales: weights.iter().map(|w| w.sqrt()).collect(),
+            model: &model,
+        };
+        let constraint = ParameterConstraint {
+            model: &model,
+            sizes: &sizes,
+        };
+        method.minimize(&cost, &constraint, &initial, end_criteria)?
+    };
+    model.into_inner().set_params(result.x.as_slice());
+    Ok(result)
+}
+
+/// Weighted calibration errors of a set of helpers as a function of the
+/// model parameters.
+struct WeightedErrors<'a, 'm, M: CalibratedModel> {
+    helpers: &'a [&'a dyn CalibrationHelper<M>],
+    scales: Vec<Real>,
+    model: &'a RefCell<&'m mut M>,
+}
+
+impl<M: CalibratedModel> CostFunction for WeightedErrors<'_, '_, M> {
+    fn values(&self, x: &Array) -> Array {
+        let mut model = self.model.borrow_mut();
+        model.set_params(x.as_slice());
+        let errors = self
+            .helpers
+            .iter()
+            .zip(&self.scales)
+            .map(|(h, s)| Ok(s * h.calibration_error(&**model)?))
+            .collect::<Result<Vec<_>>>();
+        // A failed pricing makes the point unattractive to the optimiser.
+        Array::from_vec(errors.unwrap_or_else(|_| vec![1e10; self.helpers.len()]))
+    }
+}
+
+/// Each parameter's own constraint, applied to its slice of the flat
+/// parameter vector.
+struct ParameterConstraint<'a, 'm, M: CalibratedModel> {
+    model: &'a RefCell<&'m mut M>,
+    sizes: &'a [usize],
+}
+
+impl<M: CalibratedModel> Constraint for ParameterConstraint<'_, '_, M> {
+    fn test(&self, x: &Array) -> bool {
+        let model = self.model.borrow();
+        let mut offset = 0;
+        model.params().iter().zip(self.sizes).all(|(p, &n)| {
+            let slice = &x.as_slice()[offset..offset + n];
+            offset += n;
+            p.constraint().test(slice)
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::calibrated_model::{Parameter, PositiveConstraint};
+    use ql_math::optimization::{LevenbergMarquardt, Simplex};
+
+    /// A one-parameter model pricing instrument `i` at `a·xᵢ`.
+    #[derive(Debug)]
+    struct Linear {
+        params: Vec<Parameter>,
+    }
+
+    impl CalibratedModel for Linear {
+        fn params(&self) -> &[Parameter] {
+            &self.params
+        }
+
+        fn set_params(&mut self, values: &[Real]) {
+            self.params[0].set_values(vec![values[0]]);
+        }
+    }
+
+    struct Quote {
+        x: Real,
+        market: Real,
+        vega: Real,
+    }
+
+    impl CalibrationHelper<Linear> for Quote {
+        fn market_value(&self) -> Real {
+            self.market
+        }
+
+        fn model_value(&self, model: &Linear) -> Result<Real> {
+            Ok(model.params[0].value() * self.x)
+        }
+
+        fn vega(&self) -> Real {
+            self.vega
+        }
+    }
+
+    fn basket(weighting: CalibrationWeighting) -> CalibrationBasket<Linear> {
+        let mut basket = CalibrationBasket::new().with_weighting(weighting);
+        // Quotes consistent with a = 2, except the low-vega one.
+        basket.push(Quote {
+            x: 1.0,
+            market: 2.0,
+            vega: 4.0,
+        });
+        basket.push(Quote {
+            x: 2.0,
+            market: 4.0,
+            vega: 2.0,
+        });
+        basket.push(Quote {
+            x: 1.0,
+            market: 3.0,
+            vega: 1.0,
+        });
+        basket.push(Quote {
+            x: 1.0,
+            market: 100.0,
+            vega: 1e-12,
+        });
+        basket
+    }
+
+    #[test]
+    fn weights_follow_the_scheme_and_skip_flat_helpers() {
+        let equal = basket(CalibrationWeighting::Equal);
+        assert_eq!(equal.active_helpers(), vec![0, 1, 2]);
+        let w = equal.weights();
+        assert!(w[..3].iter().all(|&w| (w - 1.0 / 3.0).abs() < 1e-15));
+        assert_eq!(w[3], 0.0);
+
+        let vega = basket(CalibrationWeighting::Vega).weights();
+        assert!((vega[0] - 4.0 / 7.0).abs() < 1e-15);
+        let inverse = basket(CalibrationWeighting::InverseVariance).weights();
+        assert!((inverse[2] / inverse[0] - 16.0).abs() < 1e-12);
+    }
+
+    #[test]
+    fn calibration_balances_weighted_errors() {
+        // Minimising Σ wᵢ(a·xᵢ − mᵢ)² gives a = Σ wᵢxᵢmᵢ / Σ wᵢxᵢ².
+        for weighting in [
+            CalibrationWeighting::Equal,
+            CalibrationWeighting::Vega,
+            CalibrationWeighting::InverseVariance,
+        ] {
+            let basket = basket(weighting);
+            let w = basket.weights();
+            let (x, m) = ([1.0, 2.0, 1.0], [2.0, 4.0, 3.0]);
+            let numerator: Real = (0..3).map(|i| w[i] * x[i] * m[i]).sum();
+            let denominator: Real = (0..3).map(|i| w[i] * x[i] * x[i]).sum();
+
+            let mut model = Linear {
+                params: vec![Parameter::new(vec![1.0], PositiveConstraint)],
+            };
+            basket
+                .calibrate(
+                    &mut model,
+                    &LevenbergMarquardt::new(1e-8, 1e-10, 1e-12),
+                    &EndCriteria::default(),
+                )
+                .unwrap();
+            let a = model.params[0].value();
+            assert!(
+                (a - numerator / denominator).abs() < 1e-6,
+                "{weighting:?}: a = {a}"
+            );
+
+            // Any optimisation method will do.
+            let mut model = Linear {
+                params: vec![Parameter::new(vec![1.0], PositiveConstraint)],
+            };
+            let end_criteria = EndCriteria::new(1000, 100, 1e-16, 1e-16, 1e-16);
+            basket
+                .calibrate(&mut model, &Simplex::new(0.1), &end_criteria)
+                .unwrap();
+            let a = model.params[0].value();
+            assert!(
+                (a - numerator / denominator).abs() < 1e-6,
+                "{weighting:?} with simplex: a = {a}"
+            );
+        }
+    }
+}
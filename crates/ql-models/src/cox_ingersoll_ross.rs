@@ -11,7 +11,8 @@
 
 use crate::calibrated_model::{CalibratedModel, Parameter, PositiveConstraint};
 use crate::short_rate_model::{OneFactorModel, ShortRateModel};
-use ql_core::{Real, Time};
+use ql_core::{errors::Result, Real, Time};
+use ql_math::distributions::NonCentralChiSquareDistribution;
 use ql_processes::StochasticProcess1D;
 use ql_termstructures::YieldTermStructure;
 use std::sync::Arc;
@@ -76,7 +77,7 @@ impl CoxIngersollRoss {
         let tau = big_t - t;
         let g = self.gamma();
         let exponent = 2.0 * self.a * self.b / (self.sigma * self.sigma);
-        let numerator = 2.0 * g * (-(g + self.a) * tau / 2.0).exp();
+        let numerator = 2.0 * g * ((g + self.a) * tau / 2.0).exp();
         let denominator = (g + self.a) * ((g * tau).exp() - 1.0) + 2.0 * g;
         // Avoid issues when tau is very small
         if tau.abs() < 1e-14 {
@@ -86,6 +87,58 @@ impl CoxIngersollRoss {
         }
     }
 
+    /// Price of a European option expiring at `maturity` on the unit
+    /// zero-coupon bond maturing at `bond_maturity`.
+    ///
+    /// Uses the closed form of Cox, Ingersoll & Ross (1985) in terms of the
+    /// non-central chi-square distribution; puts follow from put-call
+    /// parity against the forward bond.
+    ///
+    /// Returns an error if the parameters give a non-positive number of
+    /// degrees of freedom `4ab/σ²` or a negative non-centrality, as a
+    /// negative `r0` does.
+    ///
+    /// Corresponds to `QuantLib::CoxIngersollRoss::discountBondOption`.
+    pub fn discount_bond_option(
+        &self,
+        is_call: bool,
+        strike: Real,
+        maturity: Time,
+        bond_maturity: Time,
+    ) -> Result<Real> {
+        let discount_s = self.discount_bond(0.0, bond_maturity, self.r0);
+        if maturity < 1e-14 {
+            let intrinsic = if is_call {
+                discount_s - strike
+            } else {
+                strike - discount_s
+            };
+            return Ok(intrinsic.max(0.0));
+        }
+        let discount_t = self.discount_bond(0.0, maturity, self.r0);
+
+        let sigma2 = self.sigma * self.sigma;
+        let h = self.gamma();
+        let b = self.b_function(maturity, bond_maturity);
+        let rho = 2.0 * h / (sigma2 * ((h * maturity).exp() - 1.0));
+        let psi = (self.a + h) / sigma2;
+        let df = 4.0 * self.a * self.b / sigma2;
+        let ncp_s = 2.0 * rho * rho * self.r0 * (h * maturity).exp() / (rho + psi + b);
+        let ncp_t = 2.0 * rho * rho * self.r0 * (h * maturity).exp() / (rho + psi);
+        let chi_s = NonCentralChiSquareDistribution::new(df, ncp_s)?;
+        let chi_t = NonCentralChiSquareDistribution::new(df, ncp_t)?;
+
+        // Critical short rate at expiry below which the call ends in the money.
+        let z = (self.log_a(maturity, bond_maturity) - strike.ln()) / b;
+        let call = discount_s * chi_s.cdf(2.0 * z * (rho + psi + b))
+            - strike * discount_t * chi_t.cdf(2.0 * z * (rho + psi));
+        Ok(if is_call {
+            call
+        } else {
+            call - discount_s + strike * discount_t
+        })
+    }
+
     /// Check the Feller condition: `2ab > σ²`.
     pub fn feller_satisfied(&self) -> bool {
         2.0 * self.a * self.b > self.sigma * self.sigma
@@ -191,6 +244,20 @@ mod tests {
         assert!(p < 1.0);
     }
 
+    #[test]
+    fn cir_discount_bond_matches_closed_form() {
+        // P = A·e^{−B r} with A = [2γ e^{(a+γ)τ/2} / ((γ+a)(e^{γτ}−1) + 2γ)]^{2ab/σ²},
+        // evaluated independently for a = 0.3, b = 0.05, σ = 0.1, r = 4%, τ = 4.
+        let m = CoxIngersollRoss::new(0.3, 0.05, 0.1, 0.04, flat_ts(0.05));
+        let p = m.discount_bond(0.0, 4.0, 0.04);
+        assert!((p - 0.839_702_798_215_280_4).abs() < 1e-12, "{p}");
+
+        // Without volatility the rate stays at its mean b = r.
+        let m = CoxIngersollRoss::new(0.3, 0.05, 1e-4, 0.05, flat_ts(0.05));
+        let p = m.discount_bond(0.0, 4.0, 0.05);
+        assert!((p - (-0.05_f64 * 4.0).exp()).abs() < 1e-6, "{p}");
+    }
+
     #[test]
     fn cir_feller_condition() {
         // 2ab = 2*0.3*0.05 = 0.03, σ² = 0.01 => Feller OK
@@ -209,4 +276,61 @@ mod tests {
         let expected = 0.1 * 0.04_f64.sqrt();
         assert!((d - expected).abs() < 1e-12);
     }
+
+    #[test]
+    fn cir_bond_option_put_call_parity() {
+        let m = CoxIngersollRoss::new(0.3, 0.05, 0.1, 0.04, flat_ts(0.05));
+        let (t, s) = (1.0, 4.0);
+        let forward = m.discount_bond(0.0, s, m.r0);
+        let p_t = m.discount_bond(0.0, t, m.r0);
+        for strike in [0.80, 0.85, 0.88, 0.92] {
+            let call = m.discount_bond_option(true, strike, t, s).unwrap();
+            let put = m.discount_bond_option(false, strike, t, s).unwrap();
+            assert!(call >= 0.0 && put >= 0.0);
+            assert!(
+                (call - put - (forward - strike * p_t)).abs() < 1e-12,
+                "K={strike}: C={call}, P={put}"
+            );
+        }
+
+        // A negative short rate has no non-central chi-square law.
+        let negative = CoxIngersollRoss::new(0.3, 0.05, 0.1, -0.01, flat_ts(0.05));
+        assert!(negative.discount_bond_option(true, 0.9, t, s).is_err());
+    }
+
+    #[test]
+    fn cir_bond_option_matches_monte_carlo() {
+        use ql_math::random_numbers::InverseCumulativeNormalRng;
+
+        let m = CoxIngersollRoss::new(0.5, 0.05, 0.15, 0.04, flat_ts(0.05));
+        let (t, s, strike) = (1.0, 3.0, 0.90);
+        let analytic = m.discount_bond_option(true, strike, t, s).unwrap();
+
+        let steps = 200;
+        let dt = t / steps as Real;
+        let paths = 20_000;
+        let mut rng = InverseCumulativeNormalRng::new(42);
+        let (mut sum, mut sum_sq) = (0.0, 0.0);
+        for _ in 0..paths {
+            // Full-truncation Euler with trapezoidal integration of r.
+            let mut r: Real = m.r0;
+            let mut integral = 0.0;
+            for _ in 0..steps {
+                let rp = r.max(0.0);
+                let next = r + m.a * (m.b - rp) * dt + m.sigma * (rp * dt).sqrt() * rng.next_real();
+                integral += 0.5 * (rp + next.max(0.0)) * dt;
+                r = next;
+            }
+            let bond = m.discount_bond(t, s, r.max(0.0));
+            let v = (-integral).exp() * (bond - strike).max(0.0);
+            sum += v;
+            sum_sq += v * v;
+        }
+        let mean = sum / paths as Real;
+        let error = ((sum_sq / paths as Real - mean * mean) / paths as Real).sqrt();
+        assert!(
+            (mean - analytic).abs() < 3.0 * error,
+            "MC {mean:.6e} ± {error:.1e} vs analytic {analytic:.6e}"
+        );
+    }
 }
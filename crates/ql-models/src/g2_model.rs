@@ -13,7 +13,10 @@
 
 use crate::calibrated_model::{BoundaryConstraint, CalibratedModel, Parameter, PositiveConstraint};
 use crate::short_rate_model::{ShortRateModel, TwoFactorModel};
-use ql_core::{Real, Time};
+use ql_core::{ensure, errors::Result, Rate, Real, Size, Time};
+use ql_math::distributions::normal_cdf;
+use ql_math::integrals::{Integrator, SegmentIntegral};
+use ql_math::solvers1d::brent;
 use ql_termstructures::YieldTermStructure;
 use std::sync::Arc;
 
@@ -107,6 +110,135 @@ impl G2Model {
 
         term1 + term2 + term3
     }
+
+    /// `A(t,T) = P(0,T)/P(0,t) · exp(½[V(t,T) − V(0,T) + V(0,t)])`
+    fn a_function(&self, t: Time, big_t: Time) -> Real {
+        let ts = &self.term_structure;
+        ts.discount(big_t) / ts.discount(t)
+            * (0.5
+                * (self.v_function(t, big_t) - self.v_function(0.0, big_t)
+                    + self.v_function(0.0, t)))
+            .exp()
+    }
+
+    /// Discount bond `P(t,T)` conditional on the factor values `x(t)`, `y(t)`.
+    ///
+    /// `P(t,T) = A(t,T) exp(−B_a(T−t)·x − B_b(T−t)·y)`
+    pub fn discount_bond_factors(&self, t: Time, big_t: Time, x: Real, y: Real) -> Real {
+        let tau = big_t - t;
+        self.a_function(t, big_t) * (-self.b_a(tau) * x - self.b_b(tau) * y).exp()
+    }
+
+    /// Analytic price of a European swaption (Brigo–Mercurio, eq. 4.31).
+    ///
+    /// The swap starts at the option expiry `start` and pays `fixed_rate`
+    /// at each of `pay_times` (the last one also repays the nominal). The
+    /// outer integral over `x(start)` is taken on `mean ± range·stddev`
+    /// with a `SegmentIntegral` of `intervals` segments.
+    ///
+    /// Corresponds to `QuantLib::G2::swaption`.
+    #[allow(clippy::too_many_arguments)]
+    pub fn swaption(
+        &self,
+        payer: bool,
+        nominal: Real,
+        start: Time,
+        pay_times: &[Time],
+        fixed_rate: Rate,
+        range: Real,
+        intervals: Size,
+    ) -> Result<Real> {
+        ensure!(start > 0.0, "swaption expiry must be positive, got {start}");
+        ensure!(
+            !pay_times.is_empty(),
+            "at least one fixed payment is required"
+        );
+        ensure!(
+            pay_times[0] > start && pay_times.windows(2).all(|w| w[1] > w[0]),
+            "payment times must be increasing and after the expiry"
+        );
+
+        let (a, b, sigma, eta, rho) = (self.a, self.b, self.sigma, self.eta, self.rho);
+        let w = if payer { 1.0 } else { -1.0 };
+        let big_t = start;
+
+        let sigma_x = sigma * (0.5 * (1.0 - (-2.0 * a * big_t).exp()) / a).sqrt();
+        let sigma_y = eta * (0.5 * (1.0 - (-2.0 * b * big_t).exp()) / b).sqrt();
+        let rho_xy =
+            rho * eta * sigma * (1.0 - (-(a + b) * big_t).exp()) / ((a + b) * sigma_x * sigma_y);
+
+        let temp = sigma * sigma / (a * a);
+        let mu_x = -((temp + rho * sigma * eta / (a * b)) * (1.0 - (-a * big_t).exp())
+            - 0.5 * temp * (1.0 - (-2.0 * a * big_t).exp())
+            - rho * sigma * eta / (b * (a + b)) * (1.0 - (-(a + b) * big_t).exp()));
+        let temp = eta * eta / (b * b);
+        let mu_y = -((temp + rho * sigma * eta / (a * b)) * (1.0 - (-b * big_t).exp())
+            - 0.5 * temp * (1.0 - (-2.0 * b * big_t).exp())
+            - rho * sigma * eta / (a * (a + b)) * (1.0 - (-(a + b) * big_t).exp()));
+
+        let n = pay_times.len();
+        let coupons: Vec<Real> = (0..n)
+            .map(|i| {
+                let tau = if i == 0 {
+                    pay_times[0] - big_t
+                } else {
+                    pay_times[i] - pay_times[i - 1]
+                };
+                if i == n - 1 {
+                    1.0 + fixed_rate * tau
+                } else {
+                    fixed_rate * tau
+                }
+            })
+            .collect();
+        let a_t: Vec<Real> = pay_times
+            .iter()
+            .map(|&t| self.a_function(big_t, t))
+            .collect();
+        let ba: Vec<Real> = pay_times.iter().map(|&t| self.b_a(t - big_t)).collect();
+        let bb: Vec<Real> = pay_times.iter().map(|&t| self.b_b(t - big_t)).collect();
+
+        let txy = (1.0 - rho_xy * rho_xy).sqrt();
+        let integrand = |x: Real| -> Real {
+            let lambda: Vec<Real> = (0..n)
+                .map(|i| coupons[i] * a_t[i] * (-ba[i] * x).exp())
+                .collect();
+
+            // ȳ(x) solves Σ λᵢ exp(−B_b,ᵢ·ȳ) = 1
+            let y_bar = match brent(
+                |y| 1.0 - (0..n).map(|i| lambda[i] * (-bb[i] * y).exp()).sum::<Real>(),
+                -100.0,
+                100.0,
+                1e-6,
+            ) {
+                Ok(y) => y,
+                Err(_) => return Real::NAN,
+            };
+
+            let h1 = (y_bar - mu_y) / (sigma_y * txy) - rho_xy * (x - mu_x) / (sigma_x * txy);
+            let mut value = normal_cdf(-w * h1);
+            for i in 0..n {
+                let h2 = h1 + bb[i] * sigma_y * txy;
+                let kappa = -bb[i]
+                    * (mu_y - 0.5 * txy * txy * sigma_y * sigma_y * bb[i]
+                        + rho_xy * sigma_y * (x - mu_x) / sigma_x);
+                value -= lambda[i] * kappa.exp() * normal_cdf(-w * h2);
+            }
+
+            let z = (x - mu_x) / sigma_x;
+            (-0.5 * z * z).exp() * value / (sigma_x * (2.0 * std::f64::consts::PI).sqrt())
+        };
+
+        let lower = mu_x - range * sigma_x;
+        let upper = mu_x + range * sigma_x;
+        let integral = SegmentIntegral::new(intervals).integrate(integrand, lower, upper)?;
+        ensure!(
+            integral.is_finite(),
+            "G2 swaption: failed to solve for the critical y"
+        );
+
+        Ok(nominal * w * self.term_structure.discount(big_t) * integral)
+    }
 }
 
 impl CalibratedModel for G2Model {
@@ -190,6 +322,45 @@ mod tests {
         assert!(p < 1.0);
     }
 
+    #[test]
+    fn g2_discount_bond_factors_at_zero_fits_curve() {
+        let ts = flat_ts(0.05);
+        let g = G2Model::new(ts.clone(), 0.1, 0.01, 0.2, 0.015, -0.5);
+        let p = g.discount_bond_factors(0.0, 3.0, 0.0, 0.0);
+        assert!((p - ts.discount(3.0)).abs() < 1e-14);
+    }
+
+    #[test]
+    fn g2_swaption_put_call_parity() {
+        // Payer − receiver = value of the underlying payer swap
+        let ts = flat_ts(0.05);
+        let g = G2Model::new(ts.clone(), 0.1, 0.01, 0.2, 0.015, -0.5);
+        let pay_times = [2.0, 3.0, 4.0, 5.0];
+        let k = 0.05;
+        let payer = g
+            .swaption(true, 1.0, 1.0, &pay_times, k, 8.0, 1000)
+            .unwrap();
+        let receiver = g
+            .swaption(false, 1.0, 1.0, &pay_times, k, 8.0, 1000)
+            .unwrap();
+        let annuity: Real = pay_times.iter().map(|&t| ts.discount(t)).sum();
+        let swap = ts.discount(1.0) - ts.discount(5.0) - k * annuity;
+        assert!(payer > 0.0 && receiver > 0.0);
+        assert!(
+            (payer - receiver - swap).abs() < 1e-7,
+            "payer {payer} − receiver {receiver} ≠ swap {swap}"
+        );
+    }
+
+    #[test]
+    fn g2_swaption_rejects_bad_schedule() {
+        let g = G2Model::new(flat_ts(0.05), 0.1, 0.01, 0.2, 0.015, -0.5);
+        assert!(g.swaption(true, 1.0, 1.0, &[], 0.05, 8.0, 100).is_err());
+        assert!(g
+            .swaption(true, 1.0, 2.0, &[1.5, 3.0], 0.05, 8.0, 100)
+            .is_err());
+    }
+
     #[test]
     fn g2_set_params() {
         let mut g = G2Model::new(flat_ts(0.05), 0.1, 0.01, 0.2, 0.015, -0.5);
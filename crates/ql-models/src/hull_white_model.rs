@@ -13,10 +13,17 @@
 //!
 //! where `B` is the same as Vasicek and `A` is adjusted to fit the
 //! initial curve.
+//!
+//! [`HullWhite::tree`] builds the fitted trinomial [`ShortRateTree`]
+//! (`QuantLib::OneFactorModel::ShortRateTree`), and
+//! [`HullWhite::tree_vs_analytic_error`] benchmarks it against the
+//! closed-form bond and caplet prices.  [`ShortRateTree::bermudan_swaption`]
+//! prices co-terminal Bermudan swaptions by backward induction on the tree.
 
 use crate::calibrated_model::{CalibratedModel, Parameter, PositiveConstraint};
 use crate::short_rate_model::{OneFactorModel, ShortRateModel};
-use ql_core::{Real, Time};
+use ql_core::{ensure, errors::Result, Real, Time};
+use ql_math::distributions::normal_cdf;
 use ql_processes::StochasticProcess1D;
 use ql_termstructures::YieldTermStructure;
 use std::sync::Arc;
@@ -62,7 +69,7 @@ impl HullWhite {
 
     /// `A(t,T)` using the initial yield curve for exact fitting.
     ///
-    /// `ln A(t,T) = ln(P(0,T)/P(0,t)) − B(t,T)·f(0,t) − σ²/(4a)·B²·(1−e^{-2at})`
+    /// `ln A(t,T) = ln(P(0,T)/P(0,t)) + B(t,T)·f(0,t) − σ²/(4a)·B²·(1−e^{-2at})`
     fn log_a(&self, t: Time, big_t: Time) -> Real {
         let b_val = self.b_function(t, big_t);
         let ts = &self.term_structure;
@@ -77,8 +84,7 @@ impl HullWhite {
         let f0t = ts.forward_rate_impl(t);
         let sigma2 = self.sigma * self.sigma;
 
-        (ln_pt - ln_p0)
-            - b_val * f0t
+        (ln_pt - ln_p0) + b_val * f0t
             - sigma2 / (4.0 * self.a) * b_val * b_val * (1.0 - (-2.0 * self.a * t).exp())
     }
 
@@ -92,6 +98,401 @@ impl HullWhite {
             + self.a * f_t
             + self.sigma * self.sigma / (2.0 * self.a) * (1.0 - (-2.0 * self.a * t).exp())
     }
+
+    /// Price at time 0 of a European option expiring at `maturity` on a
+    /// unit zero-coupon bond maturing at `bond_maturity`.
+    ///
+    /// Corresponds to `QuantLib::HullWhite::discountBondOption`.
+    pub fn discount_bond_option(
+        &self,
+        is_call: bool,
+        strike: Real,
+        maturity: Time,
+        bond_maturity: Time,
+    ) -> Real {
+        let p_maturity = self.term_structure.discount(maturity);
+        let p_bond = self.term_structure.discount(bond_maturity);
+        let b = self.b_function(maturity, bond_maturity);
+        let v = if self.a.abs() < 1e-12 {
+            self.sigma * b * maturity.sqrt()
+        } else {
+            self.sigma * b * ((1.0 - (-2.0 * self.a * maturity).exp()) / (2.0 * self.a)).sqrt()
+        };
+        let k = strike * p_maturity;
+        if v < 1e-15 {
+            let intrinsic = if is_call { p_bond - k } else { k - p_bond };
+            return intrinsic.max(0.0);
+        }
+        let d1 = (p_bond / k).ln() / v + 0.5 * v;
+        let d2 = d1 - v;
+        if is_call {
+            p_bond * normal_cdf(d1) - k * normal_cdf(d2)
+        } else {
+            k * normal_cdf(-d2) - p_bond * normal_cdf(-d1)
+        }
+    }
+
+    /// Price at time 0 of a caplet on the simply-compounded rate fixing at
+    /// `reset` for the period ending at `payment`, as a put on the bond
+    /// maturing at `payment`.
+    pub fn caplet(&self, strike: Real, reset: Time, payment: Time, nominal: Real) -> Real {
+        let k = 1.0 + strike * (payment - reset);
+        nominal * k * self.discount_bond_option(false, 1.0 / k, reset, payment)
+    }
+
+    /// Build a trinomial tree of `steps` uniform steps up to `maturity`,
+    /// fitted to the initial term structure.
+    ///
+    /// The tree follows `x = r − α(t)`, an Ornstein-Uhlenbeck process with
+    /// the model's mean reversion, on nodes spaced `√(3V)` apart.  Node
+    /// rates apply over a whole step, so `V` is the variance of the
+    /// `Δt`-period rate: the short-rate variance scaled by
+    /// `((1 − e^{−aΔt})/(aΔt))²`.  The shifts `α(tᵢ)` are solved by forward
+    /// induction on Arrow-Debreu prices so that the tree reprices every
+    /// zero-coupon bond on its grid.
+    pub fn tree(&self, maturity: Time, steps: usize) -> Result<ShortRateTree> {
+        ensure!(maturity > 0.0, "maturity must be positive");
+        ensure!(steps > 0, "at least one time step is required");
+
+        let dt = maturity / steps as Real;
+        let a = self.a;
+        let mean_factor = (-a * dt).exp();
+        let (period_factor, step_variance) = if a.abs() < 1e-12 {
+            (1.0, self.sigma * self.sigma * dt)
+        } else {
+            (
+                (1.0 - mean_factor) / (a * dt),
+                self.sigma * self.sigma * (1.0 - mean_factor * mean_factor) / (2.0 * a),
+            )
+        };
+        let variance = period_factor * period_factor * step_variance;
+        let dx = (3.0 * variance).sqrt();
+
+        let mut j_min = vec![0_i32];
+        let mut branchings = Vec::with_capacity(steps);
+        let mut alpha = Vec::with_capacity(steps);
+        let mut arrow_debreu = vec![vec![1.0]];
+
+        for i in 0..steps {
+            let q = &arrow_debreu[i];
+            let x = |j: usize| (j_min[i] + j as i32) as Real * dx;
+
+            let target = self.term_structure.discount((i + 1) as Real * dt);
+            let sum: Real = q
+                .iter()
+                .enumerate()
+                .map(|(j, &qj)| qj * (-x(j) * dt).exp())
+                .sum();
+            ensure!(sum > 0.0 && target > 0.0, "cannot fit the tree at step {i}");
+            let alpha_i = (sum / target).ln() / dt;
+
+            let mut branching = Vec::with_capacity(q.len());
+            for j in 0..q.len() {
+                let e = x(j) * mean_factor;
+                let k = (e / dx).round() as i32;
+                let eta = e - k as Real * dx;
+                let p_up = (1.0 + eta * eta / variance) / 6.0 + eta / (2.0 * dx);
+                let p_mid = 2.0 / 3.0 - eta * eta / (3.0 * variance);
+                branching.push((k, [1.0 - p_up - p_mid, p_mid, p_up]));
+            }
+            let next_min = branching.iter().map(|b| b.0).min().unwrap() - 1;
+            let next_max = branching.iter().map(|b| b.0).max().unwrap() + 1;
+
+            let mut next = vec![0.0; (next_max - next_min + 1) as usize];
+            for (j, (k, probs)) in branching.iter().enumerate() {
+                let discounted = q[j] * (-(x(j) + alpha_i) * dt).exp();
+                let base = (k - 1 - next_min) as usize;
+                for (b, p) in probs.iter().enumerate() {
+                    next[base + b] += p * discounted;
+                }
+            }
+
+            alpha.push(alpha_i);
+            branchings.push(branching);
+            j_min.push(next_min);
+            arrow_debreu.push(next);
+        }
+
+        Ok(ShortRateTree {
+            dt,
+            dx,
+            j_min,
+            alpha,
+            branchings,
+            arrow_debreu,
+        })
+    }
+
+    /// Maximum relative errors of a `steps`-step tree up to `maturity`
+    /// against the closed-form prices.
+    ///
+    /// Zero-coupon bonds are checked at every grid time; caplets are the
+    /// nine at-the-money caplets on consecutive periods of `maturity / 10`
+    /// starting at the first period end, so `steps` must be a multiple of 10.
+    pub fn tree_vs_analytic_error(
+        &self,
+        maturity: Time,
+        steps: usize,
+    ) -> Result<TreeAnalyticError> {
+        ensure!(
+            steps >= 10 && steps % 10 == 0,
+            "steps must be a positive multiple of 10"
+        );
+        let tree = self.tree(maturity, steps)?;
+        let dt = tree.dt();
+        let ts = &self.term_structure;
+
+        let zero_bond = (1..=steps)
+            .map(|i| {
+                let expected = ts.discount(i as Real * dt);
+                (tree.zero_coupon_bond(i) / expected - 1.0).abs()
+            })
+            .fold(0.0, Real::max);
+
+        let period = steps / 10;
+        let caplet = (1..10)
+            .map(|k| {
+                let (reset, pay) = (k * period, (k + 1) * period);
+                let (t_reset, t_pay) = (reset as Real * dt, pay as Real * dt);
+                let strike = (ts.discount(t_reset) / ts.discount(t_pay) - 1.0) / (t_pay - t_reset);
+                let expected = self.caplet(strike, t_reset, t_pay, 1.0);
+                (tree.caplet(strike, reset, pay, 1.0) / expected - 1.0).abs()
+            })
+            .fold(0.0, Real::max);
+
+        Ok(TreeAnalyticError { zero_bond, caplet })
+    }
+}
+
+/// Maximum relative tree-vs-analytic errors, see
+/// [`HullWhite::tree_vs_analytic_error`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct TreeAnalyticError {
+    /// Over zero-coupon bonds.
+    pub zero_bond: Real,
+    /// Over at-the-money caplets.
+    pub caplet: Real,
+}
+
+// ─── ShortRateTree ────────────────────────────────────────────────────────────
+
+/// Recombining trinomial short-rate tree fitted to the initial curve.
+///
+/// Node `j` of step `i` carries the rate `r = x + α(tᵢ)`, applied
+/// continuously compounded over the following `dt`; `x` sits on a uniform
+/// grid whose extent grows until mean reversion bounds it.
+///
+/// Built by [`HullWhite::tree`].
+///
+/// Corresponds to `QuantLib::OneFactorModel::ShortRateTree`.
+#[derive(Debug, Clone)]
+pub struct ShortRateTree {
+    dt: Time,
+    dx: Real,
+    /// Grid index of node 0 at each step (`steps + 1` entries).
+    j_min: Vec<i32>,
+    alpha: Vec<Real>,
+    /// Per step and node: central descendant index and (down, mid, up)
+    /// probabilities.
+    branchings: Vec<Vec<(i32, [Real; 3])>>,
+    arrow_debreu: Vec<Vec<Real>>,
+}
+
+impl ShortRateTree {
+    /// Number of time steps.
+    pub fn steps(&self) -> usize {
+        self.alpha.len()
+    }
+
+    /// Length of each time step.
+    pub fn dt(&self) -> Time {
+        self.dt
+    }
+
+    /// Number of nodes at step `i` (for `i ≤ steps`).
+    pub fn size(&self, i: usize) -> usize {
+        self.arrow_debreu[i].len()
+    }
+
+    /// Fitted shift `α(tᵢ)` at step `i`.
+    pub fn alpha(&self, i: usize) -> Real {
+        self.alpha[i]
+    }
+
+    /// Short rate on node `j` of step `i`.
+    pub fn rate(&self, i: usize, j: usize) -> Real {
+        assert!(j < self.size(i), "node {j} does not exist at step {i}");
+        (self.j_min[i] + j as i32) as Real * self.dx + self.alpha[i]
+    }
+
+    /// Arrow-Debreu price of node `j` at step `i` (for `i ≤ steps`).
+    pub fn arrow_debreu(&self, i: usize, j: usize) -> Real {
+        self.arrow_debreu[i][j]
+    }
+
+    /// Price at time 0 of a unit zero-coupon bond maturing at step
+    /// `maturity_step`, by backward induction.
+    pub fn zero_coupon_bond(&self, maturity_step: usize) -> Real {
+        self.discount_bond_values(0, maturity_step)[0]
+    }
+
+    /// Values on each node of step `from_step` of a unit zero-coupon bond
+    /// maturing at step `maturity_step`.
+    pub fn discount_bond_values(&self, from_step: usize, maturity_step: usize) -> Vec<Real> {
+        assert!(
+            from_step <= maturity_step && maturity_step <= self.steps(),
+            "invalid bond steps {from_step}..{maturity_step}"
+        );
+        let mut values = vec![1.0; self.size(maturity_step)];
+        for i in (from_step..maturity_step).rev() {
+            values = self.rollback_step(i, &values);
+        }
+        values
+    }
+
+    /// Price at time 0 of a caplet on the simply-compounded rate fixing at
+    /// step `reset_step` for the period ending at step `pay_step`.
+    ///
+    /// The payoff `τ·(L − K)⁺` at the payment date is valued at the reset
+    /// date as `(1 + Kτ)·(1/(1 + Kτ) − P(t_reset, t_pay))⁺`.
+    ///
+    /// Summing a kinked payoff over equally spaced nodes is only first-order
+    /// accurate, with an error depending on where the kink falls between
+    /// two nodes; the sum is corrected as derived on `kink_correction`.
+    pub fn caplet(&self, strike: Real, reset_step: usize, pay_step: usize, nominal: Real) -> Real {
+        assert!(reset_step < pay_step, "payment must follow the reset");
+        let tau = (pay_step - reset_step) as Real * self.dt;
+        let k = 1.0 + strike * tau;
+        let payoff: Vec<Real> = self
+            .discount_bond_values(reset_step, pay_step)
+            .iter()
+            .map(|&p| 1.0 - k * p)
+            .collect();
+        let q = &self.arrow_debreu[reset_step];
+
+        let value = payoff
+            .iter()
+            .zip(q)
+            .map(|(&g, &qj)| qj * g.max(0.0))
+            .sum::<Real>()
+            + kink_correction(&payoff, q);
+        nominal * value
+    }
+
+    /// Price at time 0 of a Bermudan swaption exercisable at each of
+    /// `exercise_steps` into the remaining part of a fixed-vs-floating swap
+    /// with fixed payments at `pay_steps`.
+    ///
+    /// Exercising at step `e` enters the swap whose fixed leg pays
+    /// `K·τⱼ` at every `pay_steps[j] > e`, each accruing from the later of
+    /// `e` and the previous payment; the floating leg starts at `e` and is
+    /// worth par, so a payer swap is worth
+    /// `1 − P(e, Tₙ) − K·Σ τⱼ P(e, Tⱼ)`.
+    ///
+    /// Corresponds to `QuantLib::TreeSwaptionEngine` on a co-terminal
+    /// Bermudan swaption.
+    pub fn bermudan_swaption(
+        &self,
+        payer: bool,
+        strike: Real,
+        exercise_steps: &[usize],
+        pay_steps: &[usize],
+        nominal: Real,
+    ) -> Real {
+        assert!(!exercise_steps.is_empty(), "no exercise steps given");
+        assert!(
+            exercise_steps.windows(2).all(|w| w[0] < w[1])
+                && pay_steps.windows(2).all(|w| w[0] < w[1]),
+            "exercise and payment steps must be increasing"
+        );
+        let maturity = *pay_steps.last().expect("no payment steps given");
+        assert!(
+            *exercise_steps.last().unwrap() < maturity,
+            "last exercise must precede the last payment"
+        );
+        let phi = if payer { 1.0 } else { -1.0 };
+
+        let underlying = |e: usize| -> Vec<Real> {
+            let mut value = vec![1.0; self.size(e)];
+            let mut accrual_start = e;
+            for &pay in pay_steps.iter().filter(|&&p| p > e) {
+                let tau = (pay - accrual_start) as Real * self.dt;
+                let mut coupon = strike * tau;
+                if pay == maturity {
+                    coupon += 1.0;
+                }
+                for (v, p) in value.iter_mut().zip(self.discount_bond_values(e, pay)) {
+                    *v -= coupon * p;
+                }
+                accrual_start = pay;
+            }
+            value.into_iter().map(|v| (phi * v).max(0.0)).collect()
+        };
+
+        let last = *exercise_steps.last().unwrap();
+        let mut values = underlying(last);
+        let mut step = last;
+        for &e in exercise_steps.iter().rev().skip(1) {
+            for i in (e..step).rev() {
+                values = self.rollback_step(i, &values);
+            }
+            for (v, x) in values.iter_mut().zip(underlying(e)) {
+                *v = v.max(x);
+            }
+            step = e;
+        }
+        for i in (0..step).rev() {
+            values = self.rollback_step(i, &values);
+        }
+        nominal * values[0]
+    }
+
+    /// Discount node values at step `i + 1` back to step `i`.
+    fn rollback_step(&self, i: usize, values: &[Real]) -> Vec<Real> {
+        let next_min = self.j_min[i + 1];
+        self.branchings[i]
+            .iter()
+            .enumerate()
+            .map(|(j, (k, probs))| {
+                let base = (k - 1 - next_min) as usize;
+                let expected: Real = probs
+                    .iter()
+                    .enumerate()
+                    .map(|(b, p)| p * values[base + b])
+                    .sum();
+                expected * (-self.rate(i, j) * self.dt).exp()
+            })
+            .collect()
+    }
+}
+
+/// Euler-Maclaurin correction to `Σ qⱼ·max(gⱼ, 0)` for the kink of the
+/// truncated payoff.
+///
+/// With node spacing `h` and Arrow-Debreu prices `qⱼ ≈ p(xⱼ)·h` for a smooth
+/// density `p`, the sum is a rectangle rule for `∫ f dx` with
+/// `f = p·max(g, 0)`.  Away from the kink the rule is accurate to high
+/// order, but a jump `[f′]` in the derivative at `x* = xⱼ + θh` leaves
+///
+/// `Σ h·f(xⱼ) − ∫ f dx = −h²·[f′]·B₂(θ)/2 + O(h³)`, with `B₂(θ) = θ² − θ + 1/6`
+///
+/// (for `θ = 0` this is the `−h²·f′(0)/12` Euler-Maclaurin term of the rule
+/// on a half line).  Here `[f′] = p(x*)·|g′(x*)|`, so `h²·[f′] ≈ q·|Δg|`,
+/// where `q` is the Arrow-Debreu price interpolated at `x*` and
+/// `Δg = gⱼ₊₁ − gⱼ`.  Adding `q·|Δg|·B₂(θ)/2` at every sign change of `g`
+/// makes the sum second-order accurate.
+fn kink_correction(payoff: &[Real], q: &[Real]) -> Real {
+    payoff
+        .windows(2)
+        .zip(q.windows(2))
+        .filter(|(g, _)| (g[0] < 0.0) != (g[1] < 0.0))
+        .map(|(g, q)| {
+            let theta = g[0] / (g[0] - g[1]);
+            let weight = (1.0 - theta) * q[0] + theta * q[1];
+            let b2 = theta * theta - theta + 1.0 / 6.0;
+            0.5 * weight * (g[1] - g[0]).abs() * b2
+        })
+        .sum()
 }
 
 impl CalibratedModel for HullWhite {
@@ -219,6 +620,17 @@ mod tests {
         assert!(p < 1.0);
     }
 
+    #[test]
+    fn hw_discount_bond_at_forward_rate_matches_curve() {
+        // At r(t) = f(0,t) the bond price is the forward price up to the
+        // small convexity term.
+        let ts = flat_ts(0.05);
+        let hw = HullWhite::new(ts.clone(), 0.1, 0.01);
+        let p = hw.discount_bond(1.0, 5.0, 0.05);
+        let forward = ts.discount(5.0) / ts.discount(1.0);
+        assert!((p / forward - 1.0).abs() < 1e-3, "{p} vs {forward}");
+    }
+
     #[test]
     fn hw_b_function() {
         let hw = HullWhite::new(flat_ts(0.05), 0.1, 0.01);
@@ -234,6 +646,80 @@ mod tests {
         assert!((proc.x0() - 0.05).abs() < 0.01);
     }
 
+    #[test]
+    fn hw_discount_bond_option_parity() {
+        let ts = flat_ts(0.05);
+        let hw = HullWhite::new(ts.clone(), 0.1, 0.01);
+        let (t, s, k) = (2.0, 5.0, 0.86);
+        let call = hw.discount_bond_option(true, k, t, s);
+        let put = hw.discount_bond_option(false, k, t, s);
+        assert!(call > 0.0 && put > 0.0);
+        assert!((call - put - (ts.discount(s) - k * ts.discount(t))).abs() < 1e-14);
+    }
+
+    #[test]
+    fn tree_bermudan_swaption_dominates_its_europeans() {
+        let hw = HullWhite::new(flat_ts(0.05), 0.1, 0.01);
+        let tree = hw.tree(5.0, 100).unwrap();
+        let pay_steps = [20, 40, 60, 80, 100];
+        let strike = 0.05_f64.exp() - 1.0;
+
+        let bermudan = tree.bermudan_swaption(true, strike, &[20, 40, 60, 80], &pay_steps, 1.0);
+        let europeans: Vec<Real> = [20, 40, 60, 80]
+            .iter()
+            .map(|&e| tree.bermudan_swaption(true, strike, &[e], &pay_steps, 1.0))
+            .collect();
+        let best = europeans.iter().cloned().fold(0.0, Real::max);
+        assert!(
+            bermudan > best,
+            "Bermudan {bermudan} vs best European {best}"
+        );
+        assert!(bermudan < europeans.iter().sum::<Real>());
+
+        // Deep in the money, a payer swaption is worth the swap it enters.
+        let swap = 1.0
+            - tree.zero_coupon_bond(100)
+            - 0.01
+                * pay_steps
+                    .iter()
+                    .map(|&p| tree.zero_coupon_bond(p))
+                    .sum::<Real>();
+        let deep = tree.bermudan_swaption(true, 0.01, &[0], &pay_steps, 1.0);
+        assert!((deep - swap).abs() < 1e-12);
+    }
+
+    #[test]
+    fn caplet_kink_correction_across_strikes_and_steps() {
+        let ts = flat_ts(0.04);
+        let model = HullWhite::new(ts.clone(), 0.1, 0.01);
+        for steps in [50, 100, 200, 400] {
+            let tree = model.tree(5.0, steps).unwrap();
+            let (reset, pay) = (3 * steps / 5, 4 * steps / 5);
+            let (t_reset, t_pay) = (reset as Real * tree.dt(), pay as Real * tree.dt());
+            let forward = (ts.discount(t_reset) / ts.discount(t_pay) - 1.0) / (t_pay - t_reset);
+
+            let (mut corrected, mut uncorrected): (Real, Real) = (0.0, 0.0);
+            for moneyness in [0.5, 0.8, 1.0, 1.25, 1.5] {
+                let strike = moneyness * forward;
+                let expected = model.caplet(strike, t_reset, t_pay, 1.0);
+                let value = tree.caplet(strike, reset, pay, 1.0);
+                let k = 1.0 + strike * (t_pay - t_reset);
+                let payoff: Vec<Real> = tree
+                    .discount_bond_values(reset, pay)
+                    .iter()
+                    .map(|&p| 1.0 - k * p)
+                    .collect();
+                let raw = value - kink_correction(&payoff, &tree.arrow_debreu[reset]);
+                corrected = corrected.max((value / expected - 1.0).abs());
+                uncorrected = uncorrected.max((raw / expected - 1.0).abs());
+            }
+            assert!(
+                corrected < 0.2 / steps as Real && corrected < uncorrected / 3.0,
+                "{steps} steps: corrected {corrected:.2e}, uncorrected {uncorrected:.2e}"
+            );
+        }
+    }
+
     #[test]
     fn hw_diffusion_constant() {
         let hw = HullWhite::new(flat_ts(0.05), 0.1, 0.01);
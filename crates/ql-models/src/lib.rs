@@ -8,6 +8,8 @@
 //! CalibratedModel
 //! ├── ShortRateModel
 //! │   ├── OneFactorModel  → Vasicek, HullWhite, BlackKarasinski, CIR
+//! │                     (BlackDermanToy: tree-based;
+//! │                      CirPlusPlus: CIR shifted onto the curve)
 //! │   └── TwoFactorModel  → G2
 //! └── (equity models)     → HestonModel, BatesModel
 //! ```
@@ -17,11 +19,14 @@
 
 // ── Infrastructure ───────────────────────────────────────────────────────
 pub mod calibrated_model;
+pub mod calibration_helper;
 pub mod short_rate_model;
 
 // ── One-factor short-rate models ─────────────────────────────────────────
+pub mod black_derman_toy;
 pub mod black_karasinski;
 pub mod cox_ingersoll_ross;
+pub mod extended_cox_ingersoll_ross;
 pub mod hull_white_model;
 pub mod vasicek;
 
@@ -34,13 +39,18 @@ pub mod heston_model;
 
 // ── Re-exports ───────────────────────────────────────────────────────────
 pub use bates_model::BatesModel;
+pub use black_derman_toy::{BdtLattice, BlackDermanToy};
 pub use black_karasinski::BlackKarasinski;
 pub use calibrated_model::{
     BoundaryConstraint, CalibratedModel, Constraint, NoConstraint, Parameter, PositiveConstraint,
 };
+pub use calibration_helper::{
+    CalibrationBasket, CalibrationHelper, CalibrationReport, CalibrationWeighting,
+};
 pub use cox_ingersoll_ross::CoxIngersollRoss;
+pub use extended_cox_ingersoll_ross::CirPlusPlus;
 pub use g2_model::G2Model;
 pub use heston_model::HestonModel;
-pub use hull_white_model::HullWhite;
+pub use hull_white_model::{HullWhite, ShortRateTree, TreeAnalyticError};
 pub use short_rate_model::{OneFactorModel, ShortRateModel, TwoFactorModel};
 pub use vasicek::Vasicek;
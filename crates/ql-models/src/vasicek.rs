@@ -10,11 +10,15 @@
 //!
 //! where
 //! * `B(t,T) = (1 − e^{−a(T−t)}) / a`
-//! * `A(t,T) = exp((B−(T−t))(ab²−σ²/2)/a² − σ²B²/(4a))`
+//! * `A(t,T) = exp((B − (T−t))(b − σ²/(2a²)) − σ²B²/(4a))`
+//!
+//! and options on discount bonds have the Gaussian (Jamshidian) closed form
+//! of [`Vasicek::discount_bond_option`].
 
 use crate::calibrated_model::{CalibratedModel, Parameter, PositiveConstraint};
 use crate::short_rate_model::{OneFactorModel, ShortRateModel};
 use ql_core::{Real, Time};
+use ql_math::distributions::normal_cdf;
 use ql_processes::StochasticProcess1D;
 use ql_termstructures::YieldTermStructure;
 use std::sync::Arc;
@@ -82,11 +86,49 @@ impl Vasicek {
         let sigma2 = self.sigma * self.sigma;
 
         if self.a.abs() < 1e-12 {
-            -sigma2 * tau * tau * tau / 6.0
+            // Driftless Gaussian rate: the convexity term alone.
+            sigma2 * tau * tau * tau / 6.0
         } else {
             let a2 = self.a * self.a;
-            (b_val - tau) * (self.a * self.b - sigma2 / (2.0 * a2))
-                - sigma2 * b_val * b_val / (4.0 * self.a)
+            (b_val - tau) * (self.b - sigma2 / (2.0 * a2)) - sigma2 * b_val * b_val / (4.0 * self.a)
+        }
+    }
+
+    /// Price at time 0, with the short rate at `r₀`, of a European option
+    /// expiring at `maturity` on a unit zero-coupon bond maturing at
+    /// `bond_maturity`.
+    ///
+    /// The forward bond price is lognormal with total volatility
+    /// `σ_P = σ B(maturity, bond_maturity) √((1 − e^{−2a·maturity}) / 2a)`,
+    /// which gives a Black formula on the model's own discount bonds.
+    ///
+    /// Corresponds to `QuantLib::Vasicek::discountBondOption`.
+    pub fn discount_bond_option(
+        &self,
+        is_call: bool,
+        strike: Real,
+        maturity: Time,
+        bond_maturity: Time,
+    ) -> Real {
+        let p_maturity = self.discount_bond(0.0, maturity, self.r0);
+        let p_bond = self.discount_bond(0.0, bond_maturity, self.r0);
+        let b = self.b_function(maturity, bond_maturity);
+        let v = if self.a.abs() < 1e-12 {
+            self.sigma * b * maturity.sqrt()
+        } else {
+            self.sigma * b * ((1.0 - (-2.0 * self.a * maturity).exp()) / (2.0 * self.a)).sqrt()
+        };
+        let k = strike * p_maturity;
+        if v < 1e-15 {
+            let intrinsic = if is_call { p_bond - k } else { k - p_bond };
+            return intrinsic.max(0.0);
+        }
+        let d1 = (p_bond / k).ln() / v + 0.5 * v;
+        let d2 = d1 - v;
+        if is_call {
+            p_bond * normal_cdf(d1) - k * normal_cdf(d2)
+        } else {
+            k * normal_cdf(-d2) - p_bond * normal_cdf(-d1)
         }
     }
 }
@@ -201,6 +243,95 @@ mod tests {
         assert!(p < 1.0);
     }
 
+    #[test]
+    fn vasicek_bond_tends_to_driftless_limit() {
+        let (r, sigma, tau): (Real, Real, Time) = (0.04, 0.01, 5.0);
+        // Without mean reversion the rate is a Brownian motion, whose
+        // bond price is exp(−rτ + σ²τ³/6); without volatility either,
+        // continuous compounding at the short rate.
+        let driftless = (-r * tau + sigma * sigma * tau.powi(3) / 6.0).exp();
+        for a in [1e-3, 1e-4, 1e-5] {
+            let v = Vasicek::new(a, 0.06, sigma, r, flat_ts(r));
+            let p = v.discount_bond(1.0, 1.0 + tau, r);
+            assert!(
+                (p / driftless - 1.0).abs() < 20.0 * a,
+                "a = {a}: {p} vs {driftless}"
+            );
+        }
+        let v = Vasicek::new(0.0, 0.06, sigma, r, flat_ts(r));
+        assert!((v.discount_bond(0.0, tau, r) - driftless).abs() < 1e-15);
+        let v = Vasicek::new(1e-7, 0.06, 0.0, r, flat_ts(r));
+        assert!((v.discount_bond(0.0, tau, r) - (-r * tau).exp()).abs() < 1e-6);
+    }
+
+    /// A curve discounting with the Vasicek model's own bond prices.
+    #[derive(Debug)]
+    struct VasicekCurve(Vasicek);
+
+    impl ql_termstructures::TermStructure for VasicekCurve {
+        fn reference_date(&self) -> Date {
+            Date::from_ymd(2025, 1, 2).unwrap()
+        }
+
+        fn day_counter(&self) -> &dyn ql_time::DayCounter {
+            &Actual365Fixed
+        }
+
+        fn calendar(&self) -> &dyn ql_time::Calendar {
+            &ql_time::NullCalendar
+        }
+
+        fn max_date(&self) -> Date {
+            Date::MAX
+        }
+    }
+
+    impl YieldTermStructure for VasicekCurve {
+        fn discount_impl(&self, t: Time) -> Real {
+            self.0.discount_bond(0.0, t, self.0.r0)
+        }
+    }
+
+    #[test]
+    fn vasicek_bond_options_match_hull_white_tree() {
+        use crate::hull_white_model::HullWhite;
+
+        let (a, b, sigma, r0) = (0.1, 0.05, 0.015, 0.03);
+        let vasicek = Vasicek::new(a, b, sigma, r0, flat_ts(r0));
+        let curve: Arc<dyn YieldTermStructure> =
+            Arc::new(VasicekCurve(Vasicek::new(a, b, sigma, r0, flat_ts(r0))));
+        let hull_white = HullWhite::new(curve.clone(), a, sigma);
+
+        let (expiry, bond_maturity, steps) = (1.0, 2.0, 400);
+        let tree = hull_white.tree(bond_maturity, steps).unwrap();
+        let expiry_step = steps / 2;
+        let bonds = tree.discount_bond_values(expiry_step, steps);
+        let forward = curve.discount(bond_maturity) / curve.discount(expiry);
+        for strike in [0.97 * forward, forward, 1.02 * forward] {
+            for is_call in [true, false] {
+                let analytic = vasicek.discount_bond_option(is_call, strike, expiry, bond_maturity);
+                // A Hull-White model fitted to the Vasicek curve is the
+                // Vasicek model itself.
+                let fitted =
+                    hull_white.discount_bond_option(is_call, strike, expiry, bond_maturity);
+                assert!((analytic - fitted).abs() < 1e-6 * analytic.max(1e-3));
+
+                let lattice: Real = bonds
+                    .iter()
+                    .enumerate()
+                    .map(|(j, &p)| {
+                        let payoff = if is_call { p - strike } else { strike - p };
+                        tree.arrow_debreu(expiry_step, j) * payoff.max(0.0)
+                    })
+                    .sum();
+                assert!(
+                    (lattice - analytic).abs() < 5e-3 * analytic,
+                    "call = {is_call}, K = {strike}: {lattice} vs {analytic}"
+                );
+            }
+        }
+    }
+
     #[test]
     fn vasicek_b_function() {
         let v = Vasicek::new(0.1, 0.05, 0.01, 0.05, flat_ts(0.05));
@@ -0,0 +1,83 @@
+//! Hull-White trinomial tree benchmark against the model's closed forms.
+//!
+//! Builds a 200-step `ShortRateTree` for a grid of mean-reversion and
+//! volatility parameters and checks zero-coupon bonds and at-the-money
+//! caplets against `HullWhite::discount_bond_option`.
+
+use std::sync::Arc;
+
+use ql_core::Real;
+use ql_models::HullWhite;
+use ql_termstructures::{FlatForward, InterpolatedZeroCurve, Linear, YieldTermStructure};
+use ql_time::{Actual365Fixed, Date};
+
+const STEPS: usize = 200;
+
+fn ref_date() -> Date {
+    Date::from_ymd(2025, 1, 2).unwrap()
+}
+
+fn flat_curve() -> Arc<dyn YieldTermStructure> {
+    Arc::new(FlatForward::continuous(ref_date(), 0.04, Actual365Fixed))
+}
+
+fn upward_curve() -> Arc<dyn YieldTermStructure> {
+    let d = ref_date();
+    let dates = [d, d + 365, d + 2 * 365, d + 5 * 365, d + 10 * 365];
+    let rates = [0.02, 0.025, 0.03, 0.038, 0.045];
+    Arc::new(InterpolatedZeroCurve::new(&dates, &rates, Actual365Fixed, &Linear).unwrap())
+}
+
+#[test]
+fn tree_matches_analytic_across_parameters() {
+    for curve in [flat_curve(), upward_curve()] {
+        for a in [0.01, 0.05, 0.1, 0.3] {
+            for sigma in [0.005, 0.01, 0.02] {
+                let model = HullWhite::new(curve.clone(), a, sigma);
+                let error = model.tree_vs_analytic_error(10.0, STEPS).unwrap();
+                assert!(
+                    error.zero_bond < 1e-6,
+                    "a={a}, sigma={sigma}: zero-bond error {:.2e}",
+                    error.zero_bond
+                );
+                assert!(
+                    error.caplet < 1e-3,
+                    "a={a}, sigma={sigma}: caplet error {:.2e}",
+                    error.caplet
+                );
+            }
+        }
+    }
+}
+
+#[test]
+fn tree_regression_values() {
+    let model = HullWhite::new(upward_curve(), 0.1, 0.01);
+    let tree = model.tree(10.0, STEPS).unwrap();
+
+    // Without truncation the grid widens until mean reversion pulls the
+    // central branch back, near |j| = 100 for a·dt = 0.005.
+    assert_eq!(tree.size(100), 201);
+    assert_eq!(tree.size(STEPS), 203);
+
+    let checks: [(&str, Real, Real); 4] = [
+        ("alpha at 5Y", tree.alpha(100), 0.045840233024),
+        ("top node at 5Y", tree.rate(100, 200), 0.431208113414),
+        (
+            "3Y-4Y caplet",
+            tree.caplet(0.04, 60, 80, 1.0),
+            0.007231469924,
+        ),
+        (
+            "9Y-10Y caplet",
+            tree.caplet(0.05, 180, 200, 1.0),
+            0.008704276217,
+        ),
+    ];
+    for (name, value, expected) in checks {
+        assert!(
+            (value - expected).abs() < 1e-11,
+            "{name}: {value:.12} vs {expected:.12}"
+        );
+    }
+}
@@ -2,14 +2,16 @@
 //!
 //! Translates `ql/pricingengines/barrier/analyticbarrierengine.hpp`.
 //!
-//! Prices European single-barrier options using the closed-form solution
-//! from Reiner & Rubinstein (1991).
+//! Prices European single-barrier options, on plain-vanilla or
+//! cash-or-nothing payoffs, using the closed-form solutions of Reiner &
+//! Rubinstein (1991).
 
 use std::sync::Arc;
 
-use ql_core::{errors::Result, Real};
+use ql_core::{ensure, errors::Result, fail, patterns::visitor::Visitor, Real};
 use ql_instruments::{
-    BarrierOptionArguments, BarrierType, OptionType, PricingEngine, PricingResults,
+    AssetOrNothingPayoff, BarrierOptionArguments, BarrierType, CashOrNothingPayoff, GapPayoff,
+    OptionType, PlainVanillaPayoff, PricingEngine, PricingResults,
 };
 use ql_math::distributions::normal_cdf;
 use ql_processes::GeneralizedBlackScholesProcess;
@@ -23,6 +25,10 @@ use ql_processes::GeneralizedBlackScholesProcess;
 ///
 /// $$C_{\text{do}} = C_{\text{BS}} - C_{\text{di}}$$
 ///
+/// As in QuantLib, pricing fails once the spot has touched the barrier,
+/// for every payoff: a knocked-in or knocked-out option is no longer a
+/// barrier option.
+///
 /// Corresponds to `QuantLib::AnalyticBarrierEngine`.
 #[derive(Debug)]
 pub struct AnalyticBarrierEngine {
@@ -68,8 +74,6 @@ pub fn analytic_barrier_price(
     let sigma2 = sigma * sigma;
     let sqrt_t = t.sqrt();
     let mu = (r - q - 0.5 * sigma2) / sigma2;
-    let lambda = (mu * mu * sigma2 + 2.0 * r).sqrt() / sigma;
-    let z = (barrier / spot).ln() / (sigma * sqrt_t) + lambda * sigma * sqrt_t;
 
     let phi = option_type.sign(); // +1 call, -1 put
     let eta = match barrier_type {
@@ -107,15 +111,8 @@ pub fn analytic_barrier_price(
             * (barrier / spot).powf(2.0 * mu)
             * normal_cdf(eta * y2 - eta * sigma * sqrt_t);
 
-    let e = rebate
-        * df_r
-        * (normal_cdf(eta * x2 - eta * sigma * sqrt_t)
-            - (barrier / spot).powf(2.0 * mu) * normal_cdf(eta * y2 - eta * sigma * sqrt_t));
-
-    let f = rebate
-        * ((barrier / spot).powf(mu + lambda) * normal_cdf(eta * z)
-            + (barrier / spot).powf(mu - lambda)
-                * normal_cdf(eta * z - 2.0 * eta * lambda * sigma * sqrt_t));
+    let e = rebate_if_untouched(eta, spot, barrier, rebate, r, q, sigma, t);
+    let f = rebate_at_hit(eta, spot, barrier, rebate, r, q, sigma, t);
 
     // Combine based on barrier type and option type
     match (barrier_type, option_type) {
@@ -145,9 +142,133 @@ pub fn analytic_barrier_price(
     }
 }
 
+/// Closed-form price of a binary barrier option paying `cash` at expiry
+/// if it ends in the money (Reiner-Rubinstein, as tabulated by Haug).
+///
+/// With `B₁ = c·e^{−rT}N(φx₁ − φσ√T)` the plain cash-or-nothing price and
+/// `B₂`…`B₄` its barrier-reflected counterparts, each of the eight
+/// barrier/option types is a sum of these terms, plus the `rebate` paid at
+/// expiry for knock-ins that never knock in or at the hit for knock-outs.
+pub fn binary_barrier_price(
+    option_type: OptionType,
+    barrier_type: BarrierType,
+    spot: Real,
+    strike: Real,
+    cash: Real,
+    barrier: Real,
+    rebate: Real,
+    r: Real,
+    q: Real,
+    sigma: Real,
+    t: Real,
+) -> Real {
+    let phi = option_type.sign();
+    if t <= 0.0 {
+        let in_the_money = phi * (spot - strike) > 0.0;
+        return match barrier_type {
+            BarrierType::DownOut | BarrierType::UpOut if in_the_money => cash,
+            _ => 0.0,
+        };
+    }
+
+    let std_dev = sigma * t.sqrt();
+    let mu = (r - q - 0.5 * sigma * sigma) / (sigma * sigma);
+    let eta = match barrier_type {
+        BarrierType::DownIn | BarrierType::DownOut => 1.0,
+        BarrierType::UpIn | BarrierType::UpOut => -1.0,
+    };
+
+    let x1 = (spot / strike).ln() / std_dev + (1.0 + mu) * std_dev;
+    let x2 = (spot / barrier).ln() / std_dev + (1.0 + mu) * std_dev;
+    let y1 = (barrier * barrier / (spot * strike)).ln() / std_dev + (1.0 + mu) * std_dev;
+    let y2 = (barrier / spot).ln() / std_dev + (1.0 + mu) * std_dev;
+
+    let cash_df = cash * (-r * t).exp();
+    let reflection = (barrier / spot).powf(2.0 * mu);
+    let b1 = cash_df * normal_cdf(phi * (x1 - std_dev));
+    let b2 = cash_df * normal_cdf(phi * (x2 - std_dev));
+    let b3 = cash_df * reflection * normal_cdf(eta * (y1 - std_dev));
+    let b4 = cash_df * reflection * normal_cdf(eta * (y2 - std_dev));
+
+    let e = rebate_if_untouched(eta, spot, barrier, rebate, r, q, sigma, t);
+    let f = rebate_at_hit(eta, spot, barrier, rebate, r, q, sigma, t);
+
+    let above = strike > barrier;
+    match (barrier_type, option_type) {
+        (BarrierType::DownIn, OptionType::Call) if above => b3 + e,
+        (BarrierType::DownIn, OptionType::Call) => b1 - b2 + b4 + e,
+        (BarrierType::UpIn, OptionType::Call) if above => b1 + e,
+        (BarrierType::UpIn, OptionType::Call) => b2 - b3 + b4 + e,
+        (BarrierType::DownIn, OptionType::Put) if above => b2 - b3 + b4 + e,
+        (BarrierType::DownIn, OptionType::Put) => b1 + e,
+        (BarrierType::UpIn, OptionType::Put) if above => b1 - b2 + b4 + e,
+        (BarrierType::UpIn, OptionType::Put) => b3 + e,
+
+        (BarrierType::DownOut, OptionType::Call) if above => b1 - b3 + f,
+        (BarrierType::DownOut, OptionType::Call) => b2 - b4 + f,
+        (BarrierType::UpOut, OptionType::Call) if above => f,
+        (BarrierType::UpOut, OptionType::Call) => b1 - b2 + b3 - b4 + f,
+        (BarrierType::DownOut, OptionType::Put) if above => b1 - b2 + b3 - b4 + f,
+        (BarrierType::DownOut, OptionType::Put) => f,
+        (BarrierType::UpOut, OptionType::Put) if above => b2 - b4 + f,
+        (BarrierType::UpOut, OptionType::Put) => b1 - b3 + f,
+    }
+}
+
+/// Value of `rebate` paid at expiry if the barrier is never touched: the
+/// `E` term of Reiner-Rubinstein, with `eta = 1` for a down barrier and
+/// `−1` for an up barrier.
+pub(crate) fn rebate_if_untouched(
+    eta: Real,
+    spot: Real,
+    barrier: Real,
+    rebate: Real,
+    r: Real,
+    q: Real,
+    sigma: Real,
+    t: Real,
+) -> Real {
+    let std_dev = sigma * t.sqrt();
+    let mu = (r - q - 0.5 * sigma * sigma) / (sigma * sigma);
+    let x2 = (spot / barrier).ln() / std_dev + (1.0 + mu) * std_dev;
+    let y2 = (barrier / spot).ln() / std_dev + (1.0 + mu) * std_dev;
+    rebate
+        * (-r * t).exp()
+        * (normal_cdf(eta * (x2 - std_dev))
+            - (barrier / spot).powf(2.0 * mu) * normal_cdf(eta * (y2 - std_dev)))
+}
+
+/// Value of `rebate` paid as soon as the barrier is touched: the `F` term
+/// of Reiner-Rubinstein, the Laplace transform of the first-passage time.
+pub(crate) fn rebate_at_hit(
+    eta: Real,
+    spot: Real,
+    barrier: Real,
+    rebate: Real,
+    r: Real,
+    q: Real,
+    sigma: Real,
+    t: Real,
+) -> Real {
+    let std_dev = sigma * t.sqrt();
+    let mu = (r - q - 0.5 * sigma * sigma) / (sigma * sigma);
+    let lambda = (mu * mu + 2.0 * r / (sigma * sigma)).sqrt();
+    let z = (barrier / spot).ln() / std_dev + lambda * std_dev;
+    rebate
+        * ((barrier / spot).powf(mu + lambda) * normal_cdf(eta * z)
+            + (barrier / spot).powf(mu - lambda)
+                * normal_cdf(eta * z - 2.0 * eta * lambda * std_dev))
+}
+
 impl PricingEngine<BarrierOptionArguments> for AnalyticBarrierEngine {
     fn calculate(&self, args: &BarrierOptionArguments) -> Result<PricingResults> {
         let spot = self.process.spot();
+        ensure!(args.barrier > 0.0, "barrier must be positive");
+        let touched = match args.barrier_type {
+            BarrierType::DownIn | BarrierType::DownOut => spot <= args.barrier,
+            BarrierType::UpIn | BarrierType::UpOut => spot >= args.barrier,
+        };
+        ensure!(!touched, "barrier {} already touched", args.barrier);
         let strike = args.payoff.strike();
         let option_type = args.payoff.option_type();
         let expiry = args.exercise.last_date();
@@ -164,23 +285,69 @@ impl PricingEngine<BarrierOptionArguments> for AnalyticBarrierEngine {
             .expect("process must have a black vol surface")
             .black_vol_time(t, strike);
 
-        let price = analytic_barrier_price(
-            option_type,
-            args.barrier_type,
-            spot,
-            strike,
-            args.barrier,
-            args.rebate,
-            r,
-            q,
-            sigma,
-            t,
-        );
+        let mut payoff = BarrierPayoff::default();
+        args.payoff.accept(&mut payoff);
+        let price = match payoff.0 {
+            Some(None) => analytic_barrier_price(
+                option_type,
+                args.barrier_type,
+                spot,
+                strike,
+                args.barrier,
+                args.rebate,
+                r,
+                q,
+                sigma,
+                t,
+            ),
+            Some(Some(cash_payoff)) => binary_barrier_price(
+                option_type,
+                args.barrier_type,
+                spot,
+                strike,
+                cash_payoff,
+                args.barrier,
+                args.rebate,
+                r,
+                q,
+                sigma,
+                t,
+            ),
+            None => fail!(
+                "unsupported payoff for analytic barrier engine: {}",
+                args.payoff.name()
+            ),
+        };
 
         Ok(PricingResults::from_npv(price))
     }
 }
 
+/// The payoffs the barrier engine prices: `Some(None)` for plain vanilla,
+/// `Some(Some(cash))` for cash-or-nothing, `None` for anything else.
+#[derive(Debug, Default)]
+struct BarrierPayoff(Option<Option<Real>>);
+
+impl Visitor<PlainVanillaPayoff> for BarrierPayoff {
+    fn visit(&mut self, _: &PlainVanillaPayoff) {
+        self.0 = Some(None);
+    }
+}
+
+impl Visitor<CashOrNothingPayoff> for BarrierPayoff {
+    fn visit(&mut self, payoff: &CashOrNothingPayoff) {
+        self.0 = Some(Some(payoff.cash_payoff));
+    }
+}
+
+impl Visitor<AssetOrNothingPayoff> for BarrierPayoff {
+    fn visit(&mut self, _: &AssetOrNothingPayoff) {}
+}
+
+impl Visitor<GapPayoff> for BarrierPayoff {
+    fn visit(&mut self, _: &GapPayoff) {}
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -342,4 +509,171 @@ mod tests {
             assert!(p >= 0.0, "barrier={bt:?}, price={p}");
         }
     }
+
+    /// Cash-or-nothing price `c·e^{−rT}·N(φd₂)`.
+    fn plain_binary(option_type: OptionType, strike: Real, cash: Real) -> Real {
+        let (s, _, r, q, sigma, t) = params();
+        let phi = option_type.sign();
+        let d2 = ((s / strike).ln() + (r - q - 0.5 * sigma * sigma) * t) / (sigma * t.sqrt());
+        cash * (-r * t).exp() * normal_cdf(phi * d2)
+    }
+
+    #[test]
+    fn binary_in_plus_out_equals_plain_binary() {
+        let (s, _, r, q, sigma, t) = params();
+        for (knock_in, knock_out, barrier) in [
+            (BarrierType::DownIn, BarrierType::DownOut, 90.0),
+            (BarrierType::UpIn, BarrierType::UpOut, 110.0),
+        ] {
+            for option_type in [OptionType::Call, OptionType::Put] {
+                // Strikes on both sides of the barrier select different terms.
+                for strike in [85.0, 100.0, 115.0] {
+                    let price = |bt| {
+                        binary_barrier_price(
+                            option_type,
+                            bt,
+                            s,
+                            strike,
+                            10.0,
+                            barrier,
+                            0.0,
+                            r,
+                            q,
+                            sigma,
+                            t,
+                        )
+                    };
+                    let (p_in, p_out) = (price(knock_in), price(knock_out));
+                    let plain = plain_binary(option_type, strike, 10.0);
+                    assert!(p_in >= -1e-12 && p_out >= -1e-12);
+                    assert!(
+                        (p_in + p_out - plain).abs() < 1e-12,
+                        "{knock_in:?}/{knock_out:?} {option_type:?} K={strike}: \
+                         {p_in} + {p_out} vs {plain}"
+                    );
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn binary_barrier_limits() {
+        let (s, _, r, q, sigma, t) = params();
+        let price = |option_type, bt, strike, barrier| {
+            binary_barrier_price(
+                option_type,
+                bt,
+                s,
+                strike,
+                1.0,
+                barrier,
+                0.0,
+                r,
+                q,
+                sigma,
+                t,
+            )
+        };
+        // A remote barrier is never reached...
+        let plain_call = plain_binary(OptionType::Call, 105.0, 1.0);
+        for (bt, barrier) in [(BarrierType::DownOut, 1.0), (BarrierType::UpOut, 1e4)] {
+            let knocked = price(OptionType::Call, bt, 105.0, barrier);
+            assert!((knocked - plain_call).abs() < 1e-12, "{bt:?}: {knocked}");
+        }
+        // ...and one next to the spot is reached at once.
+        let plain_put = plain_binary(OptionType::Put, 95.0, 1.0);
+        let knocked_in = price(OptionType::Put, BarrierType::UpIn, 95.0, 100.0001);
+        assert!((knocked_in - plain_put).abs() < 1e-3, "{knocked_in}");
+    }
+
+    #[test]
+    fn down_and_in_binary_put_rises_as_barrier_nears_spot() {
+        let (s, k, r, q, sigma, t) = params();
+        let prices: Vec<Real> = [70.0, 80.0, 90.0, 95.0, 99.0]
+            .iter()
+            .map(|&barrier| {
+                binary_barrier_price(
+                    OptionType::Put,
+                    BarrierType::DownIn,
+                    s,
+                    k,
+                    1.0,
+                    barrier,
+                    0.0,
+                    r,
+                    q,
+                    sigma,
+                    t,
+                )
+            })
+            .collect();
+        assert!(prices.windows(2).all(|w| w[1] > w[0]), "{prices:?}");
+    }
+
+    #[test]
+    fn engine_dispatches_on_payoff() {
+        use ql_instruments::{Exercise, StrikedPayoff};
+        use ql_termstructures::{BlackConstantVol, FlatForward};
+        use ql_time::{Actual365Fixed, Date};
+
+        let (s, k, r, q, sigma, _) = params();
+        let ref_date = Date::from_ymd(2025, 1, 2).unwrap();
+        let engine = AnalyticBarrierEngine::new(Arc::new(GeneralizedBlackScholesProcess::new(
+            s,
+            Arc::new(FlatForward::continuous(ref_date, r, Actual365Fixed)),
+            Arc::new(FlatForward::continuous(ref_date, q, Actual365Fixed)),
+            Arc::new(BlackConstantVol::new(ref_date, sigma, Actual365Fixed)),
+        )));
+        let args = |payoff: Arc<dyn StrikedPayoff>, barrier_type, barrier| BarrierOptionArguments {
+            payoff,
+            exercise: Exercise::european(Date::from_ymd(2026, 1, 2).unwrap()),
+            barrier_type,
+            barrier,
+            rebate: 0.0,
+        };
+
+        let binary = Arc::new(CashOrNothingPayoff::new(OptionType::Put, k, 10.0));
+        let npv = engine
+            .calculate(&args(binary.clone(), BarrierType::DownIn, 90.0))
+            .unwrap()
+            .npv;
+        let expected = binary_barrier_price(
+            OptionType::Put,
+            BarrierType::DownIn,
+            s,
+            k,
+            10.0,
+            90.0,
+            0.0,
+            r,
+            q,
+            sigma,
+            1.0,
+        );
+        assert!((npv - expected).abs() < 1e-12);
+
+        let asset = Arc::new(AssetOrNothingPayoff::new(OptionType::Put, k));
+        assert!(engine
+            .calculate(&args(asset, BarrierType::DownIn, 90.0))
+            .is_err());
+        // A down barrier above the spot has already been crossed, and an up
+        // barrier below it, whatever the payoff.
+        assert!(engine
+            .calculate(&args(binary, BarrierType::DownOut, 105.0))
+            .is_err());
+        let vanilla = Arc::new(PlainVanillaPayoff::new(OptionType::Call, k));
+        for (barrier_type, barrier) in [
+            (BarrierType::DownIn, s),
+            (BarrierType::DownOut, 105.0),
+            (BarrierType::UpIn, 95.0),
+            (BarrierType::UpOut, s),
+        ] {
+            assert!(engine
+                .calculate(&args(vanilla.clone(), barrier_type, barrier))
+                .is_err());
+        }
+        assert!(engine
+            .calculate(&args(vanilla, BarrierType::UpOut, 120.0))
+            .is_ok());
+    }
 }
@@ -0,0 +1,199 @@
+//! Analytic engine for European digital options.
+//!
+//! QuantLib prices these payoffs in `AnalyticEuropeanEngine` through
+//! `BlackCalculator`; here they have an engine of their own, which
+//! recognises the payoff with a
+//! [`PayoffVisitor`](ql_instruments::PayoffVisitor) and rejects the ones
+//! it cannot price.
+//!
+//! With `D` the discount factor, `F` the forward and `d₁,₂` taken at the
+//! (trigger) strike `K`:
+//!
+//! * cash-or-nothing: `C D N(φ d₂)`
+//! * asset-or-nothing: `F D N(φ d₁)`
+//! * gap, paying `φ(S − K₂)`: `φ D (F N(φ d₁) − K₂ N(φ d₂))`
+
+use crate::black_calculator::BlackCalculator;
+use ql_core::{ensure, errors::Result, fail, patterns::visitor::Visitor, Real};
+use ql_instruments::{
+    AssetOrNothingPayoff, CashOrNothingPayoff, ExerciseType, GapPayoff, Payoff, PlainVanillaPayoff,
+    PricingEngine, PricingResults, VanillaOptionArguments,
+};
+use ql_processes::GeneralizedBlackScholesProcess;
+use std::sync::Arc;
+
+/// Analytic pricing engine for European cash-or-nothing, asset-or-nothing
+/// and gap options.
+#[derive(Debug)]
+pub struct AnalyticDigitalEngine {
+    process: Arc<GeneralizedBlackScholesProcess>,
+}
+
+impl AnalyticDigitalEngine {
+    /// Create a new engine with the given Black-Scholes process.
+    pub fn new(process: Arc<GeneralizedBlackScholesProcess>) -> Self {
+        Self { process }
+    }
+}
+
+/// The digital payoffs the engine prices.
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum DigitalPayoff {
+    CashOrNothing { cash: Real },
+    AssetOrNothing,
+    Gap { second_strike: Real },
+}
+
+/// Visitor sorting a payoff into a [`DigitalPayoff`], or into the name of
+/// a payoff that is not one.
+#[derive(Debug, Default)]
+struct DigitalPayoffVisitor(Option<std::result::Result<DigitalPayoff, &'static str>>);
+
+impl Visitor<PlainVanillaPayoff> for DigitalPayoffVisitor {
+    fn visit(&mut self, _: &PlainVanillaPayoff) {
+        self.0 = Some(Err("plain-vanilla"));
+    }
+}
+
+impl Visitor<CashOrNothingPayoff> for DigitalPayoffVisitor {
+    fn visit(&mut self, payoff: &CashOrNothingPayoff) {
+        self.0 = Some(Ok(DigitalPayoff::CashOrNothing {
+            cash: payoff.cash_payoff,
+        }));
+    }
+}
+
+impl Visitor<AssetOrNothingPayoff> for DigitalPayoffVisitor {
+    fn visit(&mut self, _: &AssetOrNothingPayoff) {
+        self.0 = Some(Ok(DigitalPayoff::AssetOrNothing));
+    }
+}
+
+impl Visitor<GapPayoff> for DigitalPayoffVisitor {
+    fn visit(&mut self, payoff: &GapPayoff) {
+        self.0 = Some(Ok(DigitalPayoff::Gap {
+            second_strike: payoff.second_strike,
+        }));
+    }
+}
+
+impl DigitalPayoff {
+    fn of(payoff: &dyn Payoff) -> Result<Self> {
+        let mut visitor = DigitalPayoffVisitor::default();
+        payoff.accept(&mut visitor);
+        match visitor.0 {
+            Some(Ok(digital)) => Ok(digital),
+            Some(Err(kind)) => fail!("analytic digital engine cannot price a {kind} payoff"),
+            None => fail!(
+                "analytic digital engine cannot price payoff {}",
+                payoff.name()
+            ),
+        }
+    }
+}
+
+impl PricingEngine<VanillaOptionArguments> for AnalyticDigitalEngine {
+    fn calculate(&self, args: &VanillaOptionArguments) -> Result<PricingResults> {
+        ensure!(
+            args.exercise.exercise_type() == ExerciseType::European,
+            "analytic digital engine requires European exercise"
+        );
+        let digital = DigitalPayoff::of(args.payoff.as_ref())?;
+
+        let spot = self.process.spot();
+        let strike = args.payoff.strike();
+        let ref_date = self.process.risk_free_rate().reference_date();
+        let dc = self.process.risk_free_rate().day_counter();
+        let t = dc.year_fraction(ref_date, args.exercise.last_date());
+
+        let r = self.process.risk_free_rate().zero_rate_impl(t);
+        let q = self.process.dividend_yield().zero_rate_impl(t);
+        let sigma = self
+            .process
+            .black_volatility()
+            .expect("process must have a black vol surface")
+            .black_vol_time(t, strike);
+
+        let forward = spot * ((r - q) * t).exp();
+        let discount = (-r * t).exp();
+        let black = BlackCalculator::new(
+            args.payoff.option_type(),
+            strike,
+            forward,
+            sigma * t.sqrt(),
+            discount,
+        )?;
+        let phi = args.payoff.option_type().sign();
+        let cash_or_nothing = discount * black.itm_cash_probability();
+        let asset_or_nothing = forward * discount * black.itm_asset_probability();
+
+        let npv = match digital {
+            DigitalPayoff::CashOrNothing { cash } => cash * cash_or_nothing,
+            DigitalPayoff::AssetOrNothing => asset_or_nothing,
+            DigitalPayoff::Gap { second_strike } => {
+                phi * (asset_or_nothing - second_strike * cash_or_nothing)
+            }
+        };
+        Ok(PricingResults::from_npv(npv))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::analytic_european_engine::black_scholes_merton;
+    use ql_instruments::{Exercise, OptionType, StrikedPayoff};
+    use ql_termstructures::{BlackConstantVol, FlatForward};
+    use ql_time::{Actual365Fixed, Date};
+
+    fn engine() -> (AnalyticDigitalEngine, Date) {
+        let today = Date::from_ymd(2025, 1, 2).unwrap();
+        let process = GeneralizedBlackScholesProcess::new(
+            100.0,
+            Arc::new(FlatForward::continuous(today, 0.05, Actual365Fixed)),
+            Arc::new(FlatForward::continuous(today, 0.02, Actual365Fixed)),
+            Arc::new(BlackConstantVol::new(today, 0.25, Actual365Fixed)),
+        );
+        (AnalyticDigitalEngine::new(Arc::new(process)), today + 365)
+    }
+
+    fn npv(payoff: Arc<dyn StrikedPayoff>) -> Result<Real> {
+        let (engine, expiry) = engine();
+        let args = VanillaOptionArguments {
+            payoff,
+            exercise: Exercise::european(expiry),
+        };
+        Ok(engine.calculate(&args)?.npv)
+    }
+
+    #[test]
+    fn digitals_decompose_the_vanilla() {
+        for option_type in [OptionType::Call, OptionType::Put] {
+            let phi = option_type.sign();
+            let (vanilla, ..) =
+                black_scholes_merton(option_type, 100.0, 105.0, 0.05, 0.02, 0.25, 1.0);
+            let asset = npv(Arc::new(AssetOrNothingPayoff::new(option_type, 105.0))).unwrap();
+            let cash = npv(Arc::new(CashOrNothingPayoff::new(option_type, 105.0, 1.0))).unwrap();
+            assert!((phi * (asset - 105.0 * cash) - vanilla).abs() < 1e-10);
+
+            // A gap whose strikes coincide is the vanilla.
+            let gap = npv(Arc::new(GapPayoff::new(option_type, 105.0, 105.0))).unwrap();
+            assert!(
+                (gap - vanilla).abs() < 1e-10,
+                "{option_type:?}: {gap} vs {vanilla}"
+            );
+            // Moving the payoff strike trades cash-or-nothing units.
+            let shifted = npv(Arc::new(GapPayoff::new(option_type, 105.0, 100.0))).unwrap();
+            assert!((shifted - gap - phi * 5.0 * cash).abs() < 1e-10);
+        }
+    }
+
+    #[test]
+    fn plain_vanilla_payoff_is_rejected() {
+        let err = npv(Arc::new(PlainVanillaPayoff::new(OptionType::Call, 100.0))).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "analytic digital engine cannot price a plain-vanilla payoff"
+        );
+    }
+}
@@ -8,6 +8,7 @@
 use ql_core::{errors::Result, Real};
 use ql_instruments::{OptionType, PricingEngine, PricingResults, VanillaOptionArguments};
 use ql_math::distributions::{normal_cdf, normal_pdf};
+use ql_math::dual::Scalar;
 use ql_processes::GeneralizedBlackScholesProcess;
 
 use std::sync::Arc;
@@ -34,10 +35,30 @@ impl AnalyticEuropeanEngine {
     }
 }
 
-/// Compute Black-Scholes price and Greeks for a European option.
+/// Black-Scholes-Merton price and sensitivities of a European option.
 ///
-/// Returns `(price, delta, gamma, vega, theta, rho)`.
-pub fn black_scholes_merton(
+/// All sensitivities are per unit change of the input: `vega` per 1.0 of
+/// volatility, `rho`/`dividend_rho` per 1.0 of rate, and `theta` per year.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct BlackScholesGreeks {
+    /// Option value.
+    pub price: Real,
+    /// ∂V/∂S.
+    pub delta: Real,
+    /// ∂²V/∂S².
+    pub gamma: Real,
+    /// ∂V/∂σ.
+    pub vega: Real,
+    /// ∂V/∂t (calendar time, per year) — the decay as expiry approaches.
+    pub theta: Real,
+    /// ∂V/∂r.
+    pub rho: Real,
+    /// ∂V/∂q.
+    pub dividend_rho: Real,
+}
+
+/// Compute Black-Scholes-Merton price and Greeks for a European option.
+pub fn black_scholes_merton_greeks(
     option_type: OptionType,
     spot: Real,
     strike: Real,
@@ -45,13 +66,21 @@ pub fn black_scholes_merton(
     dividend_yield: Real,
     volatility: Real,
     time_to_expiry: Real,
-) -> (Real, Real, Real, Real, Real, Real) {
+) -> BlackScholesGreeks {
     let phi = option_type.sign();
     let t = time_to_expiry;
 
     if t <= 0.0 {
         let intrinsic = (phi * (spot - strike)).max(0.0);
-        return (intrinsic, 0.0, 0.0, 0.0, 0.0, 0.0);
+        return BlackScholesGreeks {
+            price: intrinsic,
+            delta: 0.0,
+            gamma: 0.0,
+            vega: 0.0,
+            theta: 0.0,
+            rho: 0.0,
+            dividend_rho: 0.0,
+        };
     }
 
     let r = risk_free_rate;
@@ -76,14 +105,6 @@ pub fn black_scholes_merton(
     let nd2 = normal_cdf(phi * d2);
     let npd1 = normal_pdf(d1);
 
-    // Price
-    let price = phi * (spot * df_q * nd1 - strike * df_r * nd2);
-    // Delta
-    let delta = phi * df_q * nd1;
-    // Gamma
-    let gamma = df_q * npd1 / (spot * std_dev);
-    // Vega (per 1.0 absolute vol, not per 1%)
-    let vega = spot * df_q * npd1 * sqrt_t;
     // Theta (per year)
     let theta = {
         let term1 = -(spot * df_q * npd1 * sigma) / (2.0 * sqrt_t);
@@ -91,10 +112,98 @@ pub fn black_scholes_merton(
         let term3 = phi * q * spot * df_q * nd1;
         term1 + term2 + term3
     };
-    // Rho (per 1.0 rate shift)
-    let rho = phi * strike * t * df_r * nd2;
 
-    (price, delta, gamma, vega, theta, rho)
+    BlackScholesGreeks {
+        price: black_scholes_merton_value(
+            option_type,
+            spot,
+            strike,
+            risk_free_rate,
+            dividend_yield,
+            volatility,
+            time_to_expiry,
+        ),
+        delta: phi * df_q * nd1,
+        gamma: df_q * npd1 / (spot * std_dev),
+        vega: spot * df_q * npd1 * sqrt_t,
+        theta,
+        rho: phi * strike * t * df_r * nd2,
+        dividend_rho: -phi * spot * t * df_q * nd1,
+    }
+}
+
+/// Black-Scholes-Merton price of a European option, generic over the
+/// number type.
+///
+/// With [`Real`] inputs this is the plain price. Seeding one input as a
+/// [`Dual`](ql_math::dual::Dual) variable and the others as constants yields
+/// the exact derivative with respect to that input alongside the price —
+/// e.g. delta with `Dual::variable(spot)`, vega with
+/// `Dual::variable(volatility)`.
+pub fn black_scholes_merton_value<S: Scalar>(
+    option_type: OptionType,
+    spot: S,
+    strike: S,
+    risk_free_rate: S,
+    dividend_yield: S,
+    volatility: S,
+    time_to_expiry: S,
+) -> S {
+    let phi = S::from_real(option_type.sign());
+    if time_to_expiry.value() <= 0.0 {
+        let intrinsic = phi * (spot - strike);
+        return if intrinsic.value() > 0.0 {
+            intrinsic
+        } else {
+            S::from_real(0.0)
+        };
+    }
+
+    let t = time_to_expiry;
+    let df_r = (-risk_free_rate * t).exp();
+    let df_q = (-dividend_yield * t).exp();
+    let std_dev = volatility * t.sqrt();
+    if std_dev.value() <= 1e-15 {
+        // Zero volatility: the discounted forward intrinsic value.
+        let value = phi * (spot * df_q - strike * df_r);
+        return if value.value() > 0.0 {
+            value
+        } else {
+            S::from_real(0.0)
+        };
+    }
+
+    let half = S::from_real(0.5);
+    let d1 = ((spot / strike).ln()
+        + (risk_free_rate - dividend_yield + half * volatility * volatility) * t)
+        / std_dev;
+    let d2 = d1 - std_dev;
+    phi * (spot * df_q * (phi * d1).normal_cdf() - strike * df_r * (phi * d2).normal_cdf())
+}
+
+/// Compute Black-Scholes price and Greeks for a European option.
+///
+/// Returns `(price, delta, gamma, vega, theta, rho)`; see
+/// [`black_scholes_merton_greeks`] for the named form.
+pub fn black_scholes_merton(
+    option_type: OptionType,
+    spot: Real,
+    strike: Real,
+    risk_free_rate: Real,
+    dividend_yield: Real,
+    volatility: Real,
+    time_to_expiry: Real,
+) -> (Real, Real, Real, Real, Real, Real) {
+    let g = black_scholes_merton_greeks(
+        option_type,
+        spot,
+        strike,
+        risk_free_rate,
+        dividend_yield,
+        volatility,
+        time_to_expiry,
+    );
+    (g.price, g.delta, g.gamma, g.vega, g.theta, g.rho)
 }
 
 impl PricingEngine<VanillaOptionArguments> for AnalyticEuropeanEngine {
@@ -119,15 +228,15 @@ impl PricingEngine<VanillaOptionArguments> for AnalyticEuropeanEngine {
             .expect("process must have a black vol surface")
             .black_vol_time(t, strike);
 
-        let (price, delta, gamma, vega, theta, rho) =
-            black_scholes_merton(option_type, spot, strike, r, q, sigma, t);
+        let greeks = black_scholes_merton_greeks(option_type, spot, strike, r, q, sigma, t);
 
-        Ok(PricingResults::from_npv(price)
-            .with_result("delta", delta)
-            .with_result("gamma", gamma)
-            .with_result("vega", vega)
-            .with_result("theta", theta)
-            .with_result("rho", rho))
+        Ok(PricingResults::from_npv(greeks.price)
+            .with_result("delta", greeks.delta)
+            .with_result("gamma", greeks.gamma)
+            .with_result("vega", greeks.vega)
+            .with_result("theta", greeks.theta)
+            .with_result("rho", greeks.rho)
+            .with_result("dividend_rho", greeks.dividend_rho))
     }
 }
 
@@ -148,6 +257,49 @@ mod tests {
         assert!(rho > 0.0, "rho = {rho}");
     }
 
+    #[test]
+    fn bs_greeks_textbook_values() {
+        // S=100, K=100, r=5%, q=0%, σ=20%, T=1
+        let call =
+            black_scholes_merton_greeks(OptionType::Call, 100.0, 100.0, 0.05, 0.0, 0.20, 1.0);
+        let put = black_scholes_merton_greeks(OptionType::Put, 100.0, 100.0, 0.05, 0.0, 0.20, 1.0);
+        let close = |a: Real, b: Real| (a - b).abs() < 1e-4;
+
+        assert!(close(call.price, 10.45058), "{call:?}");
+        assert!(close(call.delta, 0.63683));
+        assert!(close(call.gamma, 0.01876));
+        assert!(close(call.vega, 37.52403));
+        assert!(close(call.theta, -6.41403));
+        assert!(close(call.rho, 53.23248));
+        assert!(close(call.dividend_rho, -63.68307));
+
+        assert!(close(put.price, 5.57353), "{put:?}");
+        assert!(close(put.delta, -0.36317));
+        assert_eq!(put.gamma, call.gamma);
+        assert_eq!(put.vega, call.vega);
+        assert!(close(put.theta, -1.65788));
+        assert!(close(put.rho, -41.89046));
+        assert!(close(put.dividend_rho, 36.31693));
+
+        // Theta is per year: one day of decay is roughly theta / 365.
+        let later = black_scholes_merton_greeks(
+            OptionType::Call,
+            100.0,
+            100.0,
+            0.05,
+            0.0,
+            0.20,
+            1.0 - 1.0 / 365.0,
+        );
+        assert!(((later.price - call.price) * 365.0 - call.theta).abs() < 0.02);
+
+        let tuple = black_scholes_merton(OptionType::Put, 100.0, 100.0, 0.05, 0.0, 0.20, 1.0);
+        assert_eq!(
+            tuple,
+            (put.price, put.delta, put.gamma, put.vega, put.theta, put.rho)
+        );
+    }
+
     #[test]
     fn bs_put_price() {
         // Put via put-call parity: P = C - S*exp(-qT) + K*exp(-rT)
@@ -226,4 +378,107 @@ mod tests {
         assert!(result.additional_results.contains_key("gamma"));
         assert!(result.additional_results.contains_key("vega"));
     }
+
+    #[test]
+    fn short_option_flips_npv_and_greeks() {
+        use ql_core::Position;
+        use ql_instruments::VanillaOption;
+        use ql_termstructures::{BlackConstantVol, FlatForward};
+        use ql_time::{Actual365Fixed, Date};
+
+        let ref_date = Date::from_ymd(2025, 1, 15).unwrap();
+        let rf = Arc::new(FlatForward::continuous(ref_date, 0.05, Actual365Fixed));
+        let div = Arc::new(FlatForward::continuous(ref_date, 0.01, Actual365Fixed));
+        let vol = Arc::new(BlackConstantVol::new(ref_date, 0.20, Actual365Fixed));
+        let process = Arc::new(GeneralizedBlackScholesProcess::new(100.0, rf, div, vol));
+        let engine = AnalyticEuropeanEngine::new(process);
+
+        let expiry = Date::from_ymd(2026, 1, 15).unwrap();
+        let long = VanillaOption::european(OptionType::Put, 95.0, expiry);
+        let short =
+            VanillaOption::european(OptionType::Put, 95.0, expiry).with_position(Position::Short);
+        let long_result = long.price(&engine).unwrap();
+        let short_result = short.price(&engine).unwrap();
+
+        assert!(long_result.npv > 0.0);
+        assert_eq!(short_result.npv, -long_result.npv);
+        for (key, value) in &long_result.additional_results {
+            assert_eq!(short_result.additional_results[key], -value, "{key}");
+        }
+
+        // A long and a short of the same option net to nothing.
+        let portfolio: Real = [&long, &short]
+            .iter()
+            .map(|option| option.price(&engine).unwrap().npv)
+            .sum();
+        assert_eq!(portfolio, 0.0);
+    }
+
+    #[test]
+    fn implied_volatility_recovers_engine_vol() {
+        use ql_instruments::VanillaOption;
+        use ql_termstructures::{BlackConstantVol, FlatForward};
+        use ql_time::{Actual365Fixed, Date};
+
+        let ref_date = Date::from_ymd(2025, 1, 15).unwrap();
+        let rf = Arc::new(FlatForward::continuous(ref_date, 0.05, Actual365Fixed));
+        let div = Arc::new(FlatForward::continuous(ref_date, 0.01, Actual365Fixed));
+        let vol = Arc::new(BlackConstantVol::new(ref_date, 0.20, Actual365Fixed));
+        let process = Arc::new(GeneralizedBlackScholesProcess::new(100.0, rf, div, vol));
+        let engine = AnalyticEuropeanEngine::new(process.clone());
+
+        let expiry = Date::from_ymd(2026, 7, 15).unwrap();
+        for strike in [70.0, 100.0, 130.0] {
+            let option = VanillaOption::european(OptionType::Call, strike, expiry);
+            let npv = option.price(&engine).unwrap().npv;
+            let implied = option
+                .implied_volatility(npv, &process, 1e-10, 4.0)
+                .unwrap();
+            assert!((implied - 0.20).abs() < 1e-6, "K={strike}: {implied}");
+        }
+    }
+
+    #[test]
+    fn dual_delta_and_vega_are_exact() {
+        use ql_math::dual::Dual;
+
+        let (spot, strike, r, q, vol, t) = (105.0, 100.0, 0.04, 0.015, 0.25, 0.75);
+        let c = Dual::constant;
+        let by_spot = black_scholes_merton_value(
+            OptionType::Call,
+            Dual::variable(spot),
+            c(strike),
+            c(r),
+            c(q),
+            c(vol),
+            c(t),
+        );
+        let by_vol = black_scholes_merton_value(
+            OptionType::Call,
+            c(spot),
+            c(strike),
+            c(r),
+            c(q),
+            Dual::variable(vol),
+            c(t),
+        );
+
+        let std_dev = vol * Real::sqrt(t);
+        let d1 = ((spot / strike).ln() + (r - q + 0.5 * vol * vol) * t) / std_dev;
+        let df_q = (-q * t).exp();
+        let delta = normal_cdf(d1) * df_q;
+        let vega = spot * df_q * normal_pdf(d1) * t.sqrt();
+        assert!(
+            (by_spot.derivative - delta).abs() < 1e-14,
+            "{by_spot:?} vs {delta}"
+        );
+        assert!(
+            (by_vol.derivative - vega).abs() < 1e-12,
+            "{by_vol:?} vs {vega}"
+        );
+
+        let price = black_scholes_merton(OptionType::Call, spot, strike, r, q, vol, t).0;
+        assert_eq!(by_spot.value, price);
+        assert_eq!(by_vol.value, price);
+    }
 }
@@ -8,10 +8,12 @@
 use std::f64::consts::PI;
 use std::sync::Arc;
 
+use num_complex::Complex64;
 use ql_core::{errors::Result, Real};
 use ql_instruments::{OptionType, PricingEngine, PricingResults, VanillaOptionArguments};
-use ql_math::integrals::{Integrator, SimpsonIntegral};
+use ql_math::integrals::gaussianquadratures::{GaussLaguerreIntegration, GaussianQuadrature};
 use ql_models::HestonModel;
+use ql_processes::heston_exponent;
 
 /// Semi-analytic Heston pricing engine using Gauss-Laguerre quadrature.
 ///
@@ -48,11 +50,14 @@ impl AnalyticHestonEngine {
     }
 }
 
-/// Heston characteristic function (log-transform, Albrecher et al. formulation).
+/// Heston characteristic function `f_j(φ)` of the log-forward moneyness
+/// under the measure of `P_j`, for `j ∈ {1, 2}`.
 ///
-/// Computes $\phi_j(\xi; t, v_0, \kappa, \theta, \sigma, \rho)$ for $j \in \{1, 2\}$.
-///
-/// Returns `(Re, Im)` of the integrand for the probability $P_j$.
+/// `f_2` is `e^{ψ(φ)}` for the exponent `ψ` of
+/// [`heston_exponent`](ql_processes::heston_exponent), in the "little
+/// Heston trap" form of Albrecher et al. (2007) that keeps the logarithm on
+/// its principal branch.  Under the share measure of `P_1` the density is
+/// tilted by `S_T/F_T`, so `f_1(φ) = f_2(φ − i)`.
 fn heston_char_func(
     phi: Real,
     t: Real,
@@ -62,94 +67,13 @@ fn heston_char_func(
     sigma: Real,
     rho: Real,
     j: usize, // 1 or 2
-) -> (Real, Real) {
-    // For j=1: u = 0.5,  b = kappa - rho*sigma
-    // For j=2: u = -0.5, b = kappa
-    let (u, b) = if j == 1 {
-        (0.5, kappa - rho * sigma)
+) -> Complex64 {
+    let u = if j == 1 {
+        Complex64::new(phi, -1.0)
     } else {
-        (-0.5, kappa)
+        Complex64::new(phi, 0.0)
     };
-
-    // d = sqrt((rho*sigma*i*phi - b)^2 - sigma^2*(2*u*i*phi - phi^2))
-    // Let alpha = rho*sigma*phi, beta = b
-    // (alpha*i - beta)^2 = -alpha^2 + 2*alpha*beta*i + beta^2 (wrong sign)
-    // Actually: (rho*sigma*i*phi - b)^2 = (b - rho*sigma*i*phi)^2
-    //   = b^2 - 2*b*rho*sigma*i*phi + (rho*sigma*phi)^2*(-1)
-    //   = b^2 - (rho*sigma*phi)^2 - 2*b*rho*sigma*phi*i
-    // sigma^2*(2*u*i*phi - phi^2) = -sigma^2*phi^2 + 2*u*sigma^2*phi*i
-    //
-    // d^2 = (b^2 - rho^2*sigma^2*phi^2 + sigma^2*phi^2) + (-2*b*rho*sigma*phi - 2*u*sigma^2*phi)*i
-    //      = b^2 + sigma^2*phi^2*(1-rho^2) + (-2*sigma*phi*(b*rho+u*sigma))*i
-
-    let sigma2 = sigma * sigma;
-    let phi2 = phi * phi;
-
-    let d_re = b * b + sigma2 * phi2 * (1.0 - rho * rho);
-    let d_im = -2.0 * sigma * phi * (b * rho + u * sigma);
-
-    // d = sqrt(d_re + d_im * i)
-    let (d_r, d_i) = complex_sqrt(d_re, d_im);
-    // Enforce Re(d) >= 0 for the numerically stable branch
-    let (d_r, d_i) = if d_r < 0.0 { (-d_r, -d_i) } else { (d_r, d_i) };
-
-    // c = b - rho*sigma*i*phi = (b, -rho*sigma*phi)
-    let c_r = b;
-    let c_i = -rho * sigma * phi;
-
-    // Numerically stable formulation: g_m = (c - d)/(c + d)
-    // (small when c ≈ d, avoiding the g = (c+d)/(c-d) → ∞ singularity)
-    let gm_num_r = c_r - d_r;
-    let gm_num_i = c_i - d_i;
-    let gm_den_r = c_r + d_r;
-    let gm_den_i = c_i + d_i;
-    let (gm_r, gm_i) = complex_div(gm_num_r, gm_num_i, gm_den_r, gm_den_i);
-
-    // exp(-d*T) — use negative exponent for stability (Re(d) >= 0 → decays)
-    let emdt_mag = (-d_r * t).exp();
-    let emdt_r = emdt_mag * (-d_i * t).cos();
-    let emdt_i = emdt_mag * (-d_i * t).sin();
-
-    // g_m * exp(-dT)
-    let gme_r = gm_r * emdt_r - gm_i * emdt_i;
-    let gme_i = gm_r * emdt_i + gm_i * emdt_r;
-
-    // 1 - g_m * exp(-dT)
-    let one_m_gme_r = 1.0 - gme_r;
-    let one_m_gme_i = -gme_i;
-
-    // 1 - g_m
-    let one_m_gm_r = 1.0 - gm_r;
-    let one_m_gm_i = -gm_i;
-
-    // D = (c - d)/σ² * (1 - exp(-dT)) / (1 - g_m * exp(-dT))
-    let one_m_emdt_r = 1.0 - emdt_r;
-    let one_m_emdt_i = -emdt_i;
-    let (frac_r, frac_i) = complex_div(one_m_emdt_r, one_m_emdt_i, one_m_gme_r, one_m_gme_i);
-
-    let cmd_r = c_r - d_r;
-    let cmd_i = c_i - d_i;
-    let big_d_r = (cmd_r * frac_r - cmd_i * frac_i) / sigma2;
-    let big_d_i = (cmd_r * frac_i + cmd_i * frac_r) / sigma2;
-
-    // C = κθ/σ² * [(c - d)T - 2*ln((1 - g_m*exp(-dT)) / (1 - g_m))]
-    let cmdt_r = cmd_r * t;
-    let cmdt_i = cmd_i * t;
-    let (log_arg_r, log_arg_i) = complex_div(one_m_gme_r, one_m_gme_i, one_m_gm_r, one_m_gm_i);
-    let (log_r, log_i) = complex_log(log_arg_r, log_arg_i);
-
-    let big_c_r = kappa * theta / sigma2 * (cmdt_r - 2.0 * log_r);
-    let big_c_i = kappa * theta / sigma2 * (cmdt_i - 2.0 * log_i);
-
-    // Characteristic function: exp(C + D*v0)
-    let exp_arg_r = big_c_r + big_d_r * v0;
-    let exp_arg_i = big_c_i + big_d_i * v0;
-
-    let exp_mag = exp_arg_r.exp();
-    let cf_r = exp_mag * exp_arg_i.cos();
-    let cf_i = exp_mag * exp_arg_i.sin();
-
-    (cf_r, cf_i)
+    heston_exponent(u, t, v0, kappa, theta, sigma, rho).exp()
 }
 
 /// Compute $P_j = \frac{1}{2} + \frac{1}{\pi} \int_0^\infty
@@ -166,7 +90,7 @@ fn compute_pj(
     theta: Real,
     sigma: Real,
     rho: Real,
-    _integration_order: usize,
+    quadrature: &GaussianQuadrature,
 ) -> Real {
     // Combine log(S*exp((r-q)T)) - log(K) = log-moneyness of forward
     let x = spot.ln() + (r - q) * t - strike.ln();
@@ -175,20 +99,22 @@ fn compute_pj(
         if phi < 1e-12 {
             return 0.0;
         }
-        let (cf_r, cf_i) = heston_char_func(phi, t, v0, kappa, theta, sigma, rho, j);
-
-        // exp(i*phi*x) = cos(phi*x) + i*sin(phi*x)
-        let cos_px = (phi * x).cos();
-        let sin_px = (phi * x).sin();
-
-        // Re[cf * exp(i*phi*x) / (i*phi)] = Im[cf * exp(i*phi*x)] / phi
-        (cf_r * sin_px + cf_i * cos_px) / phi
+        let cf = heston_char_func(phi, t, v0, kappa, theta, sigma, rho, j);
+        // Re[cf · e^{iφx} / (iφ)] = Im[cf · e^{iφx}] / φ
+        (cf * Complex64::from_polar(1.0, phi * x)).im / phi
     };
 
-    // Adaptive Simpson integration over [ε, upper_bound].
-    // The integrand decays as phi → ∞ due to the CF exponential decay.
-    let integrator = SimpsonIntegral::new(1e-10, 200_000);
-    let integral = integrator.integrate(integrand, 1e-8, 500.0).unwrap_or(0.0);
+    // Gauss-Laguerre on [0, ∞): ∫ f(φ) dφ ≈ Σ wᵢ e^{φᵢ} f(φᵢ).  Past φ ≈ 709
+    // e^{φᵢ} overflows while wᵢ underflows, so the two are combined in log
+    // space; nodes whose weight has underflowed to zero are dropped, the
+    // characteristic function having decayed long before them.
+    let integral: Real = quadrature
+        .x()
+        .iter()
+        .zip(quadrature.w())
+        .filter(|&(_, &w)| w > 0.0)
+        .map(|(&phi, &w)| (w.ln() + phi).exp() * integrand(phi))
+        .sum();
     0.5 + integral / PI
 }
 
@@ -209,35 +135,45 @@ pub fn heston_price(
     rho: Real,
     integration_order: usize,
 ) -> Real {
-    let _phi = option_type.sign();
-
-    let p1 = compute_pj(
-        1,
+    let quadrature = GaussLaguerreIntegration::new(integration_order, 0.0);
+    heston_price_on(
+        &quadrature,
+        option_type,
         spot,
         strike,
-        t,
         r,
         q,
+        t,
         v0,
         kappa,
         theta,
         sigma,
         rho,
-        integration_order,
+    )
+}
+
+/// [`heston_price`] on a prebuilt Gauss-Laguerre rule, shared by both
+/// probabilities and reusable across calls since the nodes are costly to
+/// build.
+pub(crate) fn heston_price_on(
+    quadrature: &GaussianQuadrature,
+    option_type: OptionType,
+    spot: Real,
+    strike: Real,
+    r: Real,
+    q: Real,
+    t: Real,
+    v0: Real,
+    kappa: Real,
+    theta: Real,
+    sigma: Real,
+    rho: Real,
+) -> Real {
+    let p1 = compute_pj(
+        1, spot, strike, t, r, q, v0, kappa, theta, sigma, rho, quadrature,
     );
     let p2 = compute_pj(
-        2,
-        spot,
-        strike,
-        t,
-        r,
-        q,
-        v0,
-        kappa,
-        theta,
-        sigma,
-        rho,
-        integration_order,
+        2, spot, strike, t, r, q, v0, kappa, theta, sigma, rho, quadrature,
     );
 
     let df_q = (-q * t).exp();
@@ -293,31 +229,6 @@ impl PricingEngine<VanillaOptionArguments> for AnalyticHestonEngine {
     }
 }
 
-// ─── Complex arithmetic helpers ─────────────────────────────────────────────
-
-fn complex_sqrt(re: Real, im: Real) -> (Real, Real) {
-    let r = (re * re + im * im).sqrt().sqrt();
-    let theta = im.atan2(re) / 2.0;
-    (r * theta.cos(), r * theta.sin())
-}
-
-fn complex_div(a_r: Real, a_i: Real, b_r: Real, b_i: Real) -> (Real, Real) {
-    let denom = b_r * b_r + b_i * b_i;
-    if denom < 1e-300 {
-        return (0.0, 0.0);
-    }
-    (
-        (a_r * b_r + a_i * b_i) / denom,
-        (a_i * b_r - a_r * b_i) / denom,
-    )
-}
-
-fn complex_log(re: Real, im: Real) -> (Real, Real) {
-    let r = (re * re + im * im).sqrt();
-    let theta = im.atan2(re);
-    (r.ln(), theta)
-}
-
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -489,4 +400,159 @@ mod tests {
         assert!(otm_put > 0.0, "otm_put = {otm_put}");
         assert!(atm_call > 0.0, "atm_call = {atm_call}");
     }
+
+    /// Quadratic-Exponential Monte Carlo reproduces the semi-analytic price.
+    #[test]
+    fn qe_monte_carlo_matches_analytic_price() {
+        use ql_math::random_numbers::InverseCumulativeNormalRng;
+        use ql_math::Array;
+        use ql_processes::{HestonDiscretization, HestonProcess, StochasticProcess};
+        use ql_termstructures::{FlatForward, YieldTermStructure};
+        use ql_time::{Actual365Fixed, Date};
+
+        let (spot, strike, r, q, t) = (100.0, 100.0, 0.05, 0.02, 1.0);
+        let (v0, kappa, theta, sigma_v, rho) = (0.04, 1.5, 0.04, 0.5, -0.7);
+        let ref_date = Date::from_ymd(2025, 1, 2).unwrap();
+        let rf: Arc<dyn YieldTermStructure> =
+            Arc::new(FlatForward::continuous(ref_date, r, Actual365Fixed));
+        let div: Arc<dyn YieldTermStructure> =
+            Arc::new(FlatForward::continuous(ref_date, q, Actual365Fixed));
+        let process = HestonProcess::new(spot, v0, rf, div, kappa, theta, sigma_v, rho)
+            .with_discretization(HestonDiscretization::QuadraticExponentialMartingale);
+
+        let (steps, pairs) = (20, 50_000);
+        let dt = t / steps as Real;
+        let mut rng = InverseCumulativeNormalRng::new(2024);
+        let mut sum = 0.0;
+        for _ in 0..pairs {
+            let draws: Vec<(Real, Real)> = (0..steps)
+                .map(|_| (rng.next_real(), rng.next_real()))
+                .collect();
+            // Antithetic pair: the path and its mirror image.
+            for sign in [1.0, -1.0] {
+                let mut x = process.initial_values();
+                for (i, &(z_s, z_v)) in draws.iter().enumerate() {
+                    let dw = Array::from_vec(vec![sign * z_s, sign * z_v]);
+                    x = process.evolve(i as Real * dt, &x, dt, &dw);
+                    assert!(x[1] >= 0.0);
+                }
+                sum += (x[0] - strike).max(0.0);
+            }
+        }
+        let mc = (-r * t).exp() * sum / (2 * pairs) as Real;
+
+        let analytic = heston_price(
+            OptionType::Call,
+            spot,
+            strike,
+            r,
+            q,
+            t,
+            v0,
+            kappa,
+            theta,
+            sigma_v,
+            rho,
+            128,
+        );
+        assert!(
+            (mc - analytic).abs() < 0.01 * analytic,
+            "MC {mc:.4} vs analytic {analytic:.4}"
+        );
+    }
+
+    /// Fang & Oosterlee (2008) reference prices for an at-the-money call,
+    /// including the ten-year case where Heston's original formulation
+    /// jumps branches.
+    #[test]
+    fn matches_reference_prices_at_long_maturities() {
+        let (v0, kappa, theta, sigma_v, rho) = (0.0175, 1.5768, 0.0398, 0.5751, -0.5711);
+        for (t, reference) in [(1.0, 5.785155450), (10.0, 22.318945791)] {
+            let price = heston_price(
+                OptionType::Call,
+                100.0,
+                100.0,
+                0.0,
+                0.0,
+                t,
+                v0,
+                kappa,
+                theta,
+                sigma_v,
+                rho,
+                128,
+            );
+            assert!(
+                (price - reference).abs() < 1e-6,
+                "T={t}: {price:.9} vs {reference:.9}"
+            );
+        }
+    }
+
+    /// The characteristic function has no branch jumps along the
+    /// integration path, unlike the original Heston form.
+    #[test]
+    fn char_func_is_continuous_along_integration_path() {
+        let (t, v0, kappa, theta, sigma, rho) = (10.0, 0.0175, 1.5768, 0.0398, 0.5751, -0.5711);
+        let h = 1e-3;
+        let max_step = |f: &dyn Fn(Real) -> Complex64| {
+            (1..20_000)
+                .map(|n| (f((n + 1) as Real * h) - f(n as Real * h)).norm())
+                .fold(0.0, Real::max)
+        };
+
+        for j in [1, 2] {
+            let little_trap = |phi| heston_char_func(phi, t, v0, kappa, theta, sigma, rho, j);
+            assert!(max_step(&little_trap) < 1e-2, "P{j}: jump in f_j");
+
+            // Heston (1993): g = (c + d)/(c − d) and the e^{+dT} form.
+            let original = |phi: Real| {
+                let (u, b) = if j == 1 {
+                    (0.5, kappa - rho * sigma)
+                } else {
+                    (-0.5, kappa)
+                };
+                let i = Complex64::i();
+                let c = b - rho * sigma * phi * i;
+                let d = (c * c - sigma * sigma * (2.0 * u * phi * i - phi * phi)).sqrt();
+                let g = (c + d) / (c - d);
+                let e = (d * t).exp();
+                let big_d = (c + d) / (sigma * sigma) * (1.0 - e) / (1.0 - g * e);
+                let big_c = kappa * theta / (sigma * sigma)
+                    * ((c + d) * t - 2.0 * ((1.0 - g * e) / (1.0 - g)).ln());
+                (big_c + big_d * v0).exp()
+            };
+            assert!(max_step(&original) > 0.1, "P{j}: expected a branch jump");
+        }
+    }
+
+    /// High-order rules, whose outer nodes pass e^{709}, stay finite and
+    /// agree with the default order.
+    #[test]
+    fn high_integration_orders_stay_finite() {
+        let price = |order| {
+            heston_price(
+                OptionType::Call,
+                100.0,
+                100.0,
+                0.05,
+                0.0,
+                1.0,
+                0.04,
+                2.0,
+                0.04,
+                0.3,
+                -0.5,
+                order,
+            )
+        };
+        let reference = price(128);
+        for order in [32, 64, 192, 256, 400] {
+            let value = price(order);
+            assert!(
+                (value - reference).abs() < 1e-6,
+                "order {order}: {value} vs {reference}"
+            );
+        }
+    }
 }
@@ -0,0 +1,221 @@
+//! Analytic one-touch and no-touch option engine.
+//!
+//! Translates the cash-rebate cases of
+//! `ql/pricingengines/barrier/analyticbinarybarrierengine.hpp`.
+//!
+//! Both products are the rebate legs of a Reiner-Rubinstein barrier
+//! option.  Under Black-Scholes the log price is a Brownian motion with
+//! drift, whose first-passage time to the barrier has a closed-form
+//! distribution and Laplace transform; they give, for a payout `R`:
+//!
+//! * a no-touch, `R·e^{−rT}·P(τ > T)`;
+//! * a one-touch paid at expiry, `R·e^{−rT}·P(τ ≤ T)`;
+//! * a one-touch paid at hit, `R·E[e^{−rτ}; τ ≤ T]`.
+
+use std::sync::Arc;
+
+use ql_core::{ensure, errors::Result, Real};
+use ql_instruments::{
+    BarrierType, PricingEngine, PricingResults, TouchOptionArguments, TouchPayment,
+};
+use ql_math::distributions::normal_cdf;
+use ql_processes::GeneralizedBlackScholesProcess;
+
+use crate::analytic_barrier_engine::{rebate_at_hit, rebate_if_untouched};
+
+/// Analytic engine for one-touch and no-touch options.
+///
+/// Corresponds to `QuantLib::AnalyticBinaryBarrierEngine` for
+/// cash-rebate payoffs.
+#[derive(Debug)]
+pub struct AnalyticTouchEngine {
+    process: Arc<GeneralizedBlackScholesProcess>,
+}
+
+impl AnalyticTouchEngine {
+    /// Create a new engine with the given Black-Scholes process.
+    pub fn new(process: Arc<GeneralizedBlackScholesProcess>) -> Self {
+        Self { process }
+    }
+}
+
+/// Closed-form value of a touch option paying `payout`.
+///
+/// `DownIn`/`UpIn` barriers give a one-touch, paid as `payment` says;
+/// `DownOut`/`UpOut` give a no-touch, paid at expiry.  A barrier already
+/// breached by `spot` counts as touched.
+pub fn analytic_touch_price(
+    barrier_type: BarrierType,
+    payment: TouchPayment,
+    spot: Real,
+    barrier: Real,
+    payout: Real,
+    r: Real,
+    q: Real,
+    sigma: Real,
+    t: Real,
+) -> Real {
+    let (eta, one_touch) = match barrier_type {
+        BarrierType::DownIn => (1.0, true),
+        BarrierType::DownOut => (1.0, false),
+        BarrierType::UpIn => (-1.0, true),
+        BarrierType::UpOut => (-1.0, false),
+    };
+    let touched = eta * (spot - barrier) <= 0.0;
+    let t = t.max(0.0);
+    let df_r = (-r * t).exp();
+
+    if touched {
+        return match (one_touch, payment) {
+            (false, _) => 0.0,
+            (true, TouchPayment::AtHit) => payout,
+            (true, TouchPayment::AtExpiry) => payout * df_r,
+        };
+    }
+    if t == 0.0 {
+        return if one_touch { 0.0 } else { payout };
+    }
+
+    match (one_touch, payment) {
+        (false, _) => rebate_if_untouched(eta, spot, barrier, payout, r, q, sigma, t),
+        (true, TouchPayment::AtHit) => rebate_at_hit(eta, spot, barrier, payout, r, q, sigma, t),
+        (true, TouchPayment::AtExpiry) => {
+            // R·e^{−rT}·P(τ ≤ T): the reflection principle adds the paths
+            // that end on the far side of the barrier to their mirror
+            // images, weighted by the drift's likelihood ratio (H/S)^{2μ}.
+            let std_dev = sigma * t.sqrt();
+            let mu = (r - q - 0.5 * sigma * sigma) / (sigma * sigma);
+            let x2 = (spot / barrier).ln() / std_dev + (1.0 + mu) * std_dev;
+            let y2 = (barrier / spot).ln() / std_dev + (1.0 + mu) * std_dev;
+            payout
+                * df_r
+                * (normal_cdf(-eta * (x2 - std_dev))
+                    + (barrier / spot).powf(2.0 * mu) * normal_cdf(eta * (y2 - std_dev)))
+        }
+    }
+}
+
+impl PricingEngine<TouchOptionArguments> for AnalyticTouchEngine {
+    fn calculate(&self, args: &TouchOptionArguments) -> Result<PricingResults> {
+        ensure!(args.barrier > 0.0, "barrier must be positive");
+        let spot = self.process.spot();
+        let expiry = args.exercise.last_date();
+
+        let rf = self.process.risk_free_rate();
+        let t = rf.day_counter().year_fraction(rf.reference_date(), expiry);
+        let r = rf.zero_rate_impl(t);
+        let q = self.process.dividend_yield().zero_rate_impl(t);
+        let sigma = self
+            .process
+            .black_volatility()
+            .expect("process must have a black vol surface")
+            .black_vol_time(t, args.barrier);
+
+        let price = analytic_touch_price(
+            args.barrier_type,
+            args.payment,
+            spot,
+            args.barrier,
+            args.payout,
+            r,
+            q,
+            sigma,
+            t,
+        );
+        Ok(PricingResults::from_npv(price))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use ql_instruments::TouchOption;
+    use ql_termstructures::{BlackConstantVol, FlatForward};
+    use ql_time::{Actual365Fixed, Date};
+
+    const S: Real = 100.0;
+    const R: Real = 0.05;
+    const Q: Real = 0.02;
+    const SIGMA: Real = 0.2;
+    const T: Real = 1.0;
+
+    fn price(barrier_type: BarrierType, payment: TouchPayment, barrier: Real) -> Real {
+        analytic_touch_price(barrier_type, payment, S, barrier, 10.0, R, Q, SIGMA, T)
+    }
+
+    #[test]
+    fn one_touch_and_no_touch_sum_to_discounted_payout() {
+        let discounted = 10.0 * (-R * T).exp();
+        for (one, no, barrier) in [
+            (BarrierType::DownIn, BarrierType::DownOut, 85.0),
+            (BarrierType::UpIn, BarrierType::UpOut, 120.0),
+        ] {
+            let one_touch = price(one, TouchPayment::AtExpiry, barrier);
+            let no_touch = price(no, TouchPayment::AtExpiry, barrier);
+            assert!(one_touch > 0.0 && no_touch > 0.0);
+            assert!(
+                (one_touch + no_touch - discounted).abs() < 1e-12,
+                "{one_touch} + {no_touch} vs {discounted}"
+            );
+            // Paying at the hit is worth more under positive rates.
+            assert!(price(one, TouchPayment::AtHit, barrier) > one_touch);
+        }
+    }
+
+    #[test]
+    fn payment_timing_is_irrelevant_without_rates() {
+        for (barrier_type, barrier) in [(BarrierType::DownIn, 90.0), (BarrierType::UpIn, 115.0)] {
+            let at = |payment| {
+                analytic_touch_price(barrier_type, payment, S, barrier, 1.0, 0.0, 0.03, SIGMA, T)
+            };
+            assert!((at(TouchPayment::AtHit) - at(TouchPayment::AtExpiry)).abs() < 1e-12);
+        }
+    }
+
+    #[test]
+    fn one_touch_rises_as_the_barrier_nears_spot() {
+        for payment in [TouchPayment::AtHit, TouchPayment::AtExpiry] {
+            let down: Vec<Real> = [60.0, 75.0, 90.0, 99.0]
+                .iter()
+                .map(|&h| price(BarrierType::DownIn, payment, h))
+                .collect();
+            let up: Vec<Real> = [160.0, 130.0, 110.0, 101.0]
+                .iter()
+                .map(|&h| price(BarrierType::UpIn, payment, h))
+                .collect();
+            for values in [down, up] {
+                assert!(
+                    values.windows(2).all(|w| w[1] > w[0]),
+                    "{payment:?}: {values:?}"
+                );
+            }
+        }
+        // A breached barrier pays at once.
+        assert_eq!(price(BarrierType::DownIn, TouchPayment::AtHit, 100.0), 10.0);
+        assert_eq!(
+            price(BarrierType::DownOut, TouchPayment::AtExpiry, 101.0),
+            0.0
+        );
+    }
+
+    #[test]
+    fn engine_prices_touch_options() {
+        let ref_date = Date::from_ymd(2025, 1, 2).unwrap();
+        let process = Arc::new(GeneralizedBlackScholesProcess::new(
+            S,
+            Arc::new(FlatForward::continuous(ref_date, R, Actual365Fixed)),
+            Arc::new(FlatForward::continuous(ref_date, Q, Actual365Fixed)),
+            Arc::new(BlackConstantVol::new(ref_date, SIGMA, Actual365Fixed)),
+        ));
+        let engine = AnalyticTouchEngine::new(process);
+        let expiry = Date::from_ymd(2026, 1, 2).unwrap();
+        let one_touch =
+            TouchOption::one_touch(BarrierType::UpIn, 120.0, 10.0, TouchPayment::AtHit, expiry);
+        let npv = engine.calculate(&one_touch.arguments()).unwrap().npv;
+        assert!((npv - price(BarrierType::UpIn, TouchPayment::AtHit, 120.0)).abs() < 1e-12);
+
+        let no_touch = TouchOption::no_touch(BarrierType::UpOut, 120.0, 10.0, expiry);
+        let npv = engine.calculate(&no_touch.arguments()).unwrap().npv;
+        assert!((npv - price(BarrierType::UpOut, TouchPayment::AtExpiry, 120.0)).abs() < 1e-12);
+    }
+}
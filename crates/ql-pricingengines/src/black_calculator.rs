@@ -0,0 +1,257 @@
+//! Black (1976) formula calculator in forward terms.
+//!
+//! Translates `ql/pricingengines/blackcalculator.hpp`, for plain-vanilla
+//! payoffs.
+//!
+//! Given the forward `F`, strike `K`, standard deviation `σ√T` and discount
+//! factor `D`, the value is
+//!
+//! `V = φ D (F N(φ d₁) − K N(φ d₂))`, with `d₁,₂ = ln(F/K) / σ√T ± σ√T / 2`,
+//!
+//! and `φ = ±1` for calls and puts.  Sensitivities are to the forward
+//! first; the spot versions take the spot that the forward was grown from,
+//! so that `∂F/∂S = F/S`.
+
+use ql_core::{ensure, errors::Result, Real, Time};
+use ql_instruments::OptionType;
+use ql_math::distributions::{normal_cdf, normal_pdf};
+
+/// Black value and Greeks of a vanilla option on a forward.
+///
+/// Sensitivities are per unit change of the input, as for
+/// [`BlackScholesGreeks`](crate::BlackScholesGreeks).
+///
+/// Corresponds to `QuantLib::BlackCalculator`.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct BlackCalculator {
+    phi: Real,
+    strike: Real,
+    forward: Real,
+    std_dev: Real,
+    discount: Real,
+    d1: Real,
+    d2: Real,
+}
+
+impl BlackCalculator {
+    /// Set up the calculator for a vanilla option struck at `strike` on
+    /// `forward`, with total standard deviation `std_dev` (`σ√T`) and
+    /// `discount` to the payment date.
+    ///
+    /// # Errors
+    /// Fails unless the forward and discount are positive and the strike
+    /// and standard deviation non-negative.
+    pub fn new(
+        option_type: OptionType,
+        strike: Real,
+        forward: Real,
+        std_dev: Real,
+        discount: Real,
+    ) -> Result<Self> {
+        ensure!(strike >= 0.0, "strike ({strike}) must be non-negative");
+        ensure!(forward > 0.0, "forward ({forward}) must be positive");
+        ensure!(std_dev >= 0.0, "stdDev ({std_dev}) must be non-negative");
+        ensure!(discount > 0.0, "discount ({discount}) must be positive");
+
+        let (d1, d2) = if std_dev > 1e-15 && strike > 0.0 {
+            let d1 = (forward / strike).ln() / std_dev + 0.5 * std_dev;
+            (d1, d1 - std_dev)
+        } else {
+            // Degenerate distribution: exercise is certain or impossible.
+            let big = if forward > strike { 1e15 } else { -1e15 };
+            (big, big)
+        };
+        Ok(Self {
+            phi: option_type.sign(),
+            strike,
+            forward,
+            std_dev,
+            discount,
+            d1,
+            d2,
+        })
+    }
+
+    /// The `d₁` of the Black formula.
+    pub fn d1(&self) -> Real {
+        self.d1
+    }
+
+    /// The `d₂ = d₁ − σ√T` of the Black formula.
+    pub fn d2(&self) -> Real {
+        self.d2
+    }
+
+    /// Discounted option value.
+    pub fn value(&self) -> Real {
+        let phi = self.phi;
+        phi * self.discount
+            * (self.forward * normal_cdf(phi * self.d1) - self.strike * normal_cdf(phi * self.d2))
+    }
+
+    /// ∂V/∂F.
+    pub fn delta_forward(&self) -> Real {
+        self.phi * self.discount * self.itm_asset_probability()
+    }
+
+    /// ∂²V/∂F².
+    pub fn gamma_forward(&self) -> Real {
+        if self.std_dev <= 1e-15 {
+            return 0.0;
+        }
+        self.discount * normal_pdf(self.d1) / (self.forward * self.std_dev)
+    }
+
+    /// Forward elasticity, `(∂V/∂F) · F / V`; zero for a worthless option.
+    pub fn elasticity_forward(&self) -> Real {
+        let value = self.value();
+        if value > Real::EPSILON {
+            self.delta_forward() * self.forward / value
+        } else {
+            0.0
+        }
+    }
+
+    /// ∂V/∂S, for the forward `F = S e^{(r − q)T}` of `spot`.
+    pub fn delta(&self, spot: Real) -> Real {
+        self.delta_forward() * self.forward / spot
+    }
+
+    /// ∂²V/∂S², for the forward of `spot`.
+    pub fn gamma(&self, spot: Real) -> Real {
+        let dforward_dspot = self.forward / spot;
+        self.gamma_forward() * dforward_dspot * dforward_dspot
+    }
+
+    /// Spot elasticity, `(∂V/∂S) · S / V`; equal to the forward elasticity.
+    pub fn elasticity(&self, spot: Real) -> Real {
+        let value = self.value();
+        if value > Real::EPSILON {
+            self.delta(spot) * spot / value
+        } else {
+            0.0
+        }
+    }
+
+    /// ∂V/∂σ, for an option expiring in `maturity` years.
+    pub fn vega(&self, maturity: Time) -> Real {
+        self.discount * self.forward * normal_pdf(self.d1) * maturity.sqrt()
+    }
+
+    /// ∂V/∂t (calendar time, per year) with flat rates, volatility and
+    /// carry implied by `spot`, `maturity`, the forward and the discount.
+    ///
+    /// # Panics
+    /// Panics if `maturity` is not positive.
+    pub fn theta(&self, spot: Real, maturity: Time) -> Real {
+        assert!(maturity > 0.0, "maturity ({maturity}) must be positive");
+        let variance = self.std_dev * self.std_dev;
+        // The Black-Scholes PDE: Θ = rV − (r − q) S Δ − ½ σ² S² Γ.
+        -(self.discount.ln() * self.value()
+            + (self.forward / spot).ln() * spot * self.delta(spot)
+            + 0.5 * variance * spot * spot * self.gamma(spot))
+            / maturity
+    }
+
+    /// ∂V/∂r, spot and dividend yield held fixed.
+    pub fn rho(&self, maturity: Time) -> Real {
+        maturity * (self.delta_forward() * self.forward - self.value())
+    }
+
+    /// ∂V/∂q, spot and risk-free rate held fixed.
+    pub fn dividend_rho(&self, maturity: Time) -> Real {
+        -maturity * self.delta_forward() * self.forward
+    }
+
+    /// Probability of exercise under the payment measure, `N(φ d₂)`.
+    pub fn itm_cash_probability(&self) -> Real {
+        normal_cdf(self.phi * self.d2)
+    }
+
+    /// Probability of exercise under the asset measure, `N(φ d₁)`.
+    pub fn itm_asset_probability(&self) -> Real {
+        normal_cdf(self.phi * self.d1)
+    }
+
+    /// ∂V/∂K.
+    pub fn strike_sensitivity(&self) -> Real {
+        -self.phi * self.discount * self.itm_cash_probability()
+    }
+
+    /// ∂²V/∂K², the discounted density of the forward at the strike.
+    pub fn strike_gamma(&self) -> Real {
+        if self.std_dev <= 1e-15 || self.strike <= 0.0 {
+            return 0.0;
+        }
+        self.discount * normal_pdf(self.d2) / (self.strike * self.std_dev)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::analytic_european_engine::black_scholes_merton_greeks;
+
+    #[test]
+    fn matches_black_scholes_merton_on_the_spot_forward() {
+        let (spot, r, q, sigma, t): (Real, Real, Real, Real, Time) = (100.0, 0.05, 0.02, 0.25, 1.5);
+        for option_type in [OptionType::Call, OptionType::Put] {
+            for strike in [70.0, 95.0, 100.0, 130.0] {
+                let forward = spot * ((r - q) * t).exp();
+                let discount = (-r * t).exp();
+                let black =
+                    BlackCalculator::new(option_type, strike, forward, sigma * t.sqrt(), discount)
+                        .unwrap();
+                let bsm = black_scholes_merton_greeks(option_type, spot, strike, r, q, sigma, t);
+
+                let pairs = [
+                    ("value", black.value(), bsm.price),
+                    ("delta", black.delta(spot), bsm.delta),
+                    ("gamma", black.gamma(spot), bsm.gamma),
+                    ("vega", black.vega(t), bsm.vega),
+                    ("theta", black.theta(spot, t), bsm.theta),
+                    ("rho", black.rho(t), bsm.rho),
+                    ("dividend rho", black.dividend_rho(t), bsm.dividend_rho),
+                ];
+                for (name, calculated, expected) in pairs {
+                    assert!(
+                        (calculated - expected).abs() < 1e-10,
+                        "{option_type:?} K = {strike} {name}: {calculated} vs {expected}"
+                    );
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn call_forward_delta_is_n_of_d1() {
+        let (forward, std_dev) = (0.035, 0.2 * 2.0_f64.sqrt());
+        for strike in [0.02, 0.035, 0.05] {
+            let call =
+                BlackCalculator::new(OptionType::Call, strike, forward, std_dev, 1.0).unwrap();
+            let d1 = (forward / strike).ln() / std_dev + 0.5 * std_dev;
+            assert!((call.d1() - d1).abs() < 1e-14);
+            assert!((call.delta_forward() - normal_cdf(d1)).abs() < 1e-14);
+
+            // Put-call parity in forward terms: Δ_C − Δ_P = D.
+            let put = BlackCalculator::new(OptionType::Put, strike, forward, std_dev, 1.0).unwrap();
+            assert!((call.delta_forward() - put.delta_forward() - 1.0).abs() < 1e-14);
+            assert!((call.value() - put.value() - (forward - strike)).abs() < 1e-14);
+        }
+
+        // Sensitivities agree with bumping the forward, to within the
+        // accuracy of the rational approximation of N(x).
+        let price = |f| {
+            BlackCalculator::new(OptionType::Call, 0.03, f, std_dev, 0.9)
+                .unwrap()
+                .value()
+        };
+        let call = BlackCalculator::new(OptionType::Call, 0.03, forward, std_dev, 0.9).unwrap();
+        let h = 1e-5;
+        let delta = (price(forward + h) - price(forward - h)) / (2.0 * h);
+        let gamma = (price(forward + h) - 2.0 * price(forward) + price(forward - h)) / (h * h);
+        assert!((call.delta_forward() - delta).abs() < 1e-6);
+        assert!((call.gamma_forward() - gamma).abs() < 1e-2 * gamma);
+        assert!(BlackCalculator::new(OptionType::Call, 0.03, 0.0, std_dev, 1.0).is_err());
+    }
+}
@@ -0,0 +1,290 @@
+//! Black-formula cap/floor engine.
+//!
+//! Translates `ql/pricingengines/capfloor/blackcapfloorengine.hpp`.
+//!
+//! Each optionlet on a coupon paying `g·L + s` is an option on the index
+//! rate `L`, since `max(φ(g L + s − K), 0) = g max(φ(L − (K − s)/g), 0)`.
+//! With the index forward `F` projected off the forecast curve over the
+//! accrual period and the Black volatility `σ` at the strike, its value is
+//!
+//! `N τ g D(t_pay) · Black(F, (K − s)/g, σ √t_fix)`,
+//!
+//! where `t_fix` is the time to the fixing on the discount curve.
+//! Optionlets paid on or before the discount curve's reference date are
+//! worth nothing; those fixed but not yet paid are worth their intrinsic
+//! value on the stored index fixing, or on the forward if the fixing date
+//! is the reference date and no fixing is stored yet.
+
+use crate::black_calculator::BlackCalculator;
+use ql_core::{ensure, errors::Result, fail, Real, Volatility};
+use ql_instruments::{CapFloorArguments, CapFloorType, OptionType, PricingEngine, PricingResults};
+use ql_termstructures::{SmileSection, YieldTermStructure};
+use std::sync::Arc;
+
+/// Caplet volatilities: one number, or read off a smile at each strike.
+#[derive(Debug, Clone)]
+enum CapletVolatility {
+    Flat(Volatility),
+    Smile(Arc<dyn SmileSection>),
+}
+
+/// Black pricing engine for caps and floors.
+///
+/// Corresponds to `QuantLib::BlackCapFloorEngine`.
+#[derive(Debug)]
+pub struct BlackCapFloorEngine {
+    forecast_curve: Arc<dyn YieldTermStructure>,
+    discount_curve: Arc<dyn YieldTermStructure>,
+    volatility: CapletVolatility,
+}
+
+impl BlackCapFloorEngine {
+    /// Engine with a flat lognormal caplet volatility.
+    pub fn new(
+        forecast_curve: Arc<dyn YieldTermStructure>,
+        discount_curve: Arc<dyn YieldTermStructure>,
+        volatility: Volatility,
+    ) -> Self {
+        Self {
+            forecast_curve,
+            discount_curve,
+            volatility: CapletVolatility::Flat(volatility),
+        }
+    }
+
+    /// Engine reading each caplet's volatility off `smile` at its
+    /// effective strike.
+    ///
+    /// The smile's own expiry is not used: it stands for the smile of
+    /// every caplet.
+    pub fn with_smile(
+        forecast_curve: Arc<dyn YieldTermStructure>,
+        discount_curve: Arc<dyn YieldTermStructure>,
+        smile: Arc<dyn SmileSection>,
+    ) -> Self {
+        Self {
+            forecast_curve,
+            discount_curve,
+            volatility: CapletVolatility::Smile(smile),
+        }
+    }
+
+    /// Value of each caplet or floorlet, in the order of the coupons.
+    ///
+    /// # Errors
+    /// Fails if the arguments' vectors differ in length, a coupon has a
+    /// non-positive gearing or an index forward at which no lognormal
+    /// option can be written, or a coupon fixed before the reference date
+    /// has no stored fixing.
+    pub fn optionlet_prices(&self, args: &CapFloorArguments) -> Result<Vec<Real>> {
+        let n = args.payment_dates.len();
+        ensure!(
+            [
+                args.start_dates.len(),
+                args.end_dates.len(),
+                args.fixing_dates.len(),
+                args.accrual_times.len(),
+                args.nominals.len(),
+                args.gearings.len(),
+                args.spreads.len(),
+                args.strikes.len(),
+                args.fixings.len(),
+            ]
+            .iter()
+            .all(|&len| len == n),
+            "cap/floor arguments differ in length"
+        );
+        let option_type = match args.cap_floor_type {
+            CapFloorType::Cap => OptionType::Call,
+            CapFloorType::Floor => OptionType::Put,
+        };
+        let reference = self.discount_curve.reference_date();
+
+        (0..n)
+            .map(|i| {
+                if args.payment_dates[i] <= reference {
+                    return Ok(0.0);
+                }
+                let gearing = args.gearings[i];
+                ensure!(
+                    gearing > 0.0,
+                    "coupon {i} has non-positive gearing {gearing}"
+                );
+                let tau = args.accrual_times[i];
+                let strike = ((args.strikes[i] - args.spreads[i]) / gearing).max(0.0);
+                let t_fix = self
+                    .discount_curve
+                    .time_from_reference(args.fixing_dates[i]);
+                let forward = match args.fixings[i] {
+                    Some(fixing) if t_fix <= 0.0 => fixing,
+                    None if t_fix < 0.0 => fail!(
+                        "coupon {i} fixed on {} but no fixing is stored",
+                        args.fixing_dates[i]
+                    ),
+                    _ => {
+                        (self.forecast_curve.discount_date(args.start_dates[i])
+                            / self.forecast_curve.discount_date(args.end_dates[i])
+                            - 1.0)
+                            / tau
+                    }
+                };
+                let std_dev = if t_fix > 0.0 {
+                    let vol = match &self.volatility {
+                        CapletVolatility::Flat(vol) => *vol,
+                        CapletVolatility::Smile(smile) => smile.volatility(strike),
+                    };
+                    vol * t_fix.sqrt()
+                } else {
+                    0.0
+                };
+                let discount = self.discount_curve.discount_date(args.payment_dates[i]);
+                let black = BlackCalculator::new(option_type, strike, forward, std_dev, discount)?;
+                Ok(args.nominals[i] * tau * gearing * black.value())
+            })
+            .collect()
+    }
+}
+
+impl PricingEngine<CapFloorArguments> for BlackCapFloorEngine {
+    fn calculate(&self, args: &CapFloorArguments) -> Result<PricingResults> {
+        let npv = self.optionlet_prices(args)?.iter().sum();
+        Ok(PricingResults::from_npv(npv))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use ql_cashflows::{Coupon, IborCoupon, IborLegBuilder, Leg};
+    use ql_indexes::euribor;
+    use ql_indexes::Index;
+    use ql_instruments::CapFloor;
+    use ql_termstructures::{FlatForward, FlatSmileSection};
+    use ql_time::{
+        Actual365Fixed, Date, NullCalendar, Period, Schedule, ScheduleBuilder, TimeUnit,
+    };
+
+    struct Market {
+        forecast: Arc<dyn YieldTermStructure>,
+        discount: Arc<dyn YieldTermStructure>,
+    }
+
+    fn market() -> Market {
+        let today = Date::from_ymd(2025, 1, 15).unwrap();
+        Market {
+            forecast: Arc::new(FlatForward::continuous(today, 0.035, Actual365Fixed)),
+            discount: Arc::new(FlatForward::continuous(today, 0.03, Actual365Fixed)),
+        }
+    }
+
+    /// Quarterly Ibor coupons from three months out to five years.
+    fn floating_leg() -> Leg {
+        let start = Date::from_ymd(2025, 4, 15).unwrap();
+        let end = Date::from_ymd(2030, 1, 15).unwrap();
+        let cal = NullCalendar;
+        let schedule = ScheduleBuilder::new(start, end, Period::new(3, TimeUnit::Months), &cal)
+            .build()
+            .unwrap();
+        IborLegBuilder::new(
+            &schedule,
+            Arc::new(euribor(Period::new(3, TimeUnit::Months))),
+        )
+        .with_notionals(vec![1_000_000.0])
+        .build()
+    }
+
+    /// Value of the floating leg and of its annuity on the market's curves.
+    fn leg_values(market: &Market) -> (Real, Real) {
+        floating_leg()
+            .iter()
+            .map(|cf| {
+                let coupon = cf.as_any().downcast_ref::<IborCoupon>().unwrap();
+                let discount = market.discount.discount_date(cf.date());
+                (
+                    coupon.projected_amount(&*market.forecast) * discount,
+                    coupon.nominal() * coupon.accrual_period() * discount,
+                )
+            })
+            .fold((0.0, 0.0), |(v, a), (dv, da)| (v + dv, a + da))
+    }
+
+    #[test]
+    fn atm_cap_is_sum_of_caplets_and_equals_floor() {
+        let market = market();
+        let (floating, annuity) = leg_values(&market);
+        let atm = floating / annuity;
+        let engine =
+            BlackCapFloorEngine::new(market.forecast.clone(), market.discount.clone(), 0.2);
+
+        let cap = CapFloor::cap(floating_leg(), atm).unwrap();
+        let npv = cap.price(&engine).unwrap().npv;
+        let caplets = engine.optionlet_prices(&cap.arguments().unwrap()).unwrap();
+        assert_eq!(caplets.len(), 19);
+        assert!(caplets.iter().all(|&c| c > 0.0));
+        assert!((caplets.iter().sum::<Real>() - npv).abs() < 1e-9);
+        // Longer-dated caplets are worth more at the money.
+        assert!(caplets.last().unwrap() > &caplets[0]);
+
+        // At the swap rate the cap and floor are worth the same.
+        let floor = CapFloor::floor(floating_leg(), atm).unwrap();
+        let floor_npv = floor.price(&engine).unwrap().npv;
+        assert!((npv - floor_npv).abs() < 1e-6 * npv, "{npv} vs {floor_npv}");
+    }
+
+    #[test]
+    fn cap_minus_floor_is_payer_swap() {
+        let market = market();
+        let (floating, annuity) = leg_values(&market);
+        let smile: Arc<dyn SmileSection> = Arc::new(FlatSmileSection::new(2.0, 0.25, 0.035));
+        let engines = [
+            BlackCapFloorEngine::new(market.forecast.clone(), market.discount.clone(), 0.25),
+            BlackCapFloorEngine::with_smile(
+                market.forecast.clone(),
+                market.discount.clone(),
+                smile,
+            ),
+        ];
+        for engine in &engines {
+            for strike in [0.02, 0.035, 0.05] {
+                let cap = CapFloor::cap(floating_leg(), strike).unwrap();
+                let floor = CapFloor::floor(floating_leg(), strike).unwrap();
+                let collar = cap.price(engine).unwrap().npv - floor.price(engine).unwrap().npv;
+                let swap = floating - strike * annuity;
+                assert!(
+                    (collar - swap).abs() < 1e-6,
+                    "K = {strike}: {collar} vs {swap}"
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn seasoned_caplet_uses_the_stored_fixing() {
+        let market = market();
+        let today = market.discount.reference_date();
+        // A quarter that fixed a month ago and pays in two months.
+        let start = Date::from_ymd(2024, 12, 16).unwrap();
+        let end = Date::from_ymd(2025, 3, 17).unwrap();
+        let schedule = Schedule::from_dates(vec![start, end]);
+        let index = Arc::new(euribor(Period::new(3, TimeUnit::Months)));
+        let leg = IborLegBuilder::new(&schedule, index.clone())
+            .with_notionals(vec![1_000_000.0])
+            .build();
+        let cap = CapFloor::cap(leg, 0.03).unwrap();
+        let args = cap.arguments().unwrap();
+        assert!(args.fixing_dates[0] < today && args.payment_dates[0] > today);
+
+        let engine =
+            BlackCapFloorEngine::new(market.forecast.clone(), market.discount.clone(), 0.2);
+        assert!(engine.optionlet_prices(&args).is_err());
+
+        index.add_fixing(args.fixing_dates[0], 0.042);
+        let args = cap.arguments().unwrap();
+        let expected = args.nominals[0]
+            * args.accrual_times[0]
+            * (0.042 - 0.03)
+            * market.discount.discount_date(args.payment_dates[0]);
+        let price = engine.optionlet_prices(&args).unwrap()[0];
+        assert!((price - expected).abs() < 1e-9, "{price} vs {expected}");
+    }
+}
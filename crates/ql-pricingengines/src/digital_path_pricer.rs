@@ -0,0 +1,104 @@
+//! Path pricer for digital payoffs.
+//!
+//! Translates the `DigitalPathPricer` of `ql/pricingengines/vanilla/mcdigitalengine.hpp`,
+//! restricted to exercise at expiry.
+
+use ql_core::Real;
+use ql_instruments::Payoff;
+use ql_methods::{Path, PathPricer};
+
+/// Prices a cash-or-nothing or asset-or-nothing payoff on the terminal value
+/// of each path.
+///
+/// Any [`Payoff`] is accepted; the name reflects the intended use with
+/// [`CashOrNothingPayoff`](ql_instruments::CashOrNothingPayoff) and
+/// [`AssetOrNothingPayoff`](ql_instruments::AssetOrNothingPayoff).
+///
+/// Corresponds to `QuantLib::DigitalPathPricer`.
+#[derive(Debug)]
+pub struct DigitalPathPricer<P> {
+    payoff: P,
+    discount: Real,
+}
+
+impl<P: Payoff> DigitalPathPricer<P> {
+    /// Create a pricer paying `payoff(S_T)` discounted by `discount`.
+    pub fn new(payoff: P, discount: Real) -> Self {
+        Self { payoff, discount }
+    }
+}
+
+impl<P: Payoff> PathPricer for DigitalPathPricer<P> {
+    fn value(&self, path: &Path) -> Real {
+        self.payoff.value(path.back()) * self.discount
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use ql_instruments::{AssetOrNothingPayoff, CashOrNothingPayoff, OptionType};
+    use ql_math::distributions::normal_cdf;
+    use ql_methods::MonteCarloModel;
+    use ql_processes::GeneralizedBlackScholesProcess;
+    use ql_termstructures::{BlackConstantVol, FlatForward};
+    use ql_time::{Actual365Fixed, Date};
+    use std::sync::Arc;
+
+    #[test]
+    fn digital_prices_match_closed_form() {
+        let (spot, strike, r, q, sigma, t): (Real, Real, Real, Real, Real, Real) =
+            (100.0, 105.0, 0.05, 0.02, 0.25, 1.0);
+        let ref_date = Date::from_ymd(2025, 1, 2).unwrap();
+        let process = GeneralizedBlackScholesProcess::new(
+            spot,
+            Arc::new(FlatForward::continuous(ref_date, r, Actual365Fixed)),
+            Arc::new(FlatForward::continuous(ref_date, q, Actual365Fixed)),
+            Arc::new(BlackConstantVol::new(ref_date, sigma, Actual365Fixed)),
+        );
+        let d1 = ((spot / strike).ln() + (r - q + 0.5 * sigma * sigma) * t) / (sigma * t.sqrt());
+        let d2 = d1 - sigma * t.sqrt();
+        let discount = (-r * t).exp();
+
+        let model = MonteCarloModel::new(&process, t, 1, 17);
+        let cases = [
+            (
+                model.simulate(
+                    &DigitalPathPricer::new(
+                        CashOrNothingPayoff::new(OptionType::Call, strike, 10.0),
+                        discount,
+                    ),
+                    50_000,
+                ),
+                10.0 * discount * normal_cdf(d2),
+            ),
+            (
+                model.simulate(
+                    &DigitalPathPricer::new(
+                        CashOrNothingPayoff::new(OptionType::Put, strike, 10.0),
+                        discount,
+                    ),
+                    50_000,
+                ),
+                10.0 * discount * normal_cdf(-d2),
+            ),
+            (
+                model.simulate(
+                    &DigitalPathPricer::new(
+                        AssetOrNothingPayoff::new(OptionType::Call, strike),
+                        discount,
+                    ),
+                    50_000,
+                ),
+                spot * (-q * t).exp() * normal_cdf(d1),
+            ),
+        ];
+        for (stats, expected) in cases {
+            let (mean, error) = (stats.mean().unwrap(), stats.error_estimate().unwrap());
+            assert!(
+                (mean - expected).abs() < 3.0 * error,
+                "MC {mean:.4} ± {error:.4} vs closed form {expected:.4}"
+            );
+        }
+    }
+}
@@ -60,7 +60,8 @@ impl DiscountingSwapEngine {
         Ok(PricingResults::from_npv(npv)
             .with_result("fixed_leg_npv", fixed_npv)
             .with_result("floating_leg_npv", floating_npv)
-            .with_result("fair_spread", 0.0)) // placeholder
+            .with_result("fair_spread", 0.0) // placeholder
+            .with_position(swap.position))
     }
 
     /// Price a generic multi-leg swap.
@@ -82,6 +83,7 @@ impl DiscountingSwapEngine {
 mod tests {
     use super::*;
     use ql_cashflows::SimpleCashFlow;
+    use ql_core::Position;
     use ql_termstructures::FlatForward;
     use ql_time::Actual365Fixed;
 
@@ -126,6 +128,7 @@ mod tests {
             fixed_leg,
             floating_leg,
             fixed_maturity: Date::from_ymd(2027, 1, 15).unwrap(),
+            position: Position::Long,
         };
 
         let result = engine.price_swap(&swap, ref_date).unwrap();
@@ -150,6 +153,7 @@ mod tests {
             fixed_leg: fixed1,
             floating_leg: float1,
             fixed_maturity: Date::from_ymd(2027, 1, 15).unwrap(),
+            position: Position::Long,
         };
 
         let receiver_swap = VanillaSwap {
@@ -160,6 +164,7 @@ mod tests {
             fixed_leg: fixed2,
             floating_leg: float2,
             fixed_maturity: Date::from_ymd(2027, 1, 15).unwrap(),
+            position: Position::Long,
         };
 
         let payer = engine.price_swap(&payer_swap, ref_date).unwrap();
@@ -173,6 +178,35 @@ mod tests {
         );
     }
 
+    #[test]
+    fn short_swap_flips_npv_but_not_leg_values() {
+        let ref_date = Date::from_ymd(2025, 1, 15).unwrap();
+        let curve = Arc::new(FlatForward::continuous(ref_date, 0.05, Actual365Fixed));
+        let engine = DiscountingSwapEngine::new(curve);
+
+        let make = |position| {
+            let (fixed_leg, floating_leg) = make_swap_legs();
+            VanillaSwap {
+                swap_type: SwapType::Payer,
+                nominal: 100.0,
+                fixed_rate: 0.03,
+                spread: 0.0,
+                fixed_leg,
+                floating_leg,
+                fixed_maturity: Date::from_ymd(2027, 1, 15).unwrap(),
+                position,
+            }
+        };
+        let long = engine.price_swap(&make(Position::Long), ref_date).unwrap();
+        let short = engine.price_swap(&make(Position::Short), ref_date).unwrap();
+
+        assert!((long.npv + short.npv).abs() < 1e-10);
+        assert_eq!(
+            long.additional_results["fixed_leg_npv"],
+            short.additional_results["fixed_leg_npv"]
+        );
+    }
+
     #[test]
     fn multi_leg_pricing() {
         let ref_date = Date::from_ymd(2025, 1, 15).unwrap();
@@ -198,4 +232,100 @@ mod tests {
             expected
         );
     }
+
+    #[test]
+    fn bootstrapped_curve_reprices_input_swaps() {
+        use ql_cashflows::{FixedRateLegBuilder, IborLegBuilder};
+        use ql_indexes::{euribor, Index};
+        use ql_termstructures::interpolated_zero_curve::Linear;
+        use ql_termstructures::rate_helpers::{DepositRateHelper, RateHelper, SwapRateHelper};
+        use ql_termstructures::PiecewiseYieldCurve;
+        use ql_time::calendars::target::Target;
+        use ql_time::{BusinessDayConvention, DayCounter, Frequency, Period, TimeUnit};
+
+        let ref_date = Date::from_ymd(2025, 1, 2).unwrap();
+        let mf = BusinessDayConvention::ModifiedFollowing;
+        let mut helpers: Vec<Box<dyn RateHelper>> = [(3, 0.030), (6, 0.031)]
+            .into_iter()
+            .map(|(months, rate)| {
+                Box::new(DepositRateHelper::from_tenor(
+                    rate,
+                    Period::new(months, TimeUnit::Months),
+                    2,
+                    &Target,
+                    mf,
+                    false,
+                    Actual365Fixed,
+                    ref_date,
+                )) as Box<dyn RateHelper>
+            })
+            .collect();
+        let swap_quotes = [(2, 0.032), (5, 0.034), (10, 0.036)];
+        let swap_helpers: Vec<SwapRateHelper> = swap_quotes
+            .iter()
+            .map(|&(years, rate)| {
+                SwapRateHelper::from_conventions(
+                    rate,
+                    Period::new(years, TimeUnit::Years),
+                    &Target,
+                    Frequency::Semiannual,
+                    mf,
+                    Actual365Fixed,
+                    ref_date,
+                    2,
+                )
+                .unwrap()
+            })
+            .collect();
+        let schedules: Vec<_> = swap_helpers
+            .iter()
+            .map(|h| h.fixed_schedule().clone())
+            .collect();
+        helpers.extend(
+            swap_helpers
+                .into_iter()
+                .map(|h| Box::new(h) as Box<dyn RateHelper>),
+        );
+
+        let curve = Arc::new(
+            PiecewiseYieldCurve::new(ref_date, &helpers, Actual365Fixed, &Linear).unwrap(),
+        );
+        let engine = DiscountingSwapEngine::new(curve.clone());
+
+        for (schedule, &(years, rate)) in schedules.iter().zip(&swap_quotes) {
+            // Single-curve setup: the floating leg fixes at the forwards
+            // implied by the bootstrapped curve itself.
+            let index = Arc::new(euribor(Period::new(6, TimeUnit::Months)));
+            for period in schedule.dates().windows(2) {
+                let tau = Actual365Fixed.year_fraction(period[0], period[1]);
+                let forward =
+                    (curve.discount_date(period[0]) / curve.discount_date(period[1]) - 1.0) / tau;
+                index.add_fixing(period[0], forward);
+            }
+            let nominal = 1_000_000.0;
+            let swap = VanillaSwap {
+                swap_type: SwapType::Payer,
+                nominal,
+                fixed_rate: rate,
+                spread: 0.0,
+                fixed_leg: FixedRateLegBuilder::new(schedule)
+                    .with_notionals(vec![nominal])
+                    .with_coupon_rate(rate)
+                    .build(),
+                floating_leg: IborLegBuilder::new(schedule, index)
+                    .with_notionals(vec![nominal])
+                    .with_fixing_days(0)
+                    .build(),
+                fixed_maturity: schedule.end_date().unwrap(),
+                position: Position::Long,
+            };
+            let result = engine.price_swap(&swap, ref_date).unwrap();
+            assert!(
+                result.npv.abs() < 1e-6,
+                "{years}Y swap: npv = {}",
+                result.npv
+            );
+            assert!(result.additional_results["fixed_leg_npv"] > 0.0);
+        }
+    }
 }
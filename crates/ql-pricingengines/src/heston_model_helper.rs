@@ -0,0 +1,302 @@
+//! Calibration helper for the Heston model.
+//!
+//! Translates `ql/models/equity/hestonmodelhelper.hpp`.
+//!
+//! The helper quotes a European option by its Black volatility and prices
+//! it under a [`HestonModel`] with the semi-analytic engine.  As in
+//! QuantLib, the option is a call for strikes at or above the forward and
+//! a put below it, so every quote is out of the money.
+
+use ql_core::{ensure, errors::Result, Real, Time, Volatility};
+use ql_instruments::OptionType;
+use ql_math::integrals::gaussianquadratures::{GaussLaguerreIntegration, GaussianQuadrature};
+use ql_models::{CalibrationHelper, HestonModel};
+use ql_termstructures::YieldTermStructure;
+
+use crate::analytic_european_engine::black_scholes_merton_greeks;
+use crate::analytic_heston_engine::heston_price_on;
+
+/// A European option quoted by Black volatility, for calibrating a
+/// [`HestonModel`].
+///
+/// Corresponds to `QuantLib::HestonModelHelper`.
+pub struct HestonModelHelper {
+    option_type: OptionType,
+    maturity: Time,
+    strike: Real,
+    volatility: Volatility,
+    risk_free_rate: Real,
+    dividend_yield: Real,
+    market_value: Real,
+    vega: Real,
+    quadrature: GaussianQuadrature,
+}
+
+impl std::fmt::Debug for HestonModelHelper {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("HestonModelHelper")
+            .field("option_type", &self.option_type)
+            .field("maturity", &self.maturity)
+            .field("strike", &self.strike)
+            .field("volatility", &self.volatility)
+            .finish()
+    }
+}
+
+impl HestonModelHelper {
+    /// Quote the option of `strike` expiring in `maturity` years at Black
+    /// volatility `volatility`, on an underlying at `spot`.
+    pub fn new(
+        maturity: Time,
+        spot: Real,
+        strike: Real,
+        volatility: Volatility,
+        risk_free_rate: &dyn YieldTermStructure,
+        dividend_yield: &dyn YieldTermStructure,
+    ) -> Result<Self> {
+        ensure!(maturity > 0.0, "maturity must be positive, got {maturity}");
+        ensure!(
+            spot > 0.0 && strike > 0.0,
+            "spot and strike must be positive"
+        );
+        ensure!(volatility >= 0.0, "negative volatility {volatility}");
+
+        let r = risk_free_rate.zero_rate_impl(maturity);
+        let q = dividend_yield.zero_rate_impl(maturity);
+        let forward = spot * ((r - q) * maturity).exp();
+        let option_type = if strike >= forward {
+            OptionType::Call
+        } else {
+            OptionType::Put
+        };
+        let black =
+            black_scholes_merton_greeks(option_type, spot, strike, r, q, volatility, maturity);
+        Ok(Self {
+            option_type,
+            maturity,
+            strike,
+            volatility,
+            risk_free_rate: r,
+            dividend_yield: q,
+            market_value: black.price,
+            vega: black.vega,
+            quadrature: GaussLaguerreIntegration::new(128, 0.0),
+        })
+    }
+
+    /// Option maturity in years.
+    pub fn maturity(&self) -> Time {
+        self.maturity
+    }
+
+    /// Option strike.
+    pub fn strike(&self) -> Real {
+        self.strike
+    }
+
+    /// Quoted Black volatility.
+    pub fn volatility(&self) -> Volatility {
+        self.volatility
+    }
+
+    /// Call or put, whichever is out of the money.
+    pub fn option_type(&self) -> OptionType {
+        self.option_type
+    }
+}
+
+impl CalibrationHelper<HestonModel> for HestonModelHelper {
+    fn market_value(&self) -> Real {
+        self.market_value
+    }
+
+    fn model_value(&self, model: &HestonModel) -> Result<Real> {
+        Ok(heston_price_on(
+            &self.quadrature,
+            self.option_type,
+            model.s0(),
+            self.strike,
+            self.risk_free_rate,
+            self.dividend_yield,
+            self.maturity,
+            model.v0(),
+            model.kappa(),
+            model.theta(),
+            model.sigma(),
+            model.rho(),
+        ))
+    }
+
+    fn vega(&self) -> Real {
+        self.vega
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use ql_math::optimization::{EndCriteria, LevenbergMarquardt};
+    use ql_models::{CalibratedModel, CalibrationBasket, CalibrationWeighting};
+    use ql_termstructures::FlatForward;
+    use ql_time::{Actual365Fixed, Date};
+    use std::sync::Arc;
+
+    const SPOT: Real = 100.0;
+
+    fn curves() -> (Arc<dyn YieldTermStructure>, Arc<dyn YieldTermStructure>) {
+        let ref_date = Date::from_ymd(2025, 1, 2).unwrap();
+        (
+            Arc::new(FlatForward::continuous(ref_date, 0.03, Actual365Fixed)),
+            Arc::new(FlatForward::continuous(ref_date, 0.01, Actual365Fixed)),
+        )
+    }
+
+    fn model() -> HestonModel {
+        let (rf, div) = curves();
+        HestonModel::from_params(SPOT, 0.05, rf, div, 1.0, 0.05, 0.4, -0.3)
+    }
+
+    /// A skewed smile that Heston cannot match exactly, with illiquid wing
+    /// quotes pushed away from it.
+    fn smile(t: Time, strike: Real) -> Volatility {
+        let m = (strike / SPOT).ln() / t.sqrt();
+        let noise = if m.abs() > 0.3 { 0.03 } else { 0.0 };
+        0.2 - 0.1 * m + 0.12 * m * m + noise
+    }
+
+    fn basket(weighting: CalibrationWeighting) -> CalibrationBasket<HestonModel> {
+        let (rf, div) = curves();
+        let mut basket = CalibrationBasket::new().with_weighting(weighting);
+        for t in [0.25, 0.5, 1.0, 2.0] {
+            for strike in [70.0, 80.0, 90.0, 100.0, 110.0, 125.0, 140.0] {
+                let vol = smile(t, strike);
+                basket.push(HestonModelHelper::new(t, SPOT, strike, vol, &*rf, &*div).unwrap());
+            }
+        }
+        basket
+    }
+
+    fn calibrate(basket: &CalibrationBasket<HestonModel>) -> HestonModel {
+        let mut model = model();
+        basket
+            .calibrate(
+                &mut model,
+                &LevenbergMarquardt::new(1e-8, 1e-8, 1e-8),
+                &EndCriteria::new(200, 20, 1e-12, 1e-10, 1e-10),
+            )
+            .unwrap();
+        model
+    }
+
+    /// Largest relative price error over the near-the-money helpers.
+    fn max_atm_error(basket: &CalibrationBasket<HestonModel>, model: &HestonModel) -> Real {
+        let helpers: Vec<_> = (0..basket.len()).map(|i| basket.helper(i)).collect();
+        let max_vega = helpers.iter().map(|h| h.vega()).fold(0.0, Real::max);
+        helpers
+            .iter()
+            .filter(|h| h.vega() > 0.8 * max_vega)
+            .map(|h| (h.model_value(model).unwrap() / h.market_value() - 1.0).abs())
+            .fold(0.0, Real::max)
+    }
+
+    #[test]
+    fn vega_weighting_fits_liquid_options_better() {
+        let equal = basket(CalibrationWeighting::Equal);
+        let vega = basket(CalibrationWeighting::Vega);
+        let equal_error = max_atm_error(&equal, &calibrate(&equal));
+        let vega_error = max_atm_error(&vega, &calibrate(&vega));
+        assert!(
+            vega_error < equal_error,
+            "vega-weighted {vega_error:.5} vs equal {equal_error:.5}"
+        );
+    }
+
+    #[test]
+    fn zero_vega_helper_is_dropped() {
+        let (rf, div) = curves();
+        let reference = calibrate(&basket(CalibrationWeighting::InverseVariance));
+
+        // A far out-of-the-money strike has no vega to speak of; with
+        // 1/vega² weights it would otherwise swamp the objective.
+        let mut with_dead_quote = basket(CalibrationWeighting::InverseVariance);
+        with_dead_quote.push(HestonModelHelper::new(0.25, SPOT, 400.0, 0.2, &*rf, &*div).unwrap());
+        let n = with_dead_quote.len();
+        assert!(with_dead_quote.helper(n - 1).vega() < 1e-30);
+        assert!(!with_dead_quote.active_helpers().contains(&(n - 1)));
+        assert_eq!(with_dead_quote.weights()[n - 1], 0.0);
+
+        let calibrated = calibrate(&with_dead_quote);
+        for (a, b) in calibrated.params().iter().zip(reference.params()) {
+            assert!((a.value() - b.value()).abs() < 1e-10);
+        }
+    }
+
+    /// A helper quoting the price of its option under a reference model.
+    struct Synthetic {
+        helper: HestonModelHelper,
+        market: Real,
+    }
+
+    impl CalibrationHelper<HestonModel> for Synthetic {
+        fn market_value(&self) -> Real {
+            self.market
+        }
+
+        fn model_value(&self, model: &HestonModel) -> Result<Real> {
+            self.helper.model_value(model)
+        }
+
+        fn vega(&self) -> Real {
+            self.helper.vega()
+        }
+    }
+
+    #[test]
+    fn model_recovers_synthetic_heston_prices() {
+        let (rf, div) = curves();
+        let truth =
+            HestonModel::from_params(SPOT, 0.04, rf.clone(), div.clone(), 1.5, 0.06, 0.5, -0.6);
+        let quotes: Vec<Synthetic> = [0.5, 1.0, 2.0]
+            .iter()
+            .flat_map(|&t| [80.0, 95.0, 105.0, 120.0].map(move |k| (t, k)))
+            .map(|(t, strike)| {
+                let helper = HestonModelHelper::new(t, SPOT, strike, 0.2, &*rf, &*div).unwrap();
+                let market = helper.model_value(&truth).unwrap();
+                Synthetic { helper, market }
+            })
+            .collect();
+        let helpers: Vec<&dyn CalibrationHelper<HestonModel>> = quotes
+            .iter()
+            .map(|q| q as &dyn CalibrationHelper<HestonModel>)
+            .collect();
+
+        let mut model = model();
+        let report = model
+            .calibrate(
+                &helpers,
+                &LevenbergMarquardt::new(1e-8, 1e-8, 1e-8),
+                &vec![1.0; helpers.len()],
+                &EndCriteria::new(400, 40, 1e-14, 1e-12, 1e-12),
+            )
+            .unwrap();
+        assert_eq!(report.residuals.len(), 12);
+        assert!(
+            report.max_residual() < 1e-4,
+            "residuals {:?}",
+            report.residuals
+        );
+        for (helper, residual) in helpers.iter().zip(&report.residuals) {
+            assert_eq!(*residual, helper.calibration_error(&model).unwrap());
+        }
+
+        // One weight per helper is required.
+        assert!(model
+            .calibrate(
+                &helpers,
+                &LevenbergMarquardt::new(1e-8, 1e-8, 1e-8),
+                &[1.0; 3],
+                &EndCriteria::default(),
+            )
+            .is_err());
+    }
+}
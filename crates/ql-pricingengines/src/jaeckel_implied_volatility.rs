@@ -0,0 +1,526 @@
+//! Black implied volatility by Jäckel's "Let's Be Rational" method.
+//!
+//! QuantLib has no counterpart; this follows P. Jäckel, *Let's Be Rational*
+//! (Wilmott, 2015).
+//!
+//! Prices are normalised by `√(FK)` and reduced, by put-call symmetry and
+//! subtraction of the intrinsic value, to an out-of-the-money call on
+//! log-moneyness `x = −|ln(F/K)| ≤ 0`:
+//!
+//! `b(x, s) = e^{x/2}·Φ(x/s + s/2) − e^{−x/2}·Φ(x/s − s/2)`,
+//!
+//! whose value lies in `[0, e^{x/2})` for total volatility `s = σ√T ≥ 0`.
+//! Around the inflexion point `s_c = √(2|x|)` the price range splits into
+//! four branches.  The two central ones interpolate `s` as a function of
+//! `b` with a rational cubic; the outer two interpolate the transformed
+//! values `f_l(b) ∝ Φ(−|x|/(√3 s))³` and `f_u(b) = Φ(−s/2)`, which are close
+//! to linear near `b = 0` and `b = e^{x/2}`, and invert the transform.  A few
+//! third-order Householder steps on an objective suited to the branch
+//! (`1/ln b` below, `b` in the middle, `ln(e^{x/2} − b)` above) then reach
+//! machine precision.
+
+use std::f64::consts::{PI, SQRT_2};
+
+use ql_core::{ensure, errors::Result, Real, Time, Volatility};
+use ql_instruments::OptionType;
+use ql_math::distributions::{erfc, normal_cdf_inverse};
+
+/// Householder steps allowed before giving up on further refinement.
+const MAX_ITERATIONS: usize = 10;
+
+/// Relative step below which the iteration stops.  Convergence is cubic,
+/// so the step just taken has already brought `s` to machine precision;
+/// asking for less would only chase rounding noise in `b`.
+const STEP_TOLERANCE: Real = 1e-10;
+
+/// Black volatility that reproduces the undiscounted `price` of a European
+/// option on `forward` struck at `strike`, expiring in `maturity` years.
+///
+/// A price equal to the intrinsic value gives zero volatility.
+///
+/// # Errors
+/// Fails for non-positive forward, strike or maturity, and for prices below
+/// the intrinsic value or at or above the no-arbitrage bound (`F` for a
+/// call, `K` for a put).
+pub fn implied_volatility_jaeckel(
+    price: Real,
+    forward: Real,
+    strike: Real,
+    maturity: Time,
+    option_type: OptionType,
+) -> Result<Volatility> {
+    ensure!(
+        forward > 0.0 && strike > 0.0,
+        "forward ({forward}) and strike ({strike}) must be positive"
+    );
+    ensure!(maturity > 0.0, "maturity must be positive, got {maturity}");
+    let intrinsic = (option_type.sign() * (forward - strike)).max(0.0);
+    let upper_bound = match option_type {
+        OptionType::Call => forward,
+        OptionType::Put => strike,
+    };
+    ensure!(
+        price >= intrinsic,
+        "price ({price}) is below the intrinsic value ({intrinsic})"
+    );
+    ensure!(
+        price < upper_bound,
+        "price ({price}) is at or above the no-arbitrage bound ({upper_bound})"
+    );
+
+    let beta = (price - intrinsic) / (forward * strike).sqrt();
+    let x = -(forward / strike).ln().abs();
+    let (s, _) = normalised_implied_std_dev(beta, x);
+    Ok(s / maturity.sqrt())
+}
+
+/// Which part of the price range a price falls in, and so which objective
+/// the Householder steps use.  The two central initial-guess branches
+/// share the same objective and are both [`Branch::Middle`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum Branch {
+    /// Below `b_l`: objective `1/ln b`.
+    Lower,
+    /// Between `b_l` and `b_u`: objective `b`.
+    Middle,
+    /// Above `b_u`: objective `ln(e^{x/2} − b)`.
+    Upper,
+}
+
+/// Total volatility `s` with `b(x, s) = beta`, for `x ≤ 0`, and the number
+/// of Householder steps taken.
+fn normalised_implied_std_dev(beta: Real, x: Real) -> (Real, usize) {
+    if beta <= 0.0 {
+        return (0.0, 0);
+    }
+    let b_max = (0.5 * x).exp();
+    let (mut s, branch) = initial_guess(beta, x, b_max);
+
+    let mut iterations = 0;
+    while iterations < MAX_ITERATIONS && s > 0.0 {
+        iterations += 1;
+        let b = normalised_black(x, s);
+        let vega = normalised_vega(x, s);
+        if vega <= Real::MIN_POSITIVE {
+            break;
+        }
+        // b''/b' and b'''/b' of the normalised price.
+        let a1 = x * x / (s * s * s) - 0.25 * s;
+        let a3 = a1 * a1 - 3.0 * (x / (s * s)).powi(2) - 0.25;
+
+        let (newton, halley, householder) = match branch {
+            Branch::Lower if b > 0.0 => {
+                let (ln_b, ln_beta) = (b.ln(), beta.ln());
+                let u = vega / b;
+                let g = 1.0 / ln_b - 1.0 / ln_beta;
+                let dg = -u / (ln_b * ln_b);
+                (
+                    -g / dg,
+                    a1 - u * (1.0 + 2.0 / ln_b),
+                    6.0 * u * u / (ln_b * ln_b) - 6.0 * (a1 - u) * u / ln_b + a3 - 3.0 * u * a1
+                        + 2.0 * u * u,
+                )
+            }
+            Branch::Upper => {
+                let gap = normalised_black_complement(x, s);
+                let w = vega / gap;
+                let g = gap.ln() - (b_max - beta).ln();
+                (g / w, a1 + w, a3 + 3.0 * a1 * w + 2.0 * w * w)
+            }
+            _ => ((beta - b) / vega, a1, a3),
+        };
+        let factor =
+            (1.0 + 0.5 * halley * newton) / (1.0 + newton * (halley + householder * newton / 6.0));
+        let ds = (newton * factor).max(-0.5 * s);
+        s += ds;
+        if ds.abs() <= STEP_TOLERANCE * s {
+            break;
+        }
+    }
+    (s, iterations)
+}
+
+/// Rational-cubic initial guess and the branch it came from.
+fn initial_guess(beta: Real, x: Real, b_max: Real) -> (Real, Branch) {
+    if x == 0.0 {
+        // At the money b = 1 − 2Φ(−s/2) exactly.
+        return (
+            -2.0 * normal_cdf_inverse(0.5 * (b_max - beta)),
+            Branch::Upper,
+        );
+    }
+    let s_c = (2.0 * x.abs()).sqrt();
+    let b_c = normalised_black(x, s_c);
+    let v_c = normalised_vega(x, s_c);
+
+    let s_l = s_c - b_c / v_c;
+    let b_l = if s_l > 0.0 {
+        normalised_black(x, s_l)
+    } else {
+        0.0
+    };
+    if beta < b_l {
+        let (f_l, df_l, d2f_l) = lower_map_with_derivatives(x, s_l);
+        let r = control_to_fit_right_curvature(0.0, b_l, 0.0, f_l, 1.0, df_l, d2f_l, true);
+        let mut f = rational_cubic(beta, 0.0, b_l, 0.0, f_l, 1.0, df_l, r);
+        if f <= 0.0 {
+            let t = beta / b_l;
+            f = (f_l * t + b_l * (1.0 - t)) * t;
+        }
+        return (inverse_lower_map(x, f), Branch::Lower);
+    }
+
+    let s_u = s_c + (b_max - b_c) / v_c;
+    let b_u = normalised_black(x, s_u);
+    if beta <= b_c {
+        let v_l = normalised_vega(x, s_l);
+        let r =
+            control_to_fit_right_curvature(b_l, b_c, s_l, s_c, 1.0 / v_l, 1.0 / v_c, 0.0, false);
+        let s = rational_cubic(beta, b_l, b_c, s_l, s_c, 1.0 / v_l, 1.0 / v_c, r);
+        return (s, Branch::Middle);
+    }
+    if beta <= b_u {
+        let v_u = normalised_vega(x, s_u);
+        let r = control_to_fit_left_curvature(b_c, b_u, s_c, s_u, 1.0 / v_c, 1.0 / v_u, 0.0, false);
+        let s = rational_cubic(beta, b_c, b_u, s_c, s_u, 1.0 / v_c, 1.0 / v_u, r);
+        return (s, Branch::Middle);
+    }
+
+    let (f_u, df_u, d2f_u) = upper_map_with_derivatives(x, s_u);
+    let mut f = if d2f_u.is_finite() {
+        let r = control_to_fit_left_curvature(b_u, b_max, f_u, 0.0, df_u, -0.5, d2f_u, true);
+        rational_cubic(beta, b_u, b_max, f_u, 0.0, df_u, -0.5, r)
+    } else {
+        0.0
+    };
+    if f <= 0.0 {
+        let h = b_max - b_u;
+        let t = (beta - b_u) / h;
+        f = (f_u * (1.0 - t) + 0.5 * h * t) * (1.0 - t);
+    }
+    (-2.0 * normal_cdf_inverse(f), Branch::Upper)
+}
+
+/// Standard normal CDF to full double precision.
+fn cdf(x: Real) -> Real {
+    0.5 * erfc(-x / SQRT_2)
+}
+
+/// Normalised out-of-the-money call price `b(x, s)`, `x ≤ 0`.
+fn normalised_black(x: Real, s: Real) -> Real {
+    if s <= 0.0 {
+        return 0.0;
+    }
+    let (h, t) = (x / s, 0.5 * s);
+    let b = (0.5 * x).exp() * cdf(h + t) - (-0.5 * x).exp() * cdf(h - t);
+    b.max(0.0)
+}
+
+/// `e^{x/2} − b(x, s)`, computed without cancellation.
+fn normalised_black_complement(x: Real, s: Real) -> Real {
+    let (h, t) = (x / s, 0.5 * s);
+    (0.5 * x).exp() * cdf(-h - t) + (-0.5 * x).exp() * cdf(h - t)
+}
+
+/// `∂b/∂s`.
+fn normalised_vega(x: Real, s: Real) -> Real {
+    (-0.5 * ((x / s).powi(2) + 0.25 * s * s)).exp() / (2.0 * PI).sqrt()
+}
+
+/// `f_l = 2π|x|/√27 · Φ(−|x|/(√3 s))³` and its first two derivatives with
+/// respect to `b`.
+fn lower_map_with_derivatives(x: Real, s: Real) -> (Real, Real, Real) {
+    let ax = x.abs();
+    let z = ax / (3.0_f64.sqrt() * s);
+    let y = z * z;
+    let phi_cdf = cdf(-z);
+    let phi_pdf = (-0.5 * y).exp() / (2.0 * PI).sqrt();
+    let e = (y + 0.125 * s * s).exp();
+
+    let f = 2.0 * PI / 27.0_f64.sqrt() * ax * phi_cdf.powi(3);
+    let df = 2.0 * PI * y * phi_cdf * phi_cdf * e;
+    let ddf_ds = 2.0
+        * PI
+        * y
+        * phi_cdf
+        * e
+        * (2.0 * (phi_pdf * z - phi_cdf) / s + phi_cdf * (0.25 * s - 2.0 * y / s));
+    (f, df, ddf_ds / normalised_vega(x, s))
+}
+
+/// Inverse of the lower map: the `s` with `f_l(s) = f`.
+fn inverse_lower_map(x: Real, f: Real) -> Real {
+    if f <= 0.0 {
+        return 0.0;
+    }
+    let p = (f / (2.0 * PI / 27.0_f64.sqrt() * x.abs())).cbrt();
+    (x / (3.0_f64.sqrt() * normal_cdf_inverse(p))).abs()
+}
+
+/// `f_u = Φ(−s/2)` and its first two derivatives with respect to `b`.
+fn upper_map_with_derivatives(x: Real, s: Real) -> (Real, Real, Real) {
+    let w = (x / s).powi(2);
+    (
+        cdf(-0.5 * s),
+        -0.5 * (0.5 * w).exp(),
+        (0.5 * PI).sqrt() * (w + 0.125 * s * s).exp() * w / s,
+    )
+}
+
+/// Delbourgo-Gregory rational cubic through `(x_l, y_l)` and `(x_r, y_r)`
+/// with end slopes `d_l`, `d_r` and control parameter `r` (3 gives the
+/// Hermite cubic, large values tend to the straight line).
+#[allow(clippy::too_many_arguments)]
+fn rational_cubic(
+    x: Real,
+    x_l: Real,
+    x_r: Real,
+    y_l: Real,
+    y_r: Real,
+    d_l: Real,
+    d_r: Real,
+    r: Real,
+) -> Real {
+    let h = x_r - x_l;
+    if h.abs() <= 0.0 {
+        return 0.5 * (y_l + y_r);
+    }
+    let t = (x - x_l) / h;
+    let omt = 1.0 - t;
+    if r >= Real::MAX.sqrt() {
+        return y_r * t + y_l * omt;
+    }
+    let (t2, omt2) = (t * t, omt * omt);
+    (y_r * t2 * t
+        + (r * y_r - h * d_r) * t2 * omt
+        + (r * y_l + h * d_l) * t * omt2
+        + y_l * omt2 * omt)
+        / (1.0 + (r - 3.0) * t * omt)
+}
+
+/// Smallest control parameter keeping the rational cubic monotone and
+/// convex (or concave) where its data are, `s` being the secant slope.
+fn minimum_control(d_l: Real, d_r: Real, s: Real, prefer_shape: bool) -> Real {
+    let lower_limit = -(1.0 - Real::EPSILON.sqrt());
+    let huge = Real::MAX.sqrt();
+    let monotonic = d_l * s >= 0.0 && d_r * s >= 0.0;
+    let convex = d_l <= s && s <= d_r;
+    let concave = d_l >= s && s >= d_r;
+    if !monotonic && !convex && !concave {
+        return lower_limit;
+    }
+    let mut r1 = -huge;
+    let mut r2 = -huge;
+    if monotonic {
+        if s != 0.0 {
+            r1 = (d_r + d_l) / s;
+        } else if prefer_shape {
+            r1 = huge;
+        }
+    }
+    if convex || concave {
+        let (s_m_d_l, d_r_m_s) = (s - d_l, d_r - s);
+        if s_m_d_l != 0.0 && d_r_m_s != 0.0 {
+            let d_r_m_d_l = d_r - d_l;
+            r2 = (d_r_m_d_l / d_r_m_s).abs().max((d_r_m_d_l / s_m_d_l).abs());
+        } else if prefer_shape {
+            r2 = huge;
+        }
+    } else if monotonic && prefer_shape {
+        r2 = huge;
+    }
+    lower_limit.max(r1.max(r2))
+}
+
+/// Control parameter giving the rational cubic second derivative `d2_l` at
+/// its left end, subject to [`minimum_control`].
+#[allow(clippy::too_many_arguments)]
+fn control_to_fit_left_curvature(
+    x_l: Real,
+    x_r: Real,
+    y_l: Real,
+    y_r: Real,
+    d_l: Real,
+    d_r: Real,
+    d2_l: Real,
+    prefer_shape: bool,
+) -> Real {
+    let h = x_r - x_l;
+    let numerator = 0.5 * h * d2_l + (d_r - d_l);
+    let denominator = (y_r - y_l) / h - d_l;
+    fit_control(
+        numerator,
+        denominator,
+        d_l,
+        d_r,
+        (y_r - y_l) / h,
+        prefer_shape,
+    )
+}
+
+/// Control parameter giving the rational cubic second derivative `d2_r` at
+/// its right end, subject to [`minimum_control`].
+#[allow(clippy::too_many_arguments)]
+fn control_to_fit_right_curvature(
+    x_l: Real,
+    x_r: Real,
+    y_l: Real,
+    y_r: Real,
+    d_l: Real,
+    d_r: Real,
+    d2_r: Real,
+    prefer_shape: bool,
+) -> Real {
+    let h = x_r - x_l;
+    let numerator = 0.5 * h * d2_r + (d_r - d_l);
+    let denominator = d_r - (y_r - y_l) / h;
+    fit_control(
+        numerator,
+        denominator,
+        d_l,
+        d_r,
+        (y_r - y_l) / h,
+        prefer_shape,
+    )
+}
+
+fn fit_control(
+    numerator: Real,
+    denominator: Real,
+    d_l: Real,
+    d_r: Real,
+    secant: Real,
+    prefer_shape: bool,
+) -> Real {
+    let minimum = minimum_control(d_l, d_r, secant, prefer_shape);
+    let huge = Real::MAX.sqrt();
+    let r = if numerator == 0.0 {
+        0.0
+    } else if denominator == 0.0 {
+        if numerator > 0.0 {
+            huge
+        } else {
+            -huge
+        }
+    } else {
+        numerator / denominator
+    };
+    r.max(minimum)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use ql_math::solvers1d::brent;
+    use std::cell::Cell;
+
+    /// Undiscounted Black price built from the normalised OTM price, as the
+    /// solver sees it.
+    fn black(option_type: OptionType, forward: Real, strike: Real, sigma: Real, t: Time) -> Real {
+        let intrinsic = (option_type.sign() * (forward - strike)).max(0.0);
+        let x = -(forward / strike).ln().abs();
+        intrinsic + (forward * strike).sqrt() * normalised_black(x, sigma * t.sqrt())
+    }
+
+    #[test]
+    fn recovers_volatility_across_moneyness() {
+        let forward = 100.0;
+        let mut checked = 0;
+        for strike in [
+            2.0, 25.0, 60.0, 90.0, 99.0, 100.0, 101.0, 115.0, 250.0, 1_000.0, 5_000.0,
+        ] {
+            for sigma in [0.01, 0.05, 0.2, 0.6, 1.5, 4.0] {
+                for t in [0.05, 1.0, 10.0] {
+                    let x = -(forward / strike as Real).ln().abs();
+                    let s = sigma * Real::sqrt(t);
+                    let beta = normalised_black(x, s);
+                    // Skip prices out of the representable range, and those
+                    // so close to the bound e^{x/2} that its gap to β, and
+                    // so σ, is known only to ε·e^{x/2}/gap.
+                    let gap = normalised_black_complement(x, s);
+                    if beta < 1e-280 || gap < 1e-3 * (0.5 * x).exp() {
+                        continue;
+                    }
+                    let (implied, iterations) = normalised_implied_std_dev(beta, x);
+                    assert!(
+                        (implied / s - 1.0).abs() < 1e-12,
+                        "K = {strike}, σ = {sigma}, T = {t}: {implied} vs {s}"
+                    );
+                    assert!(iterations <= 4, "K = {strike}, σ = {sigma}, T = {t}");
+                    checked += 1;
+
+                    // Out-of-the-money quotes carry all of the normalised
+                    // price, so the public entry point is exact too.
+                    let option_type = if strike >= forward {
+                        OptionType::Call
+                    } else {
+                        OptionType::Put
+                    };
+                    let price = black(option_type, forward, strike, sigma, t);
+                    let vol =
+                        implied_volatility_jaeckel(price, forward, strike, t, option_type).unwrap();
+                    assert!(
+                        (vol / sigma - 1.0).abs() < 1e-12,
+                        "K = {strike}, σ = {sigma}"
+                    );
+                }
+            }
+        }
+        assert!(checked > 150, "{checked}");
+    }
+
+    #[test]
+    fn near_intrinsic_prices_converge_where_brent_labours() {
+        let forward = 100.0;
+        for (option_type, strike, sigma, t) in [
+            // Deep in the money: a tiny time value atop the intrinsic.
+            (OptionType::Call, 80.0, 0.08, 0.5),
+            (OptionType::Put, 125.0, 0.07, 1.0),
+            // Deep out of the money, a long way into the tail.
+            (OptionType::Call, 300.0, 0.12, 1.0),
+            (OptionType::Put, 20.0, 0.2, 2.0),
+            // Near the upper bound.
+            (OptionType::Call, 100.0, 3.0, 1.0),
+        ] {
+            let price = black(option_type, forward, strike, sigma, t);
+            let time_value = price - (option_type.sign() * (forward - strike)).max(0.0);
+            let vol = implied_volatility_jaeckel(price, forward, strike, t, option_type).unwrap();
+            // The time value carries only as many digits as the price leaves
+            // it after the intrinsic value; the vol is good to that level.
+            let tolerance = 1e-12_f64.max(Real::EPSILON * price / time_value);
+            assert!(
+                (vol / sigma - 1.0).abs() < tolerance,
+                "{option_type:?} K = {strike}: {vol} vs {sigma}"
+            );
+
+            let x = -(forward / strike).ln().abs();
+            let beta = time_value / (forward * strike).sqrt();
+            let (_, iterations) = normalised_implied_std_dev(beta, x);
+
+            let evaluations = Cell::new(0);
+            let objective = |v: Real| {
+                evaluations.set(evaluations.get() + 1);
+                black(option_type, forward, strike, v, t) - price
+            };
+            let brent_result = brent(objective, 1e-7, 5.0, 1e-14);
+            assert!(
+                brent_result.is_err() || evaluations.get() > 3 * iterations,
+                "{option_type:?} K = {strike}: Brent {} evaluations, Householder {iterations}",
+                evaluations.get()
+            );
+        }
+    }
+
+    #[test]
+    fn rejects_arbitrage_prices() {
+        let (f, k, t) = (100.0, 90.0, 1.0);
+        assert!(implied_volatility_jaeckel(9.0, f, k, t, OptionType::Call).is_err());
+        assert!(implied_volatility_jaeckel(100.0, f, k, t, OptionType::Call).is_err());
+        assert!(implied_volatility_jaeckel(90.0, f, k, t, OptionType::Put).is_err());
+        assert!(implied_volatility_jaeckel(1.0, f, -k, t, OptionType::Put).is_err());
+        assert_eq!(
+            implied_volatility_jaeckel(10.0, f, k, t, OptionType::Call).unwrap(),
+            0.0
+        );
+    }
+}
@@ -6,9 +6,24 @@
 //! ## Engines
 //!
 //! - [`AnalyticEuropeanEngine`] — Black-Scholes-Merton closed-form for European options
+//! - [`BlackCalculator`] — Black-76 value and Greeks of a vanilla option on a forward
 //! - [`AnalyticHestonEngine`] — Semi-analytic Heston engine (Gauss-Laguerre integration)
+//! - [`CarrMadanFftEngine`] — Carr-Madan FFT over any [`CharacteristicFunction`](ql_processes::CharacteristicFunction)
+//! - [`fourier_european_price`] — Carr-Madan, COS or Gauss-Laguerre pricing of any characteristic function
 //! - [`BaroneAdesiWhaleyEngine`] — Quadratic approximation for American options
-//! - [`AnalyticBarrierEngine`] — Reiner-Rubinstein barrier option engine
+//! - [`AnalyticBarrierEngine`] — Reiner-Rubinstein barrier option engine, vanilla or cash-or-nothing
+//! - [`AnalyticDigitalEngine`] — Closed forms for European cash-or-nothing, asset-or-nothing and gap options
+//! - [`AnalyticTouchEngine`] — First-passage pricing of one-touch and no-touch options
+//! - [`ContinuousGeometricAveragePriceAsianEngine`] — Kemna-Vorst closed form for continuous geometric Asians
+//! - [`McContinuousArithmeticAsianEngine`] — Monte Carlo continuous arithmetic Asians with a geometric control
+//! - [`implied_volatility_jaeckel`] — Black implied volatility by Jäckel's "Let's Be Rational"
+//! - [`BlackCapFloorEngine`] — Black-76 caplets and floorlets on a forecast curve, flat or smile volatility
+//! - [`JamshidianSwaptionEngine`] — Hull-White European swaptions by Jamshidian decomposition
+//! - [`FdmLocalVolEngine`] — Crank-Nicolson finite differences under a local vol surface
+//! - [`Merton76Engine`] — Merton jump-diffusion series for European options
+//! - [`McEuropeanEngine`] — Monte Carlo European engine over pseudo- or quasi-random draws
+//! - [`DigitalPathPricer`] / [`BarrierPathPricer`] — Monte Carlo path pricers for digital and barrier payoffs
+//! - [`HistoricalSimulationVaR`] — Rolling historical-simulation VaR and expected shortfall of a portfolio
 //! - [`DiscountingBondEngine`] — Discounted cash flow engine for bonds
 //! - [`DiscountingSwapEngine`] — Discounted cash flow engine for swaps
 
@@ -16,15 +31,60 @@
 #![forbid(unsafe_code)]
 
 pub mod analytic_barrier_engine;
+pub mod analytic_continuous_geometric_asian_engine;
+pub mod analytic_digital_engine;
 pub mod analytic_european_engine;
 pub mod analytic_heston_engine;
+pub mod analytic_touch_engine;
 pub mod barone_adesi_whaley_engine;
+pub mod barrier_path_pricer;
+pub mod black_calculator;
+pub mod black_cap_floor_engine;
+pub mod carr_madan_fft_engine;
+pub mod digital_path_pricer;
 pub mod discounting_bond_engine;
 pub mod discounting_swap_engine;
+pub mod fdm_local_vol_engine;
+pub mod fourier_european;
+pub mod heston_model_helper;
+pub mod historical_simulation_var;
+pub mod jaeckel_implied_volatility;
+pub mod jamshidian_swaption_engine;
+pub mod mc_continuous_arithmetic_asian_engine;
+pub mod mc_european_engine;
+pub mod merton76_engine;
+pub mod perpetual_american;
 
-pub use analytic_barrier_engine::{analytic_barrier_price, AnalyticBarrierEngine};
-pub use analytic_european_engine::{black_scholes_merton, AnalyticEuropeanEngine};
+pub use analytic_barrier_engine::{
+    analytic_barrier_price, binary_barrier_price, AnalyticBarrierEngine,
+};
+pub use analytic_continuous_geometric_asian_engine::{
+    continuous_geometric_asian_price, ContinuousGeometricAveragePriceAsianEngine,
+};
+pub use analytic_digital_engine::AnalyticDigitalEngine;
+pub use analytic_european_engine::{
+    black_scholes_merton, black_scholes_merton_greeks, black_scholes_merton_value,
+    AnalyticEuropeanEngine, BlackScholesGreeks,
+};
 pub use analytic_heston_engine::{heston_price, AnalyticHestonEngine};
+pub use analytic_touch_engine::{analytic_touch_price, AnalyticTouchEngine};
 pub use barone_adesi_whaley_engine::{barone_adesi_whaley, BaroneAdesiWhaleyEngine};
+pub use barrier_path_pricer::BarrierPathPricer;
+pub use black_calculator::BlackCalculator;
+pub use black_cap_floor_engine::BlackCapFloorEngine;
+pub use carr_madan_fft_engine::CarrMadanFftEngine;
+pub use digital_path_pricer::DigitalPathPricer;
 pub use discounting_bond_engine::{clean_price, DiscountingBondEngine};
 pub use discounting_swap_engine::DiscountingSwapEngine;
+pub use fdm_local_vol_engine::FdmLocalVolEngine;
+pub use fourier_european::{fourier_european_price, FourierMethod};
+pub use heston_model_helper::HestonModelHelper;
+pub use historical_simulation_var::{
+    HistoricalSimulationVaR, HistoricalVaRResults, RiskFactor, ScenarioShift,
+};
+pub use jaeckel_implied_volatility::implied_volatility_jaeckel;
+pub use jamshidian_swaption_engine::{jamshidian_swaption_price, JamshidianSwaptionEngine};
+pub use mc_continuous_arithmetic_asian_engine::McContinuousArithmeticAsianEngine;
+pub use mc_european_engine::McEuropeanEngine;
+pub use merton76_engine::{merton_jump_price, Merton76Engine};
+pub use perpetual_american::{perpetual_american_boundary, perpetual_american_price};
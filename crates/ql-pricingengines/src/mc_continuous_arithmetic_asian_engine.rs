@@ -0,0 +1,183 @@
+//! Monte Carlo engine for continuously averaged arithmetic Asian options.
+//!
+//! Translates the continuous-averaging case of
+//! `ql/pricingengines/asian/mc_discr_arith_av_price.hpp`.
+//!
+//! The continuous average is approximated by the trapezoidal rule on a
+//! uniform grid.  With the control variate enabled, the same rule is applied
+//! to `ln S` and the continuous geometric average-price option, whose exact
+//! value [`continuous_geometric_asian_price`] is known, is used as the
+//! control; the two averages are so closely correlated that the error falls
+//! by an order of magnitude.
+
+use std::sync::Arc;
+
+use ql_core::{ensure, errors::Result, Real};
+use ql_instruments::{
+    AverageType, ContinuousAveragingAsianOptionArguments, PricingEngine, PricingResults,
+    StrikedPayoff,
+};
+use ql_methods::{MonteCarloModel, Path, PathPricer};
+use ql_processes::GeneralizedBlackScholesProcess;
+
+use crate::analytic_continuous_geometric_asian_engine::continuous_geometric_asian_price;
+
+/// Monte Carlo engine for continuous arithmetic average-price Asian
+/// options.
+///
+/// Corresponds to `QuantLib::MCDiscreteArithmeticAPEngine`, sampled finely
+/// enough to stand for continuous averaging.
+#[derive(Debug)]
+pub struct McContinuousArithmeticAsianEngine {
+    process: Arc<GeneralizedBlackScholesProcess>,
+    time_steps: usize,
+    samples: usize,
+    seed: u64,
+    control_variate: bool,
+}
+
+impl McContinuousArithmeticAsianEngine {
+    /// Create a new engine simulating `samples` paths of `time_steps` steps.
+    pub fn new(
+        process: Arc<GeneralizedBlackScholesProcess>,
+        time_steps: usize,
+        samples: usize,
+        seed: u64,
+    ) -> Self {
+        Self {
+            process,
+            time_steps,
+            samples,
+            seed,
+            control_variate: false,
+        }
+    }
+
+    /// Enable or disable the geometric-average control variate.
+    pub fn with_control_variate(mut self, control_variate: bool) -> Self {
+        self.control_variate = control_variate;
+        self
+    }
+}
+
+/// Average-price payoff on the trapezoidal average of the path, taken on
+/// the values themselves or on their logarithms.
+struct TrapezoidalAveragePricer {
+    payoff: Arc<dyn StrikedPayoff>,
+    discount: Real,
+    geometric: bool,
+}
+
+impl PathPricer for TrapezoidalAveragePricer {
+    fn value(&self, path: &Path) -> Real {
+        let f = |s: Real| if self.geometric { s.ln() } else { s };
+        let n = path.steps();
+        let interior: Real = path.values[1..n].iter().map(|&s| f(s)).sum();
+        let mean = (0.5 * (f(path.front()) + f(path.back())) + interior) / n as Real;
+        let average = if self.geometric { mean.exp() } else { mean };
+        self.payoff.value(average) * self.discount
+    }
+}
+
+impl PricingEngine<ContinuousAveragingAsianOptionArguments> for McContinuousArithmeticAsianEngine {
+    fn calculate(&self, args: &ContinuousAveragingAsianOptionArguments) -> Result<PricingResults> {
+        ensure!(
+            args.average_type == AverageType::Arithmetic,
+            "not an arithmetic average option"
+        );
+        ensure!(self.time_steps > 0, "at least one time step is required");
+        ensure!(self.samples > 1, "at least two samples are required");
+
+        let rf = self.process.risk_free_rate();
+        let t = rf
+            .day_counter()
+            .year_fraction(rf.reference_date(), args.exercise.last_date());
+        ensure!(t > 0.0, "option has expired");
+
+        let discount = rf.discount(t);
+        let pricer = |geometric| TrapezoidalAveragePricer {
+            payoff: args.payoff.clone(),
+            discount,
+            geometric,
+        };
+        let model = MonteCarloModel::new(&*self.process, t, self.time_steps, self.seed);
+        let stats = if self.control_variate {
+            let strike = args.payoff.strike();
+            let sigma = self
+                .process
+                .black_volatility()
+                .expect("process must have a black vol surface")
+                .black_vol_time(t, strike);
+            let control_value = continuous_geometric_asian_price(
+                args.payoff.option_type(),
+                self.process.spot(),
+                strike,
+                rf.zero_rate_impl(t),
+                self.process.dividend_yield().zero_rate_impl(t),
+                sigma,
+                t,
+            );
+            model.simulate_with_control(&pricer(false), &pricer(true), control_value, self.samples)
+        } else {
+            model.simulate(&pricer(false), self.samples)
+        };
+
+        let mut results = PricingResults::from_npv(stats.mean().unwrap_or(0.0));
+        results.error_estimate = stats.error_estimate();
+        Ok(results)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::analytic_continuous_geometric_asian_engine::ContinuousGeometricAveragePriceAsianEngine;
+    use ql_instruments::{
+        ContinuousAveragingAsianOption, Exercise, OptionType, PlainVanillaPayoff,
+    };
+    use ql_termstructures::{BlackConstantVol, FlatForward};
+    use ql_time::{Actual365Fixed, Date};
+
+    #[test]
+    fn arithmetic_average_is_worth_more_than_geometric() {
+        let ref_date = Date::from_ymd(2025, 1, 2).unwrap();
+        let process = Arc::new(GeneralizedBlackScholesProcess::new(
+            100.0,
+            Arc::new(FlatForward::continuous(ref_date, 0.05, Actual365Fixed)),
+            Arc::new(FlatForward::continuous(ref_date, 0.02, Actual365Fixed)),
+            Arc::new(BlackConstantVol::new(ref_date, 0.3, Actual365Fixed)),
+        ));
+        let option = |average_type| {
+            ContinuousAveragingAsianOption::new(
+                average_type,
+                Arc::new(PlainVanillaPayoff::new(OptionType::Call, 100.0)),
+                Exercise::european(Date::from_ymd(2026, 1, 2).unwrap()),
+            )
+            .arguments()
+        };
+        let geometric = ContinuousGeometricAveragePriceAsianEngine::new(process.clone())
+            .calculate(&option(AverageType::Geometric))
+            .unwrap()
+            .npv;
+
+        let engine = |cv| {
+            McContinuousArithmeticAsianEngine::new(process.clone(), 250, 10_000, 42)
+                .with_control_variate(cv)
+                .calculate(&option(AverageType::Arithmetic))
+                .unwrap()
+        };
+        let (plain, controlled) = (engine(false), engine(true));
+        let (plain_err, cv_err) = (
+            plain.error_estimate.unwrap(),
+            controlled.error_estimate.unwrap(),
+        );
+        assert!(cv_err < plain_err / 10.0, "{cv_err:.2e} vs {plain_err:.2e}");
+        assert!((plain.npv - controlled.npv).abs() < 3.0 * plain_err);
+        // AM-GM: the arithmetic average dominates path by path.
+        assert!(
+            controlled.npv > geometric + 3.0 * cv_err,
+            "arithmetic {:.4} ± {cv_err:.4} vs geometric {geometric:.4}",
+            controlled.npv
+        );
+    }
+}
@@ -0,0 +1,245 @@
+//! Monte Carlo European option engine.
+//!
+//! Translates `ql/pricingengines/vanilla/mceuropeanengine.hpp`.
+//!
+//! Simulates the underlying with a [`MonteCarloModel`] driven by any
+//! [`UniformRsg`] — pseudo-random (`RandomSequenceGenerator`) or
+//! low-discrepancy (`SobolRsg`, `HaltonRsg`) — and reports the mean
+//! discounted payoff together with its standard error.
+
+use std::fmt;
+use std::marker::PhantomData;
+use std::sync::Arc;
+
+use ql_core::{ensure, errors::Result};
+use ql_instruments::{
+    ExerciseType, PricingEngine, PricingResults, StrikedPayoff, VanillaOptionArguments,
+};
+use ql_math::random_numbers::UniformRsg;
+use ql_methods::{EuropeanPathPricer, MonteCarloModel, PathPricer};
+use ql_processes::GeneralizedBlackScholesProcess;
+
+/// Monte Carlo engine for European vanilla options, generic over the
+/// uniform sequence generator `R`.
+///
+/// The control variate, when enabled, is the discounted terminal spot,
+/// whose expectation `S₀·e^{−qT}` is known exactly.
+///
+/// Corresponds to `QuantLib::MCEuropeanEngine<RNG>`.
+pub struct McEuropeanEngine<R> {
+    process: Arc<GeneralizedBlackScholesProcess>,
+    time_steps: usize,
+    samples: usize,
+    seed: u64,
+    antithetic: bool,
+    control_variate: bool,
+    rsg: PhantomData<fn() -> R>,
+}
+
+// Not derived: the generator type itself need not implement `Debug`.
+impl<R> fmt::Debug for McEuropeanEngine<R> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("McEuropeanEngine")
+            .field("process", &self.process)
+            .field("time_steps", &self.time_steps)
+            .field("samples", &self.samples)
+            .field("seed", &self.seed)
+            .field("antithetic", &self.antithetic)
+            .field("control_variate", &self.control_variate)
+            .finish()
+    }
+}
+
+impl<R: UniformRsg> McEuropeanEngine<R> {
+    /// Create a new engine simulating `samples` paths of `time_steps` steps.
+    ///
+    /// `seed` is passed to pseudo-random generators and ignored by
+    /// low-discrepancy ones.
+    pub fn new(
+        process: Arc<GeneralizedBlackScholesProcess>,
+        time_steps: usize,
+        samples: usize,
+        seed: u64,
+    ) -> Self {
+        Self {
+            process,
+            time_steps,
+            samples,
+            seed,
+            antithetic: false,
+            control_variate: false,
+            rsg: PhantomData,
+        }
+    }
+
+    /// Enable or disable antithetic variates.
+    ///
+    /// Each of the `samples` draws then yields a path and its mirror image.
+    pub fn with_antithetic(mut self, antithetic: bool) -> Self {
+        self.antithetic = antithetic;
+        self
+    }
+
+    /// Enable or disable the terminal-spot control variate.
+    pub fn with_control_variate(mut self, control_variate: bool) -> Self {
+        self.control_variate = control_variate;
+        self
+    }
+}
+
+impl<R: UniformRsg> PricingEngine<VanillaOptionArguments> for McEuropeanEngine<R> {
+    fn calculate(&self, args: &VanillaOptionArguments) -> Result<PricingResults> {
+        ensure!(
+            args.exercise.exercise_type() == ExerciseType::European,
+            "not a European option"
+        );
+        ensure!(self.time_steps > 0, "at least one time step is required");
+        ensure!(self.samples > 1, "at least two samples are required");
+
+        let rf = self.process.risk_free_rate();
+        let t = rf
+            .day_counter()
+            .year_fraction(rf.reference_date(), args.exercise.last_date());
+        ensure!(t > 0.0, "option has expired");
+
+        let discount = rf.discount(t);
+        let payoff: Arc<dyn StrikedPayoff> = args.payoff.clone();
+        let pricer = EuropeanPathPricer::new(move |s| payoff.value(s), discount);
+        let spot_pricer = EuropeanPathPricer::new(|s| s, discount);
+        let control_variate = self.control_variate.then(|| {
+            let forward_value = self.process.spot() * self.process.dividend_yield().discount(t);
+            (&spot_pricer as &dyn PathPricer, forward_value)
+        });
+
+        let model = MonteCarloModel::new(&*self.process, t, self.time_steps, self.seed);
+        let mut rsg = R::from_seed(self.time_steps, self.seed);
+        let stats = model.simulate_sequence(
+            &mut rsg,
+            &pricer,
+            self.samples,
+            self.antithetic,
+            control_variate,
+        );
+
+        let mut results = PricingResults::from_npv(stats.mean().unwrap_or(0.0));
+        results.error_estimate = stats.error_estimate();
+        Ok(results)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::analytic_european_engine::black_scholes_merton;
+    use ql_core::Real;
+    use ql_instruments::{Exercise, OptionType, PlainVanillaPayoff};
+    use ql_math::random_numbers::{sobol::SobolRsg, HaltonRsg, RandomSequenceGenerator};
+    use ql_methods::mc_european_price;
+    use ql_termstructures::{BlackConstantVol, FlatForward};
+    use ql_time::{Actual365Fixed, Date};
+
+    fn process() -> Arc<GeneralizedBlackScholesProcess> {
+        let ref_date = Date::from_ymd(2025, 1, 2).unwrap();
+        let rf = Arc::new(FlatForward::continuous(ref_date, 0.05, Actual365Fixed));
+        let div = Arc::new(FlatForward::continuous(ref_date, 0.02, Actual365Fixed));
+        let vol = Arc::new(BlackConstantVol::new(ref_date, 0.20, Actual365Fixed));
+        Arc::new(GeneralizedBlackScholesProcess::new(100.0, rf, div, vol))
+    }
+
+    fn call_args() -> VanillaOptionArguments {
+        VanillaOptionArguments {
+            payoff: Arc::new(PlainVanillaPayoff::new(OptionType::Call, 100.0)),
+            exercise: Exercise::european(Date::from_ymd(2026, 1, 2).unwrap()),
+        }
+    }
+
+    fn bs_call() -> Real {
+        black_scholes_merton(OptionType::Call, 100.0, 100.0, 0.05, 0.02, 0.20, 1.0).0
+    }
+
+    #[test]
+    fn pseudo_random_engine_matches_mc_european_price() {
+        let process = process();
+        let engine =
+            McEuropeanEngine::<RandomSequenceGenerator>::new(process.clone(), 4, 20_000, 7);
+        let result = engine.calculate(&call_args()).unwrap();
+
+        let (price, error) = mc_european_price(
+            &*process,
+            |s| (s - 100.0).max(0.0),
+            (-0.05_f64).exp(),
+            1.0,
+            4,
+            20_000,
+            7,
+        );
+        assert!(
+            (result.npv - price).abs() < 1e-9,
+            "{} vs {price}",
+            result.npv
+        );
+        assert!((result.error_estimate.unwrap() - error).abs() < 1e-9);
+        assert!((result.npv - bs_call()).abs() < 3.0 * error);
+    }
+
+    #[test]
+    fn quasi_random_engines_beat_pseudo_random() {
+        let bs = bs_call();
+        let samples = 8191;
+        let args = call_args();
+        let pseudo = McEuropeanEngine::<RandomSequenceGenerator>::new(process(), 1, samples, 11)
+            .calculate(&args)
+            .unwrap();
+        let pseudo_error = pseudo.error_estimate.unwrap();
+
+        let sobol = McEuropeanEngine::<SobolRsg>::new(process(), 1, samples, 0)
+            .calculate(&args)
+            .unwrap()
+            .npv;
+        let halton = McEuropeanEngine::<HaltonRsg>::new(process(), 1, samples, 0)
+            .calculate(&args)
+            .unwrap()
+            .npv;
+        assert!(
+            (sobol - bs).abs() < 0.2 * pseudo_error,
+            "Sobol {sobol:.5} vs BS {bs:.5}, pseudo std error {pseudo_error:.5}"
+        );
+        assert!(
+            (halton - bs).abs() < 0.2 * pseudo_error,
+            "Halton {halton:.5} vs BS {bs:.5}, pseudo std error {pseudo_error:.5}"
+        );
+    }
+
+    #[test]
+    fn variance_reduction_lowers_error_estimate() {
+        let args = call_args();
+        let engine = |antithetic, cv| {
+            McEuropeanEngine::<RandomSequenceGenerator>::new(process(), 1, 10_000, 3)
+                .with_antithetic(antithetic)
+                .with_control_variate(cv)
+        };
+        let plain = engine(false, false).calculate(&args).unwrap();
+        let antithetic = engine(true, false).calculate(&args).unwrap();
+        let controlled = engine(false, true).calculate(&args).unwrap();
+
+        let plain_error = plain.error_estimate.unwrap();
+        assert!(antithetic.error_estimate.unwrap() < 0.7 * plain_error);
+        assert!(controlled.error_estimate.unwrap() < 0.8 * plain_error);
+        let bs = bs_call();
+        assert!((antithetic.npv - bs).abs() < 3.0 * antithetic.error_estimate.unwrap());
+        assert!((controlled.npv - bs).abs() < 3.0 * controlled.error_estimate.unwrap());
+    }
+
+    #[test]
+    fn rejects_american_exercise() {
+        let args = VanillaOptionArguments {
+            payoff: Arc::new(PlainVanillaPayoff::new(OptionType::Put, 100.0)),
+            exercise: Exercise::american(
+                Date::from_ymd(2025, 1, 2).unwrap(),
+                Date::from_ymd(2026, 1, 2).unwrap(),
+            ),
+        };
+        let engine = McEuropeanEngine::<SobolRsg>::new(process(), 1, 100, 0);
+        assert!(engine.calculate(&args).is_err());
+    }
+}
@@ -0,0 +1,159 @@
+//! Closed-form price of a perpetual American option.
+//!
+//! With no expiry the pricing PDE loses its time derivative and the value
+//! solves `½σ²S²V'' + (r − q)SV' − rV = 0` on the continuation region.  Its
+//! solutions are powers `S^λ`, where `λ` is a root of
+//! `½σ²λ² + (r − q − ½σ²)λ − r = 0`; smooth pasting at the exercise
+//! boundary then fixes both the boundary and the price (McKean, 1965;
+//! Merton, 1973).
+
+use ql_core::{ensure, errors::Result, Real};
+use ql_instruments::OptionType;
+
+/// Roots `(λ₋, λ₊)` of `½σ²λ² + (r − q − ½σ²)λ − r = 0`.
+fn characteristic_roots(r: Real, q: Real, sigma: Real) -> (Real, Real) {
+    let sigma2 = sigma * sigma;
+    let b = r - q - 0.5 * sigma2;
+    let root = (b * b + 2.0 * r * sigma2).sqrt();
+    ((-b - root) / sigma2, (-b + root) / sigma2)
+}
+
+/// Optimal exercise boundary `S*` of a perpetual American option.
+///
+/// Exercise is optimal at or above `S*` for a call and at or below it for a
+/// put.  Returns `+∞` for a call when `q ≤ 0` and `0` for a put when
+/// `r ≤ 0`; early exercise is then never optimal.
+///
+/// # Errors
+/// Fails unless `sigma` is positive.
+pub fn perpetual_american_boundary(
+    option_type: OptionType,
+    strike: Real,
+    r: Real,
+    q: Real,
+    sigma: Real,
+) -> Result<Real> {
+    ensure!(sigma > 0.0, "volatility must be positive, got {sigma}");
+    let (lambda_minus, lambda_plus) = characteristic_roots(r, q, sigma);
+    Ok(match option_type {
+        OptionType::Call if q <= 0.0 => Real::INFINITY,
+        OptionType::Call => strike * lambda_plus / (lambda_plus - 1.0),
+        OptionType::Put if r <= 0.0 => 0.0,
+        OptionType::Put => strike * lambda_minus / (lambda_minus - 1.0),
+    })
+}
+
+/// Price of a perpetual American option.
+///
+/// Inside the continuation region the value is `(φ(S* − K))·(S/S*)^λ`, with
+/// `λ = λ₊` for a call and `λ₋` for a put and `S*` from
+/// [`perpetual_american_boundary`]; beyond the boundary it is the intrinsic
+/// value.  Without dividends a call is never exercised and is worth the
+/// underlying itself; a put with `r ≤ 0` is likewise worth its strike.
+///
+/// # Errors
+/// Fails unless `sigma` is positive.
+pub fn perpetual_american_price(
+    option_type: OptionType,
+    spot: Real,
+    strike: Real,
+    r: Real,
+    q: Real,
+    sigma: Real,
+) -> Result<Real> {
+    let phi = option_type.sign();
+    let intrinsic = (phi * (spot - strike)).max(0.0);
+    let boundary = perpetual_american_boundary(option_type, strike, r, q, sigma)?;
+    let (lambda_minus, lambda_plus) = characteristic_roots(r, q, sigma);
+    Ok(match option_type {
+        OptionType::Call if q <= 0.0 => spot,
+        OptionType::Put if r <= 0.0 => strike,
+        OptionType::Call if spot >= boundary => intrinsic,
+        OptionType::Put if spot <= boundary => intrinsic,
+        OptionType::Call => (boundary - strike) * (spot / boundary).powf(lambda_plus),
+        OptionType::Put => (strike - boundary) * (spot / boundary).powf(lambda_minus),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use ql_methods::{price_american, BinomialTree};
+    use ql_processes::GeneralizedBlackScholesProcess;
+    use ql_termstructures::{BlackConstantVol, FlatForward};
+    use ql_time::{Actual365Fixed, Date};
+    use std::sync::Arc;
+
+    const R: Real = 0.05;
+    const Q: Real = 0.02;
+    const SIGMA: Real = 0.30;
+
+    #[test]
+    fn long_dated_american_put_approaches_perpetual() {
+        let ref_date = Date::from_ymd(2025, 1, 2).unwrap();
+        let process = GeneralizedBlackScholesProcess::new(
+            100.0,
+            Arc::new(FlatForward::continuous(ref_date, R, Actual365Fixed)),
+            Arc::new(FlatForward::continuous(ref_date, Q, Actual365Fixed)),
+            Arc::new(BlackConstantVol::new(ref_date, SIGMA, Actual365Fixed)),
+        );
+        let perpetual =
+            perpetual_american_price(OptionType::Put, 100.0, 100.0, R, Q, SIGMA).unwrap();
+
+        let mut previous = 0.0;
+        let mut gaps = Vec::new();
+        for maturity in [1.0, 5.0, 20.0, 100.0] {
+            let steps = (40.0 * maturity) as usize + 500;
+            let tree = BinomialTree::cox_ross_rubinstein(&process, maturity, steps);
+            let discount = (-R * maturity / steps as Real).exp();
+            let price = price_american(&tree, &|s| (100.0 - s).max(0.0), discount);
+            assert!(price > previous, "T = {maturity}: {price} ≤ {previous}");
+            assert!(price < perpetual, "T = {maturity}: {price} ≥ {perpetual}");
+            previous = price;
+            gaps.push(perpetual - price);
+        }
+        assert!(gaps[3] < 0.02, "gap at T = 100: {}", gaps[3]);
+    }
+
+    #[test]
+    fn perpetual_call_without_dividends_is_the_underlying() {
+        for spot in [50.0, 100.0, 200.0] {
+            assert_eq!(
+                perpetual_american_price(OptionType::Call, spot, 100.0, R, 0.0, SIGMA).unwrap(),
+                spot
+            );
+        }
+        assert_eq!(
+            perpetual_american_boundary(OptionType::Call, 100.0, R, 0.0, SIGMA).unwrap(),
+            Real::INFINITY
+        );
+        // A small dividend yield pulls the value below the underlying but
+        // keeps it above intrinsic.
+        let call =
+            perpetual_american_price(OptionType::Call, 100.0, 100.0, R, 1e-4, SIGMA).unwrap();
+        assert!(call < 100.0 && call > 99.0, "call = {call}");
+    }
+
+    #[test]
+    fn perpetual_price_pastes_smoothly_at_the_boundary() {
+        for option_type in [OptionType::Call, OptionType::Put] {
+            let boundary = perpetual_american_boundary(option_type, 100.0, R, Q, SIGMA).unwrap();
+            let price = |s| perpetual_american_price(option_type, s, 100.0, R, Q, SIGMA).unwrap();
+            let h = 1e-4 * boundary;
+            let phi = option_type.sign();
+            // Continuous value and unit-magnitude delta on the boundary.
+            assert!((price(boundary) - phi * (boundary - 100.0)).abs() < 1e-12);
+            let inside = boundary - phi * h;
+            let delta = (price(boundary) - price(inside)) / (boundary - inside);
+            assert!((delta - phi).abs() < 1e-3, "{option_type}: delta {delta}");
+        }
+    }
+
+    #[test]
+    fn non_positive_volatility_is_an_error() {
+        for sigma in [0.0, -0.2] {
+            assert!(perpetual_american_boundary(OptionType::Put, 100.0, R, Q, sigma).is_err());
+            assert!(perpetual_american_price(OptionType::Call, 100.0, 100.0, R, Q, sigma).is_err());
+        }
+    }
+}
@@ -10,6 +10,7 @@
 //! * `GeneralizedBlackScholesProcess` — the most general form
 //! * `BlackScholesProcess` — no dividends
 //! * `BlackScholesMertonProcess` — continuous dividend yield
+//! * `BlackProcess` — a forward or futures price, with zero cost of carry
 
 use crate::stochastic_process::StochasticProcess1D;
 use ql_core::{Real, Time};
@@ -88,16 +89,71 @@ impl GeneralizedBlackScholesProcess {
         self.local_vol.as_deref()
     }
 
-    /// Get the volatility at time `t` and underlying `x`.
-    fn vol(&self, t: Time, x: Real) -> Real {
-        if let Some(ref lv) = self.local_vol {
-            lv.local_vol_impl(t, x)
-        } else if let Some(ref bv) = self.black_vol {
-            bv.black_vol_impl(t, x)
+    /// ATM Black total variance `V(t) = σ²(t)·t` up to time `t`.
+    ///
+    /// Taken from the Black volatility term structure at strike `S₀`; for a
+    /// process built on a local-volatility surface it is the integral of
+    /// `σ_loc²(s, S₀)` over `[0, t]`.
+    pub fn black_variance(&self, t: Time) -> Real {
+        if t <= 0.0 {
+            return 0.0;
+        }
+        if let Some(ref bv) = self.black_vol {
+            bv.black_variance_impl(t, self.x0)
+        } else if let Some(ref lv) = self.local_vol {
+            let n = 100;
+            let h = t / n as Real;
+            (0..n)
+                .map(|i| {
+                    let sigma = lv.local_vol_impl((i as Real + 0.5) * h, self.x0);
+                    sigma * sigma * h
+                })
+                .sum()
         } else {
             0.0
         }
     }
+
+    /// Instantaneous volatility at time `t`.
+    ///
+    /// With a Black volatility term structure this is the deterministic
+    /// `√(dV/dt)` of the ATM total variance, taken as a forward difference
+    /// so that it is right-continuous where `V` has kinks.
+    pub fn local_vol(&self, t: Time) -> Real {
+        if let Some(ref lv) = self.local_vol {
+            return lv.local_vol_impl(t, self.x0);
+        }
+        let t = t.max(0.0);
+        let h = 1e-5 * t.max(1.0);
+        ((self.black_variance(t + h) - self.black_variance(t)) / h)
+            .max(0.0)
+            .sqrt()
+    }
+
+    /// Volatility `σ_loc(t, x)` at time `t` and underlying `x` of a process
+    /// built on a local-volatility surface; `None` for a Black volatility
+    /// term structure, whose volatility [`local_vol`](Self::local_vol) does
+    /// not depend on the underlying.
+    fn surface_vol(&self, t: Time, x: Real) -> Option<Real> {
+        self.local_vol.as_ref().map(|lv| lv.local_vol_impl(t, x))
+    }
+
+    /// Integral of `r − q` over `[t, t+dt]`, read off the discount curves as
+    /// `ln(P_r(t)/P_r(t+dt)) − ln(P_q(t)/P_q(t+dt))`.
+    fn step_carry(&self, t: Time, dt: Time) -> Real {
+        let log_growth = |curve: &dyn YieldTermStructure| {
+            (curve.discount_impl(t) / curve.discount_impl(t + dt)).ln()
+        };
+        log_growth(&*self.risk_free_rate) - log_growth(&*self.dividend_yield)
+    }
+
+    /// Variance of `ln S` accumulated over `[t, t+dt]` starting from `x`.
+    fn step_variance(&self, t: Time, x: Real, dt: Time) -> Real {
+        match self.surface_vol(t, x) {
+            Some(sigma) => sigma * sigma * dt,
+            None => (self.black_variance(t + dt) - self.black_variance(t)).max(0.0),
+        }
+    }
 }
 
 impl StochasticProcess1D for GeneralizedBlackScholesProcess {
@@ -106,9 +162,9 @@ impl StochasticProcess1D for GeneralizedBlackScholesProcess {
     }
 
     fn drift_1d(&self, t: Time, x: Real) -> Real {
-        let sigma = self.vol(t, x);
-        let r = self.risk_free_rate.zero_rate_impl(t);
-        let q = self.dividend_yield.zero_rate_impl(t);
+        let sigma = self.surface_vol(t, x).unwrap_or_else(|| self.local_vol(t));
+        let r = self.risk_free_rate.forward_rate_impl(t);
+        let q = self.dividend_yield.forward_rate_impl(t);
         // For log-price: drift = (r - q - σ²/2)
         // For price: drift = (r - q) * S
         // Using price-level process: dS = (r-q)·S·dt + σ·S·dW
@@ -116,31 +172,28 @@ impl StochasticProcess1D for GeneralizedBlackScholesProcess {
     }
 
     fn diffusion_1d(&self, t: Time, x: Real) -> Real {
-        let sigma = self.vol(t, x);
+        let sigma = self.surface_vol(t, x).unwrap_or_else(|| self.local_vol(t));
         sigma * x
     }
 
     fn expectation_1d(&self, t: Time, x: Real, dt: Time) -> Real {
         // For a GBM: E[S(t+dt)] = S(t) * exp((r-q) * dt)
         // But for the Euler scheme on log-space this is more accurate:
-        let sigma = self.vol(t, x);
-        let r = self.risk_free_rate.zero_rate_impl(t);
-        let q = self.dividend_yield.zero_rate_impl(t);
-        x * ((r - q - 0.5 * sigma * sigma) * dt).exp()
+        let var = self.step_variance(t, x, dt);
+        x * (self.step_carry(t, dt) - 0.5 * var).exp()
     }
 
     fn std_deviation_1d(&self, t: Time, x: Real, dt: Time) -> Real {
-        let sigma = self.vol(t, x);
-        sigma * x * dt.sqrt()
+        x * self.step_variance(t, x, dt).sqrt()
     }
 
     fn evolve_1d(&self, t: Time, x: Real, dt: Time, dw: Real) -> Real {
-        // Exact GBM evolution when σ is treated as constant over [t, t+dt]:
-        // S(t+dt) = S(t) * exp((r - q - σ²/2)·dt + σ·√dt·dw)
-        let sigma = self.vol(t, x);
-        let r = self.risk_free_rate.zero_rate_impl(t);
-        let q = self.dividend_yield.zero_rate_impl(t);
-        x * ((r - q - 0.5 * sigma * sigma) * dt + sigma * dt.sqrt() * dw).exp()
+        // Exact lognormal evolution when the volatility is deterministic:
+        // S(t+dt) = S(t) * exp(∫(r - q) − ½ΔV + √ΔV·dw), with the carry
+        // integrated from the curves and ΔV the total variance accumulated
+        // over the step.
+        let var = self.step_variance(t, x, dt);
+        x * (self.step_carry(t, dt) - 0.5 * var + var.sqrt() * dw).exp()
     }
 }
 
@@ -176,13 +229,30 @@ pub fn black_scholes_merton_process(
     GeneralizedBlackScholesProcess::new(x0, risk_free_rate, dividend_yield, black_vol)
 }
 
+/// A Black (1976) process for a forward or futures price.
+///
+/// The forward is driftless under the risk-neutral measure, which is
+/// achieved by using the risk-free curve as the dividend curve as well
+/// (`q = r`); discounting still uses `r`.
+///
+/// Corresponds to `QuantLib::BlackProcess`.
+pub fn black_process(
+    x0: Real,
+    risk_free_rate: Arc<dyn YieldTermStructure>,
+    black_vol: Arc<dyn BlackVolTermStructure>,
+) -> GeneralizedBlackScholesProcess {
+    GeneralizedBlackScholesProcess::new(x0, risk_free_rate.clone(), risk_free_rate, black_vol)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
     use crate::stochastic_process::StochasticProcess;
     use approx::assert_abs_diff_eq;
-    use ql_termstructures::{BlackConstantVol, FlatForward};
-    use ql_time::{Actual365Fixed, Date};
+    use ql_termstructures::{
+        BlackConstantVol, FlatForward, TermStructure, VolatilityTermStructure,
+    };
+    use ql_time::{Actual365Fixed, Calendar, Date, DayCounter, NullCalendar};
 
     fn make_bsm() -> GeneralizedBlackScholesProcess {
         let ref_date = Date::from_ymd(2025, 1, 2).unwrap();
@@ -252,4 +322,197 @@ mod tests {
         // Dividend yield should be zero
         assert_abs_diff_eq!(p.dividend_yield().zero_rate_impl(1.0), 0.0, epsilon = 1e-15);
     }
+
+    #[test]
+    fn black_process_has_zero_carry() {
+        let ref_date = Date::from_ymd(2025, 1, 2).unwrap();
+        let r: Arc<dyn YieldTermStructure> =
+            Arc::new(FlatForward::continuous(ref_date, 0.05, Actual365Fixed));
+        let vol: Arc<dyn BlackVolTermStructure> =
+            Arc::new(BlackConstantVol::new(ref_date, 0.20, Actual365Fixed));
+
+        let p = black_process(100.0, r, vol);
+        assert_abs_diff_eq!(
+            p.risk_free_rate().zero_rate_impl(1.0),
+            0.05,
+            epsilon = 1e-15
+        );
+        assert_abs_diff_eq!(
+            p.dividend_yield().zero_rate_impl(1.0),
+            0.05,
+            epsilon = 1e-15
+        );
+        // Only the convexity term is left: −σ²/2 · F = −0.02 · 100
+        assert_abs_diff_eq!(p.drift_1d(0.0, 100.0), -2.0, epsilon = 1e-10);
+        // No carry accrues over a zero-shock step either.
+        let expected = 100.0 * (-0.02_f64).exp();
+        assert_abs_diff_eq!(p.evolve_1d(0.0, 100.0, 1.0, 0.0), expected, epsilon = 1e-10);
+    }
+
+    /// Total variance `V(t)` linear between pillars (flat forward vols)
+    /// and extended with the last forward vol.
+    #[derive(Debug)]
+    struct PiecewiseVarianceVol {
+        times: Vec<Time>,
+        variances: Vec<Real>,
+    }
+
+    impl TermStructure for PiecewiseVarianceVol {
+        fn reference_date(&self) -> Date {
+            Date::from_ymd(2025, 1, 2).unwrap()
+        }
+        fn day_counter(&self) -> &dyn DayCounter {
+            &Actual365Fixed
+        }
+        fn calendar(&self) -> &dyn Calendar {
+            &NullCalendar
+        }
+        fn max_date(&self) -> Date {
+            Date::MAX
+        }
+    }
+
+    impl VolatilityTermStructure for PiecewiseVarianceVol {
+        fn min_strike(&self) -> Real {
+            0.0
+        }
+        fn max_strike(&self) -> Real {
+            Real::MAX
+        }
+    }
+
+    impl BlackVolTermStructure for PiecewiseVarianceVol {
+        fn black_variance_impl(&self, t: Time, _strike: Real) -> Real {
+            let n = self.times.len();
+            let i = self.times.partition_point(|&ti| ti < t).clamp(1, n - 1);
+            let (t0, t1) = (self.times[i - 1], self.times[i]);
+            let (v0, v1) = (self.variances[i - 1], self.variances[i]);
+            v0 + (v1 - v0) * (t - t0) / (t1 - t0)
+        }
+
+        fn black_vol_impl(&self, t: Time, strike: Real) -> Real {
+            if t <= 0.0 {
+                return 0.0;
+            }
+            (self.black_variance_impl(t, strike) / t).sqrt()
+        }
+    }
+
+    fn term_structured_process() -> GeneralizedBlackScholesProcess {
+        let ref_date = Date::from_ymd(2025, 1, 2).unwrap();
+        let r: Arc<dyn YieldTermStructure> =
+            Arc::new(FlatForward::continuous(ref_date, 0.03, Actual365Fixed));
+        let q: Arc<dyn YieldTermStructure> =
+            Arc::new(FlatForward::continuous(ref_date, 0.01, Actual365Fixed));
+        // Forward vols 30% on [0, 0.5], 20% on [0.5, 1.5], 25% afterwards.
+        let vol = Arc::new(PiecewiseVarianceVol {
+            times: vec![0.0, 0.5, 1.5, 3.0],
+            variances: vec![0.0, 0.045, 0.085, 0.17875],
+        });
+        GeneralizedBlackScholesProcess::new(100.0, r, q, vol)
+    }
+
+    #[test]
+    fn term_structured_vol_gives_piecewise_local_vol() {
+        let p = term_structured_process();
+        for (t, expected) in [
+            (0.0, 0.30),
+            (0.25, 0.30),
+            (0.5, 0.20),
+            (1.0, 0.20),
+            (2.0, 0.25),
+        ] {
+            assert_abs_diff_eq!(p.local_vol(t), expected, epsilon = 1e-8);
+        }
+        assert_abs_diff_eq!(p.diffusion_1d(1.0, 80.0), 0.20 * 80.0, epsilon = 1e-6);
+        assert_abs_diff_eq!(p.black_variance(1.5), 0.085, epsilon = 1e-15);
+        // The Black (implied) vol averages the local vol: √(0.085/1.5) ≠ 0.20
+        let implied = p.black_volatility().unwrap().black_vol_impl(1.5, 100.0);
+        assert!((implied - 0.20).abs() > 0.03);
+    }
+
+    #[test]
+    fn simulation_reproduces_term_structured_variance() {
+        use ql_math::random_numbers::InverseCumulativeNormalRng;
+
+        let p = term_structured_process();
+        // Observation times straddle the vol pillars on a non-uniform grid.
+        let times = [0.0, 0.2, 0.5, 0.8, 1.5, 2.0, 3.0];
+        let n = 40_000;
+        let mut rng = InverseCumulativeNormalRng::new(17);
+        let mut sum = vec![0.0; times.len()];
+        let mut sum_sq = vec![0.0; times.len()];
+        for _ in 0..n {
+            let mut x = p.x0();
+            for i in 1..times.len() {
+                let (t, dt) = (times[i - 1], times[i] - times[i - 1]);
+                x = p.evolve_1d(t, x, dt, rng.next_real());
+                let y = (x / p.x0()).ln();
+                sum[i] += y;
+                sum_sq[i] += y * y;
+            }
+        }
+        for i in 1..times.len() {
+            let mean = sum[i] / n as Real;
+            let var = sum_sq[i] / n as Real - mean * mean;
+            let expected = p.black_variance(times[i]);
+            // Sampling error of a variance estimate: √(2/n)·V ≈ 0.7% of V.
+            assert!(
+                (var / expected - 1.0).abs() < 0.03,
+                "t={}: simulated {var:.5} vs {expected:.5}",
+                times[i]
+            );
+            let drift = 0.02 * times[i] - 0.5 * expected;
+            assert!((mean - drift).abs() < 4.0 * (expected / n as Real).sqrt());
+        }
+    }
+
+    #[test]
+    fn evolve_integrates_curve_drift() {
+        use ql_termstructures::{InterpolatedZeroCurve, Linear};
+
+        let ref_date = Date::from_ymd(2025, 1, 2).unwrap();
+        let dates = [
+            ref_date,
+            Date::from_ymd(2026, 1, 2).unwrap(),
+            Date::from_ymd(2027, 1, 2).unwrap(),
+        ];
+        let steep: Arc<dyn YieldTermStructure> = Arc::new(
+            InterpolatedZeroCurve::new(&dates, &[0.01, 0.05, 0.10], Actual365Fixed, &Linear)
+                .unwrap(),
+        );
+        let flat: Arc<dyn YieldTermStructure> =
+            Arc::new(FlatForward::continuous(ref_date, 0.01, Actual365Fixed));
+        let q: Arc<dyn YieldTermStructure> =
+            Arc::new(FlatForward::continuous(ref_date, 0.0, Actual365Fixed));
+        let vol: Arc<dyn BlackVolTermStructure> =
+            Arc::new(BlackConstantVol::new(ref_date, 0.20, Actual365Fixed));
+        let make = |r: &Arc<dyn YieldTermStructure>| {
+            GeneralizedBlackScholesProcess::new(100.0, r.clone(), q.clone(), vol.clone())
+        };
+        let (steep_process, flat_process) = (make(&steep), make(&flat));
+
+        // Step the conditional mean out to the last pillar; with a
+        // deterministic carry it compounds to the forward S₀/P(T).
+        let t_end = Actual365Fixed.year_fraction(ref_date, dates[2]);
+        let steps = 8;
+        let dt = t_end / steps as Real;
+        let terminal_mean = |p: &GeneralizedBlackScholesProcess| {
+            (0..steps).fold(100.0, |x, i| {
+                p.expectation_1d(i as Real * dt, x, dt) * (0.5 * p.step_variance(0.0, x, dt)).exp()
+            })
+        };
+        let steep_mean = terminal_mean(&steep_process);
+        let flat_mean = terminal_mean(&flat_process);
+        assert_abs_diff_eq!(steep_mean, 100.0 / steep.discount(t_end), epsilon = 1e-8);
+        assert_abs_diff_eq!(flat_mean, 100.0 / flat.discount(t_end), epsilon = 1e-8);
+        assert!(steep_mean > flat_mean + 10.0, "{steep_mean} vs {flat_mean}");
+
+        // With no noise the path is the ATM forward shifted by −½V(T).
+        let path_end = (0..steps).fold(100.0, |x, i| {
+            steep_process.evolve_1d(i as Real * dt, x, dt, 0.0)
+        });
+        let expected = 100.0 / steep.discount(t_end) * (-0.5 * 0.04 * t_end).exp();
+        assert_abs_diff_eq!(path_end, expected, epsilon = 1e-8);
+    }
 }
@@ -0,0 +1,330 @@
+//! Characteristic functions of log-price processes.
+//!
+//! Transform engines (Carr-Madan FFT, COS) need only the characteristic
+//! function of the terminal log price, so affine and Lévy processes share
+//! it through the [`CharacteristicFunction`] trait.  Each implementation
+//! is the forward factor `e^{iu·ln F_t}` times the exponential of an
+//! exponent for `ln(S_t / F_t)`; the exponents are exposed as free
+//! functions so models outside this crate can build on them.
+
+use crate::bates_process::BatesProcess;
+use crate::heston_process::HestonProcess;
+use crate::merton76_process::Merton76Process;
+use crate::variance_gamma_process::VarianceGammaProcess;
+use num_complex::Complex64;
+use ql_core::{Real, Time};
+use ql_termstructures::YieldTermStructure;
+
+/// A process given by the characteristic function of its log price.
+pub trait CharacteristicFunction: Send + Sync {
+    /// `φ(u) = E[e^{iu·ln S_t}]`, for complex `u` in the process's strip of
+    /// regularity.  Risk neutrality makes `φ(−i) = E[S_t] = F_t`.
+    fn cf(&self, u: Complex64, t: Time) -> Complex64;
+}
+
+/// Log characteristic function of `ln(S_t / F_t)` under Heston, in the
+/// "little trap" form of Albrecher et al., which stays on the principal
+/// branch of the logarithm.
+pub fn heston_exponent(
+    u: Complex64,
+    t: Time,
+    v0: Real,
+    kappa: Real,
+    theta: Real,
+    sigma: Real,
+    rho: Real,
+) -> Complex64 {
+    let i = Complex64::i();
+    let sigma2 = sigma * sigma;
+    let xi = kappa - rho * sigma * i * u;
+    let d = (xi * xi + sigma2 * (i * u + u * u)).sqrt();
+    if (xi + d).norm() < 1e-300 {
+        // Only at u = 0 or u = −i, where the exponent vanishes.
+        return Complex64::new(0.0, 0.0);
+    }
+    if d.norm() * t < 1e-6 {
+        // Removable singularity at d = 0, where g = 1: the limits of the
+        // log term and of the variance coefficient, to O((dt)²).
+        let c = kappa * theta / sigma2 * (xi * t - 2.0 * (1.0 + 0.5 * xi * t).ln());
+        let dv = xi * xi * t / (sigma2 * (2.0 + xi * t));
+        return c + dv * v0;
+    }
+    let g = (xi - d) / (xi + d);
+    let e = (-d * t).exp();
+    let c = kappa * theta / sigma2 * ((xi - d) * t - 2.0 * ((1.0 - g * e) / (1.0 - g)).ln());
+    let dv = (xi - d) / sigma2 * (1.0 - e) / (1.0 - g * e);
+    c + dv * v0
+}
+
+/// Compensated lognormal jumps: `λt·(E[e^{iuJ}] − 1 − iu·E[e^J − 1])` for
+/// log jump sizes `J ~ N(mean, vol²)`.
+pub fn lognormal_jump_exponent(
+    u: Complex64,
+    t: Time,
+    lambda: Real,
+    mean: Real,
+    vol: Real,
+) -> Complex64 {
+    let i = Complex64::i();
+    let jump = (i * u * mean - 0.5 * vol * vol * u * u).exp() - 1.0;
+    let compensator = (mean + 0.5 * vol * vol).exp() - 1.0;
+    lambda * t * (jump - i * u * compensator)
+}
+
+/// Martingale-corrected variance-gamma exponent of Madan, Carr and Chang
+/// (1998), for Brownian drift `theta` and volatility `sigma` on a gamma
+/// clock of variance rate `nu`.
+pub fn variance_gamma_exponent(
+    u: Complex64,
+    t: Time,
+    sigma: Real,
+    nu: Real,
+    theta: Real,
+) -> Complex64 {
+    let i = Complex64::i();
+    let exponent =
+        |u: Complex64| -(1.0 - i * u * theta * nu + 0.5 * sigma * sigma * nu * u * u).ln() / nu;
+    // The drift correction is minus the exponent at u = −i.
+    let omega = -exponent(-i);
+    t * (exponent(u) + i * u * omega)
+}
+
+/// `iu·ln F_t` for the forward of `spot` under the given curves.
+fn forward_exponent(
+    u: Complex64,
+    t: Time,
+    spot: Real,
+    risk_free_rate: &dyn YieldTermStructure,
+    dividend_yield: &dyn YieldTermStructure,
+) -> Complex64 {
+    let forward = spot * dividend_yield.discount(t) / risk_free_rate.discount(t);
+    Complex64::i() * u * forward.ln()
+}
+
+impl CharacteristicFunction for HestonProcess {
+    fn cf(&self, u: Complex64, t: Time) -> Complex64 {
+        let forward = forward_exponent(
+            u,
+            t,
+            self.s0(),
+            self.risk_free_rate(),
+            self.dividend_yield(),
+        );
+        let exponent = heston_exponent(
+            u,
+            t,
+            self.v0(),
+            self.kappa(),
+            self.theta(),
+            self.sigma(),
+            self.rho(),
+        );
+        (forward + exponent).exp()
+    }
+}
+
+impl CharacteristicFunction for BatesProcess {
+    fn cf(&self, u: Complex64, t: Time) -> Complex64 {
+        self.heston().cf(u, t)
+            * lognormal_jump_exponent(u, t, self.lambda, self.delta, self.nu).exp()
+    }
+}
+
+impl CharacteristicFunction for Merton76Process {
+    fn cf(&self, u: Complex64, t: Time) -> Complex64 {
+        let bs = &self.bs_process;
+        let forward = forward_exponent(u, t, bs.spot(), bs.risk_free_rate(), bs.dividend_yield());
+        let i = Complex64::i();
+        let diffusion = -0.5 * bs.black_variance(t) * (u * u + i * u);
+        let jumps = lognormal_jump_exponent(
+            u,
+            t,
+            self.jump_intensity,
+            self.log_jump_mean,
+            self.log_jump_vol,
+        );
+        (forward + diffusion + jumps).exp()
+    }
+}
+
+impl CharacteristicFunction for VarianceGammaProcess {
+    fn cf(&self, u: Complex64, t: Time) -> Complex64 {
+        let forward = forward_exponent(
+            u,
+            t,
+            self.s0(),
+            &**self.risk_free_rate(),
+            &**self.dividend_yield(),
+        );
+        (forward + variance_gamma_exponent(u, t, self.sigma, self.nu, self.theta)).exp()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::black_scholes_process::black_scholes_merton_process;
+    use ql_termstructures::{BlackConstantVol, BlackVolTermStructure, FlatForward};
+    use ql_time::{Actual365Fixed, Date};
+    use std::sync::Arc;
+
+    const SPOT: Real = 100.0;
+    const R: Real = 0.03;
+    const Q: Real = 0.01;
+
+    fn curves() -> (Arc<dyn YieldTermStructure>, Arc<dyn YieldTermStructure>) {
+        let ref_date = Date::from_ymd(2025, 1, 2).unwrap();
+        (
+            Arc::new(FlatForward::continuous(ref_date, R, Actual365Fixed)),
+            Arc::new(FlatForward::continuous(ref_date, Q, Actual365Fixed)),
+        )
+    }
+
+    fn processes() -> Vec<Box<dyn CharacteristicFunction>> {
+        let (rf, div) = curves();
+        let vol: Arc<dyn BlackVolTermStructure> = Arc::new(BlackConstantVol::new(
+            Date::from_ymd(2025, 1, 2).unwrap(),
+            0.2,
+            Actual365Fixed,
+        ));
+        vec![
+            Box::new(HestonProcess::new(
+                SPOT,
+                0.04,
+                rf.clone(),
+                div.clone(),
+                1.5,
+                0.04,
+                0.3,
+                -0.7,
+            )),
+            Box::new(BatesProcess::new(
+                SPOT,
+                0.04,
+                rf.clone(),
+                div.clone(),
+                1.5,
+                0.04,
+                0.3,
+                -0.7,
+                0.5,
+                -0.1,
+                0.1,
+            )),
+            Box::new(Merton76Process::new(
+                Arc::new(black_scholes_merton_process(
+                    SPOT,
+                    rf.clone(),
+                    div.clone(),
+                    vol,
+                )),
+                0.5,
+                -0.1,
+                0.1,
+            )),
+            Box::new(VarianceGammaProcess::new(SPOT, rf, div, 0.2, 0.2, -0.15)),
+        ]
+    }
+
+    #[test]
+    fn cf_is_normalised_and_reproduces_the_forward() {
+        let i = Complex64::i();
+        for process in processes() {
+            for t in [0.25, 1.0, 5.0] {
+                let forward = SPOT * ((R - Q) * t).exp();
+                let at_zero = process.cf(Complex64::new(0.0, 0.0), t);
+                let at_minus_i = process.cf(-i, t);
+                assert!((at_zero - 1.0).norm() < 1e-12);
+                assert!(
+                    (at_minus_i - forward).norm() < 1e-9 * forward,
+                    "φ(−i) = {at_minus_i}, forward {forward}"
+                );
+                // A characteristic function is bounded by one on the real line.
+                assert!(process.cf(Complex64::new(3.0, 0.0), t).norm() <= 1.0);
+            }
+        }
+    }
+
+    #[test]
+    fn heston_matches_original_closed_form() {
+        let (v0, kappa, theta, sigma, rho) = (0.04, 1.5, 0.04, 0.3, -0.7);
+        let (rf, div) = curves();
+        let process = HestonProcess::new(SPOT, v0, rf, div, kappa, theta, sigma, rho);
+
+        // Heston (1993): φ = exp(C + D·v₀ + iu·ln F) with
+        // g = (ξ + d)/(ξ − d), which for short maturities and moderate u
+        // stays on the principal branch.
+        let i = Complex64::i();
+        for (re, im, t) in [
+            (0.5, 0.0, 0.5),
+            (2.0, 0.0, 1.0),
+            (1.0, -0.5, 0.25),
+            (-3.0, 0.0, 0.5),
+        ] {
+            let u = Complex64::new(re, im);
+            let xi = kappa - rho * sigma * i * u;
+            let d = (xi * xi + sigma * sigma * (i * u + u * u)).sqrt();
+            let g = (xi + d) / (xi - d);
+            let e = (d * t).exp();
+            let c = kappa * theta / (sigma * sigma)
+                * ((xi + d) * t - 2.0 * ((1.0 - g * e) / (1.0 - g)).ln());
+            let dv = (xi + d) / (sigma * sigma) * (1.0 - e) / (1.0 - g * e);
+            let forward = SPOT * ((R - Q) * t).exp();
+            let expected = (c + dv * v0 + i * u * forward.ln()).exp();
+            let actual = process.cf(u, t);
+            assert!(
+                (actual - expected).norm() < 1e-12,
+                "u = {u}, t = {t}: {actual} vs {expected}"
+            );
+        }
+    }
+
+    #[test]
+    fn heston_exponent_is_finite_at_its_removable_singularities() {
+        let (v0, theta, t) = (0.04, 0.05, 2.0);
+        let i = Complex64::i();
+
+        // κ < ρσ puts the principal root at d = −ξ for u = −i.
+        let at_martingale_point = heston_exponent(-i, t, v0, 0.5, theta, 0.8, 0.9);
+        assert!(at_martingale_point.norm() < 1e-15, "{at_martingale_point}");
+
+        // With ρ = 0, σ = 3 and κ = 2, d vanishes at u = i/3: the exponent
+        // is continuous through it.
+        let (kappa, sigma) = (2.0, 3.0);
+        let at = |u: Complex64| heston_exponent(u, t, v0, kappa, theta, sigma, 0.0);
+        let centre = at(i / 3.0);
+        assert!(centre.re.is_finite() && centre.im.is_finite());
+        for h in [1e-4, -1e-4]
+            .map(Complex64::from)
+            .into_iter()
+            .chain([1e-4 * i, -1e-4 * i])
+        {
+            let near = at(i / 3.0 + h);
+            assert!((near - centre).norm() < 1e-3, "{near} vs {centre}");
+        }
+    }
+
+    #[test]
+    fn bates_jumps_use_the_process_jump_mean_and_volatility() {
+        // A skewed jump law (mean ≠ ±vol) so that swapping the two
+        // parameters changes the characteristic function.
+        let (lambda, mean, vol) = (0.7, -0.2, 0.05);
+        let (rf, div) = curves();
+        let heston = HestonProcess::new(SPOT, 0.04, rf.clone(), div.clone(), 1.5, 0.04, 0.3, -0.7);
+        let bates = BatesProcess::new(SPOT, 0.04, rf, div, 1.5, 0.04, 0.3, -0.7, lambda, mean, vol);
+        let i = Complex64::i();
+        for (u, t) in [(0.5, 1.0), (2.0, 0.5), (5.0, 2.0)] {
+            let u = Complex64::new(u, 0.0);
+            // λt·(e^{iuμ − ½σ²u²} − 1 − iu·(e^{μ + ½σ²} − 1)) for J ~ N(μ, σ²).
+            let compensator = (mean + 0.5 * vol * vol).exp() - 1.0;
+            let expected = lambda
+                * t
+                * ((i * u * mean - 0.5 * vol * vol * u * u).exp() - 1.0 - i * u * compensator);
+            let actual = (bates.cf(u, t) / heston.cf(u, t)).ln();
+            assert!(
+                (actual - expected).norm() < 1e-12,
+                "u = {u}, t = {t}: {actual} vs {expected}"
+            );
+        }
+    }
+}
@@ -113,6 +113,29 @@ impl StochasticProcess for G2Process {
         m[(1, 1)] = self.eta * (1.0 - self.rho * self.rho).max(0.0).sqrt() * sqrt_dt;
         m
     }
+
+    /// Exact conditional covariance of the two Ornstein-Uhlenbeck factors:
+    ///
+    /// `Var[x] = σ²·(1 − e^{−2a·Δt})/(2a)`,
+    /// `Cov[x, y] = ρ·σ·η·(1 − e^{−(a+b)·Δt})/(a+b)`,
+    /// `Var[y] = η²·(1 − e^{−2b·Δt})/(2b)`.
+    fn covariance(&self, _t: Time, _x: &Array, dt: Time) -> Matrix {
+        // (1 − e^{−k·Δt})/k, tending to Δt as k → 0
+        let integral = |k: Real| {
+            if k.abs() < 1e-12 {
+                dt
+            } else {
+                -(-k * dt).exp_m1() / k
+            }
+        };
+        let cross = self.rho * self.sigma * self.eta * integral(self.a + self.b);
+        let mut m = Matrix::zeros(2, 2);
+        m[(0, 0)] = self.sigma * self.sigma * integral(2.0 * self.a);
+        m[(0, 1)] = cross;
+        m[(1, 0)] = cross;
+        m[(1, 1)] = self.eta * self.eta * integral(2.0 * self.b);
+        m
+    }
 }
 
 #[cfg(test)]
@@ -165,4 +188,35 @@ mod tests {
         assert!((d[(1, 0)]).abs() < 1e-15);
         assert!((d[(1, 1)] - eta).abs() < 1e-15);
     }
+
+    #[test]
+    fn g2_covariance_matches_factor_covariance() {
+        let (a, sigma, b, eta, rho) = (0.1, 0.01, 0.2, 0.015, -0.5);
+        let p = G2Process::new(a, sigma, b, eta, rho, flat_ts(0.05));
+        let x = p.initial_values();
+
+        // From t = 0 the conditional covariance is the factors' analytic
+        // covariance at t.
+        let t: Real = 5.0;
+        let cov = p.covariance(0.0, &x, t);
+        let var_x = sigma * sigma / (2.0 * a) * (1.0 - (-2.0 * a * t).exp());
+        let var_y = eta * eta / (2.0 * b) * (1.0 - (-2.0 * b * t).exp());
+        let cov_xy = rho * sigma * eta / (a + b) * (1.0 - (-(a + b) * t).exp());
+        assert!((cov[(0, 0)] - var_x).abs() < 1e-15);
+        assert!((cov[(1, 1)] - var_y).abs() < 1e-15);
+        assert!((cov[(0, 1)] - cov_xy).abs() < 1e-15);
+        assert_eq!(cov[(0, 1)], cov[(1, 0)]);
+
+        // Over a short step it reduces to the local σ·σᵀ·Δt
+        let dt = 1e-6;
+        let local = p.covariance(0.0, &x, dt);
+        let d = p.diffusion(0.0, &x);
+        let euler = &(&d * &d.transpose()) * dt;
+        for i in 0..2 {
+            for j in 0..2 {
+                assert!((local[(i, j)] - euler[(i, j)]).abs() < 1e-6 * euler[(0, 0)].abs());
+            }
+        }
+        assert!((p.correlation(0.0, &x, dt)[(0, 1)] - rho).abs() < 1e-6);
+    }
 }
@@ -99,4 +99,27 @@ mod tests {
         let p = GeometricBrownianMotionProcess::new(42.0, 0.1, 0.3);
         assert!((p.x0() - 42.0).abs() < 1e-15);
     }
+
+    #[test]
+    fn gbm_milstein_step_uses_drift_and_diffusion() {
+        use crate::stochastic_process::DiscretizationScheme;
+
+        // The exact expectation x·e^{μΔt} must not leak into the Milstein
+        // step: x·(1 + μΔt + σ√Δt·dw + ½σ²Δt·(dw² − 1)).
+        let (mu, sigma) = (0.1, 0.3);
+        let p = GeometricBrownianMotionProcess::new(100.0, mu, sigma);
+        let (x, dt) = (100.0, 0.5);
+        for dw in [-1.5, 0.0, 0.7, 2.0] {
+            let expected = x
+                * (1.0
+                    + mu * dt
+                    + sigma * dt.sqrt() * dw
+                    + 0.5 * sigma * sigma * dt * (dw * dw - 1.0));
+            let actual = p.evolve_1d_with(DiscretizationScheme::Milstein, 0.0, x, dt, dw);
+            assert!(
+                (actual - expected).abs() < 1e-6,
+                "dw = {dw}: {actual} vs {expected}"
+            );
+        }
+    }
 }
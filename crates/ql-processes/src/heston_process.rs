@@ -13,10 +13,30 @@
 
 use crate::stochastic_process::StochasticProcess;
 use ql_core::{Real, Time};
+use ql_math::distributions::normal_cdf;
 use ql_math::{Array, Matrix};
 use ql_termstructures::YieldTermStructure;
 use std::sync::Arc;
 
+/// Scheme used by [`HestonProcess::evolve`].
+///
+/// Corresponds to `QuantLib::HestonProcess::Discretization` (the subset
+/// implemented here).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum HestonDiscretization {
+    /// Euler step on the variance with negative values truncated to zero,
+    /// log-Euler step on the spot.
+    #[default]
+    FullTruncation,
+    /// Andersen's Quadratic-Exponential scheme: the variance is drawn from
+    /// a moment-matched quadratic-normal or exponential-mixture law, so it
+    /// is non-negative by construction.
+    QuadraticExponential,
+    /// Quadratic-Exponential with Andersen's martingale correction, so that
+    /// the discounted simulated spot has exactly the forward as its mean.
+    QuadraticExponentialMartingale,
+}
+
 /// The Heston stochastic volatility process.
 ///
 /// * `v0`    — initial variance
@@ -36,6 +56,7 @@ pub struct HestonProcess {
     rho: Real,
     risk_free_rate: Arc<dyn YieldTermStructure>,
     dividend_yield: Arc<dyn YieldTermStructure>,
+    discretization: HestonDiscretization,
 }
 
 impl HestonProcess {
@@ -69,9 +90,21 @@ impl HestonProcess {
             theta,
             sigma,
             rho,
+            discretization: HestonDiscretization::default(),
         }
     }
 
+    /// Use the given scheme in [`evolve`](StochasticProcess::evolve).
+    pub fn with_discretization(mut self, discretization: HestonDiscretization) -> Self {
+        self.discretization = discretization;
+        self
+    }
+
+    /// Scheme used in [`evolve`](StochasticProcess::evolve).
+    pub fn discretization(&self) -> HestonDiscretization {
+        self.discretization
+    }
+
     /// Spot price.
     pub fn s0(&self) -> Real {
         self.s0
@@ -121,6 +154,96 @@ impl HestonProcess {
     pub fn dividend_yield_arc(&self) -> Arc<dyn YieldTermStructure> {
         Arc::clone(&self.dividend_yield)
     }
+
+    /// One Quadratic-Exponential step from `(s, v)` at time `t`.
+    ///
+    /// `z_s` and `z_v` are independent standard normals: `z_v` drives the
+    /// variance and `z_s` the part of the log-spot move orthogonal to it;
+    /// the correlation `ρ` enters through the variance terms of the
+    /// log-spot step (central weights `γ₁ = γ₂ = ½`). With `martingale`
+    /// the drift is corrected so that `E[S(t+dt)] = S·e^{∫(r−q)}` exactly;
+    /// on steps too long for the correction to exist (`A ≥ 1/(2a)` or
+    /// `A ≥ β` in Andersen's notation) the uncorrected drift is kept.
+    /// A zero conditional mean of the variance keeps it at zero.
+    ///
+    /// Returns the next `(s, v)`; the variance is never negative.
+    ///
+    /// See L. Andersen, "Efficient Simulation of the Heston Stochastic
+    /// Volatility Model" (2008).
+    #[allow(clippy::too_many_arguments)]
+    pub fn evolve_qe(
+        &self,
+        t: Time,
+        s: Real,
+        v: Real,
+        dt: Time,
+        z_s: Real,
+        z_v: Real,
+        martingale: bool,
+    ) -> (Real, Real) {
+        // Threshold between the quadratic and exponential regimes.
+        const PSI_CRITICAL: Real = 1.5;
+        let (kappa, theta, sigma, rho) = (self.kappa, self.theta, self.sigma, self.rho);
+        let v = v.max(0.0);
+
+        // Conditional mean and variance of v(t+dt).
+        let ex = (-kappa * dt).exp();
+        let m = theta + (v - theta) * ex;
+        let s2 = v * sigma * sigma * ex / kappa * (1.0 - ex)
+            + theta * sigma * sigma / (2.0 * kappa) * (1.0 - ex) * (1.0 - ex);
+        let psi = if m > 0.0 {
+            s2 / (m * m)
+        } else {
+            Real::INFINITY
+        };
+
+        let (g1, g2) = (0.5, 0.5);
+        let mut k0 = -rho * kappa * theta * dt / sigma;
+        let k1 = g1 * dt * (kappa * rho / sigma - 0.5) - rho / sigma;
+        let k2 = g2 * dt * (kappa * rho / sigma - 0.5) + rho / sigma;
+        let k3 = g1 * dt * (1.0 - rho * rho);
+        let k4 = g2 * dt * (1.0 - rho * rho);
+        let a_coef = k2 + 0.5 * k4;
+
+        let new_v = if psi < PSI_CRITICAL {
+            let b2 = 2.0 / psi - 1.0 + (2.0 / psi * (2.0 / psi - 1.0)).sqrt();
+            let b = b2.sqrt();
+            let a = m / (1.0 + b2);
+            if martingale && a_coef < 1.0 / (2.0 * a) {
+                k0 = -a_coef * b2 * a / (1.0 - 2.0 * a_coef * a)
+                    + 0.5 * (1.0 - 2.0 * a_coef * a).ln()
+                    - (k1 + 0.5 * k3) * v;
+            }
+            a * (b + z_v) * (b + z_v)
+        } else if m <= 0.0 {
+            // The exponential regime's limit p → 1: all mass at zero.
+            if martingale {
+                k0 = -(k1 + 0.5 * k3) * v;
+            }
+            0.0
+        } else {
+            let p = (psi - 1.0) / (psi + 1.0);
+            let beta = (1.0 - p) / m;
+            if martingale && a_coef < beta {
+                k0 = -(p + beta * (1.0 - p) / (beta - a_coef)).ln() - (k1 + 0.5 * k3) * v;
+            }
+            let u = normal_cdf(z_v);
+            if u <= p {
+                0.0
+            } else {
+                ((1.0 - p) / (1.0 - u)).ln() / beta
+            }
+        };
+
+        let carry = (self.risk_free_rate.discount_impl(t)
+            / self.risk_free_rate.discount_impl(t + dt))
+        .ln()
+            - (self.dividend_yield.discount_impl(t) / self.dividend_yield.discount_impl(t + dt))
+                .ln();
+        let log_step =
+            carry + k0 + k1 * v + k2 * new_v + (k3 * v + k4 * new_v).max(0.0).sqrt() * z_s;
+        (s * log_step.exp(), new_v)
+    }
 }
 
 impl StochasticProcess for HestonProcess {
@@ -163,7 +286,33 @@ impl StochasticProcess for HestonProcess {
         m
     }
 
+    /// `[[v·S², ρ·σ·v·S], [ρ·σ·v·S, σ²·v]]·Δt`, with `v` floored at zero.
+    fn covariance(&self, _t: Time, x: &Array, dt: Time) -> Matrix {
+        let s = x[0];
+        let v = x[1].max(0.0);
+        let cross = self.rho * self.sigma * v * s * dt;
+        let mut m = Matrix::zeros(2, 2);
+        m[(0, 0)] = v * s * s * dt;
+        m[(0, 1)] = cross;
+        m[(1, 0)] = cross;
+        m[(1, 1)] = self.sigma * self.sigma * v * dt;
+        m
+    }
+
+    /// Step with the configured [`HestonDiscretization`]; for the
+    /// Quadratic-Exponential schemes `dw[0]` drives the spot and `dw[1]`
+    /// the variance (see [`HestonProcess::evolve_qe`]).
     fn evolve(&self, t: Time, x: &Array, dt: Time, dw: &Array) -> Array {
+        let martingale = match self.discretization {
+            HestonDiscretization::FullTruncation => None,
+            HestonDiscretization::QuadraticExponential => Some(false),
+            HestonDiscretization::QuadraticExponentialMartingale => Some(true),
+        };
+        if let Some(martingale) = martingale {
+            let (s, v) = self.evolve_qe(t, x[0], x[1], dt, dw[0], dw[1], martingale);
+            return Array::from_vec(vec![s, v]);
+        }
+
         // Euler-Maruyama step with full-truncation scheme for variance
         let s = x[0];
         let v = x[1].max(0.0);
@@ -273,4 +422,98 @@ mod tests {
         let x_new = p.evolve(0.0, &x, dt, &dw);
         assert!(x_new[1] >= 0.0, "variance went negative: {}", x_new[1]);
     }
+
+    #[test]
+    fn heston_covariance_has_correlated_off_diagonal() {
+        let p = make_heston();
+        let (s, v, dt) = (100.0, 0.09, 0.25);
+        let x = Array::from_vec(vec![s, v]);
+        let cov = p.covariance(0.0, &x, dt);
+
+        // Cov[dS, dv] = ρ·(√v·S)·(σ·√v)·Δt = ρ·σ·v·S·Δt
+        assert_abs_diff_eq!(cov[(0, 1)], -0.7 * 0.3 * v * s * dt, epsilon = 1e-12);
+        assert_abs_diff_eq!(cov[(1, 0)], cov[(0, 1)], epsilon = 1e-15);
+        assert_abs_diff_eq!(cov[(0, 0)], v * s * s * dt, epsilon = 1e-10);
+        assert_abs_diff_eq!(cov[(1, 1)], 0.09 * v * dt, epsilon = 1e-12);
+
+        // Consistent with the Cholesky-factored diffusion matrix
+        let d = p.diffusion(0.0, &x);
+        let local = &(&d * &d.transpose()) * dt;
+        for i in 0..2 {
+            for j in 0..2 {
+                assert_abs_diff_eq!(cov[(i, j)], local[(i, j)], epsilon = 1e-10);
+            }
+        }
+        assert_abs_diff_eq!(p.correlation(0.0, &x, dt)[(0, 1)], -0.7, epsilon = 1e-12);
+    }
+
+    #[test]
+    fn qe_variance_non_negative_in_both_regimes() {
+        let p = make_heston().with_discretization(HestonDiscretization::QuadraticExponential);
+        assert_eq!(
+            p.discretization(),
+            HestonDiscretization::QuadraticExponential
+        );
+        // v = 0.04 stays quadratic over a short step; v ≈ 0 with a long
+        // step pushes ψ above 1.5 into the exponential regime.
+        for (v, dt) in [(0.04, 1.0 / 252.0), (1e-6, 0.5)] {
+            for z in [-8.0, -3.0, -1.0, 0.0, 1.0, 3.0, 8.0] {
+                let x = Array::from_vec(vec![100.0, v]);
+                let next = p.evolve(0.0, &x, dt, &Array::from_vec(vec![z, z]));
+                assert!(next[1] >= 0.0, "v={v}, dt={dt}, z={z}: {}", next[1]);
+                assert!(next[0] > 0.0 && next[0].is_finite());
+            }
+        }
+    }
+
+    #[test]
+    fn qe_martingale_matches_forward() {
+        use ql_math::random_numbers::InverseCumulativeNormalRng;
+
+        let p =
+            make_heston().with_discretization(HestonDiscretization::QuadraticExponentialMartingale);
+        let (steps, paths) = (4, 20_000);
+        let dt = 1.0 / steps as Real;
+        let mut rng = InverseCumulativeNormalRng::new(42);
+        let mut sum = 0.0;
+        for _ in 0..paths {
+            let mut x = p.initial_values();
+            for i in 0..steps {
+                let dw = Array::from_vec(vec![rng.next_real(), rng.next_real()]);
+                x = p.evolve(i as Real * dt, &x, dt, &dw);
+            }
+            sum += x[0];
+        }
+        let forward = 100.0 * (0.03_f64).exp();
+        let mean = sum / paths as Real;
+        assert!(
+            (mean - forward).abs() < 0.3,
+            "mean {mean} vs forward {forward}"
+        );
+    }
+
+    #[test]
+    fn qe_handles_degenerate_variance_and_long_steps() {
+        let ref_date = Date::from_ymd(2025, 1, 2).unwrap();
+        let r: Arc<dyn YieldTermStructure> =
+            Arc::new(FlatForward::continuous(ref_date, 0.05, Actual365Fixed));
+        let q: Arc<dyn YieldTermStructure> =
+            Arc::new(FlatForward::continuous(ref_date, 0.02, Actual365Fixed));
+
+        // θ = 0 and v = 0: the conditional mean of the variance is zero.
+        let p = HestonProcess::new(100.0, 0.0, r.clone(), q.clone(), 1.5, 0.0, 0.3, -0.7)
+            .with_discretization(HestonDiscretization::QuadraticExponentialMartingale);
+        let (s, v) = p.evolve_qe(0.0, 100.0, 0.0, 0.5, 0.3, -1.2, true);
+        assert_eq!(v, 0.0);
+        assert_abs_diff_eq!(s, 100.0 * (0.03_f64 * 0.5).exp(), epsilon = 1e-9);
+
+        // A ten-year step where the martingale correction does not exist.
+        let p = HestonProcess::new(100.0, 0.04, r, q, 1.5, 0.1, 1.0, 0.9);
+        for z in [-3.0, 0.0, 3.0] {
+            let (s, v) = p.evolve_qe(0.0, 100.0, 0.04, 10.0, z, z, true);
+            assert!(s > 0.0 && s.is_finite(), "z={z}: s = {s}");
+            assert!(v >= 0.0 && v.is_finite(), "z={z}: v = {v}");
+            assert_eq!((s, v), p.evolve_qe(0.0, 100.0, 0.04, 10.0, z, z, false));
+        }
+    }
 }
@@ -10,6 +10,7 @@
 
 pub mod bates_process;
 pub mod black_scholes_process;
+pub mod characteristic_function;
 pub mod g2_process;
 pub mod geometric_brownian_motion;
 pub mod gsr_process;
@@ -20,20 +21,26 @@ pub mod merton76_process;
 pub mod ornstein_uhlenbeck_process;
 pub mod square_root_process;
 pub mod stochastic_process;
+pub mod stochastic_process_array;
 pub mod variance_gamma_process;
 
 pub use bates_process::BatesProcess;
 pub use black_scholes_process::{
-    black_scholes_merton_process, black_scholes_process, GeneralizedBlackScholesProcess,
+    black_process, black_scholes_merton_process, black_scholes_process,
+    GeneralizedBlackScholesProcess,
+};
+pub use characteristic_function::{
+    heston_exponent, lognormal_jump_exponent, variance_gamma_exponent, CharacteristicFunction,
 };
 pub use g2_process::G2Process;
 pub use geometric_brownian_motion::GeometricBrownianMotionProcess;
 pub use gsr_process::GsrProcess;
-pub use heston_process::HestonProcess;
+pub use heston_process::{HestonDiscretization, HestonProcess};
 pub use hull_white_forward_process::HullWhiteForwardProcess;
 pub use hull_white_process::HullWhiteProcess;
 pub use merton76_process::Merton76Process;
 pub use ornstein_uhlenbeck_process::OrnsteinUhlenbeckProcess;
 pub use square_root_process::SquareRootProcess;
-pub use stochastic_process::{StochasticProcess, StochasticProcess1D};
+pub use stochastic_process::{DiscretizationScheme, StochasticProcess, StochasticProcess1D};
+pub use stochastic_process_array::StochasticProcessArray;
 pub use variance_gamma_process::VarianceGammaProcess;
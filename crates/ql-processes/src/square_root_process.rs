@@ -6,6 +6,16 @@
 //!
 //! This is the Cox-Ingersoll-Ross process, used in short-rate models and as
 //! the variance process in the Heston model.
+//!
+//! `X(t)` given `X(0) = x₀` is a scaled non-central chi-square variable,
+//! whose first two moments are
+//!
+//! ```text
+//! E[X(t)]   = b + (x₀ − b) e^{−at}
+//! Var[X(t)] = x₀ σ²/a (e^{−at} − e^{−2at}) + b σ²/(2a) (1 − e^{−at})²
+//! ```
+//!
+//! tending to `b` and `bσ²/(2a)` as `t → ∞`.
 
 use crate::stochastic_process::StochasticProcess1D;
 use ql_core::{Real, Time};
@@ -55,6 +65,30 @@ impl SquareRootProcess {
     pub fn volatility(&self) -> Real {
         self.volatility
     }
+
+    /// Whether the Feller condition `2ab ≥ σ²` holds, under which the
+    /// process started above zero never reaches it.
+    pub fn feller_condition_satisfied(&self) -> bool {
+        2.0 * self.speed * self.mean >= self.volatility * self.volatility
+    }
+
+    /// Exact mean of `X(t)` started at `v0`.
+    pub fn expectation(&self, t: Time, v0: Real) -> Real {
+        self.mean + (v0 - self.mean) * (-self.speed * t).exp()
+    }
+
+    /// Exact variance of `X(t)` started at `v0`.
+    pub fn variance(&self, t: Time, v0: Real) -> Real {
+        let decay = (-self.speed * t).exp();
+        // (1 − e^{−at})/a, tending to t as a → 0.
+        let g = if self.speed.abs() < 1e-12 {
+            t
+        } else {
+            -(-self.speed * t).exp_m1() / self.speed
+        };
+        let sigma2 = self.volatility * self.volatility;
+        v0 * sigma2 * decay * g + 0.5 * self.mean * sigma2 * self.speed * g * g
+    }
 }
 
 impl StochasticProcess1D for SquareRootProcess {
@@ -69,6 +103,14 @@ impl StochasticProcess1D for SquareRootProcess {
     fn diffusion_1d(&self, _t: Time, x: Real) -> Real {
         self.volatility * x.max(0.0).sqrt()
     }
+
+    fn diffusion_derivative_1d(&self, _t: Time, x: Real) -> Real {
+        if x > 0.0 {
+            0.5 * self.volatility / x.sqrt()
+        } else {
+            0.0
+        }
+    }
 }
 
 #[cfg(test)]
@@ -107,4 +149,112 @@ mod tests {
         // At mean with zero noise: x should stay at 0.04
         assert!((x_new - 0.04).abs() < 1e-10);
     }
+
+    #[test]
+    fn square_root_milstein_has_lower_mean_bias_than_euler() {
+        use crate::stochastic_process::DiscretizationScheme;
+        use ql_math::random_numbers::InverseCumulativeNormalRng;
+
+        // Feller condition violated (2ab < σ²): Euler steps frequently cross
+        // zero and are truncated, biasing the mean upwards. Starting at the
+        // long-run mean, the exact E[X(T)] is b for every T.
+        let (a, b, sigma) = (1.0, 0.04, 0.3);
+        let p = SquareRootProcess::new(a, b, sigma, b);
+        let simulated_mean = |scheme, steps: usize| {
+            let mut rng = InverseCumulativeNormalRng::new(5);
+            let dt = 1.0 / steps as Real;
+            let n = 50_000;
+            let mut sum = 0.0;
+            for _ in 0..n {
+                let mut x = p.x0();
+                for i in 0..steps {
+                    let dw = rng.next_real();
+                    x = p.evolve_1d_with(scheme, i as Real * dt, x, dt, dw).max(0.0);
+                }
+                sum += x;
+            }
+            sum / n as Real
+        };
+
+        let euler_bias = (simulated_mean(DiscretizationScheme::Euler, 4) - b).abs();
+        let milstein_bias = (simulated_mean(DiscretizationScheme::Milstein, 4) - b).abs();
+        assert!(
+            milstein_bias < 0.2 * euler_bias,
+            "Milstein bias {milstein_bias:e} vs Euler bias {euler_bias:e}"
+        );
+        // Standard error of the mean is ≈ 2e-4 with 50k paths
+        assert!(milstein_bias < 6e-4, "Milstein bias {milstein_bias:e}");
+        let fine_bias = (simulated_mean(DiscretizationScheme::Milstein, 16) - b).abs();
+        assert!(fine_bias < 6e-4, "Milstein 16-step bias {fine_bias:e}");
+    }
+
+    #[test]
+    fn square_root_diffusion_derivative() {
+        let p = SquareRootProcess::new(1.0, 0.04, 0.3, 0.04);
+        // σσ' = σ²/2 for the square-root diffusion
+        let x = 0.09;
+        let product = p.diffusion_1d(0.0, x) * p.diffusion_derivative_1d(0.0, x);
+        assert!((product - 0.045).abs() < 1e-12);
+        assert_eq!(p.diffusion_derivative_1d(0.0, 0.0), 0.0);
+    }
+
+    #[test]
+    fn square_root_feller_condition() {
+        // 2ab = 0.08 against σ² = 0.04, 0.08 and 0.09.
+        assert!(SquareRootProcess::new(1.0, 0.04, 0.2, 0.04).feller_condition_satisfied());
+        assert!(
+            SquareRootProcess::new(1.0, 0.04, 0.08_f64.sqrt(), 0.04).feller_condition_satisfied()
+        );
+        assert!(!SquareRootProcess::new(1.0, 0.04, 0.3, 0.04).feller_condition_satisfied());
+    }
+
+    #[test]
+    fn square_root_moments_reach_stationary_values() {
+        let (a, b, sigma, v0) = (1.5, 0.04, 0.3, 0.09);
+        let p = SquareRootProcess::new(a, b, sigma, v0);
+        assert_eq!(p.expectation(0.0, v0), v0);
+        assert_eq!(p.variance(0.0, v0), 0.0);
+        // Over a short step the variance is the diffusion's, σ² v₀ dt.
+        let dt = 1e-6;
+        assert!((p.variance(dt, v0) / (sigma * sigma * v0 * dt) - 1.0).abs() < 1e-5);
+
+        let stationary = b * sigma * sigma / (2.0 * a);
+        let mut previous = Real::INFINITY;
+        for t in [1.0, 2.0, 5.0, 10.0] {
+            let gap = (p.expectation(t, v0) - b).abs();
+            assert!((gap - (v0 - b) * (-a * t).exp()).abs() < 1e-15);
+            assert!(gap < previous);
+            previous = gap;
+        }
+        assert!((p.expectation(50.0, v0) - b).abs() < 1e-15);
+        assert!((p.variance(50.0, v0) - stationary).abs() < 1e-15);
+
+        // Without mean reversion the variance grows linearly.
+        let driftless = SquareRootProcess::new(0.0, b, sigma, v0);
+        assert!((driftless.variance(2.0, v0) - sigma * sigma * v0 * 2.0).abs() < 1e-15);
+    }
+
+    #[test]
+    fn square_root_moments_match_simulation() {
+        use ql_math::random_numbers::InverseCumulativeNormalRng;
+        use ql_math::IncrementalStatistics;
+
+        let p = SquareRootProcess::new(2.0, 0.04, 0.2, 0.02);
+        let (t, steps, paths) = (1.0, 200, 20_000);
+        let dt = t / steps as Real;
+        let mut rng = InverseCumulativeNormalRng::new(11);
+        let mut stats = IncrementalStatistics::new();
+        for _ in 0..paths {
+            let mut x = p.x0();
+            for i in 0..steps {
+                x = p.evolve_1d(i as Real * dt, x, dt, rng.next_real()).max(0.0);
+            }
+            stats.add(x);
+        }
+        let (mean, var) = (stats.mean().unwrap(), stats.variance().unwrap());
+        let (exact_mean, exact_var) = (p.expectation(t, 0.02), p.variance(t, 0.02));
+        // Standard errors are ≈ 1.4e-4 on the mean and ≈ 3% on the variance.
+        assert!((mean - exact_mean).abs() < 5e-4, "{mean} vs {exact_mean}");
+        assert!((var / exact_var - 1.0).abs() < 0.1, "{var} vs {exact_var}");
+    }
 }
@@ -7,6 +7,17 @@
 use ql_core::{Real, Time};
 use ql_math::{Array, Matrix};
 
+/// Time-discretization scheme used when stepping a 1-D process.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum DiscretizationScheme {
+    /// Euler-Maruyama: strong order ½.
+    #[default]
+    Euler,
+    /// Milstein: Euler plus the Itô correction `½·σ·σ'·(ΔW² − Δt)`,
+    /// strong order 1 for state-dependent diffusions.
+    Milstein,
+}
+
 /// A general multi-dimensional stochastic process.
 ///
 /// Corresponds to `QuantLib::StochasticProcess`.
@@ -56,6 +67,35 @@ pub trait StochasticProcess: std::fmt::Debug + Send + Sync {
         result
     }
 
+    /// Local covariance of `x(t+Δt)` given `x(t)`: `σ(t,x)·σ(t,x)ᵀ·Δt`.
+    ///
+    /// Computed from [`std_deviation`](Self::std_deviation), so processes
+    /// with an exact standard deviation get the matching covariance.
+    /// Returns a `size() × size()` matrix.
+    fn covariance(&self, t: Time, x: &Array, dt: Time) -> Matrix {
+        let s = self.std_deviation(t, x, dt);
+        &s * &s.transpose()
+    }
+
+    /// Local correlation matrix, the normalised [`covariance`](Self::covariance).
+    ///
+    /// Components with zero variance get unit self-correlation and zero
+    /// correlation with everything else.
+    fn correlation(&self, t: Time, x: &Array, dt: Time) -> Matrix {
+        let cov = self.covariance(t, x, dt);
+        let n = cov.rows();
+        let mut result = Matrix::identity(n);
+        for i in 0..n {
+            for j in 0..n {
+                let norm = (cov[(i, i)] * cov[(j, j)]).sqrt();
+                if i != j && norm > 0.0 {
+                    result[(i, j)] = cov[(i, j)] / norm;
+                }
+            }
+        }
+        result
+    }
+
     /// Apply a change: advance the state by an Euler step.
     ///
     /// `x(t+Δt) = E[x(t+Δt)|x(t)] + σ·√Δt · dw`
@@ -109,6 +149,41 @@ pub trait StochasticProcess1D: StochasticProcess {
         let s = self.diffusion_1d(t, x);
         s * s * dt
     }
+
+    /// Derivative `∂σ/∂x` of the diffusion, used by the Milstein scheme.
+    ///
+    /// Default: central finite difference.
+    fn diffusion_derivative_1d(&self, t: Time, x: Real) -> Real {
+        let h = 1e-6 * x.abs().max(1.0);
+        (self.diffusion_1d(t, x + h) - self.diffusion_1d(t, x - h)) / (2.0 * h)
+    }
+
+    /// Step with the given scheme; `dw` is a standard normal draw.
+    ///
+    /// [`Euler`](DiscretizationScheme::Euler) is [`evolve_1d`](Self::evolve_1d);
+    /// [`Milstein`](DiscretizationScheme::Milstein) is
+    /// `x + μ·Δt + σ·√Δt·dw + ½·σ·σ'·Δt·(dw² − 1)`, built from the drift and
+    /// diffusion rather than on top of `evolve_1d`, which processes may
+    /// override with an exact step.
+    fn evolve_1d_with(
+        &self,
+        scheme: DiscretizationScheme,
+        t: Time,
+        x: Real,
+        dt: Time,
+        dw: Real,
+    ) -> Real {
+        match scheme {
+            DiscretizationScheme::Euler => self.evolve_1d(t, x, dt, dw),
+            DiscretizationScheme::Milstein => {
+                let sigma = self.diffusion_1d(t, x);
+                let dsigma = self.diffusion_derivative_1d(t, x);
+                x + self.drift_1d(t, x) * dt
+                    + sigma * dt.sqrt() * dw
+                    + 0.5 * sigma * dsigma * dt * (dw * dw - 1.0)
+            }
+        }
+    }
 }
 
 /// Blanket implementation: any 1D process is also a multi-dimensional process
@@ -228,6 +303,21 @@ mod tests {
         assert!((x_new[0] - 100.25).abs() < 1e-12);
     }
 
+    #[test]
+    fn process_1d_milstein_correction() {
+        // Constant diffusion: σ' = 0, so Milstein coincides with Euler
+        let p = ConstantProcess {
+            x0: 100.0,
+            mu: 0.05,
+            sigma: 0.20,
+        };
+        assert!(p.diffusion_derivative_1d(0.0, 100.0).abs() < 1e-9);
+        let euler = p.evolve_1d_with(DiscretizationScheme::Euler, 0.0, 100.0, 0.5, 1.3);
+        let milstein = p.evolve_1d_with(DiscretizationScheme::Milstein, 0.0, 100.0, 0.5, 1.3);
+        assert!((euler - p.evolve_1d(0.0, 100.0, 0.5, 1.3)).abs() < 1e-15);
+        assert!((milstein - euler).abs() < 1e-9);
+    }
+
     #[test]
     fn process_1d_variance() {
         let p = ConstantProcess {
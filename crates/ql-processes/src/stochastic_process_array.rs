@@ -0,0 +1,181 @@
+//! Array of correlated 1-D processes
+//! (translates `ql/processes/stochasticprocessarray.hpp`).
+//!
+//! Each component keeps its own one-dimensional dynamics; the correlation
+//! enters only through the driving Brownian motions.  With `L` the
+//! Cholesky factor of the correlation matrix `ρ = L·Lᵀ`, independent
+//! normals `dw` become correlated ones `dz = L·dw` before each component
+//! is stepped with its own `evolve_1d`.
+
+use crate::stochastic_process::{StochasticProcess, StochasticProcess1D};
+use ql_core::{ensure, errors::Result, Real, Time};
+use ql_math::matrix_utilities::cholesky_decomposition;
+use ql_math::{Array, Matrix};
+use std::sync::Arc;
+
+/// A multi-dimensional process made of correlated 1-D processes.
+///
+/// Corresponds to `QuantLib::StochasticProcessArray`.
+#[derive(Debug)]
+pub struct StochasticProcessArray {
+    processes: Vec<Arc<dyn StochasticProcess1D>>,
+    correlation: Matrix,
+    sqrt_correlation: Matrix,
+}
+
+impl StochasticProcessArray {
+    /// Combine `processes` driven by Brownian motions with the given
+    /// correlation matrix.
+    ///
+    /// Fails unless `correlation` is a symmetric, positive-definite matrix
+    /// with unit diagonal matching the number of processes.
+    pub fn new(processes: Vec<Arc<dyn StochasticProcess1D>>, correlation: Matrix) -> Result<Self> {
+        let n = processes.len();
+        ensure!(n > 0, "no processes given");
+        ensure!(
+            correlation.rows() == n && correlation.cols() == n,
+            "correlation matrix is {}x{}, expected {n}x{n}",
+            correlation.rows(),
+            correlation.cols()
+        );
+        for i in 0..n {
+            ensure!(
+                (correlation[(i, i)] - 1.0).abs() <= 1e-12,
+                "correlation matrix must have unit diagonal"
+            );
+            for j in 0..i {
+                ensure!(
+                    (correlation[(i, j)] - correlation[(j, i)]).abs() <= 1e-12,
+                    "correlation matrix must be symmetric"
+                );
+            }
+        }
+        let sqrt_correlation = cholesky_decomposition(&correlation)?;
+        Ok(Self {
+            processes,
+            correlation,
+            sqrt_correlation,
+        })
+    }
+
+    /// The `i`-th component process.
+    pub fn process(&self, i: usize) -> &Arc<dyn StochasticProcess1D> {
+        &self.processes[i]
+    }
+
+    /// Correlation matrix of the driving Brownian motions.
+    pub fn correlation_matrix(&self) -> &Matrix {
+        &self.correlation
+    }
+
+    /// Lower-triangular Cholesky factor of the correlation matrix.
+    pub fn sqrt_correlation(&self) -> &Matrix {
+        &self.sqrt_correlation
+    }
+
+    /// Scale row `i` of the Cholesky factor by `scale(i)`.
+    fn scaled_sqrt_correlation(&self, scale: impl Fn(usize) -> Real) -> Matrix {
+        let n = self.size();
+        let mut result = self.sqrt_correlation.clone();
+        for i in 0..n {
+            let s = scale(i);
+            for j in 0..=i {
+                result[(i, j)] *= s;
+            }
+        }
+        result
+    }
+}
+
+impl StochasticProcess for StochasticProcessArray {
+    fn size(&self) -> usize {
+        self.processes.len()
+    }
+
+    fn initial_values(&self) -> Array {
+        Array::from_vec(self.processes.iter().map(|p| p.x0()).collect())
+    }
+
+    fn drift(&self, t: Time, x: &Array) -> Array {
+        Array::from_vec(
+            self.processes
+                .iter()
+                .enumerate()
+                .map(|(i, p)| p.drift_1d(t, x[i]))
+                .collect(),
+        )
+    }
+
+    fn diffusion(&self, t: Time, x: &Array) -> Matrix {
+        self.scaled_sqrt_correlation(|i| self.processes[i].diffusion_1d(t, x[i]))
+    }
+
+    fn expectation(&self, t: Time, x: &Array, dt: Time) -> Array {
+        Array::from_vec(
+            self.processes
+                .iter()
+                .enumerate()
+                .map(|(i, p)| p.expectation_1d(t, x[i], dt))
+                .collect(),
+        )
+    }
+
+    fn std_deviation(&self, t: Time, x: &Array, dt: Time) -> Matrix {
+        self.scaled_sqrt_correlation(|i| self.processes[i].std_deviation_1d(t, x[i], dt))
+    }
+
+    fn evolve(&self, t: Time, x: &Array, dt: Time, dw: &Array) -> Array {
+        let dz = self.sqrt_correlation.mul_vec(dw);
+        Array::from_vec(
+            self.processes
+                .iter()
+                .enumerate()
+                .map(|(i, p)| p.evolve_1d(t, x[i], dt, dz[i]))
+                .collect(),
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::black_scholes_process::black_scholes_process;
+    use ql_termstructures::{BlackConstantVol, FlatForward};
+    use ql_time::{Actual365Fixed, Date};
+
+    fn gbm(spot: Real, vol: Real) -> Arc<dyn StochasticProcess1D> {
+        let ref_date = Date::from_ymd(2025, 1, 2).unwrap();
+        Arc::new(black_scholes_process(
+            spot,
+            Arc::new(FlatForward::continuous(ref_date, 0.03, Actual365Fixed)),
+            Arc::new(BlackConstantVol::new(ref_date, vol, Actual365Fixed)),
+        ))
+    }
+
+    #[test]
+    fn local_covariance_carries_the_correlation() {
+        let rho = Matrix::from_row_slice(2, 2, &[1.0, 0.6, 0.6, 1.0]);
+        let array =
+            StochasticProcessArray::new(vec![gbm(100.0, 0.2), gbm(50.0, 0.3)], rho).unwrap();
+        assert_eq!(array.size(), 2);
+        assert_eq!(array.factors(), 2);
+        assert_eq!(array.initial_values().as_slice(), &[100.0, 50.0]);
+
+        let x = array.initial_values();
+        let corr = array.correlation(0.0, &x, 0.01);
+        assert!((corr[(0, 1)] - 0.6).abs() < 1e-12);
+        let cov = array.covariance(0.0, &x, 0.01);
+        let s0 = array.process(0).std_deviation_1d(0.0, 100.0, 0.01);
+        assert!((cov[(0, 0)] - s0 * s0).abs() < 1e-12);
+    }
+
+    #[test]
+    fn rejects_invalid_correlation() {
+        let processes = vec![gbm(100.0, 0.2), gbm(50.0, 0.3)];
+        let not_pd = Matrix::from_row_slice(2, 2, &[1.0, 1.5, 1.5, 1.0]);
+        assert!(StochasticProcessArray::new(processes.clone(), not_pd).is_err());
+        let asymmetric = Matrix::from_row_slice(2, 2, &[1.0, 0.5, 0.2, 1.0]);
+        assert!(StochasticProcessArray::new(processes.clone(), asymmetric).is_err());
+        assert!(StochasticProcessArray::new(processes, Matrix::identity(3)).is_err());
+    }
+}
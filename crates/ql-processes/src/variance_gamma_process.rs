@@ -59,6 +59,11 @@ impl VarianceGammaProcess {
         }
     }
 
+    /// Spot price.
+    pub fn s0(&self) -> Real {
+        self.s0
+    }
+
     /// Martingale correction `ω = ln(1 − θν − σ²ν/2)/ν`.
     pub fn omega(&self) -> Real {
         (1.0 - self.theta * self.nu - 0.5 * self.sigma * self.sigma * self.nu).ln() / self.nu
@@ -4,6 +4,10 @@
 //!
 //! The surface stores a 2D grid of Black volatilities and performs bilinear
 //! interpolation on **variance** (`σ²·t`) to ensure calendar-time consistency.
+//!
+//! [`repair_calendar_arbitrage`] removes calendar-spread arbitrage from the
+//! quoted grid by making total variance non-decreasing in expiry at every
+//! strike.
 
 use crate::black_vol_term_structure::BlackVolTermStructure;
 use crate::term_structure::TermStructure;
@@ -13,13 +17,30 @@ use ql_core::{errors::Result, Real, Time, Volatility};
 use ql_time::{Calendar, Date, DayCounter, NullCalendar};
 use std::sync::Arc;
 
-/// Extrapolation mode for out-of-range strikes.
+/// Extrapolation mode outside the quoted grid.
+///
+/// The strike and time axes are configured separately; see
+/// [`BlackVarianceSurface::new`] and
+/// [`BlackVarianceSurface::with_time_extrapolation`].  Whatever the mode,
+/// extrapolated total variance never decreases with expiry.
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum Extrapolation {
-    /// Clamp to the nearest boundary value.
+    /// Clamp to the nearest boundary value of the total variance.
     ConstantExtrapolation,
-    /// No extrapolation — panic on out-of-range queries (for debugging).
+    /// No extrapolation.  Along strikes the boundary segment is extended
+    /// unchecked; along expiries out-of-range queries panic (for
+    /// debugging).
     None,
+    /// Hold the boundary volatility.  Along strikes this coincides with
+    /// [`ConstantExtrapolation`](Self::ConstantExtrapolation), since time
+    /// is fixed; along expiries the total variance grows as
+    /// `v(t_b, K)·t/t_b` from the boundary expiry `t_b`.
+    FlatVolatility,
+    /// Extend the total variance linearly from the boundary segment,
+    /// floored at zero.  Beyond the last expiry the slope is that of the
+    /// last segment, floored at zero; before the first expiry the segment
+    /// runs from zero variance at `t = 0`.
+    LinearInVariance,
 }
 
 /// A Black-variance surface built from a grid of implied volatilities.
@@ -40,8 +61,10 @@ pub struct BlackVarianceSurface {
     /// Total variances: `variances[i][j] = σ²(t_i, K_j) * t_i`.
     /// Row i = expiry i, Col j = strike j.
     variances: Vec<Vec<Real>>,
-    /// Extrapolation mode.
-    extrapolation: Extrapolation,
+    /// Extrapolation mode along strikes.
+    strike_extrapolation: Extrapolation,
+    /// Extrapolation mode along expiries.
+    time_extrapolation: Extrapolation,
 }
 
 impl BlackVarianceSurface {
@@ -53,7 +76,10 @@ impl BlackVarianceSurface {
     /// * `strikes` — strike grid (ascending)
     /// * `vols` — `vols[i][j]` = implied vol for expiry `dates[i]`, strike `strikes[j]`
     /// * `day_counter` — used for date → time conversion
-    /// * `extrapolation` — how to handle out-of-range strikes
+    /// * `extrapolation` — how to handle out-of-range strikes; expiries
+    ///   outside the grid clamp the total variance until
+    ///   [`with_time_extrapolation`](Self::with_time_extrapolation) says
+    ///   otherwise
     pub fn new(
         reference_date: Date,
         dates: &[Date],
@@ -101,60 +127,178 @@ impl BlackVarianceSurface {
             times,
             strikes: strikes.to_vec(),
             variances,
-            extrapolation,
+            strike_extrapolation: extrapolation,
+            time_extrapolation: Extrapolation::ConstantExtrapolation,
         })
     }
 
+    /// Set how expiries before the first or after the last quoted date
+    /// are extrapolated.
+    pub fn with_time_extrapolation(mut self, extrapolation: Extrapolation) -> Self {
+        self.time_extrapolation = extrapolation;
+        self
+    }
+
     /// Set a custom calendar.
     pub fn with_calendar(mut self, calendar: impl Calendar + 'static) -> Self {
         self.data.calendar = Box::new(calendar);
         self
     }
 
+    /// Total variance at expiry row `i` and `strike`, linear in strike
+    /// inside the grid and extrapolated by the strike mode outside it.
+    fn row_variance(&self, i: usize, strike: Real) -> Real {
+        let (row, strikes) = (&self.variances[i], &self.strikes);
+        let n_k = strikes.len();
+        if n_k == 1 {
+            return row[0];
+        }
+        let outside = strike < strikes[0] || strike > strikes[n_k - 1];
+        let k = match self.strike_extrapolation {
+            Extrapolation::ConstantExtrapolation | Extrapolation::FlatVolatility if outside => {
+                strike.clamp(strikes[0], strikes[n_k - 1])
+            }
+            _ => strike,
+        };
+        let ki = find_interval(strikes, k);
+        let k_frac = (k - strikes[ki]) / (strikes[ki + 1] - strikes[ki]);
+        let v = row[ki] + k_frac * (row[ki + 1] - row[ki]);
+        if outside && self.strike_extrapolation == Extrapolation::LinearInVariance {
+            v.max(0.0)
+        } else {
+            v
+        }
+    }
+
     /// Bilinear interpolation on the total variance grid.
+    ///
+    /// Only the (at most four) nodes bracketing `(t, strike)` are read,
+    /// except in the linear-in-variance strike wing, where each expiry row
+    /// is floored by the rows before it.
     fn interpolate_variance(&self, t: Time, strike: Real) -> Real {
-        let n_t = self.times.len();
-        let n_k = self.strikes.len();
-
-        // Clamp or restrict strike
-        let k = match self.extrapolation {
-            Extrapolation::ConstantExtrapolation => {
-                strike.clamp(self.strikes[0], self.strikes[n_k - 1])
+        let outside = strike < self.strikes[0] || strike > *self.strikes.last().unwrap();
+        let wing = outside && self.strike_extrapolation == Extrapolation::LinearInVariance;
+        let row = |i: usize| {
+            if wing {
+                // Extended segments of different slopes can cross; keep the
+                // extrapolated column free of calendar arbitrage.
+                (0..=i)
+                    .map(|k| self.row_variance(k, strike))
+                    .fold(Real::NEG_INFINITY, Real::max)
+            } else {
+                self.row_variance(i, strike)
             }
-            Extrapolation::None => strike,
         };
 
-        // Clamp time to surface range
-        let t_clamped = t.clamp(self.times[0], self.times[n_t - 1]);
-
-        // Find time interval
-        let ti = find_interval(&self.times, t_clamped);
-        // Find strike interval
-        let ki = find_interval(&self.strikes, k);
-
-        // Bilinear interpolation on total variance
-        let t_frac = if self.times[ti + 1] - self.times[ti] > 0.0 {
-            (t_clamped - self.times[ti]) / (self.times[ti + 1] - self.times[ti])
-        } else {
-            0.0
-        };
+        let times = &self.times;
+        let n_t = times.len();
+        let (first, last) = (times[0], times[n_t - 1]);
+        if t < first {
+            return match self.time_extrapolation {
+                Extrapolation::ConstantExtrapolation => row(0),
+                Extrapolation::FlatVolatility | Extrapolation::LinearInVariance => {
+                    row(0) * t / first
+                }
+                Extrapolation::None => panic!("time {t} is before the first expiry {first}"),
+            };
+        }
+        if t > last {
+            let v_last = row(n_t - 1);
+            return match self.time_extrapolation {
+                Extrapolation::ConstantExtrapolation => v_last,
+                Extrapolation::FlatVolatility => v_last * t / last,
+                Extrapolation::LinearInVariance => {
+                    let (t0, v0) = if n_t > 1 {
+                        (times[n_t - 2], row(n_t - 2))
+                    } else {
+                        (0.0, 0.0)
+                    };
+                    let slope = ((v_last - v0) / (last - t0)).max(0.0);
+                    v_last + slope * (t - last)
+                }
+                Extrapolation::None => panic!("time {t} is beyond the last expiry {last}"),
+            };
+        }
 
-        let k_frac = if self.strikes[ki + 1] - self.strikes[ki] > 0.0 {
-            (k - self.strikes[ki]) / (self.strikes[ki + 1] - self.strikes[ki])
+        let ti = find_interval(times, t);
+        if ti + 1 >= n_t {
+            return row(ti);
+        }
+        let t_frac = if times[ti + 1] - times[ti] > 0.0 {
+            (t - times[ti]) / (times[ti + 1] - times[ti])
         } else {
             0.0
         };
+        let (v0, v1) = (row(ti), row(ti + 1));
+        v0 + t_frac * (v1 - v0)
+    }
+}
 
-        let v00 = self.variances[ti][ki];
-        let v01 = self.variances[ti][ki + 1];
-        let v10 = self.variances[ti + 1][ki];
-        let v11 = self.variances[ti + 1][ki + 1];
+/// A grid node moved by [`repair_calendar_arbitrage`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct CalendarArbitrageFix {
+    /// Expiry time of the node.
+    pub time: Time,
+    /// Strike of the node.
+    pub strike: Real,
+    /// Total variance before the repair.
+    pub original_variance: Real,
+    /// Total variance after the repair.
+    pub repaired_variance: Real,
+}
 
-        let v0 = v00 + k_frac * (v01 - v00);
-        let v1 = v10 + k_frac * (v11 - v10);
+/// Remove calendar-spread arbitrage from the nodes of `surface`.
+///
+/// At each strike the column of total variances is replaced by its
+/// least-squares projection onto non-decreasing sequences in expiry
+/// (isotonic regression).  Columns that are already monotone are left
+/// exactly as they are; in a violating column only the nodes pooled by the
+/// projection move, each to the mean of its pool.
+///
+/// Returns the adjusted nodes, ordered by strike and then expiry.
+pub fn repair_calendar_arbitrage(surface: &mut BlackVarianceSurface) -> Vec<CalendarArbitrageFix> {
+    let mut fixes = Vec::new();
+    for (j, &strike) in surface.strikes.iter().enumerate() {
+        let column: Vec<Real> = surface.variances.iter().map(|row| row[j]).collect();
+        let repaired = isotonic_fit(&column);
+        for (i, (&original, &fitted)) in column.iter().zip(&repaired).enumerate() {
+            if fitted != original {
+                surface.variances[i][j] = fitted;
+                fixes.push(CalendarArbitrageFix {
+                    time: surface.times[i],
+                    strike,
+                    original_variance: original,
+                    repaired_variance: fitted,
+                });
+            }
+        }
+    }
+    fixes
+}
 
-        v0 + t_frac * (v1 - v0)
+/// Unweighted least-squares isotonic (non-decreasing) fit of `values`, by
+/// pool-adjacent-violators.
+fn isotonic_fit(values: &[Real]) -> Vec<Real> {
+    // Each block is (mean, count); adjacent blocks are merged while their
+    // means decrease.
+    let mut blocks: Vec<(Real, usize)> = Vec::with_capacity(values.len());
+    for &v in values {
+        let (mut mean, mut count) = (v, 1);
+        while let Some(&(prev_mean, prev_count)) = blocks.last() {
+            if prev_mean <= mean {
+                break;
+            }
+            blocks.pop();
+            let total = prev_count + count;
+            mean = (prev_mean * prev_count as Real + mean * count as Real) / total as Real;
+            count = total;
+        }
+        blocks.push((mean, count));
     }
+    blocks
+        .into_iter()
+        .flat_map(|(mean, count)| std::iter::repeat(mean).take(count))
+        .collect()
 }
 
 /// Find the index `i` such that `xs[i] <= x < xs[i+1]`.
@@ -308,10 +452,231 @@ mod tests {
         assert_abs_diff_eq!(vol_high, 0.22, epsilon = 1e-10);
     }
 
+    fn sample_surface_with(strike: Extrapolation, time: Extrapolation) -> BlackVarianceSurface {
+        let surface = sample_surface();
+        BlackVarianceSurface {
+            strike_extrapolation: strike,
+            time_extrapolation: time,
+            ..surface
+        }
+    }
+
+    #[test]
+    fn strike_extrapolation_modes_at_the_wings() {
+        let t = 0.75;
+        let h = 1e-6;
+        for mode in [
+            Extrapolation::ConstantExtrapolation,
+            Extrapolation::FlatVolatility,
+            Extrapolation::LinearInVariance,
+        ] {
+            let surface = sample_surface_with(mode, Extrapolation::ConstantExtrapolation);
+            for (edge, inward) in [(80.0, 1.0), (120.0, -1.0)] {
+                // Continuous across the boundary.
+                let inside = surface.black_vol_impl(t, edge + inward * h);
+                let outside = surface.black_vol_impl(t, edge - inward * h);
+                assert_abs_diff_eq!(inside, outside, epsilon = 1e-6);
+
+                let far = edge - inward * 20.0;
+                let v_edge = surface.black_variance_impl(t, edge);
+                let v_in = surface.black_variance_impl(t, edge + inward * 20.0);
+                let v_far = surface.black_variance_impl(t, far);
+                if mode == Extrapolation::LinearInVariance {
+                    // The boundary segment carries on.
+                    assert_abs_diff_eq!(v_far - v_edge, v_edge - v_in, epsilon = 1e-12);
+                } else {
+                    assert_abs_diff_eq!(v_far, v_edge, epsilon = 1e-15);
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn time_extrapolation_modes_beyond_the_grid() {
+        let last = 1.0;
+        let h = 1e-6;
+        for mode in [
+            Extrapolation::ConstantExtrapolation,
+            Extrapolation::FlatVolatility,
+            Extrapolation::LinearInVariance,
+        ] {
+            let surface = sample_surface_with(Extrapolation::ConstantExtrapolation, mode);
+            for strike in [80.0, 100.0, 120.0, 150.0] {
+                let inside = surface.black_vol_impl(last - h, strike);
+                let outside = surface.black_vol_impl(last + h, strike);
+                assert_abs_diff_eq!(inside, outside, epsilon = 1e-6);
+
+                let v_last = surface.black_variance_impl(last, strike);
+                let v_far = surface.black_variance_impl(3.0, strike);
+                match mode {
+                    Extrapolation::ConstantExtrapolation => assert_eq!(v_far, v_last),
+                    Extrapolation::FlatVolatility => {
+                        let vol = surface.black_vol_impl(last, strike);
+                        assert_abs_diff_eq!(
+                            surface.black_vol_impl(3.0, strike),
+                            vol,
+                            epsilon = 1e-12
+                        );
+                    }
+                    _ => {
+                        let t_prev = surface.times[1];
+                        let v_prev = surface.black_variance_impl(t_prev, strike);
+                        let slope = (v_last - v_prev) / (last - t_prev);
+                        assert_abs_diff_eq!(v_far, v_last + slope * 2.0, epsilon = 1e-12);
+                    }
+                }
+                // No calendar arbitrage anywhere along the axis.
+                let mut previous = 0.0;
+                for i in 1..=40 {
+                    let v = surface.black_variance_impl(0.1 * i as Real, strike);
+                    assert!(v >= previous, "{mode:?}, K={strike}, t={}", 0.1 * i as Real);
+                    previous = v;
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn time_extrapolation_before_the_first_expiry() {
+        let first = sample_surface().times[0];
+        let clamped = sample_surface();
+        let flat = sample_surface_with(
+            Extrapolation::ConstantExtrapolation,
+            Extrapolation::FlatVolatility,
+        );
+        let vol = flat.black_vol_impl(first, 100.0);
+        assert_abs_diff_eq!(
+            flat.black_vol_impl(0.5 * first, 100.0),
+            vol,
+            epsilon = 1e-12
+        );
+        assert_abs_diff_eq!(
+            flat.black_vol_impl(first - 1e-6, 100.0),
+            flat.black_vol_impl(first + 1e-6, 100.0),
+            epsilon = 1e-6
+        );
+        // Clamping holds the variance, so the vol blows up instead.
+        assert!(clamped.black_vol_impl(0.5 * first, 100.0) > 1.4 * vol);
+    }
+
+    #[test]
+    fn linear_extrapolation_never_decreases_variance() {
+        let ref_date = Date::from_ymd(2025, 1, 2).unwrap();
+        let dates = [
+            Date::from_ymd(2025, 7, 2).unwrap(),
+            Date::from_ymd(2026, 1, 2).unwrap(),
+        ];
+        // The nodes are calendar-arbitrage free, but the right wing turns
+        // from rising to falling, so the extended segments cross.
+        let vols = vec![vec![0.20, 0.1_f64.sqrt()], vec![0.45, 0.30]];
+        let surface = BlackVarianceSurface::new(
+            ref_date,
+            &dates,
+            &[100.0, 120.0],
+            &vols,
+            Actual365Fixed,
+            Extrapolation::LinearInVariance,
+        )
+        .unwrap()
+        .with_time_extrapolation(Extrapolation::LinearInVariance);
+
+        for strike in [40.0, 80.0, 100.0, 110.0, 120.0, 140.0, 200.0] {
+            let mut previous = 0.0;
+            for i in 1..=30 {
+                let t = 0.1 * i as Real;
+                let v = surface.black_variance_impl(t, strike);
+                assert!(v >= previous, "K={strike}, t={t}: {v} < {previous}");
+                assert!(v >= 0.0);
+                previous = v;
+            }
+        }
+        // Far out on the right wing the last row's variance would go
+        // negative without the floor.
+        assert_eq!(surface.row_variance(1, 400.0), 0.0);
+    }
+
     #[test]
     fn surface_variance_at_zero() {
         let surface = sample_surface();
         let var = surface.black_variance_impl(0.0, 100.0);
         assert_abs_diff_eq!(var, 0.0, epsilon = 1e-15);
     }
+
+    #[test]
+    fn calendar_repair_leaves_arbitrage_free_surface_untouched() {
+        let mut surface = sample_surface();
+        let before = surface.variances.clone();
+        assert!(repair_calendar_arbitrage(&mut surface).is_empty());
+        assert_eq!(surface.variances, before);
+    }
+
+    #[test]
+    fn calendar_repair_restores_monotone_variance() {
+        let ref_date = Date::from_ymd(2025, 1, 2).unwrap();
+        let dates = [
+            Date::from_ymd(2025, 4, 2).unwrap(),
+            Date::from_ymd(2025, 7, 2).unwrap(),
+            Date::from_ymd(2026, 1, 2).unwrap(),
+            Date::from_ymd(2027, 1, 4).unwrap(),
+        ];
+        let strikes = [80.0, 100.0, 120.0];
+        // ATM total variance dips at the 6M expiry.
+        let vols = vec![
+            vec![0.25, 0.20, 0.22],
+            vec![0.24, 0.12, 0.21],
+            vec![0.23, 0.18, 0.20],
+            vec![0.22, 0.17, 0.19],
+        ];
+        let mut surface = BlackVarianceSurface::new(
+            ref_date,
+            &dates,
+            &strikes,
+            &vols,
+            Actual365Fixed,
+            Extrapolation::ConstantExtrapolation,
+        )
+        .unwrap();
+        let before = surface.variances.clone();
+        assert!(before[1][1] < before[0][1]);
+
+        let fixes = repair_calendar_arbitrage(&mut surface);
+
+        // Only the 3M and 6M ATM nodes violate; both move to their mean.
+        assert_eq!(fixes.len(), 2);
+        let pooled = 0.5 * (before[0][1] + before[1][1]);
+        for (fix, i) in fixes.iter().zip([0, 1]) {
+            assert_eq!(fix.strike, 100.0);
+            assert_eq!(fix.time, surface.times[i]);
+            assert_eq!(fix.original_variance, before[i][1]);
+            assert_abs_diff_eq!(fix.repaired_variance, pooled, epsilon = 1e-15);
+        }
+        for (i, row) in surface.variances.iter().enumerate() {
+            for (j, &v) in row.iter().enumerate() {
+                if j != 1 || i > 1 {
+                    assert_eq!(v, before[i][j]);
+                }
+            }
+        }
+        for j in 0..strikes.len() {
+            for i in 1..dates.len() {
+                assert!(surface.variances[i][j] >= surface.variances[i - 1][j]);
+            }
+        }
+        let t = surface.times[1];
+        assert_abs_diff_eq!(
+            surface.black_variance_impl(t, 100.0),
+            pooled,
+            epsilon = 1e-15
+        );
+    }
+
+    #[test]
+    fn isotonic_fit_pools_violators() {
+        assert_eq!(
+            isotonic_fit(&[1.0, 3.0, 2.0, 4.0]),
+            vec![1.0, 2.5, 2.5, 4.0]
+        );
+        assert_eq!(isotonic_fit(&[3.0, 2.0, 1.0]), vec![2.0, 2.0, 2.0]);
+        assert_eq!(isotonic_fit(&[1.0, 2.0, 2.0]), vec![1.0, 2.0, 2.0]);
+    }
 }
@@ -0,0 +1,243 @@
+//! `ImpliedDividendCurve` — expected point dividends bootstrapped from
+//! dividend-future prices.
+//!
+//! QuantLib has no dividend-future curve; dividends here are point amounts
+//! in the manner of `QuantLib::FixedDividend`, one per quoted period.
+//!
+//! A dividend future on the period `(start, end]` settles on the dividends
+//! going ex within that period, so with deterministic rates its price is the
+//! expected sum of those dividends.  Each period's dividend is placed at the
+//! period end.  Futures are bootstrapped in order of period end: the
+//! dividend at the end of a period is its future price less the dividends
+//! already implied inside it, so that overlapping contracts (quarterly and
+//! annual, say) are handled consistently.
+
+use crate::yield_term_structure::YieldTermStructure;
+use ql_core::{ensure, errors::Result, Real};
+use ql_time::Date;
+
+/// A dividend-future quote for bootstrapping an [`ImpliedDividendCurve`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct DividendFutureHelper {
+    /// Start of the dividend period (excluded).
+    pub period_start: Date,
+    /// End of the dividend period (included).
+    pub period_end: Date,
+    /// Quoted price: the expected dividends over the period.
+    pub price: Real,
+}
+
+impl DividendFutureHelper {
+    /// A quote of `price` for the dividends in `(period_start, period_end]`.
+    pub fn new(period_start: Date, period_end: Date, price: Real) -> Self {
+        Self {
+            period_start,
+            period_end,
+            price,
+        }
+    }
+}
+
+/// Expected point dividends of an equity, one per bootstrapped period.
+#[derive(Debug, Clone)]
+pub struct ImpliedDividendCurve {
+    reference_date: Date,
+    dates: Vec<Date>,
+    dividends: Vec<Real>,
+}
+
+impl ImpliedDividendCurve {
+    /// A curve of known `dividends` paid on `dates` (strictly increasing,
+    /// after `reference_date`).
+    pub fn new(reference_date: Date, dates: Vec<Date>, dividends: Vec<Real>) -> Result<Self> {
+        ensure!(
+            dates.len() == dividends.len(),
+            "{} dividend dates but {} dividends",
+            dates.len(),
+            dividends.len()
+        );
+        ensure!(
+            dates.iter().all(|&d| d > reference_date),
+            "dividend dates must be after the reference date {}",
+            reference_date
+        );
+        ensure!(
+            dates.windows(2).all(|w| w[0] < w[1]),
+            "dividend dates must be strictly increasing"
+        );
+        Ok(Self {
+            reference_date,
+            dates,
+            dividends,
+        })
+    }
+
+    /// Bootstrap the dividends implied by `helpers`.
+    ///
+    /// # Errors
+    /// Fails if a period is empty, starts before the reference date, shares
+    /// its end with another period, or implies a negative dividend.
+    pub fn bootstrap(reference_date: Date, helpers: &[DividendFutureHelper]) -> Result<Self> {
+        ensure!(
+            !helpers.is_empty(),
+            "at least one dividend future is required"
+        );
+        let mut sorted: Vec<&DividendFutureHelper> = helpers.iter().collect();
+        sorted.sort_by_key(|h| h.period_end);
+
+        let mut curve = Self {
+            reference_date,
+            dates: Vec::with_capacity(sorted.len()),
+            dividends: Vec::with_capacity(sorted.len()),
+        };
+        for helper in sorted {
+            let (start, end) = (helper.period_start, helper.period_end);
+            ensure!(start < end, "empty dividend period ({start}, {end}]");
+            ensure!(
+                start >= reference_date,
+                "dividend period ({start}, {end}] starts before the reference date {reference_date}"
+            );
+            ensure!(
+                curve.dates.last() != Some(&end),
+                "two dividend futures end on {end}"
+            );
+            let dividend = helper.price - curve.expected_dividend(start, end);
+            ensure!(
+                dividend >= 0.0,
+                "dividend future on ({start}, {end}] implies a negative dividend {dividend}"
+            );
+            curve.dates.push(end);
+            curve.dividends.push(dividend);
+        }
+        Ok(curve)
+    }
+
+    /// The reference date.
+    pub fn reference_date(&self) -> Date {
+        self.reference_date
+    }
+
+    /// Dividend dates, in increasing order.
+    pub fn dates(&self) -> &[Date] {
+        &self.dates
+    }
+
+    /// Dividend amounts, one per date.
+    pub fn dividends(&self) -> &[Real] {
+        &self.dividends
+    }
+
+    /// Expected dividends paid in `(start, end]`.
+    pub fn expected_dividend(&self, start: Date, end: Date) -> Real {
+        self.dates
+            .iter()
+            .zip(&self.dividends)
+            .filter(|(&d, _)| d > start && d <= end)
+            .map(|(_, &dividend)| dividend)
+            .sum()
+    }
+
+    /// Present value under `discount` of the dividends paid up to `date`.
+    pub fn present_value(&self, discount: &dyn YieldTermStructure, date: Date) -> Real {
+        self.dates
+            .iter()
+            .zip(&self.dividends)
+            .filter(|(&d, _)| d <= date)
+            .map(|(&d, &dividend)| dividend * discount.discount_date(d))
+            .sum()
+    }
+
+    /// Forward price to `date` of an equity at `spot` paying these dividends:
+    /// `(spot − PV(dividends to date)) / P(date)`.
+    pub fn forward(&self, spot: Real, discount: &dyn YieldTermStructure, date: Date) -> Real {
+        (spot - self.present_value(discount, date)) / discount.discount_date(date)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::FlatForward;
+    use ql_time::Actual365Fixed;
+
+    fn date(y: u16, m: u8, d: u8) -> Date {
+        Date::from_ymd(y, m, d).unwrap()
+    }
+
+    fn quarter_ends() -> Vec<Date> {
+        vec![
+            date(2025, 3, 31),
+            date(2025, 6, 30),
+            date(2025, 9, 30),
+            date(2025, 12, 31),
+        ]
+    }
+
+    #[test]
+    fn bootstrap_reproduces_futures() {
+        let reference = date(2025, 1, 2);
+        let ends = quarter_ends();
+        let mut helpers: Vec<DividendFutureHelper> = [0.8, 1.5, 0.9, 1.2]
+            .iter()
+            .enumerate()
+            .map(|(i, &price)| {
+                let start = if i == 0 { reference } else { ends[i - 1] };
+                DividendFutureHelper::new(start, ends[i], price)
+            })
+            .collect();
+        // An annual contract overlapping the last three quarters.
+        helpers.push(DividendFutureHelper::new(ends[0], date(2026, 3, 31), 4.5));
+
+        let curve = ImpliedDividendCurve::bootstrap(reference, &helpers).unwrap();
+        assert_eq!(curve.dates().len(), 5);
+        for h in &helpers {
+            let implied = curve.expected_dividend(h.period_start, h.period_end);
+            assert!((implied - h.price).abs() < 1e-14, "{h:?}: {implied}");
+        }
+        // The annual future leaves 4.5 − (1.5 + 0.9 + 1.2) for 2026 Q1.
+        assert!((curve.dividends()[4] - 0.9).abs() < 1e-14);
+
+        helpers.push(DividendFutureHelper::new(ends[1], ends[3], 1.0));
+        assert!(ImpliedDividendCurve::bootstrap(reference, &helpers).is_err());
+    }
+
+    #[test]
+    fn discounted_dividends_match_forward_curve() {
+        let reference = date(2025, 1, 2);
+        let spot = 100.0;
+        let discount = FlatForward::continuous(reference, 0.04, Actual365Fixed);
+        let ends = quarter_ends();
+        let dividends = [0.8, 1.5, 0.9, 1.2];
+
+        let helpers: Vec<_> = (0..4)
+            .map(|i| {
+                let start = if i == 0 { reference } else { ends[i - 1] };
+                DividendFutureHelper::new(start, ends[i], dividends[i])
+            })
+            .collect();
+        let curve = ImpliedDividendCurve::bootstrap(reference, &helpers).unwrap();
+
+        // By hand: the dividends fall 88, 179, 271 and 363 days out, so
+        // PV(T) = Σ Dᵢ·e^{−0.04·dᵢ/365} over dᵢ ≤ T and
+        // F(T) = (100 − PV(T))·e^{0.04·T/365}, for T = 133, 363, 544 days.
+        let expected = [
+            (date(2025, 5, 15), 0.792322013633, 100.664253122753),
+            (date(2025, 12, 31), 4.290048373739, 99.594117609972),
+            (date(2026, 6, 30), 4.290048373739, 101.589351252445),
+        ];
+        for (maturity, pv, forward) in expected {
+            let (curve_pv, curve_forward) = (
+                curve.present_value(&discount, maturity),
+                curve.forward(spot, &discount, maturity),
+            );
+            assert!(
+                (curve_pv - pv).abs() < 1e-11,
+                "{maturity}: PV {curve_pv:.12}"
+            );
+            assert!(
+                (curve_forward - forward).abs() < 1e-11,
+                "{maturity}: forward {curve_forward:.12}"
+            );
+        }
+    }
+}
@@ -5,6 +5,11 @@
 //! The curve stores (date, forward-rate) pairs and interpolates them as a
 //! function of time.  Discount factors are computed by integrating the forward
 //! rate: `P(t) = exp(−∫₀ᵗ f(s) ds)`.
+//!
+//! With a piecewise-constant interpolation such as
+//! [`BackwardFlat`](crate::BackwardFlat) — the usual choice for overnight
+//! index curves — the forward is flat between pillars and the integral is
+//! exact.
 
 use crate::interpolated_zero_curve::InterpolationBuilder;
 use crate::term_structure::TermStructure;
@@ -17,7 +22,8 @@ use std::sync::Arc;
 /// A yield curve defined by instantaneous forward rates at known dates.
 ///
 /// Discount factors are obtained by numerical integration of the interpolated
-/// forward-rate curve using the trapezoidal rule.
+/// forward-rate curve using the trapezoidal rule, or exactly when the
+/// interpolation is piecewise constant.
 ///
 /// Corresponds to `QuantLib::InterpolatedForwardCurve<Interpolator>`.
 #[derive(Debug)]
@@ -27,6 +33,8 @@ pub struct InterpolatedForwardCurve {
     times: Vec<Real>,
     forwards: Vec<Rate>,
     interp: Box<dyn Interpolation1D>,
+    /// Whether `interp` is constant between pillars.
+    piecewise_flat: bool,
     max_date: Date,
 }
 
@@ -78,6 +86,7 @@ impl InterpolatedForwardCurve {
             times,
             forwards: forwards.to_vec(),
             interp,
+            piecewise_flat: builder.is_piecewise_constant(),
             max_date,
         })
     }
@@ -104,12 +113,28 @@ impl InterpolatedForwardCurve {
     }
 
     /// Compute ∫₀ᵗ f(s) ds using the trapezoidal rule on the stored pillar
-    /// data plus the interpolated endpoint.
+    /// data plus the interpolated endpoint (exactly, for a piecewise-flat
+    /// forward).
     fn integrate_forward(&self, t: Time) -> Real {
         if t <= 0.0 {
             return 0.0;
         }
 
+        if self.piecewise_flat {
+            // Constant between pillars: evaluate each bucket at its midpoint.
+            let mut integral = 0.0;
+            let mut prev_t = 0.0;
+            for &ti in self.times.iter().filter(|&&ti| ti > 0.0) {
+                let end = ti.min(t);
+                integral += self.interp.operator(0.5 * (prev_t + end)) * (end - prev_t);
+                prev_t = end;
+                if ti >= t {
+                    return integral;
+                }
+            }
+            return integral + self.interp.operator(0.5 * (prev_t + t)) * (t - prev_t);
+        }
+
         let mut integral = 0.0;
         let mut prev_t = 0.0;
         let mut prev_f = self.interp.operator(0.0);
@@ -251,4 +276,54 @@ mod tests {
         assert!(d1 > d2);
         assert!(d2 > d5);
     }
+
+    #[test]
+    fn backward_flat_forward_curve_is_exact() {
+        use crate::interpolated_zero_curve::BackwardFlat;
+        use ql_time::Actual360;
+
+        // A 3M deposit at 4% fixes the first bucket; later buckets are
+        // overnight forwards of 4.5% and 5%.
+        let dates = vec![
+            Date::from_ymd(2025, 1, 2).unwrap(),
+            Date::from_ymd(2025, 4, 2).unwrap(),
+            Date::from_ymd(2026, 1, 2).unwrap(),
+            Date::from_ymd(2027, 1, 2).unwrap(),
+        ];
+        let tau = Actual360.year_fraction(dates[0], dates[1]);
+        let deposit_rate = 0.04;
+        let f1 = (1.0 + deposit_rate * tau).ln() / tau;
+        let forwards = vec![f1, f1, 0.045, 0.05];
+        let curve =
+            InterpolatedForwardCurve::new(&dates, &forwards, Actual360, &BackwardFlat).unwrap();
+        let t = curve.times().to_vec();
+
+        // Constant forward inside each bucket, switching at the pillars.
+        for i in 1..t.len() {
+            for w in [0.01, 0.5, 1.0] {
+                let s = t[i - 1] + w * (t[i] - t[i - 1]);
+                assert_eq!(curve.forward_rate_impl(s), forwards[i], "bucket {i}");
+            }
+        }
+
+        // Discount factors compose bucket by bucket.
+        for i in 1..t.len() {
+            let step = (-forwards[i] * (t[i] - t[i - 1])).exp();
+            assert_abs_diff_eq!(
+                curve.discount(t[i]),
+                curve.discount(t[i - 1]) * step,
+                epsilon = 1e-15
+            );
+        }
+        let mid = 0.5 * (t[1] + t[2]);
+        assert_abs_diff_eq!(
+            curve.discount(mid),
+            curve.discount(t[1]) * (-0.045 * (mid - t[1])).exp(),
+            epsilon = 1e-15
+        );
+
+        // The deposit reprices at its quoted rate.
+        let repriced = (1.0 / curve.discount(tau) - 1.0) / tau;
+        assert_abs_diff_eq!(repriced, deposit_rate, epsilon = 1e-14);
+    }
 }
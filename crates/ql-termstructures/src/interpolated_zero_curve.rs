@@ -3,7 +3,9 @@
 //! `ql/termstructures/yield/interpolatedzerocurve.hpp`).
 //!
 //! The curve stores (date, zero-rate) pairs and interpolates zero rates as a
-//! function of time.  Discount factors are computed as `P(t) = exp(-z(t) * t)`.
+//! function of time.  Discount factors are computed as `P(t) = exp(-z(t) * t)`,
+//! and forward rates — instantaneous, or between two dates through
+//! [`YieldTermStructure::forward_rate`] — from those discount factors.
 
 use crate::term_structure::TermStructure;
 use crate::yield_term_structure::{YieldTermStructure, YieldTermStructureData};
@@ -12,6 +14,9 @@ use ql_math::Interpolation1D;
 use ql_time::{Calendar, Date, DayCounter, NullCalendar};
 use std::sync::Arc;
 
+/// Step of the finite difference behind instantaneous forwards.
+const FORWARD_EPSILON: Time = 1.0e-5;
+
 /// A yield curve defined by zero rates at known dates.
 ///
 /// Interpolation of the zero rate is delegated to a pluggable `Interpolation1D`
@@ -40,6 +45,12 @@ pub struct InterpolatedZeroCurve {
 pub trait InterpolationBuilder: std::fmt::Debug {
     /// Build an interpolation from the given x and y values.
     fn build(&self, xs: &[Real], ys: &[Real]) -> Result<Box<dyn Interpolation1D>>;
+
+    /// Whether the interpolant is constant between nodes, so that curves
+    /// can integrate it exactly.
+    fn is_piecewise_constant(&self) -> bool {
+        false
+    }
 }
 
 /// Linear interpolation builder.
@@ -62,6 +73,21 @@ impl InterpolationBuilder for LogLinear {
     }
 }
 
+/// Backward-flat interpolation builder: constant on `(xᵢ₋₁, xᵢ]` at the
+/// value of `xᵢ`.
+#[derive(Debug, Clone, Copy)]
+pub struct BackwardFlat;
+
+impl InterpolationBuilder for BackwardFlat {
+    fn build(&self, xs: &[Real], ys: &[Real]) -> Result<Box<dyn Interpolation1D>> {
+        Ok(Box::new(ql_math::BackwardFlatInterpolation::new(xs, ys)?))
+    }
+
+    fn is_piecewise_constant(&self) -> bool {
+        true
+    }
+}
+
 /// Cubic natural spline interpolation builder.
 #[derive(Debug, Clone, Copy)]
 pub struct CubicNatural;
@@ -144,6 +170,20 @@ impl InterpolatedZeroCurve {
     pub fn rates(&self) -> &[Rate] {
         &self.rates
     }
+
+    /// Instantaneous forward rate `f(t) = −∂ ln P(t)/∂t` at time `t`.
+    ///
+    /// Uses a central difference of the log discount factors, switching to
+    /// a one-sided difference on `[t, t + ε]` when `t` is too close to the
+    /// reference date for the left point to exist.
+    pub fn instantaneous_forward(&self, t: Time) -> Rate {
+        let (t1, t2) = if t < 0.5 * FORWARD_EPSILON {
+            (t, t + FORWARD_EPSILON)
+        } else {
+            (t - 0.5 * FORWARD_EPSILON, t + 0.5 * FORWARD_EPSILON)
+        };
+        (self.discount_impl(t1).ln() - self.discount_impl(t2).ln()) / (t2 - t1)
+    }
 }
 
 impl TermStructure for InterpolatedZeroCurve {
@@ -176,13 +216,18 @@ impl YieldTermStructure for InterpolatedZeroCurve {
         let z = self.zero_rate_impl(t);
         (-z * t).exp()
     }
+
+    fn forward_rate_impl(&self, t: Time) -> Rate {
+        self.instantaneous_forward(t)
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
     use approx::assert_abs_diff_eq;
-    use ql_time::Actual365Fixed;
+    use ql_core::Compounding;
+    use ql_time::{Actual365Fixed, Frequency};
 
     fn sample_dates_rates() -> (Vec<Date>, Vec<Rate>) {
         let dates = vec![
@@ -234,6 +279,66 @@ mod tests {
         assert!(z_mid > 0.025 && z_mid < 0.03);
     }
 
+    #[test]
+    fn zero_curve_flat_forwards() {
+        let ref_date = Date::from_ymd(2025, 1, 2).unwrap();
+        let dates = [ref_date, ref_date + 365, ref_date + 5 * 365];
+        let curve =
+            InterpolatedZeroCurve::new(&dates, &[0.03; 3], Actual365Fixed, &Linear).unwrap();
+
+        for t in [0.0, 1e-7, 0.5, 1.0, 3.0, 5.0] {
+            assert_abs_diff_eq!(curve.instantaneous_forward(t), 0.03, epsilon = 1e-9);
+        }
+
+        let (d1, d2) = (ref_date + 200, ref_date + 900);
+        let continuous = curve.forward_rate(
+            d1,
+            d2,
+            &Actual365Fixed,
+            Compounding::Continuous,
+            Frequency::Annual,
+        );
+        assert_abs_diff_eq!(continuous.rate(), 0.03, epsilon = 1e-12);
+
+        let simple = curve.forward_rate(
+            d1,
+            d2,
+            &Actual365Fixed,
+            Compounding::Simple,
+            Frequency::Annual,
+        );
+        let tau: Real = 700.0 / 365.0;
+        assert_abs_diff_eq!(
+            simple.rate(),
+            ((0.03 * tau).exp() - 1.0) / tau,
+            epsilon = 1e-12
+        );
+    }
+
+    #[test]
+    fn zero_curve_linear_zero_forward_slope() {
+        // z(t) = 2% + 0.4%·t gives f(t) = z(t) + t·z'(t) = 2% + 0.8%·t.
+        let ref_date = Date::from_ymd(2025, 1, 2).unwrap();
+        let years = [0.0, 1.0, 2.0, 5.0, 10.0];
+        let dates: Vec<Date> = years
+            .iter()
+            .map(|&y| ref_date + (365.0 * y) as i32)
+            .collect();
+        let rates: Vec<Rate> = years.iter().map(|&y| 0.02 + 0.004 * y).collect();
+        let curve = InterpolatedZeroCurve::new(&dates, &rates, Actual365Fixed, &Linear).unwrap();
+
+        assert_abs_diff_eq!(curve.instantaneous_forward(0.0), 0.02, epsilon = 1e-7);
+        for t in [0.5, 1.5, 3.0, 7.0] {
+            assert_abs_diff_eq!(
+                curve.instantaneous_forward(t),
+                0.02 + 0.008 * t,
+                epsilon = 1e-9
+            );
+        }
+        let slope = (curve.forward_rate_impl(7.0) - curve.forward_rate_impl(3.0)) / 4.0;
+        assert_abs_diff_eq!(slope, 0.008, epsilon = 1e-8);
+    }
+
     #[test]
     fn zero_curve_discount_consistency() {
         let (dates, rates) = sample_dates_rates();
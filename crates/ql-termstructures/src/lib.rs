@@ -26,6 +26,9 @@ pub mod rate_helpers;
 /// `PiecewiseYieldCurve` — iterative bootstrap construction of a yield curve.
 pub mod piecewise_yield_curve;
 
+/// `FittedBondDiscountCurve` — parametric discount curve fitted to bond prices.
+pub mod fitted_bond_discount_curve;
+
 /// `InterpolatedDiscountCurve` — discount-factor interpolated yield curve.
 pub mod interpolated_discount_curve;
 
@@ -47,6 +50,10 @@ pub mod local_vol_term_structure;
 /// `LocalVolSurface` — Dupire local volatility surface from a Black vol surface.
 pub mod local_vol_surface;
 
+/// `RegularizedLocalVolSurface` — local vols fitted to option prices under a
+/// Tikhonov smoothness penalty.
+pub mod regularized_local_vol_surface;
+
 /// `SmileSection` — abstract smile interface and concrete smile sections
 /// (Flat, SABR, SVI).
 pub mod smile_section;
@@ -55,20 +62,39 @@ pub mod smile_section;
 /// `SmileSurface` for the full volatility surface.
 pub mod smile_calibration;
 
+/// `SwaptionVolatilityCube` — ATM swaption vols by expiry and tenor with
+/// per-node SABR smiles.
+pub mod swaption_volatility_cube;
+
 /// `DefaultProbabilityTermStructure` — credit default-probability curves.
 pub mod default_probability_term_structure;
 
+/// `ImpliedDividendCurve` — point dividends bootstrapped from dividend futures.
+pub mod implied_dividend_curve;
+
 /// Inflation term structures: zero-inflation and year-on-year inflation curves.
 pub mod inflation_term_structure;
 
+/// Conversions between zero curves and par-yield curves.
+pub mod par_yield;
+
+/// Forward-rate correlation implied by swaption and caplet volatilities.
+pub mod rate_correlation;
+
 // ── Convenience re-exports ────────────────────────────────────────────────────
 
-pub use black_variance_surface::{BlackVarianceSurface, Extrapolation};
+pub use black_variance_surface::{
+    repair_calendar_arbitrage, BlackVarianceSurface, CalendarArbitrageFix, Extrapolation,
+};
 pub use black_vol_term_structure::{BlackConstantVol, BlackVolTermStructure};
 pub use default_probability_term_structure::{
     DefaultProbabilityTermStructure, FlatHazardRate, InterpolatedHazardRateCurve,
 };
+pub use fitted_bond_discount_curve::{
+    FitReport, FittedBondDiscountCurve, FittedBondHelper, FittingMethod, NelsonSiegelFitting,
+};
 pub use flat_forward::FlatForward;
+pub use implied_dividend_curve::{DividendFutureHelper, ImpliedDividendCurve};
 pub use inflation_term_structure::{
     FlatYoYInflationCurve, FlatZeroInflationCurve, InflationTermStructure,
     YoYInflationTermStructure, ZeroInflationTermStructure,
@@ -76,22 +102,26 @@ pub use inflation_term_structure::{
 pub use interpolated_discount_curve::InterpolatedDiscountCurve;
 pub use interpolated_forward_curve::InterpolatedForwardCurve;
 pub use interpolated_zero_curve::{
-    CubicNatural, InterpolatedZeroCurve, InterpolationBuilder, Linear, LogLinear,
+    BackwardFlat, CubicNatural, InterpolatedZeroCurve, InterpolationBuilder, Linear, LogLinear,
 };
 pub use local_vol_surface::LocalVolSurface;
 pub use local_vol_term_structure::{LocalConstantVol, LocalVolTermStructure};
-pub use piecewise_yield_curve::PiecewiseYieldCurve;
+pub use par_yield::{par_yield_curve, zero_from_par};
+pub use piecewise_yield_curve::{BootstrapTrace, PiecewiseYieldCurve, PillarDiagnostics};
+pub use rate_correlation::rate_correlation_from_vols;
 pub use rate_helpers::{
     BootstrapCurve, DepositRateHelper, FraRateHelper, FuturesRateHelper, RateHelper, SwapRateHelper,
 };
+pub use regularized_local_vol_surface::RegularizedLocalVolSurface;
 pub use smile_calibration::{
     calibrate_sabr_surface, calibrate_svi_surface, ExpirySmileData, SmileCalibrationResult,
     SmileSurface,
 };
 pub use smile_section::{
-    calibrate_svi, FlatSmileSection, SabrSmileSection, SmileOptionType, SmileSection,
-    SviParameters, SviSmileSection, VolatilityType,
+    calibrate_svi, FlatSmileSection, SabrSmileSection, SmileExtrapolation, SmileOptionType,
+    SmileSection, SviParameters, SviSmileSection, VolatilityType,
 };
+pub use swaption_volatility_cube::SwaptionVolatilityCube;
 pub use term_structure::TermStructure;
-pub use volatility_term_structure::VolatilityTermStructure;
+pub use volatility_term_structure::{VolatilityTermStructure, VolatilityTermStructureData};
 pub use yield_term_structure::{YieldTermStructure, YieldTermStructureData};
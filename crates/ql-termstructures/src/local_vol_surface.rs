@@ -10,7 +10,7 @@ use crate::local_vol_term_structure::LocalVolTermStructure;
 use crate::term_structure::TermStructure;
 use crate::volatility_term_structure::VolatilityTermStructure;
 use crate::yield_term_structure::{YieldTermStructure, YieldTermStructureData};
-use ql_core::{Real, Time, Volatility};
+use ql_core::{ensure, errors::Result, Real, Time, Volatility};
 use ql_time::{Calendar, Date, DayCounter, NullCalendar};
 use std::sync::Arc;
 
@@ -26,7 +26,12 @@ use std::sync::Arc;
 ///       + \frac12 \frac{\partial^2 w}{\partial y^2}}$$
 ///
 /// where `w = σ²·T` is the total implied variance and `y = ln(K/F)` is
-/// the log-moneyness.
+/// the log-moneyness.  The derivatives of `w` are taken by finite
+/// differences at the queried `(T, K)`, or, with
+/// [`LocalVolSurface::with_grid`], precomputed on a grid and interpolated,
+/// so that repeated queries only re-evaluate the formula.  Where arbitrage
+/// in the Black surface makes the Dupire variance negative, it is floored
+/// (see [`LocalVolSurface::with_floor`]).
 ///
 /// Corresponds to `QuantLib::LocalVolSurface`.
 #[derive(Debug)]
@@ -40,6 +45,10 @@ pub struct LocalVolSurface {
     dividend_yield: Arc<dyn YieldTermStructure>,
     /// The current underlying spot price.
     underlying: Real,
+    /// Floor on the local variance.
+    min_variance: Real,
+    /// Dupire derivatives precomputed on a grid, if any.
+    grid: Option<DupireGrid>,
 }
 
 impl LocalVolSurface {
@@ -69,15 +78,79 @@ impl LocalVolSurface {
             risk_free_rate,
             dividend_yield,
             underlying,
+            min_variance: 1e-8,
+            grid: None,
         }
     }
 
+    /// Floor the local variance at `min_variance` (default `1e-8`) where
+    /// arbitrage in the Black surface would make Dupire's formula negative.
+    ///
+    /// # Errors
+    /// Fails if `min_variance` is negative or NaN.
+    pub fn with_floor(mut self, min_variance: Real) -> Result<Self> {
+        ensure!(
+            min_variance >= 0.0,
+            "local variance floor must be non-negative, got {min_variance}"
+        );
+        self.min_variance = min_variance;
+        Ok(self)
+    }
+
+    /// Precompute the Dupire derivatives on the nodes of `times × strikes`.
+    ///
+    /// Queries inside the grid interpolate the derivatives bilinearly in
+    /// time and log-strike, which is exact where `w` is linear in time and
+    /// in log-strike, and evaluate Dupire's formula on the result; queries
+    /// outside it fall back to finite differences at the queried point.
+    ///
+    /// # Errors
+    /// Fails unless both axes hold at least two strictly increasing values,
+    /// with non-negative times and positive strikes.
+    pub fn with_grid(mut self, times: &[Time], strikes: &[Real]) -> Result<Self> {
+        ensure!(
+            times.len() >= 2 && strikes.len() >= 2,
+            "a local-vol grid needs at least two times and two strikes"
+        );
+        ensure!(
+            times.windows(2).all(|w| w[0] < w[1]) && strikes.windows(2).all(|w| w[0] < w[1]),
+            "grid times and strikes must be strictly increasing"
+        );
+        ensure!(
+            times[0] >= 0.0 && strikes[0] > 0.0,
+            "grid times must be non-negative and strikes positive"
+        );
+        let terms = times
+            .iter()
+            .flat_map(|&t| strikes.iter().map(move |&k| (t, k)))
+            .map(|(t, k)| self.dupire_terms(t, k))
+            .collect();
+        self.grid = Some(DupireGrid {
+            times: times.to_vec(),
+            log_strikes: strikes.iter().map(|k| k.ln()).collect(),
+            terms,
+        });
+        Ok(self)
+    }
+
     /// Create with a specific calendar.
     pub fn with_calendar(mut self, calendar: impl Calendar + 'static) -> Self {
         self.data.calendar = Box::new(calendar);
         self
     }
 
+    /// Finite-difference Dupire derivatives at `(t, strike)`.
+    fn dupire_terms(&self, t: Time, strike: Real) -> DupireTerms {
+        DupireTerms::new(
+            t,
+            strike,
+            &*self.black_vol,
+            &*self.risk_free_rate,
+            &*self.dividend_yield,
+            self.underlying,
+        )
+    }
+
     /// Forward price for time `t`.
     #[allow(dead_code)]
     fn forward(&self, t: Time) -> Real {
@@ -117,107 +190,171 @@ impl VolatilityTermStructure for LocalVolSurface {
 
 impl LocalVolTermStructure for LocalVolSurface {
     fn local_vol_impl(&self, t: Time, strike: Real) -> Volatility {
-        dupire_local_vol(
-            t,
-            strike,
-            &*self.black_vol,
-            &*self.risk_free_rate,
-            &*self.dividend_yield,
-            self.underlying,
-        )
+        let terms = self
+            .grid
+            .as_ref()
+            .and_then(|grid| grid.terms(t, strike))
+            .unwrap_or_else(|| self.dupire_terms(t, strike));
+        terms.local_variance(self.min_variance).sqrt()
     }
 }
 
-/// Dupire's formula for local volatility.
-///
-/// Computes the local volatility at time `t` and strike `strike` using
-/// finite differences on the Black total variance surface.
-fn dupire_local_vol(
-    t: Time,
-    strike: Real,
-    black_vol: &dyn BlackVolTermStructure,
-    risk_free_rate: &dyn YieldTermStructure,
-    dividend_yield: &dyn YieldTermStructure,
-    spot: Real,
-) -> Volatility {
-    let eps_t = 1e-4_f64;
-    let eps_k = strike.max(0.001) * 0.001;
-
-    // Forward price at time t
-    let df_r = risk_free_rate.discount(t.max(eps_t));
-    let df_q = dividend_yield.discount(t.max(eps_t));
-    let forward = spot * df_q / df_r;
-
-    // Strike clamped
-    let k = strike.max(1e-8);
-
-    // Total implied variance w(t, k) = σ²(t, k) × t
-    let w_fn = |tt: Time, kk: Real| -> Real {
-        if tt <= 0.0 {
-            return 0.0;
+/// Dupire derivatives on the nodes of a time × log-strike grid.
+#[derive(Debug)]
+struct DupireGrid {
+    times: Vec<Time>,
+    log_strikes: Vec<Real>,
+    /// Row-major: `terms[i * log_strikes.len() + j]` is at
+    /// `(times[i], log_strikes[j])`.
+    terms: Vec<DupireTerms>,
+}
+
+impl DupireGrid {
+    /// Bilinearly interpolated derivatives, or `None` outside the grid.
+    fn terms(&self, t: Time, strike: Real) -> Option<DupireTerms> {
+        let x = strike.ln();
+        let (i, u) = bracket(&self.times, t)?;
+        let (j, v) = bracket(&self.log_strikes, x)?;
+        let n = self.log_strikes.len();
+        let node = |i: usize, j: usize| self.terms[i * n + j];
+        let below = node(i, j).lerp(&node(i, j + 1), v);
+        let above = node(i + 1, j).lerp(&node(i + 1, j + 1), v);
+        Some(below.lerp(&above, u))
+    }
+}
+
+/// Index `i` and weight `θ` with `x = (1 − θ)·xs[i] + θ·xs[i + 1]`, or
+/// `None` if `x` lies outside `[xs[0], xs[n − 1]]`.
+fn bracket(xs: &[Real], x: Real) -> Option<(usize, Real)> {
+    if !(xs[0]..=xs[xs.len() - 1]).contains(&x) {
+        return None;
+    }
+    let i = xs.partition_point(|&xi| xi <= x).clamp(1, xs.len() - 1) - 1;
+    Some((i, (x - xs[i]) / (xs[i + 1] - xs[i])))
+}
+
+/// Finite-difference derivatives of the total implied variance
+/// `w(t, y)` at one point, in log-moneyness `y = ln(K/F)`.
+#[derive(Debug, Clone, Copy)]
+struct DupireTerms {
+    w: Real,
+    y: Real,
+    dwdt: Real,
+    dwdy: Real,
+    d2wdy2: Real,
+}
+
+impl DupireTerms {
+    /// Finite differences on the Black total variance surface at time `t`
+    /// and strike `strike`.
+    fn new(
+        t: Time,
+        strike: Real,
+        black_vol: &dyn BlackVolTermStructure,
+        risk_free_rate: &dyn YieldTermStructure,
+        dividend_yield: &dyn YieldTermStructure,
+        spot: Real,
+    ) -> Self {
+        let eps_t = 1e-4_f64;
+        let eps_k = strike.max(0.001) * 0.001;
+
+        // Forward price at time t
+        let df_r = risk_free_rate.discount(t.max(eps_t));
+        let df_q = dividend_yield.discount(t.max(eps_t));
+        let forward = spot * df_q / df_r;
+
+        // Strike clamped
+        let k = strike.max(1e-8);
+
+        // Total implied variance w(t, k) = σ²(t, k) × t
+        let w_fn = |tt: Time, kk: Real| -> Real {
+            if tt <= 0.0 {
+                return 0.0;
+            }
+            let vol = black_vol.black_vol_impl(tt, kk);
+            vol * vol * tt
+        };
+
+        let w = w_fn(t, k);
+
+        // ── Time derivative: ∂w/∂t ──────────────────────────────────────
+        let dwdt = if t < eps_t {
+            // Forward difference at small t
+            let w_plus = w_fn(t + eps_t, k);
+            w_plus / eps_t
+        } else {
+            // Central difference
+            let w_plus = w_fn(t + eps_t, k);
+            let w_minus = w_fn((t - eps_t).max(0.0), k);
+            let dt = (t + eps_t) - (t - eps_t).max(0.0);
+            (w_plus - w_minus) / dt
+        };
+
+        // ── Strike derivatives via log-moneyness ────────────────────────
+        let ln_eps = (eps_k / k).abs().max(1e-6);
+
+        // ∂w/∂y via central differences on log-moneyness
+        let w_up = w_fn(t, k * ln_eps.exp());
+        let w_down = w_fn(t, k * (-ln_eps).exp());
+        let dwdy = (w_up - w_down) / (2.0 * ln_eps);
+
+        // ∂²w/∂y² via second central difference
+        let d2wdy2 = (w_up - 2.0 * w + w_down) / (ln_eps * ln_eps);
+
+        Self {
+            w,
+            y: (k / forward).ln(),
+            dwdt,
+            dwdy,
+            d2wdy2,
         }
-        let vol = black_vol.black_vol_impl(tt, kk);
-        vol * vol * tt
-    };
-
-    let w = w_fn(t, k);
-    if w <= 0.0 {
-        return 0.0;
-    }
-
-    // ── Time derivative: ∂w/∂t ──────────────────────────────────────────
-    let dwdt = if t < eps_t {
-        // Forward difference at small t
-        let w_plus = w_fn(t + eps_t, k);
-        w_plus / eps_t
-    } else {
-        // Central difference
-        let w_plus = w_fn(t + eps_t, k);
-        let w_minus = w_fn((t - eps_t).max(0.0), k);
-        let dt = (t + eps_t) - (t - eps_t).max(0.0);
-        (w_plus - w_minus) / dt
-    };
-
-    // Ensure non-decreasing total variance (no calendar spread arbitrage)
-    let dwdt = dwdt.max(1e-15);
-
-    // ── Log-moneyness y = ln(k / F) ────────────────────────────────────
-    let y = (k / forward).ln();
-
-    // ── Strike derivatives via log-moneyness ────────────────────────────
-    let ln_eps = (eps_k / k).abs().max(1e-6);
-
-    // ∂w/∂y via central differences on log-moneyness
-    let k_up = k * (ln_eps).exp();
-    let k_down = k * (-ln_eps).exp();
-    let w_up = w_fn(t, k_up);
-    let w_down = w_fn(t, k_down);
-    let dwdy = (w_up - w_down) / (2.0 * ln_eps);
-
-    // ∂²w/∂y² via second central difference
-    let d2wdy2 = (w_up - 2.0 * w + w_down) / (ln_eps * ln_eps);
-
-    // ── Dupire formula ──────────────────────────────────────────────────
-    // numerator = ∂w/∂t
-    // denominator = 1 - (y/w)*(∂w/∂y)
-    //   + (1/4)*(-1/4 - 1/w + y²/w²)*(∂w/∂y)²
-    //   + (1/2)*(∂²w/∂y²)
-    let den1 = 1.0 - y / w * dwdy;
-    let den2 = 0.25 * (-0.25 - 1.0 / w + y * y / (w * w)) * dwdy * dwdy;
-    let den3 = 0.5 * d2wdy2;
-
-    let denominator = den1 + den2 + den3;
-
-    if denominator <= 1e-15 {
-        // Degenerate case — return the Black vol as fallback
-        return black_vol.black_vol_impl(t, k);
-    }
-
-    let local_var = dwdt / denominator;
-    if local_var <= 0.0 {
-        return 0.0;
-    }
-    local_var.sqrt()
+    }
+
+    /// Componentwise `(1 − θ)·self + θ·other`.
+    fn lerp(&self, other: &Self, theta: Real) -> Self {
+        let mix = |a: Real, b: Real| a + theta * (b - a);
+        Self {
+            w: mix(self.w, other.w),
+            y: mix(self.y, other.y),
+            dwdt: mix(self.dwdt, other.dwdt),
+            dwdy: mix(self.dwdy, other.dwdy),
+            d2wdy2: mix(self.d2wdy2, other.d2wdy2),
+        }
+    }
+
+    /// Dupire's local variance, floored at `min_variance`.
+    ///
+    /// Calendar arbitrage makes `∂w/∂t` negative and butterfly arbitrage
+    /// the denominator; either would give a negative variance, and hence a
+    /// NaN volatility, so both fall back to the floor.
+    fn local_variance(&self, min_variance: Real) -> Real {
+        let Self {
+            w,
+            y,
+            dwdt,
+            dwdy,
+            d2wdy2,
+        } = *self;
+        if w <= 0.0 {
+            return min_variance;
+        }
+
+        // numerator = ∂w/∂t
+        // denominator = 1 - (y/w)*(∂w/∂y)
+        //   + (1/4)*(-1/4 - 1/w + y²/w²)*(∂w/∂y)²
+        //   + (1/2)*(∂²w/∂y²)
+        let den1 = 1.0 - y / w * dwdy;
+        let den2 = 0.25 * (-0.25 - 1.0 / w + y * y / (w * w)) * dwdy * dwdy;
+        let den3 = 0.5 * d2wdy2;
+        let denominator = den1 + den2 + den3;
+
+        let local_var = dwdt / denominator;
+        if denominator > 0.0 && local_var.is_finite() {
+            local_var.max(min_variance)
+        } else {
+            min_variance
+        }
+    }
 }
 
 #[cfg(test)]
@@ -266,6 +403,176 @@ mod tests {
         assert_abs_diff_eq!(surface.local_vol_impl(0.5, 120.0), 0.20, epsilon = 0.01);
     }
 
+    #[test]
+    fn flat_black_vol_gives_equal_local_vol() {
+        let (vol_surface, rf, dy) = make_flat_surface();
+        let direct = LocalVolSurface::new(vol_surface, rf, dy, 100.0, Actual365Fixed);
+        let (times, strikes) = ([0.1, 0.5, 1.0, 3.0], [50.0, 80.0, 100.0, 125.0, 200.0]);
+        for t in times {
+            for k in strikes {
+                assert_abs_diff_eq!(direct.local_vol_impl(t, k), 0.20, epsilon = 1e-8);
+            }
+        }
+
+        // On a grid, the interpolated derivatives stay exact between nodes.
+        let gridded = direct.with_grid(&times, &strikes).unwrap();
+        for (t, k) in [(0.1, 50.0), (0.3, 90.0), (2.0, 150.0), (3.0, 200.0)] {
+            assert_abs_diff_eq!(gridded.local_vol_impl(t, k), 0.20, epsilon = 1e-8);
+        }
+    }
+
+    #[test]
+    fn grid_interpolates_between_its_nodes() {
+        let (_, rf, dy) = make_flat_surface();
+        let surface = || {
+            LocalVolSurface::new(
+                Arc::new(SmileVol),
+                rf.clone(),
+                dy.clone(),
+                100.0,
+                Actual365Fixed,
+            )
+        };
+        let times: Vec<Time> = (1..=20).map(|i| 0.1 * i as Real).collect();
+        let strikes: Vec<Real> = (0..=40).map(|j| 60.0 * (j as Real / 40.0).exp()).collect();
+        let (direct, gridded) = (surface(), surface().with_grid(&times, &strikes).unwrap());
+
+        // Nodes reproduce the direct derivatives.
+        assert_eq!(
+            gridded.local_vol_impl(times[5], strikes[17]),
+            direct.local_vol_impl(times[5], strikes[17])
+        );
+        for (t, k) in [(0.15, 70.0), (0.77, 100.0), (1.33, 131.0)] {
+            assert_abs_diff_eq!(
+                gridded.local_vol_impl(t, k),
+                direct.local_vol_impl(t, k),
+                epsilon = 1e-3
+            );
+        }
+        // Outside the grid the derivatives are taken directly.
+        assert_eq!(
+            gridded.local_vol_impl(5.0, 100.0),
+            direct.local_vol_impl(5.0, 100.0)
+        );
+        assert_eq!(
+            gridded.local_vol_impl(1.0, 200.0),
+            direct.local_vol_impl(1.0, 200.0)
+        );
+    }
+
+    #[test]
+    fn invalid_floors_and_grids_are_errors() {
+        let (vol_surface, rf, dy) = make_flat_surface();
+        let surface = || {
+            LocalVolSurface::new(
+                vol_surface.clone(),
+                rf.clone(),
+                dy.clone(),
+                100.0,
+                Actual365Fixed,
+            )
+        };
+        assert!(surface().with_floor(-1e-8).is_err());
+        assert!(surface().with_floor(Real::NAN).is_err());
+        assert!(surface().with_floor(0.0).is_ok());
+        assert!(surface().with_grid(&[1.0], &[90.0, 110.0]).is_err());
+        assert!(surface().with_grid(&[1.0, 0.5], &[90.0, 110.0]).is_err());
+        assert!(surface().with_grid(&[0.5, 1.0], &[0.0, 110.0]).is_err());
+    }
+
+    /// σ(t, K) = 0.2 − 0.1·ln(K/100) + 0.2·ln²(K/100) + 0.02·t: a smooth,
+    /// arbitrage-free smile.
+    #[derive(Debug)]
+    struct SmileVol;
+
+    impl TermStructure for SmileVol {
+        fn reference_date(&self) -> Date {
+            Date::from_ymd(2025, 1, 2).unwrap()
+        }
+        fn day_counter(&self) -> &dyn DayCounter {
+            &Actual365Fixed
+        }
+        fn calendar(&self) -> &dyn Calendar {
+            &NullCalendar
+        }
+        fn max_date(&self) -> Date {
+            Date::MAX
+        }
+    }
+
+    impl VolatilityTermStructure for SmileVol {
+        fn min_strike(&self) -> Real {
+            0.0
+        }
+        fn max_strike(&self) -> Real {
+            Real::MAX
+        }
+    }
+
+    impl BlackVolTermStructure for SmileVol {
+        fn black_vol_impl(&self, t: Time, strike: Real) -> Real {
+            let x = (strike / 100.0).ln();
+            0.2 - 0.1 * x + 0.2 * x * x + 0.02 * t
+        }
+    }
+
+    /// σ(t, K) = 0.2 − 0.6·ln(K/100) − 0.02·t: a skew too steep for the
+    /// wings and a total variance that falls with time at high strikes.
+    #[derive(Debug)]
+    struct ArbitrageableVol;
+
+    impl TermStructure for ArbitrageableVol {
+        fn reference_date(&self) -> Date {
+            Date::from_ymd(2025, 1, 2).unwrap()
+        }
+        fn day_counter(&self) -> &dyn DayCounter {
+            &Actual365Fixed
+        }
+        fn calendar(&self) -> &dyn Calendar {
+            &NullCalendar
+        }
+        fn max_date(&self) -> Date {
+            Date::MAX
+        }
+    }
+
+    impl VolatilityTermStructure for ArbitrageableVol {
+        fn min_strike(&self) -> Real {
+            0.0
+        }
+        fn max_strike(&self) -> Real {
+            Real::MAX
+        }
+    }
+
+    impl BlackVolTermStructure for ArbitrageableVol {
+        fn black_vol_impl(&self, t: Time, strike: Real) -> Real {
+            (0.2 - 0.6 * (strike / 100.0).ln() - 0.02 * t).max(0.01)
+        }
+    }
+
+    #[test]
+    fn arbitrageable_surface_is_floored() {
+        let (_, rf, dy) = make_flat_surface();
+        let floor = 1e-4;
+        let surface =
+            LocalVolSurface::new(Arc::new(ArbitrageableVol), rf, dy, 100.0, Actual365Fixed)
+                .with_floor(floor)
+                .unwrap();
+        let mut floored = 0;
+        for t in [0.05, 0.25, 1.0, 2.0] {
+            for k in [60.0, 80.0, 100.0, 120.0, 140.0] {
+                let vol = surface.local_vol_impl(t, k);
+                assert!(vol.is_finite(), "t = {t}, K = {k}: {vol}");
+                assert!(vol >= floor.sqrt() - 1e-15);
+                if vol <= floor.sqrt() + 1e-15 {
+                    floored += 1;
+                }
+            }
+        }
+        assert!(floored > 0, "no point hit the floor");
+    }
+
     #[test]
     fn local_vol_surface_term_structure() {
         let (vol_surface, rf, dy) = make_flat_surface();
@@ -0,0 +1,197 @@
+//! Conversions between zero curves and par-yield (par swap rate) curves.
+//!
+//! QuantLib has no counterpart.  Maturities are year fractions from the
+//! curve's reference date.  A par rate of maturity `T` at frequency `f`
+//! pays coupons at `T, T − 1/f, T − 2/f, …`, the first period running from
+//! time 0 as a short stub when `T` is not a whole number of periods, and
+//! satisfies
+//!
+//! `S(T) · Σᵢ τᵢ P(tᵢ) + P(T) = 1`.
+//!
+//! [`zero_from_par`] inverts this pillar by pillar, interpolating the
+//! continuously-compounded zero rate linearly in time between pillars (and
+//! flat before the first), so it recovers exactly any zero curve of that
+//! shape — an [`InterpolatedZeroCurve`](crate::InterpolatedZeroCurve) with
+//! linear interpolation on the same pillars, for instance.
+
+use crate::yield_term_structure::YieldTermStructure;
+use ql_core::{ensure, errors::Result, Rate, Real, Time};
+use ql_math::solvers1d::brent;
+use ql_time::Frequency;
+
+/// Par swap rates of `zero_curve` at `maturities`, paying at `frequency`.
+///
+/// # Errors
+/// Fails if `frequency` has no whole number of periods per year, or if a
+/// maturity is not positive.
+pub fn par_yield_curve(
+    zero_curve: &dyn YieldTermStructure,
+    maturities: &[Time],
+    frequency: Frequency,
+) -> Result<Vec<Rate>> {
+    let period = coupon_period(frequency)?;
+    maturities
+        .iter()
+        .map(|&maturity| {
+            ensure!(maturity > 0.0, "maturity must be positive, got {maturity}");
+            let (times, accruals) = coupon_times(maturity, period);
+            let annuity: Real = times
+                .iter()
+                .zip(&accruals)
+                .map(|(&t, &tau)| tau * zero_curve.discount(t))
+                .sum();
+            Ok((1.0 - zero_curve.discount(maturity)) / annuity)
+        })
+        .collect()
+}
+
+/// Continuously-compounded zero rates at `maturities` bootstrapped from
+/// the par swap rates `par_rates`, paying at `frequency`.
+///
+/// # Errors
+/// Fails if the inputs differ in length, the maturities are not positive
+/// and strictly increasing, `frequency` has no whole number of periods per
+/// year, or a pillar cannot be solved for.
+pub fn zero_from_par(
+    par_rates: &[Rate],
+    maturities: &[Time],
+    frequency: Frequency,
+) -> Result<Vec<Rate>> {
+    ensure!(
+        par_rates.len() == maturities.len(),
+        "{} par rates but {} maturities",
+        par_rates.len(),
+        maturities.len()
+    );
+    ensure!(
+        maturities.first().is_some_and(|&t| t > 0.0),
+        "maturities must be positive"
+    );
+    ensure!(
+        maturities.windows(2).all(|w| w[0] < w[1]),
+        "maturities must be strictly increasing"
+    );
+    let period = coupon_period(frequency)?;
+
+    let mut zeros: Vec<Rate> = Vec::with_capacity(maturities.len());
+    for (k, (&maturity, &par)) in maturities.iter().zip(par_rates).enumerate() {
+        let (times, accruals) = coupon_times(maturity, period);
+        let previous = k.checked_sub(1).map(|j| (maturities[j], zeros[j]));
+        let zero_at = |t: Time, z: Rate| -> Rate {
+            match previous {
+                Some((t0, _)) if t < t0 => zero_rate(maturities, &zeros, t),
+                Some((t0, z0)) => z0 + (z - z0) * (t - t0) / (maturity - t0),
+                None => z,
+            }
+        };
+        let mismatch = |z: Rate| {
+            let annuity: Real = times
+                .iter()
+                .zip(&accruals)
+                .map(|(&t, &tau)| tau * (-zero_at(t, z) * t).exp())
+                .sum();
+            par * annuity + (-z * maturity).exp() - 1.0
+        };
+        zeros.push(brent(mismatch, -0.5, 2.0, 1e-14)?);
+    }
+    Ok(zeros)
+}
+
+/// Length of a coupon period in years.
+fn coupon_period(frequency: Frequency) -> Result<Time> {
+    match frequency.periods_per_year() {
+        Some(n) if n > 0 => Ok(1.0 / n as Real),
+        _ => ql_core::fail!("frequency {frequency:?} has no whole number of periods per year"),
+    }
+}
+
+/// Payment times and accrual fractions of a par swap of `maturity`,
+/// rolling back from the maturity by `period`.
+fn coupon_times(maturity: Time, period: Time) -> (Vec<Time>, Vec<Real>) {
+    let mut times = Vec::new();
+    let mut t = maturity;
+    while t > 1e-10 {
+        times.push(t);
+        t -= period;
+    }
+    times.reverse();
+    let accruals = times
+        .iter()
+        .scan(0.0, |start, &end| {
+            let tau = end - *start;
+            *start = end;
+            Some(tau)
+        })
+        .collect();
+    (times, accruals)
+}
+
+/// Zero rate at `t` on the bootstrapped pillars so far: linear between
+/// pillars, flat before the first.
+fn zero_rate(maturities: &[Time], zeros: &[Rate], t: Time) -> Rate {
+    let i = maturities[..zeros.len()].partition_point(|&m| m < t);
+    if i == 0 {
+        zeros[0]
+    } else {
+        let (t0, t1) = (maturities[i - 1], maturities[i]);
+        zeros[i - 1] + (zeros[i] - zeros[i - 1]) * (t - t0) / (t1 - t0)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::interpolated_zero_curve::{InterpolatedZeroCurve, Linear};
+    use crate::FlatForward;
+    use ql_time::{Actual365Fixed, Date};
+
+    #[test]
+    fn zero_to_par_and_back_round_trips() {
+        let reference = Date::from_ymd(2025, 1, 2).unwrap();
+        let maturities = [1.0, 2.0, 3.0, 5.0, 7.0, 10.0, 20.0, 30.0];
+        let zeros = [0.021, 0.024, 0.027, 0.031, 0.033, 0.036, 0.038, 0.037];
+
+        // Pillars a whole number of 365-day years apart, with the zero rate
+        // flat back to the reference date.
+        let mut dates = vec![reference];
+        let mut rates = vec![zeros[0]];
+        for (&t, &z) in maturities.iter().zip(&zeros) {
+            dates.push(reference + (365.0 * t) as i32);
+            rates.push(z);
+        }
+        let curve = InterpolatedZeroCurve::new(&dates, &rates, Actual365Fixed, &Linear).unwrap();
+
+        for frequency in [
+            Frequency::Annual,
+            Frequency::Semiannual,
+            Frequency::Quarterly,
+        ] {
+            let par = par_yield_curve(&curve, &maturities, frequency).unwrap();
+            let recovered = zero_from_par(&par, &maturities, frequency).unwrap();
+            for ((&t, &z), &r) in maturities.iter().zip(&zeros).zip(&recovered) {
+                assert!((z - r).abs() < 1e-8, "{frequency:?} T = {t}: {r} vs {z}");
+            }
+        }
+    }
+
+    #[test]
+    fn flat_zero_curve_gives_flat_par_curve() {
+        let reference = Date::from_ymd(2025, 1, 2).unwrap();
+        let curve = FlatForward::continuous(reference, 0.03, Actual365Fixed);
+        let maturities = [0.5, 1.0, 2.0, 4.5, 10.0, 30.0];
+        let par = par_yield_curve(&curve, &maturities, Frequency::Semiannual).unwrap();
+        // Whole periods at a flat rate make every par rate the one-period
+        // forward, 2·(e^{0.015} − 1).
+        let expected = 2.0 * (0.015_f64.exp() - 1.0);
+        for (&t, &s) in maturities.iter().zip(&par) {
+            assert!((s - expected).abs() < 1e-12, "T = {t}: {s}");
+        }
+
+        // A stub at the front only moves the rate a little.
+        let stubbed = par_yield_curve(&curve, &[1.3, 7.8], Frequency::Semiannual).unwrap();
+        for s in stubbed {
+            assert!((s - expected).abs() < 1e-4, "{s}");
+        }
+        assert!(par_yield_curve(&curve, &[1.0], Frequency::Once).is_err());
+    }
+}
@@ -12,6 +12,7 @@
 //! use ql_termstructures::piecewise_yield_curve::PiecewiseYieldCurve;
 //! use ql_termstructures::rate_helpers::DepositRateHelper;
 //! use ql_termstructures::interpolated_zero_curve::Linear;
+//! use ql_termstructures::YieldTermStructure;
 //! use ql_time::{Actual360, Date};
 //!
 //! let ref_date = Date::from_ymd(2025, 1, 2).unwrap();
@@ -52,6 +53,45 @@ const MIN_RATE: Rate = -0.10;
 /// Maximum zero rate to search (+30 %).
 const MAX_RATE: Rate = 0.30;
 
+/// Diagnostics for one bootstrapped pillar.
+#[derive(Debug, Clone, PartialEq)]
+pub struct PillarDiagnostics {
+    /// Pillar date.
+    pub date: Date,
+    /// Time from the reference date to the pillar.
+    pub time: Time,
+    /// Solved continuously-compounded zero rate.
+    pub value: Rate,
+    /// Number of objective evaluations used by the solver.
+    pub iterations: usize,
+    /// Market quote of the helper that fixed this pillar.
+    pub quote: Real,
+    /// Implied minus market quote of that helper on the final curve.
+    pub residual: Real,
+}
+
+/// Record of a bootstrap, one entry per pillar in date order.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct BootstrapTrace {
+    /// Per-pillar diagnostics.
+    pub pillars: Vec<PillarDiagnostics>,
+}
+
+impl BootstrapTrace {
+    /// Largest absolute residual over all pillars.
+    pub fn max_residual(&self) -> Real {
+        self.pillars
+            .iter()
+            .map(|p| p.residual.abs())
+            .fold(0.0, Real::max)
+    }
+
+    /// Total number of objective evaluations over all pillars.
+    pub fn total_iterations(&self) -> usize {
+        self.pillars.iter().map(|p| p.iterations).sum()
+    }
+}
+
 /// A yield curve bootstrapped from market instruments.
 ///
 /// The curve interpolates continuously-compounded zero rates on pillar dates
@@ -73,6 +113,8 @@ pub struct PiecewiseYieldCurve {
     interp: Box<dyn Interpolation1D>,
     /// The last pillar date.
     max_date: Date,
+    /// Per-pillar bootstrap diagnostics.
+    trace: BootstrapTrace,
 }
 
 impl PiecewiseYieldCurve {
@@ -85,8 +127,9 @@ impl PiecewiseYieldCurve {
     /// * `builder` — interpolation strategy (e.g. `Linear`, `LogLinear`)
     ///
     /// # Errors
-    /// Returns an error if no helpers are provided, pillar dates are
-    /// duplicated, or the solver fails to converge at any pillar.
+    /// Returns an error if no helpers are provided, a pillar date is not
+    /// after the reference date, or no zero rate within the search bounds
+    /// reprices a helper.  The error names the failing pillar.
     pub fn new(
         reference_date: Date,
         helpers: &[Box<dyn RateHelper>],
@@ -161,6 +204,8 @@ impl PiecewiseYieldCurve {
         rates[0] = first_helper.quote(); // use the first deposit rate as t=0 rate
         rates[1] = first_helper.quote();
 
+        // (helper index, evaluations) for each solved pillar
+        let mut solved = Vec::with_capacity(dates.len() - 1);
         let mut helper_cursor = 0;
         for k in 1..dates.len() {
             // Find the helper for this pillar
@@ -181,13 +226,16 @@ impl PiecewiseYieldCurve {
 
             // Use Brent solver: find rate at pillar k such that
             // implied_quote(curve) - market_quote = 0
+            let mut evaluations = 0;
             let solved_rate = {
                 let times_slice = &times[..=k];
                 let rates_mut = &mut rates;
+                let evaluations = &mut evaluations;
 
                 // We need to define the objective function for Brent
                 let result = brent_bootstrap(
                     |r| {
+                        *evaluations += 1;
                         rates_mut[k] = r;
                         // Also extrapolate rate[0] (reference date) from rate[1]
                         rates_mut[0] = rates_mut[1];
@@ -209,7 +257,7 @@ impl PiecewiseYieldCurve {
                     Ok(r) => r,
                     Err(e) => {
                         return Err(Error::Runtime(format!(
-                            "bootstrap failed at pillar {} (date {}): {e}",
+                            "bootstrap failed at pillar {} (date {}, quote {market_quote}): {e}",
                             k, dates[k]
                         )));
                     }
@@ -218,6 +266,7 @@ impl PiecewiseYieldCurve {
 
             rates[k] = solved_rate;
             rates[0] = rates[1]; // keep reference date rate in sync
+            solved.push((sorted_indices[helper_cursor], evaluations));
             helper_cursor += 1;
         }
 
@@ -225,6 +274,29 @@ impl PiecewiseYieldCurve {
         let interp = builder.build(&times, &rates)?;
         let max_date = *dates.last().unwrap();
 
+        let final_curve = BootstrapCurve {
+            reference_date,
+            day_counter: &*dc,
+            times: &times,
+            rates: &rates,
+            interp: &*interp,
+        };
+        let pillars = solved
+            .into_iter()
+            .enumerate()
+            .map(|(j, (idx, iterations))| {
+                let helper = &helpers[idx];
+                PillarDiagnostics {
+                    date: dates[j + 1],
+                    time: times[j + 1],
+                    value: rates[j + 1],
+                    iterations,
+                    quote: helper.quote(),
+                    residual: helper.implied_quote(&final_curve) - helper.quote(),
+                }
+            })
+            .collect();
+
         Ok(Self {
             data: YieldTermStructureData {
                 reference_date,
@@ -236,6 +308,7 @@ impl PiecewiseYieldCurve {
             rates,
             interp,
             max_date,
+            trace: BootstrapTrace { pillars },
         })
     }
 
@@ -259,6 +332,11 @@ impl PiecewiseYieldCurve {
     pub fn rates(&self) -> &[Rate] {
         &self.rates
     }
+
+    /// Return the per-pillar diagnostics recorded during the bootstrap.
+    pub fn trace(&self) -> &BootstrapTrace {
+        &self.trace
+    }
 }
 
 impl TermStructure for PiecewiseYieldCurve {
@@ -420,11 +498,18 @@ where
         if fb.is_nan() {
             // Fall back to bisection
             b = (a + c) / 2.0;
-            fb = f(b).unwrap_or(0.0);
+            fb = f(b).unwrap_or(f64::NAN);
+            if fb.is_nan() {
+                return Err(Error::Runtime(format!(
+                    "brent_bootstrap: f undefined at {b}"
+                )));
+            }
         }
     }
 
-    Ok(b) // return best estimate
+    Err(Error::Runtime(format!(
+        "brent_bootstrap: no convergence after {MAX_ITER} iterations (x = {b}, f(x) = {fb})"
+    )))
 }
 
 // ── Tests ─────────────────────────────────────────────────────────────────────
@@ -607,6 +692,80 @@ mod tests {
         assert!(df > 1.0, "negative rates should give DF > 1, got {df}");
     }
 
+    #[test]
+    fn bootstrap_trace_on_swap_curve() {
+        let ref_date = Date::from_ymd(2025, 1, 2).unwrap();
+        let mut helpers: Vec<Box<dyn RateHelper>> = vec![
+            Box::new(DepositRateHelper::new(
+                0.040,
+                ref_date,
+                Date::from_ymd(2025, 4, 2).unwrap(),
+                Actual365Fixed,
+            )),
+            Box::new(DepositRateHelper::new(
+                0.041,
+                ref_date,
+                Date::from_ymd(2025, 7, 2).unwrap(),
+                Actual365Fixed,
+            )),
+        ];
+        for (years, quote) in [(1, 0.042), (2, 0.043), (3, 0.0435), (5, 0.044)] {
+            let dates = (0..=years)
+                .map(|y| Date::from_ymd(2025 + y, 1, 2).unwrap())
+                .collect();
+            helpers.push(Box::new(SwapRateHelper::new(
+                quote,
+                Schedule::from_dates(dates),
+                Actual365Fixed,
+            )));
+        }
+
+        let curve = PiecewiseYieldCurve::new(ref_date, &helpers, Actual365Fixed, &Linear).unwrap();
+        let trace = curve.trace();
+        assert_eq!(trace.pillars.len(), helpers.len());
+        assert!(trace.max_residual() < 1e-10, "{trace:?}");
+        for (pillar, (&date, &rate)) in trace
+            .pillars
+            .iter()
+            .zip(curve.dates()[1..].iter().zip(&curve.rates()[1..]))
+        {
+            assert_eq!(pillar.date, date);
+            assert_eq!(pillar.value, rate);
+            assert!(
+                (3..=30).contains(&pillar.iterations),
+                "{} evaluations at {date}",
+                pillar.iterations
+            );
+        }
+        assert_eq!(trace.pillars[5].quote, 0.044);
+        assert!(trace.total_iterations() >= 3 * helpers.len());
+    }
+
+    #[test]
+    fn bootstrap_reports_failing_pillar() {
+        let ref_date = Date::from_ymd(2025, 1, 2).unwrap();
+        // The second deposit matures one day after the first but implies a
+        // discount factor far above it — a large negative overnight forward.
+        let helpers: Vec<Box<dyn RateHelper>> = vec![
+            Box::new(DepositRateHelper::new(
+                0.04,
+                ref_date,
+                Date::from_ymd(2025, 7, 2).unwrap(),
+                Actual360,
+            )),
+            Box::new(DepositRateHelper::new(
+                -0.5,
+                ref_date,
+                Date::from_ymd(2025, 7, 3).unwrap(),
+                Actual360,
+            )),
+        ];
+        let err = PiecewiseYieldCurve::new(ref_date, &helpers, Actual360, &Linear).unwrap_err();
+        let message = err.to_string();
+        assert!(message.contains("pillar 2"), "{message}");
+        assert!(message.contains("quote -0.5"), "{message}");
+    }
+
     #[test]
     fn bootstrap_error_on_empty_helpers() {
         let ref_date = Date::from_ymd(2025, 1, 2).unwrap();
@@ -0,0 +1,102 @@
+//! Forward-rate correlation implied by swaption and caplet volatilities.
+//!
+//! QuantLib has no counterpart.  Freezing the weights of a swap rate on its
+//! forwards, `S ≈ Σᵢ wᵢ Fᵢ` in relative terms, gives Rebonato's
+//! approximation of the swaption's Black variance
+//!
+//! `σ_S² ≈ Σᵢ Σⱼ wᵢ wⱼ σᵢ σⱼ ρᵢⱼ`,
+//!
+//! where `σᵢ` are the caplet volatilities of the forwards.  With a single
+//! correlation `ρᵢⱼ = ρ` off the diagonal this is linear in `ρ`:
+//!
+//! `ρ = (σ_S² − Σᵢ wᵢ²σᵢ²) / ((Σᵢ wᵢσᵢ)² − Σᵢ wᵢ²σᵢ²)`.
+//!
+//! When the weights sum to one, `ρ = 1` puts the swaption volatility at the
+//! weighted average of the caplet volatilities; decorrelation pulls it
+//! below.
+
+use ql_core::{ensure, errors::Result, Real, Volatility};
+
+/// The single forward-rate correlation that reconciles `swaption_vol`
+/// with the `caplet_vols` of its forwards, taken with `weights`.
+///
+/// # Errors
+/// Fails if `caplet_vols` and `weights` differ in length or hold fewer
+/// than two forwards, if the correlation is not identified (all but one
+/// weighted volatility zero), or if no correlation in `[−1, 1]` matches.
+pub fn rate_correlation_from_vols(
+    swaption_vol: Volatility,
+    caplet_vols: &[Volatility],
+    weights: &[Real],
+) -> Result<Real> {
+    ensure!(
+        caplet_vols.len() == weights.len(),
+        "{} caplet vols but {} weights",
+        caplet_vols.len(),
+        weights.len()
+    );
+    ensure!(caplet_vols.len() >= 2, "at least two forwards are required");
+    let (sum, sum_of_squares) = caplet_vols
+        .iter()
+        .zip(weights)
+        .map(|(&vol, &w)| w * vol)
+        .fold((0.0, 0.0), |(s, s2), x| (s + x, s2 + x * x));
+    let cross = sum * sum - sum_of_squares;
+    ensure!(
+        cross.abs() > Real::EPSILON * sum_of_squares,
+        "correlation is not identified by a single weighted volatility"
+    );
+    let rho = (swaption_vol * swaption_vol - sum_of_squares) / cross;
+    // Vols quoted exactly at the bounds may overshoot them by rounding.
+    ensure!(
+        rho.abs() <= 1.0 + 1e-12,
+        "swaption vol {swaption_vol} implies correlation {rho} outside [-1, 1]"
+    );
+    Ok(rho.clamp(-1.0, 1.0))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const CAPLET_VOLS: [Volatility; 4] = [0.22, 0.25, 0.24, 0.21];
+    const WEIGHTS: [Real; 4] = [0.27, 0.26, 0.24, 0.23];
+
+    fn weighted_average() -> Volatility {
+        CAPLET_VOLS.iter().zip(&WEIGHTS).map(|(v, w)| v * w).sum()
+    }
+
+    /// Swaption vol of the approximation for a given correlation.
+    fn swaption_vol(rho: Real) -> Volatility {
+        let mut variance = 0.0;
+        for (i, (&vi, &wi)) in CAPLET_VOLS.iter().zip(&WEIGHTS).enumerate() {
+            for (j, (&vj, &wj)) in CAPLET_VOLS.iter().zip(&WEIGHTS).enumerate() {
+                let rho_ij = if i == j { 1.0 } else { rho };
+                variance += wi * wj * vi * vj * rho_ij;
+            }
+        }
+        variance.sqrt()
+    }
+
+    #[test]
+    fn perfect_correlation_gives_weighted_average_vol() {
+        assert!((swaption_vol(1.0) - weighted_average()).abs() < 1e-15);
+        let rho = rate_correlation_from_vols(weighted_average(), &CAPLET_VOLS, &WEIGHTS).unwrap();
+        assert!((rho - 1.0).abs() < 1e-12, "{rho}");
+    }
+
+    #[test]
+    fn lower_swaption_vol_implies_decorrelation() {
+        for rho in [0.95, 0.7, 0.3] {
+            let vol = swaption_vol(rho);
+            assert!(vol < weighted_average());
+            let implied = rate_correlation_from_vols(vol, &CAPLET_VOLS, &WEIGHTS).unwrap();
+            assert!((implied - rho).abs() < 1e-12, "{implied} vs {rho}");
+        }
+        // No correlation lifts the swaption above the weighted average.
+        assert!(
+            rate_correlation_from_vols(1.05 * weighted_average(), &CAPLET_VOLS, &WEIGHTS).is_err()
+        );
+        assert!(rate_correlation_from_vols(0.2, &[0.2], &[1.0]).is_err());
+    }
+}
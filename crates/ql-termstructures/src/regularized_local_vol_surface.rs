@@ -0,0 +1,601 @@
+//! `RegularizedLocalVolSurface` — a local-volatility surface calibrated to
+//! option prices with a Tikhonov smoothness penalty.
+//!
+//! Dupire's formula differentiates the implied surface twice in strike and
+//! once in time, so noise in the quotes is amplified into the local vols.
+//! Here the local vols on a (time × strike) node grid are instead the
+//! minimiser of
+//!
+//! ```text
+//! Σ ((C_model − C_market) / vega)²  +  λ · Σ (Δ²σ)²
+//! ```
+//!
+//! where the model prices come from Dupire's forward equation and `Δ²σ`
+//! runs over the second differences of the node vols along both grid
+//! axes.  For `λ → 0` the fit reproduces the raw Dupire surface; larger `λ`
+//! trades repricing accuracy for smoothness.
+
+use crate::black_vol_term_structure::BlackVolTermStructure;
+use crate::local_vol_term_structure::LocalVolTermStructure;
+use crate::term_structure::TermStructure;
+use crate::volatility_term_structure::{VolatilityTermStructure, VolatilityTermStructureData};
+use crate::yield_term_structure::YieldTermStructure;
+use ql_core::{ensure, errors::Result, Real, Time, Volatility};
+use ql_math::distributions::{normal_cdf, normal_pdf};
+use ql_math::optimization::{CostFunction, EndCriteria, LevenbergMarquardt, PositiveConstraint};
+use ql_math::Array;
+use ql_time::{BusinessDayConvention, Calendar, Date, DayCounter, NullCalendar};
+use std::sync::Arc;
+
+/// Points of the forward-moneyness grid of the forward equation.
+const GRID_POINTS: usize = 301;
+
+/// Time steps per year of the forward equation.
+const STEPS_PER_YEAR: Real = 100.0;
+
+/// Floor on the vega used to scale price residuals.
+const MIN_VEGA: Real = 1e-4;
+
+/// A local-volatility surface fitted to Black prices under a Tikhonov
+/// penalty on its second differences.
+///
+/// The surface is parameterised by its values `σ(Tᵢ, Kⱼ)` on the node grid
+/// and interpolated linearly in time and strike, flat outside the grid.  The
+/// calibration instruments are the calls struck at the node strikes and
+/// expiring at the node times, priced from the input Black surface.
+///
+/// There is no direct QuantLib counterpart; the closest is a
+/// `FixedLocalVolSurface` filled from an external fit.
+#[derive(Debug)]
+pub struct RegularizedLocalVolSurface {
+    data: VolatilityTermStructureData,
+    times: Vec<Time>,
+    strikes: Vec<Real>,
+    /// `local_vols[i][j] = σ(times[i], strikes[j])`.
+    local_vols: Vec<Vec<Volatility>>,
+    lambda: Real,
+    /// Model minus market call price at each node.
+    price_errors: Vec<Vec<Real>>,
+}
+
+impl RegularizedLocalVolSurface {
+    /// Calibrate a local-vol surface to the calls implied by `black_vol`.
+    ///
+    /// # Arguments
+    /// * `black_vol` — implied Black volatility surface to fit
+    /// * `risk_free_rate`, `dividend_yield` — curves defining the forward
+    /// * `underlying` — spot price
+    /// * `times` — node expiries (strictly increasing, positive)
+    /// * `strikes` — node strikes (strictly increasing, positive)
+    /// * `lambda` — weight of the smoothness penalty, `λ ≥ 0`
+    /// * `day_counter` — day counter of the resulting surface
+    ///
+    /// # Errors
+    /// Returns an error on an invalid grid or a negative `lambda`, or if the
+    /// optimiser fails.
+    #[allow(clippy::too_many_arguments)]
+    pub fn new(
+        black_vol: Arc<dyn BlackVolTermStructure>,
+        risk_free_rate: Arc<dyn YieldTermStructure>,
+        dividend_yield: Arc<dyn YieldTermStructure>,
+        underlying: Real,
+        times: &[Time],
+        strikes: &[Real],
+        lambda: Real,
+        day_counter: impl DayCounter + 'static,
+    ) -> Result<Self> {
+        ensure!(!times.is_empty(), "need at least one node time");
+        ensure!(!strikes.is_empty(), "need at least one node strike");
+        ensure!(times[0] > 0.0, "node times must be positive");
+        ensure!(strikes[0] > 0.0, "node strikes must be positive");
+        ensure!(
+            times.windows(2).all(|w| w[0] < w[1]),
+            "node times must be strictly increasing"
+        );
+        ensure!(
+            strikes.windows(2).all(|w| w[0] < w[1]),
+            "node strikes must be strictly increasing"
+        );
+        ensure!(underlying > 0.0, "underlying must be positive");
+        ensure!(lambda >= 0.0, "regularization weight must be non-negative");
+
+        let forwards: Vec<Real> = times
+            .iter()
+            .map(|&t| underlying * dividend_yield.discount(t) / risk_free_rate.discount(t))
+            .collect();
+        let implied: Vec<Vec<Volatility>> = times
+            .iter()
+            .map(|&t| {
+                strikes
+                    .iter()
+                    .map(|&k| black_vol.black_vol_impl(t, k))
+                    .collect()
+            })
+            .collect();
+
+        let grid = ForwardEquation::new(underlying, times, strikes, &forwards, &implied);
+        let mut quotes = Vec::with_capacity(times.len() * strikes.len());
+        for (i, &t) in times.iter().enumerate() {
+            for (j, &k) in strikes.iter().enumerate() {
+                let moneyness = k / forwards[i];
+                let (price, vega) = normalized_black_call(moneyness, implied[i][j], t);
+                quotes.push((price, vega.max(MIN_VEGA)));
+            }
+        }
+
+        let cost = CalibrationCost {
+            grid: &grid,
+            quotes: &quotes,
+            n_times: times.len(),
+            n_strikes: strikes.len(),
+            penalty: lambda.sqrt(),
+        };
+        let guess = Array::from_vec(implied.iter().flatten().copied().collect());
+        let end_criteria = EndCriteria::new(200, 5, 1e-20, 1e-10, 1e-12);
+        let solution = LevenbergMarquardt::new(1e-8, 1e-10, 1e-14).minimize(
+            &cost,
+            &PositiveConstraint,
+            &guess,
+            &end_criteria,
+        )?;
+
+        let vols = solution.x.as_slice();
+        let model = grid.prices(vols);
+        let local_vols = vols.chunks(strikes.len()).map(<[Real]>::to_vec).collect();
+        let price_errors = (0..times.len())
+            .map(|i| {
+                let scale = risk_free_rate.discount(times[i]) * forwards[i];
+                (0..strikes.len())
+                    .map(|j| {
+                        let n = i * strikes.len() + j;
+                        scale * (model[n] - quotes[n].0)
+                    })
+                    .collect()
+            })
+            .collect();
+
+        Ok(Self {
+            data: VolatilityTermStructureData::new(
+                black_vol.reference_date(),
+                NullCalendar,
+                day_counter,
+            ),
+            times: times.to_vec(),
+            strikes: strikes.to_vec(),
+            local_vols,
+            lambda,
+            price_errors,
+        })
+    }
+
+    /// Create with a specific calendar.
+    pub fn with_calendar(mut self, calendar: impl Calendar + 'static) -> Self {
+        self.data.calendar = Box::new(calendar);
+        self
+    }
+
+    /// The regularization weight `λ`.
+    pub fn lambda(&self) -> Real {
+        self.lambda
+    }
+
+    /// Node times.
+    pub fn times(&self) -> &[Time] {
+        &self.times
+    }
+
+    /// Node strikes.
+    pub fn strikes(&self) -> &[Real] {
+        &self.strikes
+    }
+
+    /// Calibrated node vols, `local_vols()[i][j] = σ(times[i], strikes[j])`.
+    pub fn local_vols(&self) -> &[Vec<Volatility>] {
+        &self.local_vols
+    }
+
+    /// Model minus market price of the call at each node.
+    pub fn price_errors(&self) -> &[Vec<Real>] {
+        &self.price_errors
+    }
+
+    /// Sum of squared second differences of the node vols along both axes,
+    /// i.e. the penalty term without its weight `λ`.
+    pub fn roughness(&self) -> Real {
+        let flat: Vec<Real> = self.local_vols.iter().flatten().copied().collect();
+        second_differences(&flat, self.times.len(), self.strikes.len())
+            .map(|d| d * d)
+            .sum()
+    }
+}
+
+impl TermStructure for RegularizedLocalVolSurface {
+    fn reference_date(&self) -> Date {
+        self.data.reference_date
+    }
+
+    fn day_counter(&self) -> &dyn DayCounter {
+        &*self.data.day_counter
+    }
+
+    fn calendar(&self) -> &dyn Calendar {
+        &*self.data.calendar
+    }
+
+    fn max_date(&self) -> Date {
+        Date::MAX
+    }
+}
+
+impl VolatilityTermStructure for RegularizedLocalVolSurface {
+    fn business_day_convention(&self) -> BusinessDayConvention {
+        self.data.business_day_convention
+    }
+
+    fn min_strike(&self) -> Real {
+        self.strikes[0]
+    }
+
+    fn max_strike(&self) -> Real {
+        *self.strikes.last().unwrap()
+    }
+}
+
+impl LocalVolTermStructure for RegularizedLocalVolSurface {
+    fn local_vol_impl(&self, t: Time, underlying: Real) -> Volatility {
+        let row = |i: usize| interpolate(&self.strikes, &self.local_vols[i], underlying);
+        let (i, w) = bracket(&self.times, t);
+        if w == 0.0 {
+            row(i)
+        } else {
+            (1.0 - w) * row(i) + w * row(i + 1)
+        }
+    }
+}
+
+// ── Calibration ───────────────────────────────────────────────────────────────
+
+/// Scaled least-squares residuals of the calibration.
+struct CalibrationCost<'a> {
+    grid: &'a ForwardEquation,
+    /// Normalized market price and vega per node, row-major.
+    quotes: &'a [(Real, Real)],
+    n_times: usize,
+    n_strikes: usize,
+    /// `√λ`.
+    penalty: Real,
+}
+
+impl CostFunction for CalibrationCost<'_> {
+    fn values(&self, x: &Array) -> Array {
+        let vols = x.as_slice();
+        let prices = self.grid.prices(vols);
+        let misfit = prices
+            .iter()
+            .zip(self.quotes)
+            .map(|(model, &(market, vega))| (model - market) / vega);
+        let smoothness =
+            second_differences(vols, self.n_times, self.n_strikes).map(|d| self.penalty * d);
+        Array::from_vec(misfit.chain(smoothness).collect())
+    }
+}
+
+/// Second differences of a row-major `n_times × n_strikes` grid, first
+/// along strikes, then along times.
+fn second_differences(
+    vols: &[Real],
+    n_times: usize,
+    n_strikes: usize,
+) -> impl Iterator<Item = Real> + '_ {
+    let at = move |i: usize, j: usize| vols[i * n_strikes + j];
+    let along_strikes = (0..n_times).flat_map(move |i| {
+        (1..n_strikes.saturating_sub(1)).map(move |j| at(i, j - 1) - 2.0 * at(i, j) + at(i, j + 1))
+    });
+    let along_times = (1..n_times.saturating_sub(1)).flat_map(move |i| {
+        (0..n_strikes).map(move |j| at(i - 1, j) - 2.0 * at(i, j) + at(i + 1, j))
+    });
+    along_strikes.chain(along_times)
+}
+
+/// Undiscounted call price and vega on a unit forward, `c = N(d₁) − k·N(d₂)`.
+fn normalized_black_call(moneyness: Real, vol: Volatility, t: Time) -> (Real, Real) {
+    let std_dev = vol * t.sqrt();
+    let d1 = -moneyness.ln() / std_dev + 0.5 * std_dev;
+    let d2 = d1 - std_dev;
+    (
+        normal_cdf(d1) - moneyness * normal_cdf(d2),
+        normal_pdf(d1) * t.sqrt(),
+    )
+}
+
+// ── Forward equation ──────────────────────────────────────────────────────────
+
+/// Dupire's forward equation in forward moneyness `k = K/F(T)`.
+///
+/// The undiscounted, forward-normalised call price `c(T, k)` solves
+/// `∂c/∂T = ½σ²(T, k·F(T))·k²·∂²c/∂k²` with `c(0, k) = (1 − k)⁺`, whatever
+/// the rate and dividend curves, so a single sweep in `T` prices every node.
+struct ForwardEquation {
+    node_times: Vec<Time>,
+    node_strikes: Vec<Real>,
+    node_forwards: Vec<Real>,
+    dk: Real,
+    /// Time steps `(t_start, dt, forward at mid-step)` in order; the last
+    /// step of each node interval ends exactly on the node.
+    steps: Vec<(Time, Time, Real)>,
+    /// Index into `steps` just past each node time.
+    node_steps: Vec<usize>,
+}
+
+impl ForwardEquation {
+    fn new(
+        spot: Real,
+        times: &[Time],
+        strikes: &[Real],
+        forwards: &[Real],
+        implied: &[Vec<Volatility>],
+    ) -> Self {
+        // Wide enough that the call is worthless at the upper boundary.
+        let max_vol = implied.iter().flatten().fold(0.0, |m: Real, &v| m.max(v));
+        let t_max = *times.last().unwrap();
+        let max_moneyness = forwards
+            .iter()
+            .map(|&f| strikes.last().unwrap() / f)
+            .fold(0.0, Real::max);
+        let k_max = (2.0 * max_moneyness).max((6.0 * max_vol * t_max.sqrt()).exp());
+        let dk = k_max / (GRID_POINTS - 1) as Real;
+
+        let mut steps = Vec::new();
+        let mut node_steps = Vec::with_capacity(times.len());
+        let mut t0 = 0.0;
+        for (i, &t1) in times.iter().enumerate() {
+            let n = ((t1 - t0) * STEPS_PER_YEAR).ceil().max(4.0) as usize;
+            let dt = (t1 - t0) / n as Real;
+            let (f0, f1) = (if i == 0 { spot } else { forwards[i - 1] }, forwards[i]);
+            for s in 0..n {
+                // Forwards interpolated log-linearly between nodes.
+                let w = (s as Real + 0.5) / n as Real;
+                steps.push((t0 + s as Real * dt, dt, f0 * (f1 / f0).powf(w)));
+            }
+            node_steps.push(steps.len());
+            t0 = t1;
+        }
+
+        Self {
+            node_times: times.to_vec(),
+            node_strikes: strikes.to_vec(),
+            node_forwards: forwards.to_vec(),
+            dk,
+            steps,
+            node_steps,
+        }
+    }
+
+    /// Normalised model call prices at every node, row-major, for the
+    /// row-major node vols `vols`.
+    fn prices(&self, vols: &[Real]) -> Vec<Real> {
+        let n_strikes = self.node_strikes.len();
+        let grid: Vec<Real> = (0..GRID_POINTS).map(|j| j as Real * self.dk).collect();
+        let mut c: Vec<Real> = grid.iter().map(|&k| (1.0 - k).max(0.0)).collect();
+        let mut prices = Vec::with_capacity(vols.len());
+
+        let mut node = 0;
+        let mut a = vec![0.0; GRID_POINTS];
+        for (s, &(t0, dt, forward)) in self.steps.iter().enumerate() {
+            // Rannacher start-up: implicit steps smooth the payoff kink.
+            let theta = if s < 2 { 1.0 } else { 0.5 };
+            let (i, w) = bracket(&self.node_times, t0 + 0.5 * dt);
+            for (aj, &k) in a.iter_mut().zip(&grid) {
+                let strike = k * forward;
+                let row = |i: usize| {
+                    interpolate(
+                        &self.node_strikes,
+                        &vols[i * n_strikes..(i + 1) * n_strikes],
+                        strike,
+                    )
+                };
+                let vol = if w == 0.0 {
+                    row(i)
+                } else {
+                    (1.0 - w) * row(i) + w * row(i + 1)
+                };
+                *aj = 0.5 * vol * vol * k * k / (self.dk * self.dk) * dt;
+            }
+            theta_step(&mut c, &a, theta);
+
+            if s + 1 == self.node_steps[node] {
+                let f = self.node_forwards[node];
+                for &strike in &self.node_strikes {
+                    let x = strike / f / self.dk;
+                    let j = (x.floor() as usize).min(GRID_POINTS - 2);
+                    let u = x - j as Real;
+                    prices.push((1.0 - u) * c[j] + u * c[j + 1]);
+                }
+                node += 1;
+            }
+        }
+        prices
+    }
+}
+
+/// One θ-scheme step of `c_t = a·(c_{j−1} − 2c_j + c_{j+1})`, with `a`
+/// already scaled by `dt/dk²` and the boundary values held fixed.
+fn theta_step(c: &mut [Real], a: &[Real], theta: Real) {
+    let n = c.len();
+    let explicit = 1.0 - theta;
+    let mut rhs: Vec<Real> = c.to_vec();
+    for j in 1..n - 1 {
+        rhs[j] = c[j] + explicit * a[j] * (c[j - 1] - 2.0 * c[j] + c[j + 1]);
+    }
+
+    // Thomas solve of (1 + 2θa) c_j − θa (c_{j−1} + c_{j+1}) = rhs_j.
+    let mut sup = vec![0.0; n];
+    let mut d = vec![0.0; n];
+    d[0] = rhs[0];
+    for j in 1..n - 1 {
+        let off = -theta * a[j];
+        let denom = 1.0 + 2.0 * theta * a[j] - off * sup[j - 1];
+        sup[j] = off / denom;
+        d[j] = (rhs[j] - off * d[j - 1]) / denom;
+    }
+    c[n - 1] = rhs[n - 1];
+    for j in (1..n - 1).rev() {
+        c[j] = d[j] - sup[j] * c[j + 1];
+    }
+}
+
+/// Index `i` and weight `w` such that `x ≈ (1 − w)·xs[i] + w·xs[i + 1]`,
+/// with `w = 0` flat outside the range.
+fn bracket(xs: &[Real], x: Real) -> (usize, Real) {
+    let n = xs.len();
+    if x <= xs[0] {
+        return (0, 0.0);
+    }
+    if x >= xs[n - 1] {
+        return (n - 1, 0.0);
+    }
+    let i = xs.partition_point(|&v| v <= x) - 1;
+    (i, (x - xs[i]) / (xs[i + 1] - xs[i]))
+}
+
+/// Linear interpolation of `ys` on `xs`, flat outside the range.
+fn interpolate(xs: &[Real], ys: &[Real], x: Real) -> Real {
+    let (i, w) = bracket(xs, x);
+    if w == 0.0 {
+        ys[i]
+    } else {
+        (1.0 - w) * ys[i] + w * ys[i + 1]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::flat_forward::FlatForward;
+    use crate::local_vol_surface::LocalVolSurface;
+    use ql_time::Actual365Fixed;
+
+    fn ref_date() -> Date {
+        Date::from_ymd(2025, 1, 2).unwrap()
+    }
+
+    /// Smooth strike skew: σ(K) = 0.20 − 0.10·ln(K/100).
+    #[derive(Debug)]
+    struct SkewedBlackVol;
+
+    impl TermStructure for SkewedBlackVol {
+        fn reference_date(&self) -> Date {
+            ref_date()
+        }
+        fn day_counter(&self) -> &dyn DayCounter {
+            &Actual365Fixed
+        }
+        fn calendar(&self) -> &dyn Calendar {
+            &NullCalendar
+        }
+        fn max_date(&self) -> Date {
+            Date::MAX
+        }
+    }
+
+    impl VolatilityTermStructure for SkewedBlackVol {
+        fn min_strike(&self) -> Real {
+            0.0
+        }
+        fn max_strike(&self) -> Real {
+            Real::MAX
+        }
+    }
+
+    impl BlackVolTermStructure for SkewedBlackVol {
+        fn black_vol_impl(&self, _t: Time, strike: Real) -> Real {
+            0.20 - 0.10 * (strike / 100.0).ln()
+        }
+    }
+
+    fn calibrate(lambda: Real) -> (RegularizedLocalVolSurface, LocalVolSurface) {
+        let rf: Arc<dyn YieldTermStructure> =
+            Arc::new(FlatForward::continuous(ref_date(), 0.03, Actual365Fixed));
+        let div: Arc<dyn YieldTermStructure> =
+            Arc::new(FlatForward::continuous(ref_date(), 0.01, Actual365Fixed));
+        let black: Arc<dyn BlackVolTermStructure> = Arc::new(SkewedBlackVol);
+        let times = [0.25, 0.5, 1.0, 1.5];
+        let strikes = [70.0, 80.0, 90.0, 100.0, 110.0, 120.0, 130.0];
+        let surface = RegularizedLocalVolSurface::new(
+            black.clone(),
+            rf.clone(),
+            div.clone(),
+            100.0,
+            &times,
+            &strikes,
+            lambda,
+            Actual365Fixed,
+        )
+        .unwrap();
+        let dupire = LocalVolSurface::new(black, rf, div, 100.0, Actual365Fixed);
+        (surface, dupire)
+    }
+
+    fn max_abs(rows: &[Vec<Real>]) -> Real {
+        rows.iter().flatten().fold(0.0, |m, v| m.max(v.abs()))
+    }
+
+    #[test]
+    fn small_lambda_reproduces_dupire() {
+        let (surface, dupire) = calibrate(1e-8);
+        assert!(max_abs(surface.price_errors()) < 1e-5);
+
+        // The wing nodes also carry the flat extrapolation beyond them, so
+        // only interior strikes are compared.
+        let strikes = surface.strikes();
+        for (i, &t) in surface.times().iter().enumerate() {
+            let interior = 1..strikes.len() - 1;
+            for (&k, &fitted) in strikes[interior.clone()]
+                .iter()
+                .zip(&surface.local_vols()[i][interior])
+            {
+                let expected = dupire.local_vol_impl(t, k);
+                assert!(
+                    (fitted - expected).abs() < 0.01,
+                    "t = {t}, K = {k}: fitted {fitted:.4} vs Dupire {expected:.4}"
+                );
+                assert_eq!(surface.local_vol_impl(t, k), fitted);
+            }
+        }
+    }
+
+    #[test]
+    fn larger_lambda_smooths_surface() {
+        let (raw, _) = calibrate(1e-8);
+        let (smooth, _) = calibrate(1e-2);
+        assert_eq!(smooth.lambda(), 1e-2);
+        assert!(
+            smooth.roughness() < 0.25 * raw.roughness(),
+            "roughness {:.3e} vs raw {:.3e}",
+            smooth.roughness(),
+            raw.roughness()
+        );
+        // Two cents on a spot of 100.
+        assert!(max_abs(smooth.price_errors()) < 0.02);
+    }
+
+    #[test]
+    fn rejects_invalid_grid() {
+        let rf: Arc<dyn YieldTermStructure> =
+            Arc::new(FlatForward::continuous(ref_date(), 0.03, Actual365Fixed));
+        let build = |times: &[Time], lambda: Real| {
+            RegularizedLocalVolSurface::new(
+                Arc::new(SkewedBlackVol),
+                rf.clone(),
+                rf.clone(),
+                100.0,
+                times,
+                &[90.0, 100.0, 110.0],
+                lambda,
+                Actual365Fixed,
+            )
+        };
+        assert!(build(&[0.5, 0.25], 0.0).is_err());
+        assert!(build(&[0.25, 0.5], -1.0).is_err());
+    }
+}
@@ -10,8 +10,8 @@
 //! * `calibrate_sabr_surface` — calibrate SABR smiles across multiple expiries
 //! * `calibrate_svi_surface` — calibrate SVI smiles across multiple expiries
 
-use ql_core::{Real, Time, Volatility};
-use ql_math::interpolations::sabr::{calibrate_sabr, SabrParameters};
+use ql_core::{ensure, errors::Result, Real, Time, Volatility};
+use ql_math::interpolations::sabr::{calibrate_sabr, sabr_volatility, SabrParameters};
 
 use crate::smile_section::{
     calibrate_svi, SabrSmileSection, SmileSection, SviParameters, SviSmileSection,
@@ -64,6 +64,9 @@ pub struct SmileCalibrationResult<P: Clone + std::fmt::Debug> {
 pub struct SmileSurface {
     /// Per-expiry smile sections, sorted by expiry time.
     sections: Vec<(Time, Box<dyn SmileSection>)>,
+    /// Forward and parameters of the sections added as SABR fits, sorted by
+    /// expiry time.
+    sabr: Vec<(Time, Real, SabrParameters)>,
 }
 
 impl SmileSurface {
@@ -71,6 +74,7 @@ impl SmileSurface {
     pub fn new() -> Self {
         Self {
             sections: Vec::new(),
+            sabr: Vec::new(),
         }
     }
 
@@ -80,6 +84,21 @@ impl SmileSurface {
         self.sections.sort_by(|a, b| a.0.partial_cmp(&b.0).unwrap());
     }
 
+    /// Add a SABR smile for a given expiry.
+    ///
+    /// When every section of the surface is a SABR fit, [`volatility`]
+    /// interpolates the parameters themselves between expiries.
+    ///
+    /// [`volatility`]: Self::volatility
+    pub fn add_sabr_section(&mut self, expiry: Time, forward: Real, params: SabrParameters) {
+        self.add_section(
+            expiry,
+            Box::new(SabrSmileSection::new(expiry, forward, params)),
+        );
+        self.sabr.push((expiry, forward, params));
+        self.sabr.sort_by(|a, b| a.0.partial_cmp(&b.0).unwrap());
+    }
+
     /// Number of expiry slices.
     pub fn num_expiries(&self) -> usize {
         self.sections.len()
@@ -97,22 +116,29 @@ impl SmileSurface {
 
     /// Interpolate implied volatility at an arbitrary (time, strike).
     ///
-    /// Uses flat extrapolation in time beyond the surface boundaries, and
-    /// linear interpolation in variance between adjacent expiries.
-    pub fn volatility(&self, t: Time, strike: Real) -> Volatility {
+    /// Uses flat extrapolation in time beyond the surface boundaries. Between
+    /// adjacent expiries, a surface made only of SABR fits interpolates
+    /// `alpha`, `beta`, `rho`, `nu` and the forward linearly in time and
+    /// evaluates the SABR smile at `t`; any other surface interpolates total
+    /// variance linearly.
+    ///
+    /// # Errors
+    /// Fails if `t` is negative.
+    pub fn volatility(&self, t: Time, strike: Real) -> Result<Volatility> {
+        ensure!(t >= 0.0, "negative time ({t}) given");
         if self.sections.is_empty() {
-            return 0.0;
+            return Ok(0.0);
         }
 
         // Before the first expiry
         if t <= self.sections[0].0 {
-            return self.sections[0].1.volatility(strike);
+            return Ok(self.sections[0].1.volatility(strike));
         }
 
         // After the last expiry
         let n = self.sections.len();
         if t >= self.sections[n - 1].0 {
-            return self.sections[n - 1].1.volatility(strike);
+            return Ok(self.sections[n - 1].1.volatility(strike));
         }
 
         // Find the bracketing expiries
@@ -123,6 +149,20 @@ impl SmileSurface {
 
         let t1 = self.sections[i].0;
         let t2 = self.sections[i + 1].0;
+        if self.sabr.len() == n {
+            let (_, f1, p1) = self.sabr[i];
+            let (_, f2, p2) = self.sabr[i + 1];
+            let w = (t - t1) / (t2 - t1);
+            let lerp = |a: Real, b: Real| a + w * (b - a);
+            let params = SabrParameters {
+                alpha: lerp(p1.alpha, p2.alpha),
+                beta: lerp(p1.beta, p2.beta),
+                nu: lerp(p1.nu, p2.nu),
+                rho: lerp(p1.rho, p2.rho),
+            };
+            return Ok(sabr_volatility(lerp(f1, f2), strike, t, &params));
+        }
+
         let v1 = self.sections[i].1.volatility(strike);
         let v2 = self.sections[i + 1].1.volatility(strike);
 
@@ -133,9 +173,18 @@ impl SmileSurface {
         let w = w1 + alpha * (w2 - w1);
 
         if w <= 0.0 || t <= 0.0 {
-            return 0.0;
+            return Ok(0.0);
         }
-        (w / t).sqrt()
+        Ok((w / t).sqrt())
+    }
+
+    /// Total Black variance `σ²(t, K)·t`.
+    ///
+    /// # Errors
+    /// Fails if `t` is negative.
+    pub fn black_variance(&self, t: Time, strike: Real) -> Result<Real> {
+        let vol = self.volatility(t, strike)?;
+        Ok(vol * vol * t)
     }
 }
 
@@ -186,8 +235,7 @@ pub fn calibrate_sabr_surface(
             max_error: max_err,
         });
 
-        let section = SabrSmileSection::new(data.expiry, data.forward, params);
-        surface.add_section(data.expiry, Box::new(section));
+        surface.add_sabr_section(data.expiry, data.forward, params);
     }
 
     (results, surface)
@@ -208,7 +256,14 @@ pub fn calibrate_svi_surface(
     let mut surface = SmileSurface::new();
 
     for data in market_data {
-        let params = calibrate_svi(data.forward, data.expiry, &data.strikes, &data.vols, None);
+        let params = calibrate_svi(
+            data.forward,
+            data.expiry,
+            &data.strikes,
+            &data.vols,
+            None,
+            false,
+        );
 
         // Compute calibration errors
         let (rms, max_err) = calibration_errors(data, |k| {
@@ -348,13 +403,13 @@ mod tests {
         let (_, surface) = calibrate_sabr_surface(&data, 0.5);
 
         // At exactly T=0.5 and T=1.0, should match the sections
-        let v05 = surface.volatility(0.5, 0.04);
-        let v10 = surface.volatility(1.0, 0.04);
+        let v05 = surface.volatility(0.5, 0.04).unwrap();
+        let v10 = surface.volatility(1.0, 0.04).unwrap();
         assert!(v05 > 0.0);
         assert!(v10 > 0.0);
 
         // At T=0.75, should interpolate in variance
-        let v075 = surface.volatility(0.75, 0.04);
+        let v075 = surface.volatility(0.75, 0.04).unwrap();
         assert!(v075 > 0.0);
     }
 
@@ -365,13 +420,52 @@ mod tests {
         let (_, surface) = calibrate_sabr_surface(&data, 0.5);
 
         // Before first expiry — should use first section
-        let v_before = surface.volatility(0.1, 0.04);
+        let v_before = surface.volatility(0.1, 0.04).unwrap();
         let v_first = surface.section(0).unwrap().volatility(0.04);
         assert_abs_diff_eq!(v_before, v_first, epsilon = 1e-10);
 
         // After last expiry — should use last section
-        let v_after = surface.volatility(2.0, 0.04);
+        let v_after = surface.volatility(2.0, 0.04).unwrap();
         let v_last = surface.section(1).unwrap().volatility(0.04);
         assert_abs_diff_eq!(v_after, v_last, epsilon = 1e-10);
     }
+
+    #[test]
+    fn sabr_surface_interpolates_parameters() {
+        let fit = |alpha| SabrParameters {
+            alpha,
+            beta: 0.5,
+            nu: 0.3,
+            rho: -0.25,
+        };
+        let mut surface = SmileSurface::new();
+        surface.add_sabr_section(2.0, 0.04, fit(0.05));
+        surface.add_sabr_section(0.5, 0.04, fit(0.03));
+
+        let atm = |t| surface.volatility(t, 0.04).unwrap();
+        assert_abs_diff_eq!(atm(0.5), sabr_volatility(0.04, 0.04, 0.5, &fit(0.03)));
+        assert_abs_diff_eq!(atm(2.0), sabr_volatility(0.04, 0.04, 2.0, &fit(0.05)));
+        let mid = atm(1.25);
+        assert_abs_diff_eq!(mid, sabr_volatility(0.04, 0.04, 1.25, &fit(0.04)));
+
+        let mut previous = atm(0.5);
+        for i in 1..=15 {
+            let vol = atm(0.5 + 0.1 * i as Real);
+            assert!(vol > previous, "ATM vol not increasing at step {i}");
+            previous = vol;
+        }
+        assert_abs_diff_eq!(atm(5.0), surface.section(1).unwrap().volatility(0.04));
+        assert_abs_diff_eq!(
+            surface.black_variance(1.25, 0.04).unwrap(),
+            mid * mid * 1.25
+        );
+    }
+
+    #[test]
+    fn smile_surface_rejects_negative_time() {
+        let data = vec![make_sabr_data(0.5, 0.04)];
+        let (_, surface) = calibrate_sabr_surface(&data, 0.5);
+        assert!(surface.volatility(-0.1, 0.04).is_err());
+        assert!(surface.black_variance(-0.1, 0.04).is_err());
+    }
 }
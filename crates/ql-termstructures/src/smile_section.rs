@@ -7,7 +7,7 @@
 //!
 //! Corresponds to `QuantLib::SmileSection`.
 
-use ql_core::{Real, Time, Volatility};
+use ql_core::{ensure, errors::Result, Real, Time, Volatility};
 
 /// Volatility type indicator.
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
@@ -290,10 +290,35 @@ impl SmileSection for FlatSmileSection {
 
 use ql_math::interpolations::sabr::{sabr_volatility, SabrParameters};
 
+/// Wing extrapolation of a [`SabrSmileSection`] beyond a strike cutoff.
+///
+/// All modes work on total variance `w = σ²T` as a function of
+/// log-moneyness `x = ln(K/F)` (of shifted strike and forward), starting
+/// from the Hagan value `w_c` and slope `w'_c` at the cutoff `x_c`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SmileExtrapolation {
+    /// Volatility held at its cutoff value.
+    ///
+    /// Only continuous at the cutoff: on a skewed smile the kink in the
+    /// volatility puts a point mass (of either sign) into the density there.
+    Flat,
+    /// `w = w_c + s·(x − x_c)`, with the slope `s = w'_c` forced to point
+    /// away from the money and capped at Lee's moment bound `|s| ≤ 2`.
+    Linear,
+    /// Power law `w = w_c·(x/x_c)^p` with `p = x_c·w'_c/w_c` clamped to
+    /// `[0, 1]`, so the variance grows no faster than linearly in `|x|`
+    /// (Gatheral, *The Volatility Surface*, ch. 3).
+    Gatheral,
+}
+
 /// A SABR-based smile section.
 ///
 /// Wraps SABR parameters and a forward price to produce implied vols via the
-/// Hagan et al. (2002) formula.
+/// Hagan et al. (2002) formula.  Far from the money the expansion can imply
+/// a negative density; beyond optional strike cutoffs the volatility is
+/// then extrapolated instead, see [`SmileExtrapolation`].  The wings start
+/// from the Hagan smile at the cutoff, so cutoffs belong where its density
+/// is still positive.
 ///
 /// Corresponds to `QuantLib::SabrSmileSection`.
 #[derive(Debug, Clone)]
@@ -303,6 +328,8 @@ pub struct SabrSmileSection {
     params: SabrParameters,
     shift: Real,
     vol_type: VolatilityType,
+    left_extrapolation: Option<(Real, SmileExtrapolation)>,
+    right_extrapolation: Option<(Real, SmileExtrapolation)>,
 }
 
 impl SabrSmileSection {
@@ -318,9 +345,48 @@ impl SabrSmileSection {
             params,
             shift: 0.0,
             vol_type: VolatilityType::ShiftedLognormal,
+            left_extrapolation: None,
+            right_extrapolation: None,
         }
     }
 
+    /// Extrapolate the volatility below `cutoff` (which must lie below the
+    /// forward) instead of using the Hagan formula.
+    ///
+    /// # Errors
+    /// Fails if `cutoff` is not between the strike floor `−shift` and the
+    /// forward.
+    pub fn with_left_extrapolation(
+        mut self,
+        cutoff: Real,
+        mode: SmileExtrapolation,
+    ) -> Result<Self> {
+        ensure!(
+            cutoff > -self.shift && cutoff < self.forward,
+            "left cutoff must lie between the strike floor and the forward"
+        );
+        self.left_extrapolation = Some((cutoff, mode));
+        Ok(self)
+    }
+
+    /// Extrapolate the volatility above `cutoff` (which must lie above the
+    /// forward) instead of using the Hagan formula.
+    ///
+    /// # Errors
+    /// Fails if `cutoff` is not above the forward.
+    pub fn with_right_extrapolation(
+        mut self,
+        cutoff: Real,
+        mode: SmileExtrapolation,
+    ) -> Result<Self> {
+        ensure!(
+            cutoff > self.forward,
+            "right cutoff must lie above the forward"
+        );
+        self.right_extrapolation = Some((cutoff, mode));
+        Ok(self)
+    }
+
     /// Create with shift and volatility type.
     pub fn with_shift(mut self, shift: Real, vol_type: VolatilityType) -> Self {
         self.shift = shift;
@@ -337,6 +403,56 @@ impl SabrSmileSection {
     pub fn forward(&self) -> Real {
         self.forward
     }
+
+    /// Hagan volatility, with strikes floored just above `−shift`.
+    fn hagan_volatility(&self, strike: Real) -> Volatility {
+        let k = strike.max(1e-5 - self.shift);
+        sabr_volatility(self.forward, k, self.exercise_time, &self.params)
+    }
+
+    /// Hagan total variance at log-moneyness `x`.
+    fn hagan_variance(&self, x: Real) -> Real {
+        let strike = (self.forward + self.shift) * x.exp() - self.shift;
+        let vol = self.hagan_volatility(strike);
+        vol * vol * self.exercise_time
+    }
+
+    /// Extrapolated volatility at `strike`, beyond `cutoff` on the side
+    /// given by `left`.
+    fn wing_volatility(
+        &self,
+        strike: Real,
+        cutoff: Real,
+        mode: SmileExtrapolation,
+        left: bool,
+    ) -> Volatility {
+        let log_moneyness =
+            |k: Real| ((k.max(1e-5 - self.shift) + self.shift) / (self.forward + self.shift)).ln();
+        let x = log_moneyness(strike);
+        let x_c = log_moneyness(cutoff);
+        let w_c = self.hagan_variance(x_c);
+        let slope = || {
+            let h = 1e-4;
+            (self.hagan_variance(x_c + h) - self.hagan_variance(x_c - h)) / (2.0 * h)
+        };
+
+        let w = match mode {
+            SmileExtrapolation::Flat => return self.hagan_volatility(cutoff),
+            SmileExtrapolation::Linear => {
+                let s = if left {
+                    slope().clamp(-2.0, 0.0)
+                } else {
+                    slope().clamp(0.0, 2.0)
+                };
+                w_c + s * (x - x_c)
+            }
+            SmileExtrapolation::Gatheral => {
+                let p = (x_c * slope() / w_c).clamp(0.0, 1.0);
+                w_c * (x / x_c).powf(p)
+            }
+        };
+        (w / self.exercise_time).sqrt()
+    }
 }
 
 impl SmileSection for SabrSmileSection {
@@ -353,8 +469,15 @@ impl SmileSection for SabrSmileSection {
     }
 
     fn volatility_impl(&self, strike: Real) -> Volatility {
-        let k = strike.max(1e-5 - self.shift);
-        sabr_volatility(self.forward, k, self.exercise_time, &self.params)
+        match (self.left_extrapolation, self.right_extrapolation) {
+            (Some((cutoff, mode)), _) if strike < cutoff => {
+                self.wing_volatility(strike, cutoff, mode, true)
+            }
+            (_, Some((cutoff, mode))) if strike > cutoff => {
+                self.wing_volatility(strike, cutoff, mode, false)
+            }
+            _ => self.hagan_volatility(strike),
+        }
     }
 
     fn exercise_time(&self) -> Time {
@@ -423,6 +546,42 @@ impl SviParameters {
             min_var
         );
     }
+
+    /// Gatheral's butterfly function at log-moneyness `k`:
+    ///
+    /// $g(k) = \bigl(1 - \frac{k w'}{2w}\bigr)^2 - \frac{w'^2}{4}\bigl(\frac{1}{w} + \frac{1}{4}\bigr) + \frac{w''}{2}$
+    ///
+    /// The slice is free of butterfly arbitrage iff `g ≥ 0` everywhere.
+    pub fn butterfly_g(&self, k: Real) -> Real {
+        let km = k - self.m;
+        let root = (km * km + self.sigma * self.sigma).sqrt();
+        let w = svi_total_variance(self, k);
+        let w1 = self.b * (self.rho + km / root);
+        let w2 = self.b * self.sigma * self.sigma / (root * root * root);
+        let term = 1.0 - k * w1 / (2.0 * w);
+        term * term - 0.25 * w1 * w1 * (1.0 / w + 0.25) + 0.5 * w2
+    }
+
+    /// Whether the slice is free of butterfly arbitrage (Gatheral &
+    /// Jacquier, 2014).
+    ///
+    /// Requires positive total variance, wings no steeper than Lee's bound
+    /// `b(1 + |ρ|) ≤ 2` (the limit of `g` at `|k| → ∞`), and `g(k) ≥ 0` on a
+    /// dense grid of `m ± 5` plus ten smoothness lengths `σ`.
+    pub fn is_butterfly_arbitrage_free(&self) -> bool {
+        if self.a + self.b * self.sigma * (1.0 - self.rho * self.rho).sqrt() <= 0.0 {
+            return false;
+        }
+        if self.b * (1.0 + self.rho.abs()) > 2.0 {
+            return false;
+        }
+        let half_width = 5.0 + 10.0 * self.sigma;
+        let n = 4000;
+        (0..=n).all(|i| {
+            let k = self.m - half_width + 2.0 * half_width * i as Real / n as Real;
+            self.butterfly_g(k) >= 0.0
+        })
+    }
 }
 
 /// Compute SVI total variance at log-moneyness k.
@@ -466,6 +625,27 @@ impl SviSmileSection {
     pub fn forward(&self) -> Real {
         self.forward
     }
+
+    /// Risk-neutral density of the underlying at expiry implied by the
+    /// slice, in closed form (undiscounted):
+    ///
+    /// $p(K) = \frac{g(k)}{K \sqrt{2\pi w(k)}} \exp\bigl(-\tfrac{1}{2} d_-^2\bigr),
+    /// \quad d_- = -\frac{k}{\sqrt{w}} - \frac{\sqrt{w}}{2}$
+    ///
+    /// Negative values flag butterfly arbitrage.
+    pub fn density(&self, strike: Real) -> Real {
+        if strike <= 0.0 {
+            return 0.0;
+        }
+        let k = (strike / self.forward).ln();
+        let w = svi_total_variance(&self.params, k);
+        if w <= 0.0 {
+            return 0.0;
+        }
+        let d_minus = -k / w.sqrt() - 0.5 * w.sqrt();
+        self.params.butterfly_g(k) * (-0.5 * d_minus * d_minus).exp()
+            / (strike * (2.0 * std::f64::consts::PI * w).sqrt())
+    }
 }
 
 impl SmileSection for SviSmileSection {
@@ -487,6 +667,11 @@ impl SmileSection for SviSmileSection {
         (total_var.max(0.0) / self.exercise_time).sqrt()
     }
 
+    /// Closed-form density; `gap` is not needed.
+    fn density(&self, strike: Real, discount: Real, _gap: Real) -> Real {
+        discount * SviSmileSection::density(self, strike)
+    }
+
     fn exercise_time(&self) -> Time {
         self.exercise_time
     }
@@ -502,14 +687,21 @@ impl SmileSection for SviSmileSection {
 /// * `strikes` — market strikes
 /// * `vols` — market implied Black vols
 /// * `initial` — initial guess for SVI parameters (if `None`, uses defaults)
+/// * `arbitrage_free` — penalise fits with butterfly arbitrage: negative
+///   [`SviParameters::butterfly_g`] on a log-moneyness grid spanning the
+///   quotes, and wings breaching Lee's bound
 ///
-/// Returns calibrated `SviParameters`.
+/// Returns calibrated `SviParameters`.  A penalised fit may still fail
+/// [`SviParameters::is_butterfly_arbitrage_free`] when the quotes
+/// themselves are arbitrageable; check it before building a local-vol
+/// surface.
 pub fn calibrate_svi(
     forward: Real,
     expiry: Real,
     strikes: &[Real],
     vols: &[Real],
     initial: Option<SviParameters>,
+    arbitrage_free: bool,
 ) -> SviParameters {
     let n = strikes.len();
     assert_eq!(n, vols.len());
@@ -521,6 +713,23 @@ pub fn calibrate_svi(
     // Convert vols to total variances
     let total_vars: Vec<Real> = vols.iter().map(|v| v * v * expiry).collect();
     let log_moneyness: Vec<Real> = strikes.iter().map(|k| (k / forward).ln()).collect();
+    let arbitrage_grid: Vec<Real> = if arbitrage_free {
+        let lo = log_moneyness
+            .iter()
+            .copied()
+            .fold(Real::INFINITY, Real::min)
+            - 1.0;
+        let hi = log_moneyness
+            .iter()
+            .copied()
+            .fold(Real::NEG_INFINITY, Real::max)
+            + 1.0;
+        (0..=200)
+            .map(|i| lo + (hi - lo) * i as Real / 200.0)
+            .collect()
+    } else {
+        Vec::new()
+    };
 
     let init = initial.unwrap_or_else(|| {
         let rho: Real = -0.4;
@@ -547,11 +756,22 @@ pub fn calibrate_svi(
             m: x[4],
         };
         let min_var = p.a + p.b * p.sigma * (1.0 - p.rho * p.rho).sqrt();
-        let penalty = if min_var < 0.0 {
+        let mut penalty = if min_var < 0.0 {
             1e6 * min_var * min_var
         } else {
             0.0
         };
+        if arbitrage_free && min_var > 0.0 {
+            let wing = (p.b * (1.0 + p.rho.abs()) - 2.0).max(0.0);
+            penalty += 1e6 * wing * wing;
+            // Aim slightly inside the admissible region so the optimum does
+            // not settle on marginally negative densities.
+            penalty += 1e6
+                * arbitrage_grid
+                    .iter()
+                    .map(|&k| (p.butterfly_g(k) - 1e-4).min(0.0).powi(2))
+                    .sum::<Real>();
+        }
 
         let mut sse = 0.0;
         for i in 0..n {
@@ -710,6 +930,96 @@ mod tests {
         assert_abs_diff_eq!(section.volatility(0.035), direct, epsilon = 1e-10);
     }
 
+    /// Five-year smile whose Hagan density is negative below about 0.1 %.
+    fn low_strike_sabr() -> SabrSmileSection {
+        let params = SabrParameters {
+            alpha: 0.35 * 0.03_f64.sqrt(),
+            beta: 0.5,
+            nu: 0.2,
+            rho: -0.5,
+        };
+        SabrSmileSection::new(5.0, 0.03, params)
+    }
+
+    #[test]
+    fn sabr_flat_extrapolation() {
+        let hagan = low_strike_sabr();
+        let section = hagan
+            .clone()
+            .with_left_extrapolation(0.01, SmileExtrapolation::Flat)
+            .unwrap()
+            .with_right_extrapolation(0.1, SmileExtrapolation::Flat)
+            .unwrap();
+        assert_abs_diff_eq!(
+            section.volatility(0.002),
+            hagan.volatility(0.01),
+            epsilon = 1e-15
+        );
+        assert_abs_diff_eq!(
+            section.volatility(0.3),
+            hagan.volatility(0.1),
+            epsilon = 1e-15
+        );
+        assert_abs_diff_eq!(
+            section.volatility(0.03),
+            hagan.volatility(0.03),
+            epsilon = 1e-15
+        );
+    }
+
+    #[test]
+    fn sabr_cutoffs_on_the_wrong_side_are_errors() {
+        let hagan = low_strike_sabr();
+        let mode = SmileExtrapolation::Linear;
+        assert!(hagan.clone().with_left_extrapolation(0.05, mode).is_err());
+        assert!(hagan.clone().with_left_extrapolation(-0.01, mode).is_err());
+        assert!(hagan.clone().with_right_extrapolation(0.02, mode).is_err());
+        assert!(hagan.with_right_extrapolation(0.05, mode).is_ok());
+    }
+
+    #[test]
+    fn sabr_wing_extrapolation_gives_valid_density() {
+        let hagan = low_strike_sabr();
+        assert!(hagan.density(0.0005, 1.0, 1e-5) < -1.0);
+
+        for mode in [SmileExtrapolation::Linear, SmileExtrapolation::Gatheral] {
+            let section = hagan
+                .clone()
+                .with_left_extrapolation(0.006, mode)
+                .unwrap()
+                .with_right_extrapolation(0.12, mode)
+                .unwrap();
+            // Both wings are C¹ at the cutoffs.
+            for cutoff in [0.006, 0.12] {
+                let (below, above) = (section.volatility(cutoff - 1e-9), hagan.volatility(cutoff));
+                assert_abs_diff_eq!(below, above, epsilon = 1e-7);
+            }
+
+            let dk = 1e-4;
+            let strikes: Vec<Real> = (1..5000).map(|i| i as Real * dk).collect();
+            let densities: Vec<Real> = strikes
+                .iter()
+                .map(|&k| section.density(k, 1.0, 1e-5))
+                .collect();
+            for (&k, &d) in strikes.iter().zip(&densities) {
+                assert!(d > -1e-6, "{mode:?}: density {d:.3e} at K = {k}");
+            }
+
+            // The density integrates to one, counting the mass below the grid,
+            // and reproduces the forward.
+            let below = section.digital_option_price(0.5 * dk, SmileOptionType::Put, 1.0, 1e-5);
+            let mass: Real = densities.iter().sum::<Real>() * dk + below;
+            let mean: Real = strikes
+                .iter()
+                .zip(&densities)
+                .map(|(k, d)| k * d)
+                .sum::<Real>()
+                * dk;
+            assert!((mass - 1.0).abs() < 5e-3, "{mode:?}: mass {mass}");
+            assert!((mean - 0.03).abs() < 1e-3, "{mode:?}: mean {mean}");
+        }
+    }
+
     #[test]
     fn svi_total_variance_at_m() {
         // At k = m: w(m) = a + b * sigma
@@ -769,7 +1079,7 @@ mod tests {
             })
             .collect();
 
-        let calibrated = calibrate_svi(forward, expiry, &strikes, &vols, None);
+        let calibrated = calibrate_svi(forward, expiry, &strikes, &vols, None, false);
 
         // Check that the calibrated model reproduces the input vols
         for i in 0..strikes.len() {
@@ -785,4 +1095,103 @@ mod tests {
             );
         }
     }
+
+    /// Axel Vogt's slice from Gatheral & Jacquier (2014): positive total
+    /// variance everywhere, yet a negative density near `k ≈ 1`.
+    fn vogt_params() -> SviParameters {
+        SviParameters {
+            a: -0.0410,
+            b: 0.1331,
+            sigma: 0.4153,
+            rho: 0.3060,
+            m: 0.3586,
+        }
+    }
+
+    fn clean_params() -> SviParameters {
+        SviParameters {
+            a: 0.04,
+            b: 0.2,
+            sigma: 0.1,
+            rho: -0.3,
+            m: 0.0,
+        }
+    }
+
+    #[test]
+    fn svi_butterfly_check_flags_vogt_slice() {
+        let vogt = vogt_params();
+        assert!(!vogt.is_butterfly_arbitrage_free());
+        assert!(vogt.butterfly_g(0.9) < 0.0);
+        let section = SviSmileSection::new(1.0, 1.0, vogt);
+        assert!(section.density(0.9_f64.exp()) < 0.0);
+
+        assert!(clean_params().is_butterfly_arbitrage_free());
+        // Wings steeper than Lee's bound are rejected even with g ≥ 0 nearby.
+        let steep = SviParameters {
+            b: 1.8,
+            ..clean_params()
+        };
+        assert!(!steep.is_butterfly_arbitrage_free());
+    }
+
+    #[test]
+    fn svi_density_is_a_probability_density() {
+        let (forward, expiry) = (100.0, 1.0);
+        let section = SviSmileSection::new(expiry, forward, clean_params());
+        let dk = 0.05;
+        let (mut mass, mut mean) = (0.0, 0.0);
+        for i in 1..20_000 {
+            let k = i as Real * dk;
+            let p = section.density(k);
+            assert!(p >= 0.0, "negative density at K={k}");
+            mass += p * dk;
+            mean += k * p * dk;
+        }
+        assert!((mass - 1.0).abs() < 1e-4, "mass {mass}");
+        // The right wing is cut off at K = 1000.
+        assert!((mean - forward).abs() < 0.05, "mean {mean}");
+
+        // The closed form agrees with finite differences of Black prices.
+        for strike in [80.0, 100.0, 125.0] {
+            let closed = SmileSection::density(&section, strike, 0.95, 0.01);
+            let numeric = black_density_fd(&section, strike, 0.95, 0.1);
+            assert!(
+                (closed / numeric - 1.0).abs() < 1e-4,
+                "K={strike}: {closed} vs {numeric}"
+            );
+        }
+    }
+
+    /// The default trait density: second difference of Black call prices.
+    fn black_density_fd(
+        section: &SviSmileSection,
+        strike: Real,
+        discount: Real,
+        gap: Real,
+    ) -> Real {
+        let call = |k| section.option_price(k, SmileOptionType::Call, discount);
+        (call(strike - gap) - 2.0 * call(strike) + call(strike + gap)) / (gap * gap)
+    }
+
+    #[test]
+    fn svi_calibration_can_penalise_arbitrage() {
+        let (forward, expiry) = (1.0, 1.0);
+        let strikes: Vec<Real> = (-10..=25).map(|i| (0.1 * i as Real).exp()).collect();
+        let vols: Vec<Real> = strikes
+            .iter()
+            .map(|&k| (svi_total_variance(&vogt_params(), k.ln()) / expiry).sqrt())
+            .collect();
+
+        let free = calibrate_svi(forward, expiry, &strikes, &vols, Some(vogt_params()), false);
+        assert!(!free.is_butterfly_arbitrage_free());
+
+        let penalised = calibrate_svi(forward, expiry, &strikes, &vols, Some(vogt_params()), true);
+        assert!(penalised.is_butterfly_arbitrage_free());
+        // The arbitrage-free fit stays close to the quotes.
+        for (&k, &vol) in strikes.iter().zip(&vols) {
+            let fit = svi_total_variance(&penalised, k.ln()).sqrt();
+            assert!((fit - vol).abs() < 0.02, "K={k:.3}: {fit:.4} vs {vol:.4}");
+        }
+    }
 }
@@ -0,0 +1,286 @@
+//! `SwaptionVolatilityCube` — swaption volatilities by expiry, underlying
+//! swap tenor and strike.
+//!
+//! This is not a translation of QuantLib's SABR cube
+//! (`ql/termstructures/volatility/swaption/sabrswaptionvolatilitycube.hpp`),
+//! which calibrates SABR to quoted vol spreads and interpolates the
+//! parameters.  Here the SABR smiles are given, one per node of an ATM
+//! matrix over (expiry × swap tenor), and a query adds to the ATM vol the
+//! interpolated spread of the smiles over their own ATM vols,
+//!
+//! ```text
+//! σ(e, τ, K) = σ_ATM(e, τ) + Σ wᵢⱼ [σᵢⱼ(Fᵢⱼ + K − F(e, τ)) − σᵢⱼ(Fᵢⱼ)]
+//! ```
+//!
+//! with `wᵢⱼ` the bilinear weights of the four surrounding nodes and the
+//! forward `F(e, τ)` interpolated from the smiles' forwards.  Spreads are
+//! read at the same distance `K − F` from each node's forward, so a query
+//! at the money always returns the interpolated ATM vol.  Expiries and
+//! tenors outside the grid are held at the nearest node.
+
+use crate::smile_section::{SabrSmileSection, SmileSection};
+use ql_core::{ensure, errors::Result, Real, Time, Volatility};
+
+/// A swaption volatility cube built from an ATM matrix and per-node SABR
+/// smiles.
+///
+/// Plays the role of `QuantLib::SabrSwaptionVolatilityCube`, with the
+/// smiles supplied instead of calibrated.
+#[derive(Debug, Clone)]
+pub struct SwaptionVolatilityCube {
+    /// Option expiries in years (ascending).
+    option_times: Vec<Time>,
+    /// Underlying swap tenors in years (ascending).
+    swap_tenors: Vec<Time>,
+    /// `atm_vols[i][j]` — ATM vol for expiry `i` and tenor `j`.
+    atm_vols: Vec<Vec<Volatility>>,
+    /// `smiles[i][j]` — SABR smile for expiry `i` and tenor `j`.
+    smiles: Vec<Vec<SabrSmileSection>>,
+}
+
+impl SwaptionVolatilityCube {
+    /// Build a cube from its ATM matrix and node smiles.
+    ///
+    /// # Arguments
+    /// * `option_times` — option expiries in years (strictly ascending)
+    /// * `swap_tenors` — swap tenors in years (strictly ascending)
+    /// * `atm_vols` — `atm_vols[i][j]` for expiry `i`, tenor `j`
+    /// * `smiles` — `smiles[i][j]`, whose forward is the node's ATM strike
+    pub fn new(
+        option_times: &[Time],
+        swap_tenors: &[Time],
+        atm_vols: Vec<Vec<Volatility>>,
+        smiles: Vec<Vec<SabrSmileSection>>,
+    ) -> Result<Self> {
+        ensure!(!option_times.is_empty(), "need at least 1 option expiry");
+        ensure!(!swap_tenors.is_empty(), "need at least 1 swap tenor");
+        ensure!(
+            option_times.windows(2).all(|w| w[0] < w[1]),
+            "option expiries must be strictly ascending"
+        );
+        ensure!(
+            swap_tenors.windows(2).all(|w| w[0] < w[1]),
+            "swap tenors must be strictly ascending"
+        );
+        ensure!(
+            atm_vols.len() == option_times.len() && smiles.len() == option_times.len(),
+            "ATM vol and smile rows must match the option expiries"
+        );
+        for (i, (atm_row, smile_row)) in atm_vols.iter().zip(&smiles).enumerate() {
+            ensure!(
+                atm_row.len() == swap_tenors.len() && smile_row.len() == swap_tenors.len(),
+                "row {i} must have one ATM vol and one smile per swap tenor"
+            );
+        }
+        Ok(Self {
+            option_times: option_times.to_vec(),
+            swap_tenors: swap_tenors.to_vec(),
+            atm_vols,
+            smiles,
+        })
+    }
+
+    /// Option expiries of the grid.
+    pub fn option_times(&self) -> &[Time] {
+        &self.option_times
+    }
+
+    /// Swap tenors of the grid.
+    pub fn swap_tenors(&self) -> &[Time] {
+        &self.swap_tenors
+    }
+
+    /// The smile at node `(expiry_index, tenor_index)`.
+    pub fn smile(&self, expiry_index: usize, tenor_index: usize) -> &SabrSmileSection {
+        &self.smiles[expiry_index][tenor_index]
+    }
+
+    /// ATM volatility, bilinear in expiry and tenor.
+    pub fn atm_volatility(&self, option_time: Time, swap_tenor: Time) -> Volatility {
+        self.interpolate(option_time, swap_tenor, |i, j| self.atm_vols[i][j])
+    }
+
+    /// ATM strike (forward swap rate), bilinear in expiry and tenor.
+    pub fn atm_strike(&self, option_time: Time, swap_tenor: Time) -> Real {
+        self.interpolate(option_time, swap_tenor, |i, j| self.smiles[i][j].forward())
+    }
+
+    /// Volatility at `strike`: the ATM vol plus the interpolated smile
+    /// spread at the same distance from the money.
+    ///
+    /// # Errors
+    /// Fails if that distance puts the strike read from one of the
+    /// surrounding smiles at or below the smile's minimum strike.
+    pub fn volatility(
+        &self,
+        option_time: Time,
+        swap_tenor: Time,
+        strike: Real,
+    ) -> Result<Volatility> {
+        let moneyness = strike - self.atm_strike(option_time, swap_tenor);
+        let mut spread = 0.0;
+        let nodes = self.nodes(option_time, swap_tenor);
+        for (i, j, weight) in nodes.into_iter().filter(|&(.., w)| w > 0.0) {
+            let smile = &self.smiles[i][j];
+            let forward = smile.forward();
+            let node_strike = forward + moneyness;
+            ensure!(
+                node_strike > smile.min_strike(),
+                "strike {strike} maps to {node_strike} on the smile at expiry {} and tenor {}, \
+                 below its minimum strike {}",
+                self.option_times[i],
+                self.swap_tenors[j],
+                smile.min_strike()
+            );
+            spread += weight * (smile.volatility(node_strike) - smile.volatility(forward));
+        }
+        Ok(self.atm_volatility(option_time, swap_tenor) + spread)
+    }
+
+    /// Bilinear interpolation of `node(i, j)` over the grid, flat outside.
+    fn interpolate(
+        &self,
+        option_time: Time,
+        swap_tenor: Time,
+        node: impl Fn(usize, usize) -> Real,
+    ) -> Real {
+        self.nodes(option_time, swap_tenor)
+            .iter()
+            .map(|&(i, j, weight)| weight * node(i, j))
+            .sum()
+    }
+
+    /// The four grid nodes around `(option_time, swap_tenor)` with their
+    /// bilinear weights; nodes repeat, with weight zero, outside the grid.
+    fn nodes(&self, option_time: Time, swap_tenor: Time) -> [(usize, usize, Real); 4] {
+        let (i, u) = bracket(&self.option_times, option_time);
+        let (j, v) = bracket(&self.swap_tenors, swap_tenor);
+        let (i1, j1) = (
+            (i + 1).min(self.option_times.len() - 1),
+            (j + 1).min(self.swap_tenors.len() - 1),
+        );
+        [
+            (i, j, (1.0 - u) * (1.0 - v)),
+            (i, j1, (1.0 - u) * v),
+            (i1, j, u * (1.0 - v)),
+            (i1, j1, u * v),
+        ]
+    }
+}
+
+/// Index `i` and weight `w ∈ [0, 1]` such that `x` sits at
+/// `(1 − w)·xs[i] + w·xs[i + 1]`, clamped to the ends of `xs`.
+fn bracket(xs: &[Real], x: Real) -> (usize, Real) {
+    let n = xs.len();
+    if n == 1 || x <= xs[0] {
+        return (0, 0.0);
+    }
+    if x >= xs[n - 1] {
+        return (n - 2, 1.0);
+    }
+    let i = xs.partition_point(|&xi| xi <= x) - 1;
+    (i, (x - xs[i]) / (xs[i + 1] - xs[i]))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use approx::assert_abs_diff_eq;
+    use ql_math::interpolations::sabr::SabrParameters;
+
+    const EXPIRIES: [Time; 2] = [1.0, 5.0];
+    const TENORS: [Time; 2] = [2.0, 10.0];
+    const ATM: [[Volatility; 2]; 2] = [[0.30, 0.25], [0.22, 0.20]];
+    const FORWARDS: [[Real; 2]; 2] = [[0.030, 0.035], [0.038, 0.040]];
+
+    fn cube() -> SwaptionVolatilityCube {
+        let smiles = (0..2)
+            .map(|i| {
+                (0..2)
+                    .map(|j| {
+                        let params = SabrParameters {
+                            alpha: 0.03 + 0.01 * (i + j) as Real,
+                            beta: 0.5,
+                            nu: 0.4 - 0.1 * i as Real,
+                            rho: -0.2 - 0.1 * j as Real,
+                        };
+                        SabrSmileSection::new(EXPIRIES[i], FORWARDS[i][j], params)
+                    })
+                    .collect()
+            })
+            .collect();
+        let atm = ATM.iter().map(|row| row.to_vec()).collect();
+        SwaptionVolatilityCube::new(&EXPIRIES, &TENORS, atm, smiles).unwrap()
+    }
+
+    #[test]
+    fn atm_queries_return_input_vols() {
+        let cube = cube();
+        for (i, &e) in EXPIRIES.iter().enumerate() {
+            for (j, &tenor) in TENORS.iter().enumerate() {
+                let vol = cube.volatility(e, tenor, FORWARDS[i][j]).unwrap();
+                assert_abs_diff_eq!(vol, ATM[i][j], epsilon = 1e-15);
+            }
+        }
+        // In between, ATM is the bilinear blend of the nodes.
+        let centre = 0.25 * (ATM[0][0] + ATM[0][1] + ATM[1][0] + ATM[1][1]);
+        let atm_strike = cube.atm_strike(3.0, 6.0);
+        assert_abs_diff_eq!(
+            cube.volatility(3.0, 6.0, atm_strike).unwrap(),
+            centre,
+            epsilon = 1e-15
+        );
+    }
+
+    #[test]
+    fn smile_spread_is_applied_off_the_money() {
+        let cube = cube();
+        let smile = cube.smile(1, 0);
+        let (e, tenor, forward) = (EXPIRIES[1], TENORS[0], FORWARDS[1][0]);
+        for offset in [-0.01, -0.002, 0.005, 0.02] {
+            let expected =
+                ATM[1][0] + smile.volatility(forward + offset) - smile.volatility(forward);
+            let vol = cube.volatility(e, tenor, forward + offset).unwrap();
+            assert_abs_diff_eq!(vol, expected, epsilon = 1e-15);
+        }
+        // Negative rho: low strikes carry the higher vols.
+        assert!(cube.volatility(e, tenor, forward - 0.01).unwrap() > ATM[1][0]);
+    }
+
+    #[test]
+    fn flat_outside_the_grid() {
+        let cube = cube();
+        for strike in [0.02, 0.04, 0.06] {
+            assert_eq!(
+                cube.volatility(0.25, 1.0, strike).unwrap(),
+                cube.volatility(EXPIRIES[0], TENORS[0], strike).unwrap()
+            );
+            assert_eq!(
+                cube.volatility(10.0, 30.0, strike).unwrap(),
+                cube.volatility(EXPIRIES[1], TENORS[1], strike).unwrap()
+            );
+            assert_eq!(
+                cube.volatility(3.0, 30.0, strike).unwrap(),
+                cube.volatility(3.0, TENORS[1], strike).unwrap()
+            );
+        }
+    }
+
+    #[test]
+    fn strikes_below_a_smile_floor_are_errors() {
+        let cube = cube();
+        // In the middle of the grid the ATM strike is the mean forward,
+        // 3.575%, so the 3% smile is read 57.5bp below the query strike.
+        assert!(cube.volatility(3.0, 6.0, 0.007).is_ok());
+        assert!(cube.volatility(3.0, 6.0, 0.005).is_err());
+        // At a node only that node's smile is read.
+        assert!(cube.volatility(EXPIRIES[1], TENORS[1], 0.005).is_ok());
+    }
+
+    #[test]
+    fn rejects_mismatched_grids() {
+        let atm = vec![vec![0.2, 0.2]];
+        assert!(SwaptionVolatilityCube::new(&[1.0], &[2.0, 10.0], atm, vec![vec![]]).is_err());
+        assert!(SwaptionVolatilityCube::new(&[2.0, 1.0], &[2.0], vec![], vec![]).is_err());
+    }
+}
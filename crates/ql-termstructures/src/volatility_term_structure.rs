@@ -6,7 +6,8 @@
 
 use crate::term_structure::TermStructure;
 use ql_core::{Real, Time};
-use ql_time::BusinessDayConvention;
+use ql_time::{BusinessDayConvention, Calendar, Date, DayCounter};
+use std::sync::Arc;
 
 /// Base trait for all volatility term structures.
 ///
@@ -30,3 +31,34 @@ pub trait VolatilityTermStructure: TermStructure {
         _t
     }
 }
+
+// ── Helpers for concrete term structures ──────────────────────────────────────
+
+/// Common data shared by volatility-surface implementations.
+#[derive(Debug)]
+pub struct VolatilityTermStructureData {
+    /// Reference date.
+    pub reference_date: Date,
+    /// Calendar for option-expiry adjustments.
+    pub calendar: Box<dyn Calendar>,
+    /// Day counter for time calculations.
+    pub day_counter: Arc<dyn DayCounter>,
+    /// Business-day convention for option-expiry adjustments.
+    pub business_day_convention: BusinessDayConvention,
+}
+
+impl VolatilityTermStructureData {
+    /// Create a new data bundle with the `Following` convention.
+    pub fn new(
+        reference_date: Date,
+        calendar: impl Calendar + 'static,
+        day_counter: impl DayCounter + 'static,
+    ) -> Self {
+        Self {
+            reference_date,
+            calendar: Box::new(calendar),
+            day_counter: Arc::new(day_counter),
+            business_day_convention: BusinessDayConvention::Following,
+        }
+    }
+}
@@ -11,7 +11,7 @@
 //!   times
 
 use crate::term_structure::TermStructure;
-use ql_core::{Compounding, DiscountFactor, Rate, Real, Time};
+use ql_core::{errors::Result, fail, Compounding, DiscountFactor, Rate, Real, Time};
 use ql_time::{Date, DayCounter, Frequency, InterestRate};
 use std::sync::Arc;
 
@@ -131,6 +131,47 @@ pub trait YieldTermStructure: TermStructure {
         };
         InterestRate::implied_rate_time(compound, comp, freq, if t2 == t1 { DT } else { t2 - t1 })
     }
+
+    // ── Diagnostics ──────────────────────────────────────────────────────
+
+    /// Check that discount factors, zero rates and instantaneous forwards
+    /// agree with each other, up to `tolerance`.
+    ///
+    /// At a sample of times up to `max_time()` (or 30 years, if shorter) it
+    /// verifies that `P(t) = exp(−z(t)·t)` and that integrating the forward
+    /// over each sampling interval `[t₁, t₂]` recovers `ln(P(t₁)/P(t₂))`.
+    /// The error names the first offending time, or for the forward the
+    /// first sampling interval over which it fails. Meant for testing custom
+    /// curves, which may override more than one of the `*_impl` hooks.
+    fn self_consistency_check(&self, tolerance: Real) -> Result<()> {
+        const SAMPLES: usize = 40;
+        const SUBSTEPS: usize = 50;
+        let horizon = self.max_time().min(30.0);
+        let mut previous = 0.0;
+        for i in 1..=SAMPLES {
+            let t = horizon * i as Real / SAMPLES as Real;
+            let df = self.discount_impl(t);
+            let from_zero = (-self.zero_rate_impl(t) * t).exp();
+            if (df - from_zero).abs() > tolerance {
+                fail!("discount {df} and zero-rate discount {from_zero} differ at t = {t}");
+            }
+
+            // Midpoint rule for ∫ f(s) ds over [previous, t].
+            let h = (t - previous) / SUBSTEPS as Real;
+            let integral: Real = (0..SUBSTEPS)
+                .map(|k| self.forward_rate_impl(previous + (k as Real + 0.5) * h) * h)
+                .sum();
+            let log_ratio = (self.discount_impl(previous) / df).ln();
+            if (integral - log_ratio).abs() > tolerance {
+                fail!(
+                    "forward rate integrates to {integral} over [{previous}, {t}] \
+                     but ln(P({previous})/P({t})) = {log_ratio}"
+                );
+            }
+            previous = t;
+        }
+        Ok(())
+    }
 }
 
 /// Small time step used for instantaneous forward rate computations.
@@ -165,3 +206,56 @@ impl YieldTermStructureData {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::FlatForward;
+    use ql_time::{Actual365Fixed, NullCalendar};
+
+    /// Discounts at a flat 5% but reports a 7% forward beyond 10 years.
+    #[derive(Debug)]
+    struct KinkedForward(FlatForward);
+
+    impl TermStructure for KinkedForward {
+        fn reference_date(&self) -> Date {
+            self.0.reference_date()
+        }
+        fn day_counter(&self) -> &dyn DayCounter {
+            self.0.day_counter()
+        }
+        fn calendar(&self) -> &dyn Calendar {
+            &NullCalendar
+        }
+        fn max_date(&self) -> Date {
+            self.0.max_date()
+        }
+    }
+
+    impl YieldTermStructure for KinkedForward {
+        fn discount_impl(&self, t: Time) -> DiscountFactor {
+            self.0.discount_impl(t)
+        }
+        fn forward_rate_impl(&self, t: Time) -> Rate {
+            if t < 10.0 {
+                0.05
+            } else {
+                0.07
+            }
+        }
+    }
+
+    #[test]
+    fn self_consistency_check() {
+        let ref_date = Date::from_ymd(2025, 1, 15).unwrap();
+        let flat = FlatForward::continuous(ref_date, 0.05, Actual365Fixed);
+        flat.self_consistency_check(1e-8).unwrap();
+
+        let err = KinkedForward(flat)
+            .self_consistency_check(1e-8)
+            .unwrap_err();
+        // Samples are 0.75 years apart: the interval holding the kink at
+        // 10 years is [9.75, 10.5].
+        assert!(err.to_string().contains("over [9.75, 10.5]"), "{err}");
+    }
+}
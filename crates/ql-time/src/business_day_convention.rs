@@ -19,6 +19,10 @@ pub enum BusinessDayConvention {
     ModifiedPreceding,
     /// Do not adjust (keep the original date).
     Unadjusted,
+    /// Choose the first business day after the given holiday unless that
+    /// day crosses the mid-month (15th) or the end of month, in which case
+    /// choose the first business day before the holiday.
+    HalfMonthModifiedFollowing,
     /// Choose the nearest business day.  In case of a tie, use the following
     /// convention.
     Nearest,
@@ -34,6 +38,7 @@ impl std::fmt::Display for BusinessDayConvention {
             BusinessDayConvention::Preceding => "Preceding",
             BusinessDayConvention::ModifiedPreceding => "Modified Preceding",
             BusinessDayConvention::Unadjusted => "Unadjusted",
+            BusinessDayConvention::HalfMonthModifiedFollowing => "Half-Month Modified Following",
             BusinessDayConvention::Nearest => "Nearest",
             BusinessDayConvention::EndOfMonth => "End of Month",
         };
@@ -5,7 +5,10 @@
 
 use crate::business_day_convention::BusinessDayConvention;
 use crate::date::Date;
+use crate::period::Period;
+use crate::time_unit::TimeUnit;
 use crate::weekday::Weekday;
+use ql_core::errors::{Error, Result};
 
 /// A financial calendar.
 ///
@@ -64,6 +67,15 @@ pub trait Calendar: std::fmt::Debug + Send + Sync {
                 }
                 date
             }
+            BusinessDayConvention::HalfMonthModifiedFollowing => {
+                let adjusted = self.adjust(date, BusinessDayConvention::Following);
+                let crosses_mid_month = date.day_of_month() <= 15 && adjusted.day_of_month() > 15;
+                if adjusted.month() != date.month() || crosses_mid_month {
+                    self.adjust(date, BusinessDayConvention::Preceding)
+                } else {
+                    adjusted
+                }
+            }
             BusinessDayConvention::ModifiedPreceding => {
                 let adjusted = self.adjust(date, BusinessDayConvention::Preceding);
                 if adjusted.month() != date.month() {
@@ -90,6 +102,53 @@ pub trait Calendar: std::fmt::Debug + Send + Sync {
         }
     }
 
+    /// Advance `date` by `n` units and adjust the result.
+    ///
+    /// `Days` count business days of this calendar; weeks, months and years
+    /// are calendar periods whose end date is adjusted with `convention`.
+    /// With `end_of_month`, a start date on the last business day of its
+    /// month maps to the last business day of the target month.
+    ///
+    /// Corresponds to `QuantLib::Calendar::advance`.
+    fn advance(
+        &self,
+        date: Date,
+        n: i32,
+        unit: TimeUnit,
+        convention: BusinessDayConvention,
+        end_of_month: bool,
+    ) -> Result<Date> {
+        if n == 0 {
+            return Ok(self.adjust(date, convention));
+        }
+        match unit {
+            TimeUnit::Days => Ok(self.advance_business_days(date, n)),
+            TimeUnit::Weeks => Ok(self.adjust(date.advance(n, unit)?, convention)),
+            TimeUnit::Months | TimeUnit::Years => {
+                let raw = date.advance(n, unit)?;
+                if end_of_month && self.is_end_of_month(date) {
+                    Ok(self.end_of_month(raw))
+                } else {
+                    Ok(self.adjust(raw, convention))
+                }
+            }
+            _ => Err(Error::Date(format!(
+                "calendar advance does not support {unit}"
+            ))),
+        }
+    }
+
+    /// Advance `date` by `period`; see [`Calendar::advance`].
+    fn advance_by_period(
+        &self,
+        date: Date,
+        period: Period,
+        convention: BusinessDayConvention,
+        end_of_month: bool,
+    ) -> Result<Date> {
+        self.advance(date, period.length, period.unit, convention, end_of_month)
+    }
+
     /// Advance `date` by `n` business days.
     fn advance_business_days(&self, mut date: Date, n: i32) -> Date {
         let step: i32 = if n >= 0 { 1 } else { -1 };
@@ -103,24 +162,59 @@ pub trait Calendar: std::fmt::Debug + Send + Sync {
         date
     }
 
-    /// Count the number of business days between `d1` (exclusive) and `d2`
-    /// (inclusive).  Returns a negative number if `d2 < d1`.
-    fn business_days_between(&self, d1: Date, d2: Date) -> i32 {
+    /// Count the business days from `d1` to `d2`.
+    ///
+    /// `include_first` and `include_last` say whether `d1` and `d2`
+    /// themselves are counted (if they are business days), regardless of
+    /// which of the two comes first.  The count is negative when `d2 < d1`, so that
+    /// swapping the dates together with the flags flips the sign:
+    /// `business_days_between(b, a, f, l) == −business_days_between(a, b, l, f)`.
+    ///
+    /// Corresponds to `QuantLib::Calendar::businessDaysBetween`.
+    fn business_days_between(
+        &self,
+        d1: Date,
+        d2: Date,
+        include_first: bool,
+        include_last: bool,
+    ) -> i32 {
         if d1 == d2 {
-            return 0;
+            return i32::from(include_first && include_last && self.is_business_day(d1));
         }
-        let sign = if d2 > d1 { 1 } else { -1 };
-        let (start, end) = if d2 > d1 { (d1, d2) } else { (d2, d1) };
+        let (start, end, sign) = if d1 < d2 { (d1, d2, 1) } else { (d2, d1, -1) };
         let mut count = 0;
-        let mut d = start + 1;
+        let mut d = start;
         while d <= end {
             if self.is_business_day(d) {
                 count += 1;
             }
             d += 1;
         }
+        if !include_first && self.is_business_day(d1) {
+            count -= 1;
+        }
+        if !include_last && self.is_business_day(d2) {
+            count -= 1;
+        }
         sign * count
     }
+
+    /// Holidays falling on weekdays in the inclusive range `[d1, d2]`, in
+    /// date order.  Weekends are left out.
+    ///
+    /// Corresponds to `QuantLib::Calendar::holidayList` with
+    /// `includeWeekEnds = false`.
+    fn holidays_in(&self, d1: Date, d2: Date) -> Vec<Date> {
+        let mut holidays = Vec::new();
+        let mut d = d1;
+        while d <= d2 {
+            if self.is_holiday(d) && !self.is_weekend(d) {
+                holidays.push(d);
+            }
+            d += 1;
+        }
+        holidays
+    }
 }
 
 /// A null calendar — treats every day as a business day.
@@ -208,6 +302,124 @@ mod tests {
         let d1 = date(2023, 9, 4); // Monday
         let d2 = date(2023, 9, 8); // Friday
                                    // Tue, Wed, Thu, Fri = 4 business days (d1 exclusive)
-        assert_eq!(cal.business_days_between(d1, d2), 4);
+        assert_eq!(cal.business_days_between(d1, d2, false, true), 4);
+        assert_eq!(cal.business_days_between(d1, d2, true, true), 5);
+        assert_eq!(cal.business_days_between(d1, d2, true, false), 4);
+        assert_eq!(cal.business_days_between(d2, d1, true, false), -4);
+        // The flags follow the arguments, not the date order: from Friday
+        // back to Monday, leaving out Friday.
+        assert_eq!(cal.business_days_between(d2, d1, false, true), -4);
+        let sunday = date(2023, 9, 10);
+        assert_eq!(cal.business_days_between(sunday, d1, false, true), -5);
+        assert_eq!(cal.business_days_between(sunday, d1, true, false), -4);
+        for (f, l) in [(false, false), (false, true), (true, false), (true, true)] {
+            assert_eq!(
+                cal.business_days_between(sunday, d1, f, l),
+                -cal.business_days_between(d1, sunday, l, f)
+            );
+        }
+        assert_eq!(cal.business_days_between(d1, d1, true, true), 1);
+        assert_eq!(cal.business_days_between(d1, d1, true, false), 0);
+    }
+
+    #[test]
+    fn business_days_across_easter_on_target() {
+        let cal = crate::calendars::target::Target;
+        // Good Friday 29 March and Easter Monday 1 April 2024.
+        let wednesday = date(2024, 3, 27);
+        let tuesday = date(2024, 4, 2);
+        assert_eq!(cal.business_days_between(wednesday, tuesday, true, true), 3);
+        // T+2 settlement of a Wednesday trade lands on the Tuesday.
+        assert_eq!(cal.advance_business_days(wednesday, 2), tuesday);
+        assert_eq!(
+            cal.business_days_between(wednesday, tuesday, false, true),
+            2
+        );
+        assert_eq!(
+            cal.holidays_in(wednesday, tuesday),
+            vec![date(2024, 3, 29), date(2024, 4, 1)]
+        );
+    }
+
+    #[test]
+    fn adjust_half_month_modified_following_on_target() {
+        let cal = crate::calendars::target::Target;
+        let hmmf = BusinessDayConvention::HalfMonthModifiedFollowing;
+        // Sat 15 Jun 2024: Following would give Mon 17th, past the 15th.
+        assert_eq!(cal.adjust(date(2024, 6, 15), hmmf), date(2024, 6, 14));
+        assert_eq!(
+            cal.adjust(date(2024, 6, 15), BusinessDayConvention::ModifiedFollowing),
+            date(2024, 6, 17)
+        );
+        // Sat 16 Mar 2024 is already past mid-month: plain Following.
+        assert_eq!(cal.adjust(date(2024, 3, 16), hmmf), date(2024, 3, 18));
+        // Sat 30 Mar 2024: Good Friday/Easter Monday push Following into
+        // April, so roll back to Thu 28 Mar.
+        assert_eq!(cal.adjust(date(2024, 3, 30), hmmf), date(2024, 3, 28));
+        // Business days are left untouched.
+        assert_eq!(cal.adjust(date(2024, 6, 13), hmmf), date(2024, 6, 13));
+    }
+
+    #[test]
+    fn adjust_nearest_on_target() {
+        let cal = crate::calendars::target::Target;
+        let nearest = BusinessDayConvention::Nearest;
+        // Around Easter 2024 (Fri 29 Mar and Mon 1 Apr are TARGET holidays):
+        assert_eq!(cal.adjust(date(2024, 3, 29), nearest), date(2024, 3, 28));
+        assert_eq!(cal.adjust(date(2024, 3, 30), nearest), date(2024, 3, 28));
+        assert_eq!(cal.adjust(date(2024, 3, 31), nearest), date(2024, 4, 2));
+        assert_eq!(cal.adjust(date(2024, 4, 1), nearest), date(2024, 4, 2));
+        // A plain weekend: Saturday goes back, Sunday goes forward.
+        assert_eq!(cal.adjust(date(2024, 6, 15), nearest), date(2024, 6, 14));
+        assert_eq!(cal.adjust(date(2024, 6, 16), nearest), date(2024, 6, 17));
+    }
+
+    #[test]
+    fn advance_with_conventions_on_target() {
+        let cal = crate::calendars::target::Target;
+        let mf = BusinessDayConvention::ModifiedFollowing;
+        // Two business days from Wed 27 Mar 2024 skip the Easter holidays.
+        assert_eq!(
+            cal.advance(date(2024, 3, 27), 2, TimeUnit::Days, mf, false)
+                .unwrap(),
+            date(2024, 4, 2)
+        );
+        assert_eq!(
+            cal.advance(date(2024, 4, 2), -2, TimeUnit::Days, mf, false)
+                .unwrap(),
+            date(2024, 3, 27)
+        );
+        // End of month: 29 Feb 2024 → last TARGET business day of March.
+        assert_eq!(
+            cal.advance(date(2024, 2, 29), 1, TimeUnit::Months, mf, true)
+                .unwrap(),
+            date(2024, 3, 28)
+        );
+        // Tue 30 Apr 2024 → Thu 30 May, or Fri 31 May with end of month.
+        assert_eq!(
+            cal.advance(date(2024, 4, 30), 1, TimeUnit::Months, mf, false)
+                .unwrap(),
+            date(2024, 5, 30)
+        );
+        assert_eq!(
+            cal.advance(date(2024, 4, 30), 1, TimeUnit::Months, mf, true)
+                .unwrap(),
+            date(2024, 5, 31)
+        );
+        // Fri 22 Mar + 1W lands on Good Friday; Following would leave March.
+        let period = Period::new(1, TimeUnit::Weeks);
+        assert_eq!(
+            cal.advance_by_period(date(2024, 3, 22), period, mf, false)
+                .unwrap(),
+            date(2024, 3, 28)
+        );
+        assert_eq!(
+            cal.advance(date(2024, 3, 30), 0, TimeUnit::Days, mf, false)
+                .unwrap(),
+            date(2024, 3, 28)
+        );
+        assert!(cal
+            .advance(date(2024, 3, 28), 1, TimeUnit::Hours, mf, false)
+            .is_err());
     }
 }
@@ -16,6 +16,11 @@ pub enum JointCalendarRule {
     /// constituent calendars (i.e. the intersection of holiday sets — the
     /// union of business-day sets).
     JoinBusinessDays,
+    /// A day is a business day if it is a business day in at least `n` of
+    /// the constituent calendars.  `AtLeast(1)` is
+    /// [`JoinBusinessDays`](Self::JoinBusinessDays) and `AtLeast(len)`
+    /// is [`JoinHolidays`](Self::JoinHolidays).
+    AtLeast(usize),
 }
 
 /// A calendar that combines multiple calendars according to a
@@ -42,18 +47,27 @@ impl JointCalendar {
     /// rule.
     ///
     /// # Panics
-    /// Panics if `calendars` is empty.
+    /// Panics if `calendars` is empty, or if an
+    /// [`AtLeast(n)`](JointCalendarRule::AtLeast) rule asks for no calendar
+    /// or more calendars than given.
     pub fn new(calendars: Vec<Box<dyn Calendar>>, rule: JointCalendarRule) -> Self {
         assert!(
             !calendars.is_empty(),
             "JointCalendar requires at least one calendar"
         );
         let names: Vec<&str> = calendars.iter().map(|c| c.name()).collect();
-        let joiner = match rule {
-            JointCalendarRule::JoinHolidays => ", ",
-            JointCalendarRule::JoinBusinessDays => " | ",
+        let name = match rule {
+            JointCalendarRule::JoinHolidays => names.join(", "),
+            JointCalendarRule::JoinBusinessDays => names.join(" | "),
+            JointCalendarRule::AtLeast(n) => {
+                assert!(
+                    (1..=calendars.len()).contains(&n),
+                    "AtLeast({n}) needs between 1 and {} calendars open",
+                    calendars.len()
+                );
+                format!("at least {n} of ({})", names.join(", "))
+            }
         };
-        let name = names.join(joiner);
         Self {
             calendars,
             rule,
@@ -77,6 +91,13 @@ impl Calendar for JointCalendar {
                 // A day is a business day if ANY calendar says it is
                 self.calendars.iter().any(|c| c.is_business_day(date))
             }
+            JointCalendarRule::AtLeast(n) => {
+                self.calendars
+                    .iter()
+                    .filter(|c| c.is_business_day(date))
+                    .count()
+                    >= n
+            }
         }
     }
 
@@ -90,6 +111,14 @@ impl Calendar for JointCalendar {
                 // Weekend only if ALL calendars consider it a weekend
                 self.calendars.iter().all(|c| c.is_weekend(date))
             }
+            JointCalendarRule::AtLeast(n) => {
+                // Weekend once fewer than n calendars have a working day
+                self.calendars
+                    .iter()
+                    .filter(|c| !c.is_weekend(date))
+                    .count()
+                    < n
+            }
         }
     }
 }
@@ -134,6 +163,43 @@ mod tests {
         assert!(cal.is_business_day(sat));
     }
 
+    #[test]
+    fn at_least_two_of_three_open() {
+        use crate::calendars::united_kingdom::UnitedKingdomSettlement;
+        use crate::calendars::united_states::UnitedStatesSettlement;
+
+        let cal = JointCalendar::new(
+            vec![
+                Box::new(Target),
+                Box::new(UnitedKingdomSettlement),
+                Box::new(UnitedStatesSettlement),
+            ],
+            JointCalendarRule::AtLeast(2),
+        );
+        assert_eq!(
+            cal.name(),
+            "at least 2 of (TARGET, UK (Settlement), US (Settlement))"
+        );
+        // Independence Day: only the US is closed, two remain open.
+        assert!(cal.is_business_day(date(2024, 7, 4)));
+        // Spring bank holiday and Memorial Day: only TARGET is open.
+        assert!(!cal.is_business_day(date(2024, 5, 27)));
+        assert!(!cal.is_weekend(date(2024, 5, 27)));
+        // All open, none open, and a weekend.
+        assert!(cal.is_business_day(date(2024, 5, 28)));
+        assert!(!cal.is_business_day(date(2024, 12, 25)));
+        assert!(cal.is_weekend(date(2024, 6, 1)));
+    }
+
+    #[test]
+    #[should_panic(expected = "AtLeast(3)")]
+    fn at_least_more_than_the_members_panics() {
+        JointCalendar::new(
+            vec![Box::new(WeekendsOnly), Box::new(Target)],
+            JointCalendarRule::AtLeast(3),
+        );
+    }
+
     #[test]
     fn name_formatting() {
         let cal_holidays = JointCalendar::new(
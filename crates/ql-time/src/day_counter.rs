@@ -5,6 +5,7 @@
 //! between two dates — used when discounting or accruing interest.
 
 use crate::date::Date;
+use crate::time_unit::TimeUnit;
 use ql_core::{Real, Time};
 
 /// A convention for counting the fraction of a year between two dates.
@@ -26,11 +27,27 @@ pub trait DayCounter: std::fmt::Debug + Send + Sync {
 
     /// Fraction of a year between `d1` and `d2` with reference period hints.
     ///
-    /// Needed for some ISDA-style conventions.  Defaults to
-    /// [`year_fraction`][Self::year_fraction].
+    /// Needed by conventions that measure against the regular coupon
+    /// period, such as [`ActualActualIsma`].  Defaults to
+    /// [`year_fraction`][Self::year_fraction], ignoring the reference.
     fn year_fraction_with_ref(&self, d1: Date, d2: Date, _ref_start: Date, _ref_end: Date) -> Time {
         self.year_fraction(d1, d2)
     }
+
+    /// Fraction of a year between `d1` and `d2` measured against the
+    /// reference period `ref_start` to `ref_end`.
+    ///
+    /// Same as [`year_fraction_with_ref`][Self::year_fraction_with_ref],
+    /// which is the method conventions override.
+    fn year_fraction_with_reference(
+        &self,
+        d1: Date,
+        d2: Date,
+        ref_start: Date,
+        ref_end: Date,
+    ) -> Time {
+        self.year_fraction_with_ref(d1, d2, ref_start, ref_end)
+    }
 }
 
 /// Actual/365 (Fixed) day counter.
@@ -190,12 +207,46 @@ impl DayCounter for ActualActualIsda {
             let days_in_year = if is_leap_year(y1) { 366.0 } else { 365.0 };
             return self.day_count(d1, d2) as Real / days_in_year;
         }
-        // Split at year boundary
-        let jan1_y2 = Date::from_ymd(y2, 1, 1).expect("valid date");
+        // Split at the first year boundary, so that every calendar year
+        // spanned is weighted by its own length
+        let next_jan1 = Date::from_ymd(y1 + 1, 1, 1).expect("valid date");
         let days_in_y1 = if is_leap_year(y1) { 366.0 } else { 365.0 };
-        let part1 = (jan1_y2.serial() - d1.serial()) as Real / days_in_y1;
+        let part1 = (next_jan1.serial() - d1.serial()) as Real / days_in_y1;
         // Recurse for rest
-        part1 + self.year_fraction(jan1_y2, d2)
+        part1 + self.year_fraction(next_jan1, d2)
+    }
+}
+
+/// Actual/Actual (ISDA) without leap days.
+///
+/// February 29 is left out of the day count, so that every year has 365
+/// days and `year_fraction = days / 365` needs no split at year
+/// boundaries.
+///
+/// Corresponds to `QuantLib::Actual365Fixed` with the `NoLeap` convention.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct ActualActualIsdaNoLeap;
+
+impl DayCounter for ActualActualIsdaNoLeap {
+    fn name(&self) -> &str {
+        "Actual/Actual (ISDA, No Leap)"
+    }
+
+    fn day_count(&self, d1: Date, d2: Date) -> i64 {
+        use crate::date::is_leap_year;
+        if d1 > d2 {
+            return -self.day_count(d2, d1);
+        }
+        let leap_days = (d1.year()..=d2.year())
+            .filter(|&y| is_leap_year(y))
+            .map(|y| Date::from_ymd(y, 2, 29).expect("valid leap date"))
+            .filter(|&feb29| feb29 >= d1 && feb29 < d2)
+            .count() as i64;
+        (d2.serial() - d1.serial()) as i64 - leap_days
+    }
+
+    fn year_fraction(&self, d1: Date, d2: Date) -> Time {
+        self.day_count(d1, d2) as Real / 365.0
     }
 }
 
@@ -241,8 +292,17 @@ impl DayCounter for Actual366 {
 
 /// Actual/Actual (ISMA, Bond) day counter.
 ///
-/// Uses the reference period to compute year fractions per the ISMA/bond
-/// convention: `year_fraction = days / (frequency * ref_days)`.
+/// Uses the reference (regular coupon) period to compute year fractions per
+/// the ISMA/bond convention: inside a reference period of `months` months,
+/// `year_fraction = (months / 12) · days / ref_days`.  Irregular periods
+/// are split at the notional coupon dates, stepping back from the
+/// reference start for a long first coupon and forward from the reference
+/// end for a long final one, and each piece is measured against its own
+/// notional period.
+///
+/// Without a reference period, the calculation period itself is used.  A
+/// reference period that is empty, reversed or ends before the start date
+/// falls back to [`ActualActualIsda`].
 #[derive(Debug, Clone, Copy, Default)]
 pub struct ActualActualIsma;
 
@@ -256,7 +316,9 @@ impl DayCounter for ActualActualIsma {
     }
 
     fn year_fraction(&self, d1: Date, d2: Date) -> Time {
-        // Without reference period, fall back to ISDA
+        if d1 > d2 {
+            return -self.year_fraction(d2, d1);
+        }
         self.year_fraction_with_ref(d1, d2, d1, d2)
     }
 
@@ -264,20 +326,68 @@ impl DayCounter for ActualActualIsma {
         if d1 == d2 {
             return 0.0;
         }
-        if ref_start == ref_end {
-            // Fall back to ISDA when no reference period
-            let dc = ActualActualIsda;
-            return dc.year_fraction(d1, d2);
+        if d1 > d2 {
+            return -self.year_fraction_with_ref(d2, d1, ref_start, ref_end);
         }
-        let ref_days = (ref_end.serial() - ref_start.serial()) as f64;
-        if ref_days <= 0.0 {
-            let dc = ActualActualIsda;
-            return dc.year_fraction(d1, d2);
+        if ref_end <= ref_start || ref_end <= d1 {
+            // No usable reference period: fall back to ISDA.
+            return ActualActualIsda.year_fraction(d1, d2);
+        }
+        self.split_fraction(d1, d2, ref_start, ref_end)
+            .unwrap_or_else(|| ActualActualIsda.year_fraction(d1, d2))
+    }
+}
+
+impl ActualActualIsma {
+    /// The ISMA fraction for `d1 < d2` and `ref_start < ref_end`, `d1 <
+    /// ref_end`, or `None` if a notional coupon date falls outside the
+    /// supported date range.
+    fn split_fraction(&self, d1: Date, d2: Date, ref_start: Date, ref_end: Date) -> Option<Time> {
+        let advance = |date: Date, months: i32| date.advance(months, TimeUnit::Months).ok();
+
+        // Length of the reference period, rounded to whole months.
+        let mut months = (12.0 * (ref_end - ref_start) as Real / 365.0).round() as i32;
+        let (ref_start, ref_end) = if months == 0 {
+            // Periods too short to count: measure against a year from d1.
+            months = 12;
+            (d1, advance(d1, 12)?)
+        } else {
+            (ref_start, ref_end)
+        };
+        let period = months as Real / 12.0;
+
+        if d2 <= ref_end {
+            if d1 >= ref_start {
+                return Some(period * (d2 - d1) as Real / (ref_end - ref_start) as Real);
+            }
+            // Long first coupon: d1 lies before the reference period, in
+            // the notional period ending at ref_start.
+            let previous = advance(ref_start, -months)?;
+            if d2 > ref_start {
+                return Some(
+                    self.split_fraction(d1, ref_start, previous, ref_start)?
+                        + self.split_fraction(ref_start, d2, ref_start, ref_end)?,
+                );
+            }
+            return self.split_fraction(d1, d2, previous, ref_start);
+        }
+
+        // Long final coupon: whole notional periods after ref_end, then
+        // the stub.  The part up to ref_end may itself be a long first
+        // coupon.
+        let mut sum = self.split_fraction(d1, ref_end, ref_start, ref_end)?;
+        let mut i = 0;
+        loop {
+            let (start, end) = (
+                advance(ref_end, months * i)?,
+                advance(ref_end, months * (i + 1))?,
+            );
+            if d2 < end {
+                return Some(sum + self.split_fraction(start, d2, start, end)?);
+            }
+            sum += period;
+            i += 1;
         }
-        let months = (ref_end.year() as f64 - ref_start.year() as f64) * 12.0
-            + (ref_end.month() as f64 - ref_start.month() as f64);
-        let frequency = if months > 0.0 { 12.0 / months } else { 1.0 };
-        self.day_count(d1, d2) as f64 / (frequency * ref_days)
     }
 }
 
@@ -533,4 +643,31 @@ mod tests {
         // 365/365 = 1.0
         assert!((dc.year_fraction(d1, d2) - 1.0).abs() < 1e-9);
     }
+
+    #[test]
+    fn actual_actual_isda_splits_at_leap_year() {
+        let dc = ActualActualIsda;
+        // 214 days of 2023, then 152 days of the leap year 2024.
+        let calculated = dc.year_fraction(date(2023, 6, 1), date(2024, 6, 1));
+        assert!((calculated - (214.0 / 365.0 + 152.0 / 366.0)).abs() < 1e-14);
+        assert!((dc.year_fraction(date(2024, 6, 1), date(2023, 6, 1)) + calculated).abs() < 1e-15);
+        // A leap year in the middle of the period counts as exactly one:
+        // 214/365 + 366/366 + 151/365.
+        let calculated = dc.year_fraction(date(2019, 6, 1), date(2021, 6, 1));
+        assert!((calculated - 2.0).abs() < 1e-14, "{calculated}");
+        assert!((dc.year_fraction(date(2019, 1, 1), date(2022, 1, 1)) - 3.0).abs() < 1e-14);
+    }
+
+    #[test]
+    fn actual_actual_isda_no_leap_skips_feb29() {
+        let dc = ActualActualIsdaNoLeap;
+        assert_eq!(dc.day_count(date(2023, 6, 1), date(2024, 6, 1)), 365);
+        assert_eq!(dc.day_count(date(2024, 6, 1), date(2023, 6, 1)), -365);
+        assert!((dc.year_fraction(date(2023, 6, 1), date(2024, 6, 1)) - 1.0).abs() < 1e-15);
+        // Feb 29 itself is excluded, the day after it is not.
+        assert_eq!(dc.day_count(date(2024, 2, 28), date(2024, 3, 1)), 1);
+        assert_eq!(dc.day_count(date(2024, 2, 29), date(2024, 3, 1)), 0);
+        assert_eq!(dc.day_count(date(2024, 3, 1), date(2024, 3, 2)), 1);
+        assert_eq!(dc.day_count(date(2020, 1, 1), date(2030, 1, 1)), 3650);
+    }
 }
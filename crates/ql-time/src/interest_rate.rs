@@ -7,7 +7,7 @@
 
 use crate::day_counter::DayCounter;
 use crate::frequency::Frequency;
-use ql_core::{Compounding, Real, Time};
+use ql_core::{Compounding, Rate, Real, Time};
 use std::sync::Arc;
 
 /// An interest rate with associated compounding and day-counting conventions.
@@ -197,6 +197,30 @@ impl InterestRate {
     }
 }
 
+/// Convert a forward rate over an accrual period of `tau` years between
+/// compounding conventions.
+///
+/// The result accrues to the same compound factor over `tau` as `rate`
+/// does under `from_comp`/`from_freq`. Frequencies are ignored for
+/// `Simple` and `Continuous` compounding.
+pub fn convert_forward(
+    rate: Rate,
+    from_comp: Compounding,
+    from_freq: Frequency,
+    to_comp: Compounding,
+    to_freq: Frequency,
+    tau: Time,
+) -> Rate {
+    InterestRate::new(
+        rate,
+        crate::day_counter::Actual365Fixed,
+        from_comp,
+        from_freq,
+    )
+    .equivalent_rate_time(to_comp, to_freq, tau)
+    .rate()
+}
+
 fn freq_value(freq: Frequency) -> Real {
     match freq {
         Frequency::NoFrequency | Frequency::Once => 1.0,
@@ -333,4 +357,38 @@ mod tests {
         assert!(s.contains("5.0000%"));
         assert!(s.contains("Continuous"));
     }
+
+    #[test]
+    fn convert_forward_between_conventions() {
+        let tau = 0.25;
+        let continuous = convert_forward(
+            0.04,
+            Compounding::Simple,
+            Frequency::NoFrequency,
+            Compounding::Continuous,
+            Frequency::NoFrequency,
+            tau,
+        );
+        assert!((continuous - (1.0_f64 + 0.04 * tau).ln() / tau).abs() < 1e-15);
+        let back = convert_forward(
+            continuous,
+            Compounding::Continuous,
+            Frequency::NoFrequency,
+            Compounding::Simple,
+            Frequency::NoFrequency,
+            tau,
+        );
+        assert!((back - 0.04).abs() < 1e-12);
+
+        // 5% annually compounded over six months: (1.05^0.5 − 1) / 0.5.
+        let simple = convert_forward(
+            0.05,
+            Compounding::Compounded,
+            Frequency::Annual,
+            Compounding::Simple,
+            Frequency::NoFrequency,
+            0.5,
+        );
+        assert!((simple - 0.049390153191919).abs() < 1e-12, "{simple}");
+    }
 }
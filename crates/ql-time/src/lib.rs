@@ -62,13 +62,14 @@ pub use calendars::joint_calendar::{JointCalendar, JointCalendarRule};
 pub use date::Date;
 pub use day_counter::{
     Actual360, Actual364, Actual36525, Actual365Fixed, Actual366, ActualActualAfb,
-    ActualActualIsda, ActualActualIsma, Business252, DayCounter, OneDayCounter, SimpleDayCounter,
-    Thirty360, Thirty360European, Thirty360German, Thirty360Italian, Thirty365,
+    ActualActualIsda, ActualActualIsdaNoLeap, ActualActualIsma, Business252, DayCounter,
+    OneDayCounter, SimpleDayCounter, Thirty360, Thirty360European, Thirty360German,
+    Thirty360Italian, Thirty365,
 };
 pub use ecb::ECB;
 pub use frequency::Frequency;
 pub use imm::IMM;
-pub use interest_rate::InterestRate;
+pub use interest_rate::{convert_forward, InterestRate};
 pub use month::Month;
 pub use period::Period;
 pub use schedule::{DateGeneration, Schedule, ScheduleBuilder};
@@ -7,9 +7,11 @@
 use crate::business_day_convention::BusinessDayConvention;
 use crate::calendar::{Calendar, NullCalendar};
 use crate::date::Date;
+use crate::day_counter::DayCounter;
 use crate::period::Period;
 use crate::weekday::Weekday;
 use ql_core::errors::{Error, Result};
+use ql_core::Real;
 
 /// Date generation rule for schedules.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
@@ -163,6 +165,28 @@ impl Schedule {
     pub fn is_regular_vec(&self) -> &[bool] {
         &self.is_regular
     }
+
+    /// Locate the period `[dᵢ, dᵢ₊₁)` containing `date`.
+    ///
+    /// Returns the period index `i` with its start and end dates. A date on
+    /// a schedule boundary belongs to the period starting there; dates
+    /// before the start or on/after the end date return `None`.
+    pub fn locate_period(&self, date: Date) -> Option<(usize, Date, Date)> {
+        let i = self.dates.partition_point(|&d| d <= date);
+        if i == 0 || i >= self.dates.len() {
+            return None;
+        }
+        Some((i - 1, self.dates[i - 1], self.dates[i]))
+    }
+
+    /// Elapsed fraction of the period containing `date`, measured with `dc`
+    /// as `dc(start, date) / dc(start, end)`.
+    ///
+    /// Returns `None` when `date` lies outside the schedule.
+    pub fn accrual_fraction(&self, date: Date, dc: &dyn DayCounter) -> Option<Real> {
+        let (_, start, end) = self.locate_period(date)?;
+        Some(dc.year_fraction(start, date) / dc.year_fraction(start, end))
+    }
 }
 
 /// Advance a date using NullCalendar semantics, optionally snapping to
@@ -877,4 +901,34 @@ mod tests {
             }
         }
     }
+
+    #[test]
+    fn locate_period_and_accrual_fraction() {
+        use crate::day_counter::Actual365Fixed;
+
+        let sched = Schedule::from_dates(vec![
+            date(2024, 1, 15),
+            date(2024, 7, 15),
+            date(2025, 1, 15),
+        ]);
+        let dc = Actual365Fixed;
+
+        // On a boundary: the period starting there, nothing elapsed.
+        assert_eq!(
+            sched.locate_period(date(2024, 7, 15)),
+            Some((1, date(2024, 7, 15), date(2025, 1, 15)))
+        );
+        assert_eq!(sched.accrual_fraction(date(2024, 7, 15), &dc), Some(0.0));
+        assert_eq!(sched.accrual_fraction(date(2024, 1, 15), &dc), Some(0.0));
+
+        // 15 Jan → 15 Apr 2024 is 91 of the 182 days to 15 Jul.
+        let (i, start, end) = sched.locate_period(date(2024, 4, 15)).unwrap();
+        assert_eq!((i, start, end), (0, date(2024, 1, 15), date(2024, 7, 15)));
+        let fraction = sched.accrual_fraction(date(2024, 4, 15), &dc).unwrap();
+        assert!((fraction - 91.0 / 182.0).abs() < 1e-15);
+
+        assert_eq!(sched.locate_period(date(2024, 1, 14)), None);
+        assert_eq!(sched.locate_period(date(2025, 1, 15)), None);
+        assert_eq!(sched.accrual_fraction(date(2025, 2, 1), &dc), None);
+    }
 }
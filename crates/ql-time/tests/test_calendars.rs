@@ -17,21 +17,6 @@ fn date(y: u16, m: u8, d: u8) -> Date {
     Date::from_ymd(y, m, d).unwrap()
 }
 
-/// Collect all non-weekend holidays in the inclusive range `[from, to]`.
-///
-/// Mirrors C++ `Calendar::holidayList(from, to, false)` — weekends excluded.
-fn holiday_list(cal: &dyn Calendar, from: Date, to: Date) -> Vec<Date> {
-    let mut holidays = Vec::new();
-    let mut d = from;
-    while d <= to {
-        if cal.is_holiday(d) && !cal.is_weekend(d) {
-            holidays.push(d);
-        }
-        d += 1;
-    }
-    holidays
-}
-
 /// Collect all holidays including weekends in the range `[from, to]`.
 #[allow(dead_code)]
 fn holiday_list_with_weekends(cal: &dyn Calendar, from: Date, to: Date) -> Vec<Date> {
@@ -49,7 +34,7 @@ fn holiday_list_with_weekends(cal: &dyn Calendar, from: Date, to: Date) -> Vec<D
 /// Assert that every date in `expected` is a holiday, and every holiday in the
 /// range is in `expected`.  Panics on mismatches, similar to the C++ helper.
 fn check_holidays(cal: &dyn Calendar, from: Date, to: Date, expected: &[Date]) {
-    let calculated = holiday_list(cal, from, to);
+    let calculated = cal.holidays_in(from, to);
     let calc_set: std::collections::HashSet<_> = calculated.iter().copied().collect();
     let exp_set: std::collections::HashSet<_> = expected.iter().copied().collect();
 
@@ -75,6 +60,32 @@ fn check_holidays(cal: &dyn Calendar, from: Date, to: Date, expected: &[Date]) {
 
 // ─── TARGET holidays ──────────────────────────────────────────────────────────
 
+#[test]
+fn test_target_holidays_in_a_year() {
+    // New Year, Good Friday, Easter Monday, Labour Day and the two
+    // Christmas days; weekends are not listed.
+    let expected = vec![
+        date(2024, 1, 1),
+        date(2024, 3, 29),
+        date(2024, 4, 1),
+        date(2024, 5, 1),
+        date(2024, 12, 25),
+        date(2024, 12, 26),
+    ];
+    assert_eq!(
+        Target.holidays_in(date(2024, 1, 1), date(2024, 12, 31)),
+        expected
+    );
+    // 366 days, 104 weekend days and the six holidays above.
+    assert_eq!(
+        Target.business_days_between(date(2024, 1, 1), date(2024, 12, 31), true, true),
+        366 - 104 - 6
+    );
+    assert!(Target
+        .holidays_in(date(2024, 12, 31), date(2024, 1, 1))
+        .is_empty());
+}
+
 #[test]
 fn test_target_holidays() {
     let expected: Vec<Date> = vec![
@@ -548,20 +559,21 @@ fn test_start_of_month() {
 fn test_business_days_between() {
     let cal = Brazil;
 
-    // Our Rust `business_days_between(d1, d2)` counts (d1, d2] — d1 exclusive,
-    // d2 inclusive.
+    // `business_days_between(d1, d2, false, true)` counts (d1, d2] — d1
+    // exclusive, d2 inclusive.
     //
     // Verify basic properties:
     // 1) business_days_between(d, d) == 0
-    // 2) business_days_between(d1, d2) == -business_days_between(d2, d1)
+    // 2) swapping the dates and the flags flips the sign, as in QuantLib:
+    //    bdb(d1, d2, false, true) == -bdb(d2, d1, true, false)
     // 3) Known value: Feb 1 2002 (Fri) to Feb 4 2002 (Mon) = 1 business day
     //    in the interval (Feb 1, Feb 4] = {Feb 2 (Sat), Feb 3 (Sun), Feb 4 (Mon)}
     //    Only Feb 4 is a business day → 1.
     let d1 = date(2002, 2, 1);
     let d2 = date(2002, 2, 4);
-    assert_eq!(cal.business_days_between(d1, d1), 0);
-    assert_eq!(cal.business_days_between(d1, d2), 1);
-    assert_eq!(cal.business_days_between(d2, d1), -1);
+    assert_eq!(cal.business_days_between(d1, d1, false, true), 0);
+    assert_eq!(cal.business_days_between(d1, d2, false, true), 1);
+    assert_eq!(cal.business_days_between(d2, d1, false, true), -1);
 
     // More extensive test: count over a known range and check symmetry.
     let test_dates = vec![
@@ -578,8 +590,8 @@ fn test_business_days_between() {
 
     for i in 0..test_dates.len() {
         for j in 0..test_dates.len() {
-            let fwd = cal.business_days_between(test_dates[i], test_dates[j]);
-            let bwd = cal.business_days_between(test_dates[j], test_dates[i]);
+            let fwd = cal.business_days_between(test_dates[i], test_dates[j], false, true);
+            let bwd = cal.business_days_between(test_dates[j], test_dates[i], true, false);
             assert_eq!(
                 fwd, -bwd,
                 "asymmetry: bdb({}, {}) = {fwd} but bdb({}, {}) = {bwd}",
@@ -592,7 +604,7 @@ fn test_business_days_between() {
     let mut d = date(2002, 1, 1);
     let end = date(2006, 12, 31);
     while d < end {
-        let bdb = cal.business_days_between(d, d + 1);
+        let bdb = cal.business_days_between(d, d + 1, false, true);
         assert!(
             bdb == 0 || bdb == 1,
             "bdb({d}, {}) should be 0 or 1, got {bdb}",
@@ -89,12 +89,18 @@ fn test_actual_actual_isma_with_ref() {
         "ISMA case 3: calculated {calculated:.12}"
     );
 
-    // Long first (first period) with ref Jan 15 2003 → Jul 15 2003.
-    // NOTE: This case requires splitting the period into sub-periods across
-    // the reference boundary (C++ uses Schedule for this).  Our simplified
-    // ISMA implementation doesn't support this yet — it returns 0.922651933702
-    // instead of the expected 0.915760869565.  Skipped until Schedule-based
-    // ISMA is implemented.
+    // Long first (first period) with ref Jan 15 2003 → Jul 15 2003: the
+    // period is split at the notional coupon date Jan 15 2003.
+    let calculated = dc.year_fraction_with_ref(
+        date(2002, 8, 15),
+        date(2003, 7, 15),
+        date(2003, 1, 15),
+        date(2003, 7, 15),
+    );
+    assert!(
+        (calculated - 0.915760869565).abs() < 1.0e-10,
+        "ISMA case 4: calculated {calculated:.12}"
+    );
 
     // Long first (second period) with exact ref period
     let calculated = dc.year_fraction_with_ref(
@@ -133,6 +139,85 @@ fn test_actual_actual_isma_with_ref() {
     );
 }
 
+#[test]
+fn test_actual_actual_isma_irregular_coupons() {
+    let dc = ActualActualIsma;
+
+    // Long final coupon Jan 30 2000 → Sep 30 2000 against the regular
+    // period Jan 30 → Jul 30: one full half-year, then 62 of the 184 days
+    // to the notional Jan 30 2001.
+    let calculated = dc.year_fraction_with_ref(
+        date(2000, 1, 30),
+        date(2000, 9, 30),
+        date(2000, 1, 30),
+        date(2000, 7, 30),
+    );
+    let expected = 0.5 + 0.5 * 62.0 / 184.0;
+    assert!(
+        (calculated - expected).abs() < 1.0e-12,
+        "long final: calculated {calculated:.12}, expected {expected:.12}"
+    );
+
+    // A short first coupon lying wholly before the reference period is
+    // measured against the notional period preceding it.
+    let calculated = dc.year_fraction_with_ref(
+        date(2002, 10, 1),
+        date(2002, 12, 15),
+        date(2003, 1, 15),
+        date(2003, 7, 15),
+    );
+    let expected = 0.5 * 75.0 / 184.0;
+    assert!((calculated - expected).abs() < 1.0e-12);
+
+    // Swapping the dates flips the sign.
+    let forward = dc.year_fraction_with_ref(
+        date(2002, 8, 15),
+        date(2003, 7, 15),
+        date(2003, 1, 15),
+        date(2003, 7, 15),
+    );
+    let backward = dc.year_fraction_with_ref(
+        date(2003, 7, 15),
+        date(2002, 8, 15),
+        date(2003, 1, 15),
+        date(2003, 7, 15),
+    );
+    assert_eq!(backward, -forward);
+}
+
+#[test]
+fn test_year_fraction_with_reference_forwards_to_with_ref() {
+    let (d1, d2) = (date(2000, 1, 30), date(2000, 9, 30));
+    let (ref_start, ref_end) = (date(2000, 1, 30), date(2000, 7, 30));
+    assert_eq!(
+        ActualActualIsma.year_fraction_with_reference(d1, d2, ref_start, ref_end),
+        ActualActualIsma.year_fraction_with_ref(d1, d2, ref_start, ref_end)
+    );
+    assert_eq!(
+        Actual365Fixed.year_fraction_with_reference(d1, d2, ref_start, ref_end),
+        Actual365Fixed.year_fraction(d1, d2)
+    );
+}
+
+#[test]
+fn test_actual_actual_isma_reversed_dates() {
+    let dc = ActualActualIsma;
+
+    // Without a reference period the dates are ordered before the
+    // calculation period is used as the reference.
+    let forward = dc.year_fraction(date(2024, 1, 15), date(2024, 7, 15));
+    let backward = dc.year_fraction(date(2024, 7, 15), date(2024, 1, 15));
+    assert_eq!(forward, 0.5);
+    assert_eq!(backward, -forward);
+
+    // A reversed or empty reference period falls back to ISDA.
+    let (d1, d2) = (date(2024, 1, 15), date(2024, 7, 15));
+    let isda = ActualActualIsda.year_fraction(d1, d2);
+    assert_eq!(dc.year_fraction_with_ref(d1, d2, d2, d1), isda);
+    assert_eq!(dc.year_fraction_with_ref(d1, d2, d1, d1), isda);
+    assert_eq!(dc.year_fraction_with_ref(d2, d1, d2, d1), -isda);
+}
+
 #[test]
 fn test_actual_actual_afb() {
     let dc = ActualActualAfb;